@@ -55,6 +55,71 @@ static inline uint_xlen_t csr_read_write_misa(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_misa(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, misa, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_misa(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, misa, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_misa(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, misa, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_misa(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, misa, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* misa, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MISA(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, misa, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* misa, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MISA(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, misa, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* misa, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MISA(MASK)               \
+    __asm__ volatile ("csrrci    zero, misa, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MISA_EXTENSIONS_BIT_OFFSET   0
+#define MISA_EXTENSIONS_BIT_WIDTH    26
+#define MISA_EXTENSIONS_BIT_MASK     0x3ffffff
+#define MISA_EXTENSIONS_ALL_SET_MASK 0x3ffffff
+#if __riscv_xlen==32
+#define MISA_MXL_BIT_OFFSET   30
+#define MISA_MXL_BIT_WIDTH    2
+#define MISA_MXL_BIT_MASK     0xc0000000
+#define MISA_MXL_ALL_SET_MASK 0x3
+#endif
+#if __riscv_xlen==64
+#define MISA_MXL_BIT_OFFSET   62
+#define MISA_MXL_BIT_WIDTH    2
+#define MISA_MXL_BIT_MASK     0xc000000000000000
+#define MISA_MXL_ALL_SET_MASK 0x3
+#endif
 
 /*******************************************
  * mvendorid - MRO - Machine Vendor ID 
@@ -67,6 +132,14 @@ static inline uint32_t csr_read_mvendorid(void) {
                       : /* clobbers: none */);
     return value;
 }
+#define MVENDORID_OFFSET_BIT_OFFSET   0
+#define MVENDORID_OFFSET_BIT_WIDTH    7
+#define MVENDORID_OFFSET_BIT_MASK     0x7f
+#define MVENDORID_OFFSET_ALL_SET_MASK 0x7f
+#define MVENDORID_BANK_BIT_OFFSET   7
+#define MVENDORID_BANK_BIT_WIDTH    25
+#define MVENDORID_BANK_BIT_MASK     0xffffff80
+#define MVENDORID_BANK_ALL_SET_MASK 0x1ffffff
 
 /*******************************************
  * marchid - MRO - Machine Architecture ID 
@@ -182,9 +255,9 @@ static inline uint_xlen_t csr_read_clr_bits_mstatus(uint_xlen_t mask) {
 #define MSTATUS_MIE_BIT_WIDTH    1
 #define MSTATUS_MIE_BIT_MASK     0x8
 #define MSTATUS_MIE_ALL_SET_MASK 0x1
-#define MSTATUS_SIE_BIT_OFFSET   2
+#define MSTATUS_SIE_BIT_OFFSET   1
 #define MSTATUS_SIE_BIT_WIDTH    1
-#define MSTATUS_SIE_BIT_MASK     0x4
+#define MSTATUS_SIE_BIT_MASK     0x2
 #define MSTATUS_SIE_ALL_SET_MASK 0x1
 #define MSTATUS_MPIE_BIT_OFFSET   7
 #define MSTATUS_MPIE_BIT_WIDTH    1
@@ -206,6 +279,102 @@ static inline uint_xlen_t csr_read_clr_bits_mstatus(uint_xlen_t mask) {
 #define MSTATUS_SPP_BIT_WIDTH    1
 #define MSTATUS_SPP_BIT_MASK     0x100
 #define MSTATUS_SPP_ALL_SET_MASK 0x1
+#define MSTATUS_UBE_BIT_OFFSET   6
+#define MSTATUS_UBE_BIT_WIDTH    1
+#define MSTATUS_UBE_BIT_MASK     0x40
+#define MSTATUS_UBE_ALL_SET_MASK 0x1
+#define MSTATUS_VS_BIT_OFFSET   9
+#define MSTATUS_VS_BIT_WIDTH    2
+#define MSTATUS_VS_BIT_MASK     0x600
+#define MSTATUS_VS_ALL_SET_MASK 0x3
+#define MSTATUS_FS_BIT_OFFSET   13
+#define MSTATUS_FS_BIT_WIDTH    2
+#define MSTATUS_FS_BIT_MASK     0x6000
+#define MSTATUS_FS_ALL_SET_MASK 0x3
+#define MSTATUS_XS_BIT_OFFSET   15
+#define MSTATUS_XS_BIT_WIDTH    2
+#define MSTATUS_XS_BIT_MASK     0x18000
+#define MSTATUS_XS_ALL_SET_MASK 0x3
+#define MSTATUS_SUM_BIT_OFFSET   18
+#define MSTATUS_SUM_BIT_WIDTH    1
+#define MSTATUS_SUM_BIT_MASK     0x40000
+#define MSTATUS_SUM_ALL_SET_MASK 0x1
+#define MSTATUS_MXR_BIT_OFFSET   19
+#define MSTATUS_MXR_BIT_WIDTH    1
+#define MSTATUS_MXR_BIT_MASK     0x80000
+#define MSTATUS_MXR_ALL_SET_MASK 0x1
+#define MSTATUS_TVM_BIT_OFFSET   20
+#define MSTATUS_TVM_BIT_WIDTH    1
+#define MSTATUS_TVM_BIT_MASK     0x100000
+#define MSTATUS_TVM_ALL_SET_MASK 0x1
+#define MSTATUS_TW_BIT_OFFSET   21
+#define MSTATUS_TW_BIT_WIDTH    1
+#define MSTATUS_TW_BIT_MASK     0x200000
+#define MSTATUS_TW_ALL_SET_MASK 0x1
+#define MSTATUS_TSR_BIT_OFFSET   22
+#define MSTATUS_TSR_BIT_WIDTH    1
+#define MSTATUS_TSR_BIT_MASK     0x400000
+#define MSTATUS_TSR_ALL_SET_MASK 0x1
+#define MSTATUS_SPELP_BIT_OFFSET   23
+#define MSTATUS_SPELP_BIT_WIDTH    1
+#define MSTATUS_SPELP_BIT_MASK     0x800000
+#define MSTATUS_SPELP_ALL_SET_MASK 0x1
+#define MSTATUS_SDT_BIT_OFFSET   24
+#define MSTATUS_SDT_BIT_WIDTH    1
+#define MSTATUS_SDT_BIT_MASK     0x1000000
+#define MSTATUS_SDT_ALL_SET_MASK 0x1
+#if __riscv_xlen==64
+#define MSTATUS_UXL_BIT_OFFSET   32
+#define MSTATUS_UXL_BIT_WIDTH    2
+#define MSTATUS_UXL_BIT_MASK     0x300000000
+#define MSTATUS_UXL_ALL_SET_MASK 0x3
+#endif
+#if __riscv_xlen==64
+#define MSTATUS_SXL_BIT_OFFSET   34
+#define MSTATUS_SXL_BIT_WIDTH    2
+#define MSTATUS_SXL_BIT_MASK     0xc00000000
+#define MSTATUS_SXL_ALL_SET_MASK 0x3
+#endif
+#if __riscv_xlen==64
+#define MSTATUS_SBE_BIT_OFFSET   36
+#define MSTATUS_SBE_BIT_WIDTH    1
+#define MSTATUS_SBE_BIT_MASK     0x1000000000
+#define MSTATUS_SBE_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MSTATUS_MBE_BIT_OFFSET   37
+#define MSTATUS_MBE_BIT_WIDTH    1
+#define MSTATUS_MBE_BIT_MASK     0x2000000000
+#define MSTATUS_MBE_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MSTATUS_GVA_BIT_OFFSET   38
+#define MSTATUS_GVA_BIT_WIDTH    1
+#define MSTATUS_GVA_BIT_MASK     0x4000000000
+#define MSTATUS_GVA_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MSTATUS_MPV_BIT_OFFSET   39
+#define MSTATUS_MPV_BIT_WIDTH    1
+#define MSTATUS_MPV_BIT_MASK     0x8000000000
+#define MSTATUS_MPV_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MSTATUS_MPELP_BIT_OFFSET   41
+#define MSTATUS_MPELP_BIT_WIDTH    1
+#define MSTATUS_MPELP_BIT_MASK     0x20000000000
+#define MSTATUS_MPELP_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MSTATUS_MDT_BIT_OFFSET   42
+#define MSTATUS_MDT_BIT_WIDTH    1
+#define MSTATUS_MDT_BIT_MASK     0x40000000000
+#define MSTATUS_MDT_ALL_SET_MASK 0x1
+#endif
+#define MSTATUS_SD_BIT_OFFSET   (__riscv_xlen-1)
+#define MSTATUS_SD_BIT_WIDTH    1
+#define MSTATUS_SD_BIT_MASK     (0x1UL << ((__riscv_xlen-1)))
+#define MSTATUS_SD_ALL_SET_MASK 0x1
 
 /*******************************************
  * mstatush - MRW - Additional machine status register, RV32 only. 
@@ -232,6 +401,79 @@ static inline uint_xlen_t csr_read_write_mstatush(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mstatush(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, mstatush, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mstatush(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, mstatush, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_mstatush(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, mstatush, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_mstatush(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, mstatush, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mstatush, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MSTATUSH(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mstatush, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mstatush, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MSTATUSH(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mstatush, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mstatush, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MSTATUSH(MASK)               \
+    __asm__ volatile ("csrrci    zero, mstatush, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MSTATUSH_SBE_BIT_OFFSET   4
+#define MSTATUSH_SBE_BIT_WIDTH    1
+#define MSTATUSH_SBE_BIT_MASK     0x10
+#define MSTATUSH_SBE_ALL_SET_MASK 0x1
+#define MSTATUSH_MBE_BIT_OFFSET   5
+#define MSTATUSH_MBE_BIT_WIDTH    1
+#define MSTATUSH_MBE_BIT_MASK     0x20
+#define MSTATUSH_MBE_ALL_SET_MASK 0x1
+#define MSTATUSH_GVA_BIT_OFFSET   6
+#define MSTATUSH_GVA_BIT_WIDTH    1
+#define MSTATUSH_GVA_BIT_MASK     0x40
+#define MSTATUSH_GVA_ALL_SET_MASK 0x1
+#define MSTATUSH_MPV_BIT_OFFSET   7
+#define MSTATUSH_MPV_BIT_WIDTH    1
+#define MSTATUSH_MPV_BIT_MASK     0x80
+#define MSTATUSH_MPV_ALL_SET_MASK 0x1
+#define MSTATUSH_MPELP_BIT_OFFSET   9
+#define MSTATUSH_MPELP_BIT_WIDTH    1
+#define MSTATUSH_MPELP_BIT_MASK     0x200
+#define MSTATUSH_MPELP_ALL_SET_MASK 0x1
+#define MSTATUSH_MDT_BIT_OFFSET   10
+#define MSTATUSH_MDT_BIT_WIDTH    1
+#define MSTATUSH_MDT_BIT_MASK     0x400
+#define MSTATUSH_MDT_ALL_SET_MASK 0x1
 
 /*******************************************
  * mtvec - MRW - Machine Trap Vector Base Address 
@@ -309,8 +551,8 @@ static inline uint_xlen_t csr_read_clr_bits_mtvec(uint_xlen_t mask) {
                       : /* clobbers: none */)
 #define MTVEC_BASE_BIT_OFFSET   2
 #define MTVEC_BASE_BIT_WIDTH    ((__riscv_xlen-1)-(2) + 1)
-#define MTVEC_BASE_BIT_MASK     ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (2))
-#define MTVEC_BASE_ALL_SET_MASK ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (0))
+#define MTVEC_BASE_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (2))
+#define MTVEC_BASE_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (0))
 #define MTVEC_MODE_BIT_OFFSET   0
 #define MTVEC_MODE_BIT_WIDTH    2
 #define MTVEC_MODE_BIT_MASK     0x3
@@ -341,6 +583,127 @@ static inline uint_xlen_t csr_read_write_medeleg(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_medeleg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, medeleg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_medeleg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, medeleg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_medeleg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, medeleg, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_medeleg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, medeleg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* medeleg, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MEDELEG(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, medeleg, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* medeleg, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MEDELEG(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, medeleg, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* medeleg, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MEDELEG(MASK)               \
+    __asm__ volatile ("csrrci    zero, medeleg, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MEDELEG_INSTRUCTION_MISALIGNED_BIT_OFFSET   0
+#define MEDELEG_INSTRUCTION_MISALIGNED_BIT_WIDTH    1
+#define MEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK     0x1
+#define MEDELEG_INSTRUCTION_MISALIGNED_ALL_SET_MASK 0x1
+#define MEDELEG_INSTRUCTION_FAULT_BIT_OFFSET   1
+#define MEDELEG_INSTRUCTION_FAULT_BIT_WIDTH    1
+#define MEDELEG_INSTRUCTION_FAULT_BIT_MASK     0x2
+#define MEDELEG_INSTRUCTION_FAULT_ALL_SET_MASK 0x1
+#define MEDELEG_ILLEGAL_INSTRUCTION_BIT_OFFSET   2
+#define MEDELEG_ILLEGAL_INSTRUCTION_BIT_WIDTH    1
+#define MEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK     0x4
+#define MEDELEG_ILLEGAL_INSTRUCTION_ALL_SET_MASK 0x1
+#define MEDELEG_BREAKPOINT_BIT_OFFSET   3
+#define MEDELEG_BREAKPOINT_BIT_WIDTH    1
+#define MEDELEG_BREAKPOINT_BIT_MASK     0x8
+#define MEDELEG_BREAKPOINT_ALL_SET_MASK 0x1
+#define MEDELEG_LOAD_MISALIGNED_BIT_OFFSET   4
+#define MEDELEG_LOAD_MISALIGNED_BIT_WIDTH    1
+#define MEDELEG_LOAD_MISALIGNED_BIT_MASK     0x10
+#define MEDELEG_LOAD_MISALIGNED_ALL_SET_MASK 0x1
+#define MEDELEG_LOAD_FAULT_BIT_OFFSET   5
+#define MEDELEG_LOAD_FAULT_BIT_WIDTH    1
+#define MEDELEG_LOAD_FAULT_BIT_MASK     0x20
+#define MEDELEG_LOAD_FAULT_ALL_SET_MASK 0x1
+#define MEDELEG_STORE_MISALIGNED_BIT_OFFSET   6
+#define MEDELEG_STORE_MISALIGNED_BIT_WIDTH    1
+#define MEDELEG_STORE_MISALIGNED_BIT_MASK     0x40
+#define MEDELEG_STORE_MISALIGNED_ALL_SET_MASK 0x1
+#define MEDELEG_STORE_FAULT_BIT_OFFSET   7
+#define MEDELEG_STORE_FAULT_BIT_WIDTH    1
+#define MEDELEG_STORE_FAULT_BIT_MASK     0x80
+#define MEDELEG_STORE_FAULT_ALL_SET_MASK 0x1
+#define MEDELEG_ECALL_U_BIT_OFFSET   8
+#define MEDELEG_ECALL_U_BIT_WIDTH    1
+#define MEDELEG_ECALL_U_BIT_MASK     0x100
+#define MEDELEG_ECALL_U_ALL_SET_MASK 0x1
+#define MEDELEG_ECALL_S_BIT_OFFSET   9
+#define MEDELEG_ECALL_S_BIT_WIDTH    1
+#define MEDELEG_ECALL_S_BIT_MASK     0x200
+#define MEDELEG_ECALL_S_ALL_SET_MASK 0x1
+#define MEDELEG_ECALL_VS_BIT_OFFSET   10
+#define MEDELEG_ECALL_VS_BIT_WIDTH    1
+#define MEDELEG_ECALL_VS_BIT_MASK     0x400
+#define MEDELEG_ECALL_VS_ALL_SET_MASK 0x1
+#define MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_OFFSET   12
+#define MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_WIDTH    1
+#define MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK     0x1000
+#define MEDELEG_INSTRUCTION_PAGE_FAULT_ALL_SET_MASK 0x1
+#define MEDELEG_LOAD_PAGE_FAULT_BIT_OFFSET   13
+#define MEDELEG_LOAD_PAGE_FAULT_BIT_WIDTH    1
+#define MEDELEG_LOAD_PAGE_FAULT_BIT_MASK     0x2000
+#define MEDELEG_LOAD_PAGE_FAULT_ALL_SET_MASK 0x1
+#define MEDELEG_STORE_PAGE_FAULT_BIT_OFFSET   15
+#define MEDELEG_STORE_PAGE_FAULT_BIT_WIDTH    1
+#define MEDELEG_STORE_PAGE_FAULT_BIT_MASK     0x8000
+#define MEDELEG_STORE_PAGE_FAULT_ALL_SET_MASK 0x1
+#define MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_OFFSET   20
+#define MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_WIDTH    1
+#define MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_MASK     0x100000
+#define MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_ALL_SET_MASK 0x1
+#define MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_OFFSET   21
+#define MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_WIDTH    1
+#define MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_MASK     0x200000
+#define MEDELEG_LOAD_GUEST_PAGE_FAULT_ALL_SET_MASK 0x1
+#define MEDELEG_VIRTUAL_INSTRUCTION_BIT_OFFSET   22
+#define MEDELEG_VIRTUAL_INSTRUCTION_BIT_WIDTH    1
+#define MEDELEG_VIRTUAL_INSTRUCTION_BIT_MASK     0x400000
+#define MEDELEG_VIRTUAL_INSTRUCTION_ALL_SET_MASK 0x1
+#define MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_OFFSET   23
+#define MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_WIDTH    1
+#define MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_MASK     0x800000
+#define MEDELEG_STORE_GUEST_PAGE_FAULT_ALL_SET_MASK 0x1
 
 /*******************************************
  * mideleg - MRW - Machine Interrupt Delegation 
@@ -367,6 +730,87 @@ static inline uint_xlen_t csr_read_write_mideleg(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mideleg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, mideleg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mideleg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, mideleg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_mideleg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, mideleg, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_mideleg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, mideleg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mideleg, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MIDELEG(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mideleg, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mideleg, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MIDELEG(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mideleg, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mideleg, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MIDELEG(MASK)               \
+    __asm__ volatile ("csrrci    zero, mideleg, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MIDELEG_SSI_BIT_OFFSET   1
+#define MIDELEG_SSI_BIT_WIDTH    1
+#define MIDELEG_SSI_BIT_MASK     0x2
+#define MIDELEG_SSI_ALL_SET_MASK 0x1
+#define MIDELEG_VSSI_BIT_OFFSET   2
+#define MIDELEG_VSSI_BIT_WIDTH    1
+#define MIDELEG_VSSI_BIT_MASK     0x4
+#define MIDELEG_VSSI_ALL_SET_MASK 0x1
+#define MIDELEG_STI_BIT_OFFSET   5
+#define MIDELEG_STI_BIT_WIDTH    1
+#define MIDELEG_STI_BIT_MASK     0x20
+#define MIDELEG_STI_ALL_SET_MASK 0x1
+#define MIDELEG_VSTI_BIT_OFFSET   6
+#define MIDELEG_VSTI_BIT_WIDTH    1
+#define MIDELEG_VSTI_BIT_MASK     0x40
+#define MIDELEG_VSTI_ALL_SET_MASK 0x1
+#define MIDELEG_SEI_BIT_OFFSET   9
+#define MIDELEG_SEI_BIT_WIDTH    1
+#define MIDELEG_SEI_BIT_MASK     0x200
+#define MIDELEG_SEI_ALL_SET_MASK 0x1
+#define MIDELEG_VSEI_BIT_OFFSET   10
+#define MIDELEG_VSEI_BIT_WIDTH    1
+#define MIDELEG_VSEI_BIT_MASK     0x400
+#define MIDELEG_VSEI_ALL_SET_MASK 0x1
+#define MIDELEG_SGEI_BIT_OFFSET   12
+#define MIDELEG_SGEI_BIT_WIDTH    1
+#define MIDELEG_SGEI_BIT_MASK     0x1000
+#define MIDELEG_SGEI_ALL_SET_MASK 0x1
+#define MIDELEG_LCOFI_BIT_OFFSET   13
+#define MIDELEG_LCOFI_BIT_WIDTH    1
+#define MIDELEG_LCOFI_BIT_MASK     0x2000
+#define MIDELEG_LCOFI_ALL_SET_MASK 0x1
 
 /*******************************************
  * mip - MRW - Machine Interrupt Pending 
@@ -479,9 +923,13 @@ static inline uint_xlen_t csr_read_clr_bits_mip(uint_xlen_t mask) {
 #define MIP_UEI_BIT_MASK     0x100
 #define MIP_UEI_ALL_SET_MASK 0x1
 #define MIP_PLATFORM_DEFINED_BIT_OFFSET   16
-#define MIP_PLATFORM_DEFINED_BIT_WIDTH    ((__riscv_xlen)-(16) + 1)
-#define MIP_PLATFORM_DEFINED_BIT_MASK     ((1UL<<(((__riscv_xlen)-(16) + 1)-1)) << (16))
-#define MIP_PLATFORM_DEFINED_ALL_SET_MASK ((1UL<<(((__riscv_xlen)-(16) + 1)-1)) << (0))
+#define MIP_PLATFORM_DEFINED_BIT_WIDTH    ((__riscv_xlen-1)-(16) + 1)
+#define MIP_PLATFORM_DEFINED_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (16))
+#define MIP_PLATFORM_DEFINED_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (0))
+#define MIP_LCOFI_BIT_OFFSET   13
+#define MIP_LCOFI_BIT_WIDTH    1
+#define MIP_LCOFI_BIT_MASK     0x2000
+#define MIP_LCOFI_ALL_SET_MASK 0x1
 
 /*******************************************
  * mie - MRW - Machine Interrupt Enable 
@@ -594,9 +1042,13 @@ static inline uint_xlen_t csr_read_clr_bits_mie(uint_xlen_t mask) {
 #define MIE_UEI_BIT_MASK     0x100
 #define MIE_UEI_ALL_SET_MASK 0x1
 #define MIE_PLATFORM_DEFINED_BIT_OFFSET   16
-#define MIE_PLATFORM_DEFINED_BIT_WIDTH    ((__riscv_xlen)-(16) + 1)
-#define MIE_PLATFORM_DEFINED_BIT_MASK     ((1UL<<(((__riscv_xlen)-(16) + 1)-1)) << (16))
-#define MIE_PLATFORM_DEFINED_ALL_SET_MASK ((1UL<<(((__riscv_xlen)-(16) + 1)-1)) << (0))
+#define MIE_PLATFORM_DEFINED_BIT_WIDTH    ((__riscv_xlen-1)-(16) + 1)
+#define MIE_PLATFORM_DEFINED_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (16))
+#define MIE_PLATFORM_DEFINED_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (0))
+#define MIE_LCOFI_BIT_OFFSET   13
+#define MIE_LCOFI_BIT_WIDTH    1
+#define MIE_LCOFI_BIT_MASK     0x2000
+#define MIE_LCOFI_ALL_SET_MASK 0x1
 
 /*******************************************
  * mcountinhibit - MRW - Machine Counter Inhibit 
@@ -788,6 +1240,103 @@ static inline uint_xlen_t csr_read_write_mhpmevent3(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mhpmevent3(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, mhpmevent3, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mhpmevent3(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, mhpmevent3, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_mhpmevent3(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, mhpmevent3, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_mhpmevent3(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, mhpmevent3, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mhpmevent3, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MHPMEVENT3(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mhpmevent3, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent3, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MHPMEVENT3(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mhpmevent3, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent3, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MHPMEVENT3(MASK)               \
+    __asm__ volatile ("csrrci    zero, mhpmevent3, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#if __riscv_xlen==32
+#define MHPMEVENT3_EVENT_BIT_OFFSET   0
+#define MHPMEVENT3_EVENT_BIT_WIDTH    32
+#define MHPMEVENT3_EVENT_BIT_MASK     0xffffffff
+#define MHPMEVENT3_EVENT_ALL_SET_MASK 0xffffffff
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT3_EVENT_BIT_OFFSET   0
+#define MHPMEVENT3_EVENT_BIT_WIDTH    56
+#define MHPMEVENT3_EVENT_BIT_MASK     0xffffffffffffff
+#define MHPMEVENT3_EVENT_ALL_SET_MASK 0xffffffffffffff
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT3_OF_BIT_OFFSET   63
+#define MHPMEVENT3_OF_BIT_WIDTH    1
+#define MHPMEVENT3_OF_BIT_MASK     0x8000000000000000
+#define MHPMEVENT3_OF_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT3_MINH_BIT_OFFSET   62
+#define MHPMEVENT3_MINH_BIT_WIDTH    1
+#define MHPMEVENT3_MINH_BIT_MASK     0x4000000000000000
+#define MHPMEVENT3_MINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT3_SINH_BIT_OFFSET   61
+#define MHPMEVENT3_SINH_BIT_WIDTH    1
+#define MHPMEVENT3_SINH_BIT_MASK     0x2000000000000000
+#define MHPMEVENT3_SINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT3_UINH_BIT_OFFSET   60
+#define MHPMEVENT3_UINH_BIT_WIDTH    1
+#define MHPMEVENT3_UINH_BIT_MASK     0x1000000000000000
+#define MHPMEVENT3_UINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT3_VSINH_BIT_OFFSET   59
+#define MHPMEVENT3_VSINH_BIT_WIDTH    1
+#define MHPMEVENT3_VSINH_BIT_MASK     0x800000000000000
+#define MHPMEVENT3_VSINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT3_VUINH_BIT_OFFSET   58
+#define MHPMEVENT3_VUINH_BIT_WIDTH    1
+#define MHPMEVENT3_VUINH_BIT_MASK     0x400000000000000
+#define MHPMEVENT3_VUINH_ALL_SET_MASK 0x1
+#endif
 
 /*******************************************
  * mcounteren - MRW - Counter Enable 
@@ -883,28 +1432,93 @@ static inline uint32_t csr_read_clr_bits_mcounteren(uint32_t mask) {
 /*******************************************
  * scounteren - SRW - Counter Enable 
  */
-static inline uint_xlen_t csr_read_scounteren(void) {
-    uint_xlen_t value;        
+static inline uint32_t csr_read_scounteren(void) {
+    uint_csr32_t value;        
     __asm__ volatile ("csrr    %0, scounteren" 
                       : "=r" (value)  /* output : register */
                       : /* input : none */
                       : /* clobbers: none */);
     return value;
 }
-static inline void csr_write_scounteren(uint_xlen_t value) {
+static inline void csr_write_scounteren(uint_csr32_t value) {
     __asm__ volatile ("csrw    scounteren, %0" 
                       : /* output: none */ 
                       : "r" (value) /* input : from register */
                       : /* clobbers: none */);
 }
-static inline uint_xlen_t csr_read_write_scounteren(uint_xlen_t new_value) {
-    uint_xlen_t prev_value;
+static inline uint32_t csr_read_write_scounteren(uint32_t new_value) {
+    uint_csr32_t prev_value;
     __asm__ volatile ("csrrw    %0, scounteren, %1"  
                       : "=r" (prev_value) /* output: register %0 */
                       : "r" (new_value)  /* input : register */
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_scounteren(uint32_t mask) {
+    __asm__ volatile ("csrrs    zero, scounteren, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_scounteren(uint32_t mask) {
+    __asm__ volatile ("csrrc    zero, scounteren, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_set_bits_scounteren(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrs    %0, scounteren, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint32_t csr_read_clr_bits_scounteren(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrc    %0, scounteren, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* scounteren, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_SCOUNTEREN(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, scounteren, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* scounteren, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_SCOUNTEREN(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, scounteren, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* scounteren, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_SCOUNTEREN(MASK)               \
+    __asm__ volatile ("csrrci    zero, scounteren, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define SCOUNTEREN_CY_BIT_OFFSET   0
+#define SCOUNTEREN_CY_BIT_WIDTH    1
+#define SCOUNTEREN_CY_BIT_MASK     0x1
+#define SCOUNTEREN_CY_ALL_SET_MASK 0x1
+#define SCOUNTEREN_TM_BIT_OFFSET   1
+#define SCOUNTEREN_TM_BIT_WIDTH    1
+#define SCOUNTEREN_TM_BIT_MASK     0x2
+#define SCOUNTEREN_TM_ALL_SET_MASK 0x1
+#define SCOUNTEREN_IR_BIT_OFFSET   2
+#define SCOUNTEREN_IR_BIT_WIDTH    1
+#define SCOUNTEREN_IR_BIT_MASK     0x4
+#define SCOUNTEREN_IR_ALL_SET_MASK 0x1
+#define SCOUNTEREN_HPM_BIT_OFFSET   3
+#define SCOUNTEREN_HPM_BIT_WIDTH    29
+#define SCOUNTEREN_HPM_BIT_MASK     0xfffffff8
+#define SCOUNTEREN_HPM_ALL_SET_MASK 0x1fffffff
 
 /*******************************************
  * mscratch - MRW - Machine Mode Scratch Register 
@@ -1038,8 +1652,8 @@ static inline uint_xlen_t csr_read_clr_bits_mcause(uint_xlen_t mask) {
 #define MCAUSE_INTERRUPT_ALL_SET_MASK 0x1
 #define MCAUSE_EXCEPTION_CODE_BIT_OFFSET   0
 #define MCAUSE_EXCEPTION_CODE_BIT_WIDTH    ((__riscv_xlen-2)-(0) + 1)
-#define MCAUSE_EXCEPTION_CODE_BIT_MASK     ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0))
-#define MCAUSE_EXCEPTION_CODE_ALL_SET_MASK ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0))
+#define MCAUSE_EXCEPTION_CODE_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0))
+#define MCAUSE_EXCEPTION_CODE_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0))
 
 /*******************************************
  * mtval - MRW - Machine Trap Value 
@@ -1199,8 +1813,8 @@ static inline uint_xlen_t csr_read_clr_bits_scause(uint_xlen_t mask) {
 #define SCAUSE_INTERRUPT_ALL_SET_MASK 0x1
 #define SCAUSE_EXCEPTION_CODE_BIT_OFFSET   0
 #define SCAUSE_EXCEPTION_CODE_BIT_WIDTH    ((__riscv_xlen-2)-(0) + 1)
-#define SCAUSE_EXCEPTION_CODE_BIT_MASK     ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0))
-#define SCAUSE_EXCEPTION_CODE_ALL_SET_MASK ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0))
+#define SCAUSE_EXCEPTION_CODE_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0))
+#define SCAUSE_EXCEPTION_CODE_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0))
 
 /*******************************************
  * sstatus - SRW - Supervisor Status 
@@ -1276,9 +1890,9 @@ static inline uint_xlen_t csr_read_clr_bits_sstatus(uint_xlen_t mask) {
                       : /* output: none */                      \
                       : "i" (MASK)  /* input : immediate */     \
                       : /* clobbers: none */)
-#define SSTATUS_SIE_BIT_OFFSET   2
+#define SSTATUS_SIE_BIT_OFFSET   1
 #define SSTATUS_SIE_BIT_WIDTH    1
-#define SSTATUS_SIE_BIT_MASK     0x4
+#define SSTATUS_SIE_BIT_MASK     0x2
 #define SSTATUS_SIE_ALL_SET_MASK 0x1
 #define SSTATUS_SPIE_BIT_OFFSET   5
 #define SSTATUS_SPIE_BIT_WIDTH    1
@@ -1288,6 +1902,48 @@ static inline uint_xlen_t csr_read_clr_bits_sstatus(uint_xlen_t mask) {
 #define SSTATUS_SPP_BIT_WIDTH    1
 #define SSTATUS_SPP_BIT_MASK     0x100
 #define SSTATUS_SPP_ALL_SET_MASK 0x1
+#define SSTATUS_UBE_BIT_OFFSET   6
+#define SSTATUS_UBE_BIT_WIDTH    1
+#define SSTATUS_UBE_BIT_MASK     0x40
+#define SSTATUS_UBE_ALL_SET_MASK 0x1
+#define SSTATUS_VS_BIT_OFFSET   9
+#define SSTATUS_VS_BIT_WIDTH    2
+#define SSTATUS_VS_BIT_MASK     0x600
+#define SSTATUS_VS_ALL_SET_MASK 0x3
+#define SSTATUS_FS_BIT_OFFSET   13
+#define SSTATUS_FS_BIT_WIDTH    2
+#define SSTATUS_FS_BIT_MASK     0x6000
+#define SSTATUS_FS_ALL_SET_MASK 0x3
+#define SSTATUS_XS_BIT_OFFSET   15
+#define SSTATUS_XS_BIT_WIDTH    2
+#define SSTATUS_XS_BIT_MASK     0x18000
+#define SSTATUS_XS_ALL_SET_MASK 0x3
+#define SSTATUS_SUM_BIT_OFFSET   18
+#define SSTATUS_SUM_BIT_WIDTH    1
+#define SSTATUS_SUM_BIT_MASK     0x40000
+#define SSTATUS_SUM_ALL_SET_MASK 0x1
+#define SSTATUS_MXR_BIT_OFFSET   19
+#define SSTATUS_MXR_BIT_WIDTH    1
+#define SSTATUS_MXR_BIT_MASK     0x80000
+#define SSTATUS_MXR_ALL_SET_MASK 0x1
+#define SSTATUS_SPELP_BIT_OFFSET   23
+#define SSTATUS_SPELP_BIT_WIDTH    1
+#define SSTATUS_SPELP_BIT_MASK     0x800000
+#define SSTATUS_SPELP_ALL_SET_MASK 0x1
+#define SSTATUS_SDT_BIT_OFFSET   24
+#define SSTATUS_SDT_BIT_WIDTH    1
+#define SSTATUS_SDT_BIT_MASK     0x1000000
+#define SSTATUS_SDT_ALL_SET_MASK 0x1
+#if __riscv_xlen==64
+#define SSTATUS_UXL_BIT_OFFSET   32
+#define SSTATUS_UXL_BIT_WIDTH    2
+#define SSTATUS_UXL_BIT_MASK     0x300000000
+#define SSTATUS_UXL_ALL_SET_MASK 0x3
+#endif
+#define SSTATUS_SD_BIT_OFFSET   (__riscv_xlen-1)
+#define SSTATUS_SD_BIT_WIDTH    1
+#define SSTATUS_SD_BIT_MASK     (0x1UL << ((__riscv_xlen-1)))
+#define SSTATUS_SD_ALL_SET_MASK 0x1
 
 /*******************************************
  * stvec - SRW - Supervisor Trap Vector Base Address 
@@ -1365,8 +2021,8 @@ static inline uint_xlen_t csr_read_clr_bits_stvec(uint_xlen_t mask) {
                       : /* clobbers: none */)
 #define STVEC_BASE_BIT_OFFSET   2
 #define STVEC_BASE_BIT_WIDTH    ((__riscv_xlen-1)-(2) + 1)
-#define STVEC_BASE_BIT_MASK     ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (2))
-#define STVEC_BASE_ALL_SET_MASK ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (0))
+#define STVEC_BASE_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (2))
+#define STVEC_BASE_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (0))
 #define STVEC_MODE_BIT_OFFSET   0
 #define STVEC_MODE_BIT_WIDTH    2
 #define STVEC_MODE_BIT_MASK     0x3
@@ -1522,6 +2178,10 @@ static inline uint_xlen_t csr_read_clr_bits_sip(uint_xlen_t mask) {
 #define SIP_UEI_BIT_WIDTH    1
 #define SIP_UEI_BIT_MASK     0x100
 #define SIP_UEI_ALL_SET_MASK 0x1
+#define SIP_LCOFI_BIT_OFFSET   13
+#define SIP_LCOFI_BIT_WIDTH    1
+#define SIP_LCOFI_BIT_MASK     0x2000
+#define SIP_LCOFI_ALL_SET_MASK 0x1
 
 /*******************************************
  * sie - SRW - Supervisor Interrupt Enable 
@@ -1621,6 +2281,10 @@ static inline uint_xlen_t csr_read_clr_bits_sie(uint_xlen_t mask) {
 #define SIE_UEI_BIT_WIDTH    1
 #define SIE_UEI_BIT_MASK     0x100
 #define SIE_UEI_ALL_SET_MASK 0x1
+#define SIE_LCOFI_BIT_OFFSET   13
+#define SIE_LCOFI_BIT_WIDTH    1
+#define SIE_LCOFI_BIT_MASK     0x2000
+#define SIE_LCOFI_ALL_SET_MASK 0x1
 
 /*******************************************
  * ustatus - URW - User mode restricted view of mstatus 
@@ -2011,8 +2675,8 @@ static inline uint_xlen_t csr_read_clr_bits_ucause(uint_xlen_t mask) {
 #define UCAUSE_INTERRUPT_ALL_SET_MASK 0x1
 #define UCAUSE_EXCEPTION_CODE_BIT_OFFSET   0
 #define UCAUSE_EXCEPTION_CODE_BIT_WIDTH    ((__riscv_xlen-2)-(0) + 1)
-#define UCAUSE_EXCEPTION_CODE_BIT_MASK     ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0))
-#define UCAUSE_EXCEPTION_CODE_ALL_SET_MASK ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0))
+#define UCAUSE_EXCEPTION_CODE_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0))
+#define UCAUSE_EXCEPTION_CODE_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0))
 
 /*******************************************
  * utvec - URW - User Trap Vector Base Address 
@@ -2090,8 +2754,8 @@ static inline uint_xlen_t csr_read_clr_bits_utvec(uint_xlen_t mask) {
                       : /* clobbers: none */)
 #define UTVEC_BASE_BIT_OFFSET   2
 #define UTVEC_BASE_BIT_WIDTH    ((__riscv_xlen-1)-(2) + 1)
-#define UTVEC_BASE_BIT_MASK     ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (2))
-#define UTVEC_BASE_ALL_SET_MASK ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (0))
+#define UTVEC_BASE_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (2))
+#define UTVEC_BASE_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (0))
 #define UTVEC_MODE_BIT_OFFSET   0
 #define UTVEC_MODE_BIT_WIDTH    2
 #define UTVEC_MODE_BIT_MASK     0x3
@@ -2148,6 +2812,75 @@ static inline uint_xlen_t csr_read_write_fflags(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_fflags(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, fflags, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_fflags(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, fflags, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_fflags(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, fflags, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_fflags(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, fflags, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* fflags, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_FFLAGS(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, fflags, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* fflags, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_FFLAGS(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, fflags, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* fflags, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_FFLAGS(MASK)               \
+    __asm__ volatile ("csrrci    zero, fflags, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define FFLAGS_NX_BIT_OFFSET   0
+#define FFLAGS_NX_BIT_WIDTH    1
+#define FFLAGS_NX_BIT_MASK     0x1
+#define FFLAGS_NX_ALL_SET_MASK 0x1
+#define FFLAGS_UF_BIT_OFFSET   1
+#define FFLAGS_UF_BIT_WIDTH    1
+#define FFLAGS_UF_BIT_MASK     0x2
+#define FFLAGS_UF_ALL_SET_MASK 0x1
+#define FFLAGS_OF_BIT_OFFSET   2
+#define FFLAGS_OF_BIT_WIDTH    1
+#define FFLAGS_OF_BIT_MASK     0x4
+#define FFLAGS_OF_ALL_SET_MASK 0x1
+#define FFLAGS_DZ_BIT_OFFSET   3
+#define FFLAGS_DZ_BIT_WIDTH    1
+#define FFLAGS_DZ_BIT_MASK     0x8
+#define FFLAGS_DZ_ALL_SET_MASK 0x1
+#define FFLAGS_NV_BIT_OFFSET   4
+#define FFLAGS_NV_BIT_WIDTH    1
+#define FFLAGS_NV_BIT_MASK     0x10
+#define FFLAGS_NV_ALL_SET_MASK 0x1
 
 /*******************************************
  * frm - URW - Floating-Point Dynamic Rounding Mode. 
@@ -2200,15 +2933,88 @@ static inline uint_xlen_t csr_read_write_fcsr(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
-
-/*******************************************
- * cycle - URO - Cycle counter for RDCYCLE instruction. 
- */
-static inline uint_xlen_t csr_read_cycle(void) {
-    uint_xlen_t value;        
-    __asm__ volatile ("csrr    %0, cycle" 
-                      : "=r" (value)  /* output : register */
-                      : /* input : none */
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_fcsr(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, fcsr, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_fcsr(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, fcsr, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_fcsr(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, fcsr, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_fcsr(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, fcsr, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* fcsr, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_FCSR(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, fcsr, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* fcsr, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_FCSR(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, fcsr, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* fcsr, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_FCSR(MASK)               \
+    __asm__ volatile ("csrrci    zero, fcsr, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define FCSR_NX_BIT_OFFSET   0
+#define FCSR_NX_BIT_WIDTH    1
+#define FCSR_NX_BIT_MASK     0x1
+#define FCSR_NX_ALL_SET_MASK 0x1
+#define FCSR_UF_BIT_OFFSET   1
+#define FCSR_UF_BIT_WIDTH    1
+#define FCSR_UF_BIT_MASK     0x2
+#define FCSR_UF_ALL_SET_MASK 0x1
+#define FCSR_OF_BIT_OFFSET   2
+#define FCSR_OF_BIT_WIDTH    1
+#define FCSR_OF_BIT_MASK     0x4
+#define FCSR_OF_ALL_SET_MASK 0x1
+#define FCSR_DZ_BIT_OFFSET   3
+#define FCSR_DZ_BIT_WIDTH    1
+#define FCSR_DZ_BIT_MASK     0x8
+#define FCSR_DZ_ALL_SET_MASK 0x1
+#define FCSR_NV_BIT_OFFSET   4
+#define FCSR_NV_BIT_WIDTH    1
+#define FCSR_NV_BIT_MASK     0x10
+#define FCSR_NV_ALL_SET_MASK 0x1
+#define FCSR_FRM_BIT_OFFSET   5
+#define FCSR_FRM_BIT_WIDTH    3
+#define FCSR_FRM_BIT_MASK     0xe0
+#define FCSR_FRM_ALL_SET_MASK 0x7
+
+/*******************************************
+ * cycle - URO - Cycle counter for RDCYCLE instruction. 
+ */
+static inline uint_xlen_t csr_read_cycle(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, cycle" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
                       : /* clobbers: none */);
     return value;
 }
@@ -2396,6 +3202,91 @@ static inline uint_xlen_t csr_read_write_satp(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_satp(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, satp, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_satp(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, satp, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_satp(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, satp, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_satp(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, satp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* satp, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_SATP(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, satp, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* satp, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_SATP(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, satp, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* satp, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_SATP(MASK)               \
+    __asm__ volatile ("csrrci    zero, satp, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#if __riscv_xlen==32
+#define SATP_MODE_BIT_OFFSET   31
+#define SATP_MODE_BIT_WIDTH    1
+#define SATP_MODE_BIT_MASK     0x80000000
+#define SATP_MODE_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define SATP_MODE_BIT_OFFSET   60
+#define SATP_MODE_BIT_WIDTH    4
+#define SATP_MODE_BIT_MASK     0xf000000000000000
+#define SATP_MODE_ALL_SET_MASK 0xf
+#endif
+#if __riscv_xlen==32
+#define SATP_ASID_BIT_OFFSET   22
+#define SATP_ASID_BIT_WIDTH    9
+#define SATP_ASID_BIT_MASK     0x7fc00000
+#define SATP_ASID_ALL_SET_MASK 0x1ff
+#endif
+#if __riscv_xlen==64
+#define SATP_ASID_BIT_OFFSET   44
+#define SATP_ASID_BIT_WIDTH    16
+#define SATP_ASID_BIT_MASK     0xffff00000000000
+#define SATP_ASID_ALL_SET_MASK 0xffff
+#endif
+#if __riscv_xlen==32
+#define SATP_PPN_BIT_OFFSET   0
+#define SATP_PPN_BIT_WIDTH    22
+#define SATP_PPN_BIT_MASK     0x3fffff
+#define SATP_PPN_ALL_SET_MASK 0x3fffff
+#endif
+#if __riscv_xlen==64
+#define SATP_PPN_BIT_OFFSET   0
+#define SATP_PPN_BIT_WIDTH    44
+#define SATP_PPN_BIT_MASK     0xfffffffffff
+#define SATP_PPN_ALL_SET_MASK 0xfffffffffff
+#endif
 
 /*******************************************
  * hstatus - HRW - Hypervisor status register. 
@@ -2422,6 +3313,97 @@ static inline uint_xlen_t csr_read_write_hstatus(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_hstatus(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, hstatus, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_hstatus(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, hstatus, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_hstatus(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, hstatus, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_hstatus(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, hstatus, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* hstatus, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HSTATUS(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, hstatus, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hstatus, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HSTATUS(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, hstatus, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hstatus, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HSTATUS(MASK)               \
+    __asm__ volatile ("csrrci    zero, hstatus, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HSTATUS_VSBE_BIT_OFFSET   5
+#define HSTATUS_VSBE_BIT_WIDTH    1
+#define HSTATUS_VSBE_BIT_MASK     0x20
+#define HSTATUS_VSBE_ALL_SET_MASK 0x1
+#define HSTATUS_GVA_BIT_OFFSET   6
+#define HSTATUS_GVA_BIT_WIDTH    1
+#define HSTATUS_GVA_BIT_MASK     0x40
+#define HSTATUS_GVA_ALL_SET_MASK 0x1
+#define HSTATUS_SPV_BIT_OFFSET   7
+#define HSTATUS_SPV_BIT_WIDTH    1
+#define HSTATUS_SPV_BIT_MASK     0x80
+#define HSTATUS_SPV_ALL_SET_MASK 0x1
+#define HSTATUS_SPVP_BIT_OFFSET   8
+#define HSTATUS_SPVP_BIT_WIDTH    1
+#define HSTATUS_SPVP_BIT_MASK     0x100
+#define HSTATUS_SPVP_ALL_SET_MASK 0x1
+#define HSTATUS_HU_BIT_OFFSET   9
+#define HSTATUS_HU_BIT_WIDTH    1
+#define HSTATUS_HU_BIT_MASK     0x200
+#define HSTATUS_HU_ALL_SET_MASK 0x1
+#define HSTATUS_VGEIN_BIT_OFFSET   12
+#define HSTATUS_VGEIN_BIT_WIDTH    6
+#define HSTATUS_VGEIN_BIT_MASK     0x3f000
+#define HSTATUS_VGEIN_ALL_SET_MASK 0x3f
+#define HSTATUS_VTVM_BIT_OFFSET   20
+#define HSTATUS_VTVM_BIT_WIDTH    1
+#define HSTATUS_VTVM_BIT_MASK     0x100000
+#define HSTATUS_VTVM_ALL_SET_MASK 0x1
+#define HSTATUS_VTW_BIT_OFFSET   21
+#define HSTATUS_VTW_BIT_WIDTH    1
+#define HSTATUS_VTW_BIT_MASK     0x200000
+#define HSTATUS_VTW_ALL_SET_MASK 0x1
+#define HSTATUS_VTSR_BIT_OFFSET   22
+#define HSTATUS_VTSR_BIT_WIDTH    1
+#define HSTATUS_VTSR_BIT_MASK     0x400000
+#define HSTATUS_VTSR_ALL_SET_MASK 0x1
+#if __riscv_xlen==64
+#define HSTATUS_VSXL_BIT_OFFSET   32
+#define HSTATUS_VSXL_BIT_WIDTH    2
+#define HSTATUS_VSXL_BIT_MASK     0x300000000
+#define HSTATUS_VSXL_ALL_SET_MASK 0x3
+#endif
 
 /*******************************************
  * hedeleg - HRW - Hypervisor exception delegation register. 
@@ -2448,6 +3430,103 @@ static inline uint_xlen_t csr_read_write_hedeleg(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_hedeleg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, hedeleg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_hedeleg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, hedeleg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_hedeleg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, hedeleg, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_hedeleg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, hedeleg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* hedeleg, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HEDELEG(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, hedeleg, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hedeleg, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HEDELEG(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, hedeleg, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hedeleg, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HEDELEG(MASK)               \
+    __asm__ volatile ("csrrci    zero, hedeleg, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HEDELEG_INSTRUCTION_MISALIGNED_BIT_OFFSET   0
+#define HEDELEG_INSTRUCTION_MISALIGNED_BIT_WIDTH    1
+#define HEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK     0x1
+#define HEDELEG_INSTRUCTION_MISALIGNED_ALL_SET_MASK 0x1
+#define HEDELEG_INSTRUCTION_FAULT_BIT_OFFSET   1
+#define HEDELEG_INSTRUCTION_FAULT_BIT_WIDTH    1
+#define HEDELEG_INSTRUCTION_FAULT_BIT_MASK     0x2
+#define HEDELEG_INSTRUCTION_FAULT_ALL_SET_MASK 0x1
+#define HEDELEG_ILLEGAL_INSTRUCTION_BIT_OFFSET   2
+#define HEDELEG_ILLEGAL_INSTRUCTION_BIT_WIDTH    1
+#define HEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK     0x4
+#define HEDELEG_ILLEGAL_INSTRUCTION_ALL_SET_MASK 0x1
+#define HEDELEG_BREAKPOINT_BIT_OFFSET   3
+#define HEDELEG_BREAKPOINT_BIT_WIDTH    1
+#define HEDELEG_BREAKPOINT_BIT_MASK     0x8
+#define HEDELEG_BREAKPOINT_ALL_SET_MASK 0x1
+#define HEDELEG_LOAD_MISALIGNED_BIT_OFFSET   4
+#define HEDELEG_LOAD_MISALIGNED_BIT_WIDTH    1
+#define HEDELEG_LOAD_MISALIGNED_BIT_MASK     0x10
+#define HEDELEG_LOAD_MISALIGNED_ALL_SET_MASK 0x1
+#define HEDELEG_LOAD_FAULT_BIT_OFFSET   5
+#define HEDELEG_LOAD_FAULT_BIT_WIDTH    1
+#define HEDELEG_LOAD_FAULT_BIT_MASK     0x20
+#define HEDELEG_LOAD_FAULT_ALL_SET_MASK 0x1
+#define HEDELEG_STORE_MISALIGNED_BIT_OFFSET   6
+#define HEDELEG_STORE_MISALIGNED_BIT_WIDTH    1
+#define HEDELEG_STORE_MISALIGNED_BIT_MASK     0x40
+#define HEDELEG_STORE_MISALIGNED_ALL_SET_MASK 0x1
+#define HEDELEG_STORE_FAULT_BIT_OFFSET   7
+#define HEDELEG_STORE_FAULT_BIT_WIDTH    1
+#define HEDELEG_STORE_FAULT_BIT_MASK     0x80
+#define HEDELEG_STORE_FAULT_ALL_SET_MASK 0x1
+#define HEDELEG_ECALL_U_BIT_OFFSET   8
+#define HEDELEG_ECALL_U_BIT_WIDTH    1
+#define HEDELEG_ECALL_U_BIT_MASK     0x100
+#define HEDELEG_ECALL_U_ALL_SET_MASK 0x1
+#define HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_OFFSET   12
+#define HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_WIDTH    1
+#define HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK     0x1000
+#define HEDELEG_INSTRUCTION_PAGE_FAULT_ALL_SET_MASK 0x1
+#define HEDELEG_LOAD_PAGE_FAULT_BIT_OFFSET   13
+#define HEDELEG_LOAD_PAGE_FAULT_BIT_WIDTH    1
+#define HEDELEG_LOAD_PAGE_FAULT_BIT_MASK     0x2000
+#define HEDELEG_LOAD_PAGE_FAULT_ALL_SET_MASK 0x1
+#define HEDELEG_STORE_PAGE_FAULT_BIT_OFFSET   15
+#define HEDELEG_STORE_PAGE_FAULT_BIT_WIDTH    1
+#define HEDELEG_STORE_PAGE_FAULT_BIT_MASK     0x8000
+#define HEDELEG_STORE_PAGE_FAULT_ALL_SET_MASK 0x1
 
 /*******************************************
  * hideleg - HRW - Hypervisor interrupt delegation register. 
@@ -2474,6 +3553,71 @@ static inline uint_xlen_t csr_read_write_hideleg(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_hideleg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, hideleg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_hideleg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, hideleg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_hideleg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, hideleg, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_hideleg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, hideleg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* hideleg, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HIDELEG(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, hideleg, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hideleg, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HIDELEG(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, hideleg, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hideleg, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HIDELEG(MASK)               \
+    __asm__ volatile ("csrrci    zero, hideleg, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HIDELEG_VSSI_BIT_OFFSET   2
+#define HIDELEG_VSSI_BIT_WIDTH    1
+#define HIDELEG_VSSI_BIT_MASK     0x4
+#define HIDELEG_VSSI_ALL_SET_MASK 0x1
+#define HIDELEG_VSTI_BIT_OFFSET   6
+#define HIDELEG_VSTI_BIT_WIDTH    1
+#define HIDELEG_VSTI_BIT_MASK     0x40
+#define HIDELEG_VSTI_ALL_SET_MASK 0x1
+#define HIDELEG_VSEI_BIT_OFFSET   10
+#define HIDELEG_VSEI_BIT_WIDTH    1
+#define HIDELEG_VSEI_BIT_MASK     0x400
+#define HIDELEG_VSEI_ALL_SET_MASK 0x1
+#define HIDELEG_LCOFI_BIT_OFFSET   13
+#define HIDELEG_LCOFI_BIT_WIDTH    1
+#define HIDELEG_LCOFI_BIT_MASK     0x2000
+#define HIDELEG_LCOFI_ALL_SET_MASK 0x1
 
 /*******************************************
  * hcounteren - HRW - Hypervisor counter enable. 
@@ -2526,6 +3670,91 @@ static inline uint_xlen_t csr_read_write_hgatp(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_hgatp(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, hgatp, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_hgatp(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, hgatp, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_hgatp(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, hgatp, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_hgatp(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, hgatp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* hgatp, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HGATP(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, hgatp, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hgatp, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HGATP(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, hgatp, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hgatp, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HGATP(MASK)               \
+    __asm__ volatile ("csrrci    zero, hgatp, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#if __riscv_xlen==32
+#define HGATP_MODE_BIT_OFFSET   31
+#define HGATP_MODE_BIT_WIDTH    1
+#define HGATP_MODE_BIT_MASK     0x80000000
+#define HGATP_MODE_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define HGATP_MODE_BIT_OFFSET   60
+#define HGATP_MODE_BIT_WIDTH    4
+#define HGATP_MODE_BIT_MASK     0xf000000000000000
+#define HGATP_MODE_ALL_SET_MASK 0xf
+#endif
+#if __riscv_xlen==32
+#define HGATP_VMID_BIT_OFFSET   22
+#define HGATP_VMID_BIT_WIDTH    7
+#define HGATP_VMID_BIT_MASK     0x1fc00000
+#define HGATP_VMID_ALL_SET_MASK 0x7f
+#endif
+#if __riscv_xlen==64
+#define HGATP_VMID_BIT_OFFSET   44
+#define HGATP_VMID_BIT_WIDTH    14
+#define HGATP_VMID_BIT_MASK     0x3fff00000000000
+#define HGATP_VMID_ALL_SET_MASK 0x3fff
+#endif
+#if __riscv_xlen==32
+#define HGATP_PPN_BIT_OFFSET   0
+#define HGATP_PPN_BIT_WIDTH    22
+#define HGATP_PPN_BIT_MASK     0x3fffff
+#define HGATP_PPN_ALL_SET_MASK 0x3fffff
+#endif
+#if __riscv_xlen==64
+#define HGATP_PPN_BIT_OFFSET   0
+#define HGATP_PPN_BIT_WIDTH    44
+#define HGATP_PPN_BIT_MASK     0xfffffffffff
+#define HGATP_PPN_ALL_SET_MASK 0xfffffffffff
+#endif
 
 /*******************************************
  * htimedelta - HRW - Delta for VS/VU-mode timer. 
@@ -2812,6 +4041,91 @@ static inline uint_xlen_t csr_read_write_vsatp(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_vsatp(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, vsatp, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_vsatp(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, vsatp, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_vsatp(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, vsatp, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_vsatp(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, vsatp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* vsatp, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_VSATP(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, vsatp, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* vsatp, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_VSATP(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, vsatp, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* vsatp, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_VSATP(MASK)               \
+    __asm__ volatile ("csrrci    zero, vsatp, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#if __riscv_xlen==32
+#define VSATP_MODE_BIT_OFFSET   31
+#define VSATP_MODE_BIT_WIDTH    1
+#define VSATP_MODE_BIT_MASK     0x80000000
+#define VSATP_MODE_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define VSATP_MODE_BIT_OFFSET   60
+#define VSATP_MODE_BIT_WIDTH    4
+#define VSATP_MODE_BIT_MASK     0xf000000000000000
+#define VSATP_MODE_ALL_SET_MASK 0xf
+#endif
+#if __riscv_xlen==32
+#define VSATP_ASID_BIT_OFFSET   22
+#define VSATP_ASID_BIT_WIDTH    9
+#define VSATP_ASID_BIT_MASK     0x7fc00000
+#define VSATP_ASID_ALL_SET_MASK 0x1ff
+#endif
+#if __riscv_xlen==64
+#define VSATP_ASID_BIT_OFFSET   44
+#define VSATP_ASID_BIT_WIDTH    16
+#define VSATP_ASID_BIT_MASK     0xffff00000000000
+#define VSATP_ASID_ALL_SET_MASK 0xffff
+#endif
+#if __riscv_xlen==32
+#define VSATP_PPN_BIT_OFFSET   0
+#define VSATP_PPN_BIT_WIDTH    22
+#define VSATP_PPN_BIT_MASK     0x3fffff
+#define VSATP_PPN_ALL_SET_MASK 0x3fffff
+#endif
+#if __riscv_xlen==64
+#define VSATP_PPN_BIT_OFFSET   0
+#define VSATP_PPN_BIT_WIDTH    44
+#define VSATP_PPN_BIT_MASK     0xfffffffffff
+#define VSATP_PPN_ALL_SET_MASK 0xfffffffffff
+#endif
 
 /*******************************************
  * mbase - MRW - Base register. 
@@ -3358,10 +4672,107 @@ static inline uint_xlen_t csr_read_write_mhpmevent4(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
-
-/*******************************************
- * mhpmevent31 - MRW - Machine performance-monitoring event selector. 
- */
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mhpmevent4(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, mhpmevent4, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mhpmevent4(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, mhpmevent4, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_mhpmevent4(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, mhpmevent4, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_mhpmevent4(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, mhpmevent4, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mhpmevent4, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MHPMEVENT4(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mhpmevent4, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent4, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MHPMEVENT4(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mhpmevent4, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent4, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MHPMEVENT4(MASK)               \
+    __asm__ volatile ("csrrci    zero, mhpmevent4, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#if __riscv_xlen==32
+#define MHPMEVENT4_EVENT_BIT_OFFSET   0
+#define MHPMEVENT4_EVENT_BIT_WIDTH    32
+#define MHPMEVENT4_EVENT_BIT_MASK     0xffffffff
+#define MHPMEVENT4_EVENT_ALL_SET_MASK 0xffffffff
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT4_EVENT_BIT_OFFSET   0
+#define MHPMEVENT4_EVENT_BIT_WIDTH    56
+#define MHPMEVENT4_EVENT_BIT_MASK     0xffffffffffffff
+#define MHPMEVENT4_EVENT_ALL_SET_MASK 0xffffffffffffff
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT4_OF_BIT_OFFSET   63
+#define MHPMEVENT4_OF_BIT_WIDTH    1
+#define MHPMEVENT4_OF_BIT_MASK     0x8000000000000000
+#define MHPMEVENT4_OF_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT4_MINH_BIT_OFFSET   62
+#define MHPMEVENT4_MINH_BIT_WIDTH    1
+#define MHPMEVENT4_MINH_BIT_MASK     0x4000000000000000
+#define MHPMEVENT4_MINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT4_SINH_BIT_OFFSET   61
+#define MHPMEVENT4_SINH_BIT_WIDTH    1
+#define MHPMEVENT4_SINH_BIT_MASK     0x2000000000000000
+#define MHPMEVENT4_SINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT4_UINH_BIT_OFFSET   60
+#define MHPMEVENT4_UINH_BIT_WIDTH    1
+#define MHPMEVENT4_UINH_BIT_MASK     0x1000000000000000
+#define MHPMEVENT4_UINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT4_VSINH_BIT_OFFSET   59
+#define MHPMEVENT4_VSINH_BIT_WIDTH    1
+#define MHPMEVENT4_VSINH_BIT_MASK     0x800000000000000
+#define MHPMEVENT4_VSINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT4_VUINH_BIT_OFFSET   58
+#define MHPMEVENT4_VUINH_BIT_WIDTH    1
+#define MHPMEVENT4_VUINH_BIT_MASK     0x400000000000000
+#define MHPMEVENT4_VUINH_ALL_SET_MASK 0x1
+#endif
+
+/*******************************************
+ * mhpmevent31 - MRW - Machine performance-monitoring event selector. 
+ */
 static inline uint_xlen_t csr_read_mhpmevent31(void) {
     uint_xlen_t value;        
     __asm__ volatile ("csrr    %0, mhpmevent31" 
@@ -3384,6 +4795,103 @@ static inline uint_xlen_t csr_read_write_mhpmevent31(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mhpmevent31(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, mhpmevent31, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mhpmevent31(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, mhpmevent31, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_mhpmevent31(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, mhpmevent31, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_mhpmevent31(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, mhpmevent31, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mhpmevent31, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MHPMEVENT31(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mhpmevent31, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent31, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MHPMEVENT31(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mhpmevent31, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent31, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MHPMEVENT31(MASK)               \
+    __asm__ volatile ("csrrci    zero, mhpmevent31, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#if __riscv_xlen==32
+#define MHPMEVENT31_EVENT_BIT_OFFSET   0
+#define MHPMEVENT31_EVENT_BIT_WIDTH    32
+#define MHPMEVENT31_EVENT_BIT_MASK     0xffffffff
+#define MHPMEVENT31_EVENT_ALL_SET_MASK 0xffffffff
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT31_EVENT_BIT_OFFSET   0
+#define MHPMEVENT31_EVENT_BIT_WIDTH    56
+#define MHPMEVENT31_EVENT_BIT_MASK     0xffffffffffffff
+#define MHPMEVENT31_EVENT_ALL_SET_MASK 0xffffffffffffff
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT31_OF_BIT_OFFSET   63
+#define MHPMEVENT31_OF_BIT_WIDTH    1
+#define MHPMEVENT31_OF_BIT_MASK     0x8000000000000000
+#define MHPMEVENT31_OF_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT31_MINH_BIT_OFFSET   62
+#define MHPMEVENT31_MINH_BIT_WIDTH    1
+#define MHPMEVENT31_MINH_BIT_MASK     0x4000000000000000
+#define MHPMEVENT31_MINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT31_SINH_BIT_OFFSET   61
+#define MHPMEVENT31_SINH_BIT_WIDTH    1
+#define MHPMEVENT31_SINH_BIT_MASK     0x2000000000000000
+#define MHPMEVENT31_SINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT31_UINH_BIT_OFFSET   60
+#define MHPMEVENT31_UINH_BIT_WIDTH    1
+#define MHPMEVENT31_UINH_BIT_MASK     0x1000000000000000
+#define MHPMEVENT31_UINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT31_VSINH_BIT_OFFSET   59
+#define MHPMEVENT31_VSINH_BIT_WIDTH    1
+#define MHPMEVENT31_VSINH_BIT_MASK     0x800000000000000
+#define MHPMEVENT31_VSINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MHPMEVENT31_VUINH_BIT_OFFSET   58
+#define MHPMEVENT31_VUINH_BIT_WIDTH    1
+#define MHPMEVENT31_VUINH_BIT_MASK     0x400000000000000
+#define MHPMEVENT31_VUINH_ALL_SET_MASK 0x1
+#endif
 
 /*******************************************
  * tselect - MRW - Debug/Trace trigger register select. 
@@ -3618,6 +5126,71 @@ static inline uint_xlen_t csr_read_write_hie(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_hie(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, hie, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_hie(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, hie, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_hie(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, hie, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_hie(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, hie, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* hie, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HIE(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, hie, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hie, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HIE(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, hie, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hie, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HIE(MASK)               \
+    __asm__ volatile ("csrrci    zero, hie, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HIE_VSSI_BIT_OFFSET   2
+#define HIE_VSSI_BIT_WIDTH    1
+#define HIE_VSSI_BIT_MASK     0x4
+#define HIE_VSSI_ALL_SET_MASK 0x1
+#define HIE_VSTI_BIT_OFFSET   6
+#define HIE_VSTI_BIT_WIDTH    1
+#define HIE_VSTI_BIT_MASK     0x40
+#define HIE_VSTI_ALL_SET_MASK 0x1
+#define HIE_VSEI_BIT_OFFSET   10
+#define HIE_VSEI_BIT_WIDTH    1
+#define HIE_VSEI_BIT_MASK     0x400
+#define HIE_VSEI_ALL_SET_MASK 0x1
+#define HIE_SGEI_BIT_OFFSET   12
+#define HIE_SGEI_BIT_WIDTH    1
+#define HIE_SGEI_BIT_MASK     0x1000
+#define HIE_SGEI_ALL_SET_MASK 0x1
 
 /*******************************************
  * hgeie - HRW - Hypervisor guest external interrupt-enable register. 
@@ -3644,6 +5217,59 @@ static inline uint_xlen_t csr_read_write_hgeie(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_hgeie(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, hgeie, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_hgeie(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, hgeie, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_hgeie(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, hgeie, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_hgeie(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, hgeie, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* hgeie, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HGEIE(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, hgeie, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hgeie, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HGEIE(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, hgeie, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hgeie, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HGEIE(MASK)               \
+    __asm__ volatile ("csrrci    zero, hgeie, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HGEIE_GEI_BIT_OFFSET   1
+#define HGEIE_GEI_BIT_WIDTH    ((__riscv_xlen-1)-(1) + 1)
+#define HGEIE_GEI_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (1))
+#define HGEIE_GEI_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (0))
 
 /*******************************************
  * htval - HRW - Hypervisor bad guest physical address. 
@@ -3696,6 +5322,71 @@ static inline uint_xlen_t csr_read_write_hip(uint_xlen_t new_value) {
                       : /* clobbers: none */);
     return prev_value;
 }
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_hip(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, hip, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_hip(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, hip, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_hip(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, hip, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_hip(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, hip, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* hip, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HIP(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, hip, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hip, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HIP(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, hip, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hip, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HIP(MASK)               \
+    __asm__ volatile ("csrrci    zero, hip, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HIP_VSSI_BIT_OFFSET   2
+#define HIP_VSSI_BIT_WIDTH    1
+#define HIP_VSSI_BIT_MASK     0x4
+#define HIP_VSSI_ALL_SET_MASK 0x1
+#define HIP_VSTI_BIT_OFFSET   6
+#define HIP_VSTI_BIT_WIDTH    1
+#define HIP_VSTI_BIT_MASK     0x40
+#define HIP_VSTI_ALL_SET_MASK 0x1
+#define HIP_VSEI_BIT_OFFSET   10
+#define HIP_VSEI_BIT_WIDTH    1
+#define HIP_VSEI_BIT_MASK     0x400
+#define HIP_VSEI_ALL_SET_MASK 0x1
+#define HIP_SGEI_BIT_OFFSET   12
+#define HIP_SGEI_BIT_WIDTH    1
+#define HIP_SGEI_BIT_MASK     0x1000
+#define HIP_SGEI_ALL_SET_MASK 0x1
 
 /*******************************************
  * htinst - HRW - Hypervisor trap instruction (transformed). 
@@ -3734,6 +5425,10 @@ static inline uint_xlen_t csr_read_hgeip(void) {
                       : /* clobbers: none */);
     return value;
 }
+#define HGEIP_GEI_BIT_OFFSET   1
+#define HGEIP_GEI_BIT_WIDTH    ((__riscv_xlen-1)-(1) + 1)
+#define HGEIP_GEI_BIT_MASK     ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (1))
+#define HGEIP_GEI_ALL_SET_MASK ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (0))
 
 /*******************************************
  * mtinst - MRW - Machine trap instruction (transformed). 
@@ -3787,5 +5482,1842 @@ static inline uint_xlen_t csr_read_write_mtval2(uint_xlen_t new_value) {
     return prev_value;
 }
 
+/*******************************************
+ * mhpmevent3h - MRW - Upper 32 bits of mhpmevent3, RV32 only. 
+ */
+static inline uint32_t csr_read_mhpmevent3h(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, mhpmevent3h" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_mhpmevent3h(uint_csr32_t value) {
+    __asm__ volatile ("csrw    mhpmevent3h, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_mhpmevent3h(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, mhpmevent3h, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mhpmevent3h(uint32_t mask) {
+    __asm__ volatile ("csrrs    zero, mhpmevent3h, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mhpmevent3h(uint32_t mask) {
+    __asm__ volatile ("csrrc    zero, mhpmevent3h, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_set_bits_mhpmevent3h(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrs    %0, mhpmevent3h, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint32_t csr_read_clr_bits_mhpmevent3h(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrc    %0, mhpmevent3h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mhpmevent3h, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MHPMEVENT3H(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mhpmevent3h, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent3h, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MHPMEVENT3H(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mhpmevent3h, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent3h, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MHPMEVENT3H(MASK)               \
+    __asm__ volatile ("csrrci    zero, mhpmevent3h, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MHPMEVENT3H_OF_BIT_OFFSET   31
+#define MHPMEVENT3H_OF_BIT_WIDTH    1
+#define MHPMEVENT3H_OF_BIT_MASK     0x80000000
+#define MHPMEVENT3H_OF_ALL_SET_MASK 0x1
+#define MHPMEVENT3H_MINH_BIT_OFFSET   30
+#define MHPMEVENT3H_MINH_BIT_WIDTH    1
+#define MHPMEVENT3H_MINH_BIT_MASK     0x40000000
+#define MHPMEVENT3H_MINH_ALL_SET_MASK 0x1
+#define MHPMEVENT3H_SINH_BIT_OFFSET   29
+#define MHPMEVENT3H_SINH_BIT_WIDTH    1
+#define MHPMEVENT3H_SINH_BIT_MASK     0x20000000
+#define MHPMEVENT3H_SINH_ALL_SET_MASK 0x1
+#define MHPMEVENT3H_UINH_BIT_OFFSET   28
+#define MHPMEVENT3H_UINH_BIT_WIDTH    1
+#define MHPMEVENT3H_UINH_BIT_MASK     0x10000000
+#define MHPMEVENT3H_UINH_ALL_SET_MASK 0x1
+#define MHPMEVENT3H_VSINH_BIT_OFFSET   27
+#define MHPMEVENT3H_VSINH_BIT_WIDTH    1
+#define MHPMEVENT3H_VSINH_BIT_MASK     0x8000000
+#define MHPMEVENT3H_VSINH_ALL_SET_MASK 0x1
+#define MHPMEVENT3H_VUINH_BIT_OFFSET   26
+#define MHPMEVENT3H_VUINH_BIT_WIDTH    1
+#define MHPMEVENT3H_VUINH_BIT_MASK     0x4000000
+#define MHPMEVENT3H_VUINH_ALL_SET_MASK 0x1
+
+/*******************************************
+ * mhpmevent4h - MRW - Upper 32 bits of mhpmevent4, RV32 only. 
+ */
+static inline uint32_t csr_read_mhpmevent4h(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, mhpmevent4h" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_mhpmevent4h(uint_csr32_t value) {
+    __asm__ volatile ("csrw    mhpmevent4h, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_mhpmevent4h(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, mhpmevent4h, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mhpmevent4h(uint32_t mask) {
+    __asm__ volatile ("csrrs    zero, mhpmevent4h, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mhpmevent4h(uint32_t mask) {
+    __asm__ volatile ("csrrc    zero, mhpmevent4h, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_set_bits_mhpmevent4h(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrs    %0, mhpmevent4h, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint32_t csr_read_clr_bits_mhpmevent4h(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrc    %0, mhpmevent4h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mhpmevent4h, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MHPMEVENT4H(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mhpmevent4h, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent4h, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MHPMEVENT4H(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mhpmevent4h, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent4h, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MHPMEVENT4H(MASK)               \
+    __asm__ volatile ("csrrci    zero, mhpmevent4h, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MHPMEVENT4H_OF_BIT_OFFSET   31
+#define MHPMEVENT4H_OF_BIT_WIDTH    1
+#define MHPMEVENT4H_OF_BIT_MASK     0x80000000
+#define MHPMEVENT4H_OF_ALL_SET_MASK 0x1
+#define MHPMEVENT4H_MINH_BIT_OFFSET   30
+#define MHPMEVENT4H_MINH_BIT_WIDTH    1
+#define MHPMEVENT4H_MINH_BIT_MASK     0x40000000
+#define MHPMEVENT4H_MINH_ALL_SET_MASK 0x1
+#define MHPMEVENT4H_SINH_BIT_OFFSET   29
+#define MHPMEVENT4H_SINH_BIT_WIDTH    1
+#define MHPMEVENT4H_SINH_BIT_MASK     0x20000000
+#define MHPMEVENT4H_SINH_ALL_SET_MASK 0x1
+#define MHPMEVENT4H_UINH_BIT_OFFSET   28
+#define MHPMEVENT4H_UINH_BIT_WIDTH    1
+#define MHPMEVENT4H_UINH_BIT_MASK     0x10000000
+#define MHPMEVENT4H_UINH_ALL_SET_MASK 0x1
+#define MHPMEVENT4H_VSINH_BIT_OFFSET   27
+#define MHPMEVENT4H_VSINH_BIT_WIDTH    1
+#define MHPMEVENT4H_VSINH_BIT_MASK     0x8000000
+#define MHPMEVENT4H_VSINH_ALL_SET_MASK 0x1
+#define MHPMEVENT4H_VUINH_BIT_OFFSET   26
+#define MHPMEVENT4H_VUINH_BIT_WIDTH    1
+#define MHPMEVENT4H_VUINH_BIT_MASK     0x4000000
+#define MHPMEVENT4H_VUINH_ALL_SET_MASK 0x1
+
+/*******************************************
+ * mhpmevent31h - MRW - Upper 32 bits of mhpmevent31, RV32 only. 
+ */
+static inline uint32_t csr_read_mhpmevent31h(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, mhpmevent31h" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_mhpmevent31h(uint_csr32_t value) {
+    __asm__ volatile ("csrw    mhpmevent31h, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_mhpmevent31h(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, mhpmevent31h, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mhpmevent31h(uint32_t mask) {
+    __asm__ volatile ("csrrs    zero, mhpmevent31h, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mhpmevent31h(uint32_t mask) {
+    __asm__ volatile ("csrrc    zero, mhpmevent31h, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_set_bits_mhpmevent31h(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrs    %0, mhpmevent31h, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint32_t csr_read_clr_bits_mhpmevent31h(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrc    %0, mhpmevent31h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mhpmevent31h, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MHPMEVENT31H(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mhpmevent31h, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent31h, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MHPMEVENT31H(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mhpmevent31h, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mhpmevent31h, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MHPMEVENT31H(MASK)               \
+    __asm__ volatile ("csrrci    zero, mhpmevent31h, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MHPMEVENT31H_OF_BIT_OFFSET   31
+#define MHPMEVENT31H_OF_BIT_WIDTH    1
+#define MHPMEVENT31H_OF_BIT_MASK     0x80000000
+#define MHPMEVENT31H_OF_ALL_SET_MASK 0x1
+#define MHPMEVENT31H_MINH_BIT_OFFSET   30
+#define MHPMEVENT31H_MINH_BIT_WIDTH    1
+#define MHPMEVENT31H_MINH_BIT_MASK     0x40000000
+#define MHPMEVENT31H_MINH_ALL_SET_MASK 0x1
+#define MHPMEVENT31H_SINH_BIT_OFFSET   29
+#define MHPMEVENT31H_SINH_BIT_WIDTH    1
+#define MHPMEVENT31H_SINH_BIT_MASK     0x20000000
+#define MHPMEVENT31H_SINH_ALL_SET_MASK 0x1
+#define MHPMEVENT31H_UINH_BIT_OFFSET   28
+#define MHPMEVENT31H_UINH_BIT_WIDTH    1
+#define MHPMEVENT31H_UINH_BIT_MASK     0x10000000
+#define MHPMEVENT31H_UINH_ALL_SET_MASK 0x1
+#define MHPMEVENT31H_VSINH_BIT_OFFSET   27
+#define MHPMEVENT31H_VSINH_BIT_WIDTH    1
+#define MHPMEVENT31H_VSINH_BIT_MASK     0x8000000
+#define MHPMEVENT31H_VSINH_ALL_SET_MASK 0x1
+#define MHPMEVENT31H_VUINH_BIT_OFFSET   26
+#define MHPMEVENT31H_VUINH_BIT_WIDTH    1
+#define MHPMEVENT31H_VUINH_BIT_MASK     0x4000000
+#define MHPMEVENT31H_VUINH_ALL_SET_MASK 0x1
+
+/*******************************************
+ * scountovf - SRO - Supervisor count overflow. 
+ */
+static inline uint32_t csr_read_scountovf(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, scountovf" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+#define SCOUNTOVF_HPM_BIT_OFFSET   3
+#define SCOUNTOVF_HPM_BIT_WIDTH    29
+#define SCOUNTOVF_HPM_BIT_MASK     0xfffffff8
+#define SCOUNTOVF_HPM_ALL_SET_MASK 0x1fffffff
+
+/*******************************************
+ * mcyclecfg - MRW - Machine cycle counter configuration. 
+ */
+static inline uint_xlen_t csr_read_mcyclecfg(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, mcyclecfg" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_mcyclecfg(uint_xlen_t value) {
+    __asm__ volatile ("csrw    mcyclecfg, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_mcyclecfg(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, mcyclecfg, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mcyclecfg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, mcyclecfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mcyclecfg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, mcyclecfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_mcyclecfg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, mcyclecfg, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_mcyclecfg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, mcyclecfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mcyclecfg, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MCYCLECFG(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mcyclecfg, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mcyclecfg, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MCYCLECFG(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mcyclecfg, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mcyclecfg, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MCYCLECFG(MASK)               \
+    __asm__ volatile ("csrrci    zero, mcyclecfg, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#if __riscv_xlen==64
+#define MCYCLECFG_MINH_BIT_OFFSET   62
+#define MCYCLECFG_MINH_BIT_WIDTH    1
+#define MCYCLECFG_MINH_BIT_MASK     0x4000000000000000
+#define MCYCLECFG_MINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MCYCLECFG_SINH_BIT_OFFSET   61
+#define MCYCLECFG_SINH_BIT_WIDTH    1
+#define MCYCLECFG_SINH_BIT_MASK     0x2000000000000000
+#define MCYCLECFG_SINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MCYCLECFG_UINH_BIT_OFFSET   60
+#define MCYCLECFG_UINH_BIT_WIDTH    1
+#define MCYCLECFG_UINH_BIT_MASK     0x1000000000000000
+#define MCYCLECFG_UINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MCYCLECFG_VSINH_BIT_OFFSET   59
+#define MCYCLECFG_VSINH_BIT_WIDTH    1
+#define MCYCLECFG_VSINH_BIT_MASK     0x800000000000000
+#define MCYCLECFG_VSINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MCYCLECFG_VUINH_BIT_OFFSET   58
+#define MCYCLECFG_VUINH_BIT_WIDTH    1
+#define MCYCLECFG_VUINH_BIT_MASK     0x400000000000000
+#define MCYCLECFG_VUINH_ALL_SET_MASK 0x1
+#endif
+
+/*******************************************
+ * mcyclecfgh - MRW - Upper 32 bits of mcyclecfg, RV32 only. 
+ */
+static inline uint32_t csr_read_mcyclecfgh(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, mcyclecfgh" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_mcyclecfgh(uint_csr32_t value) {
+    __asm__ volatile ("csrw    mcyclecfgh, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_mcyclecfgh(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, mcyclecfgh, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mcyclecfgh(uint32_t mask) {
+    __asm__ volatile ("csrrs    zero, mcyclecfgh, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mcyclecfgh(uint32_t mask) {
+    __asm__ volatile ("csrrc    zero, mcyclecfgh, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_set_bits_mcyclecfgh(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrs    %0, mcyclecfgh, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint32_t csr_read_clr_bits_mcyclecfgh(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrc    %0, mcyclecfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mcyclecfgh, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MCYCLECFGH(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mcyclecfgh, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mcyclecfgh, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MCYCLECFGH(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mcyclecfgh, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mcyclecfgh, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MCYCLECFGH(MASK)               \
+    __asm__ volatile ("csrrci    zero, mcyclecfgh, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MCYCLECFGH_MINH_BIT_OFFSET   30
+#define MCYCLECFGH_MINH_BIT_WIDTH    1
+#define MCYCLECFGH_MINH_BIT_MASK     0x40000000
+#define MCYCLECFGH_MINH_ALL_SET_MASK 0x1
+#define MCYCLECFGH_SINH_BIT_OFFSET   29
+#define MCYCLECFGH_SINH_BIT_WIDTH    1
+#define MCYCLECFGH_SINH_BIT_MASK     0x20000000
+#define MCYCLECFGH_SINH_ALL_SET_MASK 0x1
+#define MCYCLECFGH_UINH_BIT_OFFSET   28
+#define MCYCLECFGH_UINH_BIT_WIDTH    1
+#define MCYCLECFGH_UINH_BIT_MASK     0x10000000
+#define MCYCLECFGH_UINH_ALL_SET_MASK 0x1
+#define MCYCLECFGH_VSINH_BIT_OFFSET   27
+#define MCYCLECFGH_VSINH_BIT_WIDTH    1
+#define MCYCLECFGH_VSINH_BIT_MASK     0x8000000
+#define MCYCLECFGH_VSINH_ALL_SET_MASK 0x1
+#define MCYCLECFGH_VUINH_BIT_OFFSET   26
+#define MCYCLECFGH_VUINH_BIT_WIDTH    1
+#define MCYCLECFGH_VUINH_BIT_MASK     0x4000000
+#define MCYCLECFGH_VUINH_ALL_SET_MASK 0x1
+
+/*******************************************
+ * minstretcfg - MRW - Machine instret counter configuration. 
+ */
+static inline uint_xlen_t csr_read_minstretcfg(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, minstretcfg" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_minstretcfg(uint_xlen_t value) {
+    __asm__ volatile ("csrw    minstretcfg, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_minstretcfg(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, minstretcfg, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_minstretcfg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, minstretcfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_minstretcfg(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, minstretcfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_minstretcfg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, minstretcfg, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_minstretcfg(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, minstretcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* minstretcfg, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MINSTRETCFG(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, minstretcfg, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* minstretcfg, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MINSTRETCFG(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, minstretcfg, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* minstretcfg, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MINSTRETCFG(MASK)               \
+    __asm__ volatile ("csrrci    zero, minstretcfg, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#if __riscv_xlen==64
+#define MINSTRETCFG_MINH_BIT_OFFSET   62
+#define MINSTRETCFG_MINH_BIT_WIDTH    1
+#define MINSTRETCFG_MINH_BIT_MASK     0x4000000000000000
+#define MINSTRETCFG_MINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MINSTRETCFG_SINH_BIT_OFFSET   61
+#define MINSTRETCFG_SINH_BIT_WIDTH    1
+#define MINSTRETCFG_SINH_BIT_MASK     0x2000000000000000
+#define MINSTRETCFG_SINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MINSTRETCFG_UINH_BIT_OFFSET   60
+#define MINSTRETCFG_UINH_BIT_WIDTH    1
+#define MINSTRETCFG_UINH_BIT_MASK     0x1000000000000000
+#define MINSTRETCFG_UINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MINSTRETCFG_VSINH_BIT_OFFSET   59
+#define MINSTRETCFG_VSINH_BIT_WIDTH    1
+#define MINSTRETCFG_VSINH_BIT_MASK     0x800000000000000
+#define MINSTRETCFG_VSINH_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MINSTRETCFG_VUINH_BIT_OFFSET   58
+#define MINSTRETCFG_VUINH_BIT_WIDTH    1
+#define MINSTRETCFG_VUINH_BIT_MASK     0x400000000000000
+#define MINSTRETCFG_VUINH_ALL_SET_MASK 0x1
+#endif
+
+/*******************************************
+ * minstretcfgh - MRW - Upper 32 bits of minstretcfg, RV32 only. 
+ */
+static inline uint32_t csr_read_minstretcfgh(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, minstretcfgh" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_minstretcfgh(uint_csr32_t value) {
+    __asm__ volatile ("csrw    minstretcfgh, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_minstretcfgh(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, minstretcfgh, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_minstretcfgh(uint32_t mask) {
+    __asm__ volatile ("csrrs    zero, minstretcfgh, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_minstretcfgh(uint32_t mask) {
+    __asm__ volatile ("csrrc    zero, minstretcfgh, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_set_bits_minstretcfgh(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrs    %0, minstretcfgh, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint32_t csr_read_clr_bits_minstretcfgh(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrc    %0, minstretcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* minstretcfgh, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MINSTRETCFGH(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, minstretcfgh, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* minstretcfgh, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MINSTRETCFGH(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, minstretcfgh, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* minstretcfgh, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MINSTRETCFGH(MASK)               \
+    __asm__ volatile ("csrrci    zero, minstretcfgh, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MINSTRETCFGH_MINH_BIT_OFFSET   30
+#define MINSTRETCFGH_MINH_BIT_WIDTH    1
+#define MINSTRETCFGH_MINH_BIT_MASK     0x40000000
+#define MINSTRETCFGH_MINH_ALL_SET_MASK 0x1
+#define MINSTRETCFGH_SINH_BIT_OFFSET   29
+#define MINSTRETCFGH_SINH_BIT_WIDTH    1
+#define MINSTRETCFGH_SINH_BIT_MASK     0x20000000
+#define MINSTRETCFGH_SINH_ALL_SET_MASK 0x1
+#define MINSTRETCFGH_UINH_BIT_OFFSET   28
+#define MINSTRETCFGH_UINH_BIT_WIDTH    1
+#define MINSTRETCFGH_UINH_BIT_MASK     0x10000000
+#define MINSTRETCFGH_UINH_ALL_SET_MASK 0x1
+#define MINSTRETCFGH_VSINH_BIT_OFFSET   27
+#define MINSTRETCFGH_VSINH_BIT_WIDTH    1
+#define MINSTRETCFGH_VSINH_BIT_MASK     0x8000000
+#define MINSTRETCFGH_VSINH_ALL_SET_MASK 0x1
+#define MINSTRETCFGH_VUINH_BIT_OFFSET   26
+#define MINSTRETCFGH_VUINH_BIT_WIDTH    1
+#define MINSTRETCFGH_VUINH_BIT_MASK     0x4000000
+#define MINSTRETCFGH_VUINH_ALL_SET_MASK 0x1
+
+/*******************************************
+ * menvcfg - MRW - Machine environment configuration. 
+ */
+static inline uint64_t csr_read_menvcfg(void) {
+    uint_csr64_t value;        
+    __asm__ volatile ("csrr    %0, menvcfg" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_menvcfg(uint_csr64_t value) {
+    __asm__ volatile ("csrw    menvcfg, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint64_t csr_read_write_menvcfg(uint64_t new_value) {
+    uint_csr64_t prev_value;
+    __asm__ volatile ("csrrw    %0, menvcfg, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_menvcfg(uint64_t mask) {
+    __asm__ volatile ("csrrs    zero, menvcfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_menvcfg(uint64_t mask) {
+    __asm__ volatile ("csrrc    zero, menvcfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint64_t csr_read_set_bits_menvcfg(uint64_t mask) {
+    uint_csr64_t value;
+    __asm__ volatile ("csrrs    %0, menvcfg, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint64_t csr_read_clr_bits_menvcfg(uint64_t mask) {
+    uint_csr64_t value;
+    __asm__ volatile ("csrrc    %0, menvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* menvcfg, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MENVCFG(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, menvcfg, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* menvcfg, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MENVCFG(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, menvcfg, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* menvcfg, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MENVCFG(MASK)               \
+    __asm__ volatile ("csrrci    zero, menvcfg, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MENVCFG_FIOM_BIT_OFFSET   0
+#define MENVCFG_FIOM_BIT_WIDTH    1
+#define MENVCFG_FIOM_BIT_MASK     0x1
+#define MENVCFG_FIOM_ALL_SET_MASK 0x1
+#define MENVCFG_CBIE_BIT_OFFSET   4
+#define MENVCFG_CBIE_BIT_WIDTH    2
+#define MENVCFG_CBIE_BIT_MASK     0x30
+#define MENVCFG_CBIE_ALL_SET_MASK 0x3
+#define MENVCFG_CBCFE_BIT_OFFSET   6
+#define MENVCFG_CBCFE_BIT_WIDTH    1
+#define MENVCFG_CBCFE_BIT_MASK     0x40
+#define MENVCFG_CBCFE_ALL_SET_MASK 0x1
+#define MENVCFG_CBZE_BIT_OFFSET   7
+#define MENVCFG_CBZE_BIT_WIDTH    1
+#define MENVCFG_CBZE_BIT_MASK     0x80
+#define MENVCFG_CBZE_ALL_SET_MASK 0x1
+#if __riscv_xlen==64
+#define MENVCFG_ADUE_BIT_OFFSET   61
+#define MENVCFG_ADUE_BIT_WIDTH    1
+#define MENVCFG_ADUE_BIT_MASK     0x2000000000000000
+#define MENVCFG_ADUE_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MENVCFG_PBMTE_BIT_OFFSET   62
+#define MENVCFG_PBMTE_BIT_WIDTH    1
+#define MENVCFG_PBMTE_BIT_MASK     0x4000000000000000
+#define MENVCFG_PBMTE_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define MENVCFG_STCE_BIT_OFFSET   63
+#define MENVCFG_STCE_BIT_WIDTH    1
+#define MENVCFG_STCE_BIT_MASK     0x8000000000000000
+#define MENVCFG_STCE_ALL_SET_MASK 0x1
+#endif
+
+/*******************************************
+ * menvcfgh - MRW - Upper 32 bits of menvcfg, RV32 only. 
+ */
+static inline uint32_t csr_read_menvcfgh(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, menvcfgh" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_menvcfgh(uint_csr32_t value) {
+    __asm__ volatile ("csrw    menvcfgh, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_menvcfgh(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, menvcfgh, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_menvcfgh(uint32_t mask) {
+    __asm__ volatile ("csrrs    zero, menvcfgh, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_menvcfgh(uint32_t mask) {
+    __asm__ volatile ("csrrc    zero, menvcfgh, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_set_bits_menvcfgh(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrs    %0, menvcfgh, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint32_t csr_read_clr_bits_menvcfgh(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrc    %0, menvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* menvcfgh, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MENVCFGH(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, menvcfgh, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* menvcfgh, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MENVCFGH(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, menvcfgh, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* menvcfgh, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MENVCFGH(MASK)               \
+    __asm__ volatile ("csrrci    zero, menvcfgh, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MENVCFGH_ADUE_BIT_OFFSET   29
+#define MENVCFGH_ADUE_BIT_WIDTH    1
+#define MENVCFGH_ADUE_BIT_MASK     0x20000000
+#define MENVCFGH_ADUE_ALL_SET_MASK 0x1
+#define MENVCFGH_PBMTE_BIT_OFFSET   30
+#define MENVCFGH_PBMTE_BIT_WIDTH    1
+#define MENVCFGH_PBMTE_BIT_MASK     0x40000000
+#define MENVCFGH_PBMTE_ALL_SET_MASK 0x1
+#define MENVCFGH_STCE_BIT_OFFSET   31
+#define MENVCFGH_STCE_BIT_WIDTH    1
+#define MENVCFGH_STCE_BIT_MASK     0x80000000
+#define MENVCFGH_STCE_ALL_SET_MASK 0x1
+
+/*******************************************
+ * henvcfg - HRW - Hypervisor environment configuration. 
+ */
+static inline uint64_t csr_read_henvcfg(void) {
+    uint_csr64_t value;        
+    __asm__ volatile ("csrr    %0, henvcfg" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_henvcfg(uint_csr64_t value) {
+    __asm__ volatile ("csrw    henvcfg, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint64_t csr_read_write_henvcfg(uint64_t new_value) {
+    uint_csr64_t prev_value;
+    __asm__ volatile ("csrrw    %0, henvcfg, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_henvcfg(uint64_t mask) {
+    __asm__ volatile ("csrrs    zero, henvcfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_henvcfg(uint64_t mask) {
+    __asm__ volatile ("csrrc    zero, henvcfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint64_t csr_read_set_bits_henvcfg(uint64_t mask) {
+    uint_csr64_t value;
+    __asm__ volatile ("csrrs    %0, henvcfg, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint64_t csr_read_clr_bits_henvcfg(uint64_t mask) {
+    uint_csr64_t value;
+    __asm__ volatile ("csrrc    %0, henvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* henvcfg, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HENVCFG(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, henvcfg, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* henvcfg, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HENVCFG(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, henvcfg, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* henvcfg, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HENVCFG(MASK)               \
+    __asm__ volatile ("csrrci    zero, henvcfg, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HENVCFG_FIOM_BIT_OFFSET   0
+#define HENVCFG_FIOM_BIT_WIDTH    1
+#define HENVCFG_FIOM_BIT_MASK     0x1
+#define HENVCFG_FIOM_ALL_SET_MASK 0x1
+#define HENVCFG_CBIE_BIT_OFFSET   4
+#define HENVCFG_CBIE_BIT_WIDTH    2
+#define HENVCFG_CBIE_BIT_MASK     0x30
+#define HENVCFG_CBIE_ALL_SET_MASK 0x3
+#define HENVCFG_CBCFE_BIT_OFFSET   6
+#define HENVCFG_CBCFE_BIT_WIDTH    1
+#define HENVCFG_CBCFE_BIT_MASK     0x40
+#define HENVCFG_CBCFE_ALL_SET_MASK 0x1
+#define HENVCFG_CBZE_BIT_OFFSET   7
+#define HENVCFG_CBZE_BIT_WIDTH    1
+#define HENVCFG_CBZE_BIT_MASK     0x80
+#define HENVCFG_CBZE_ALL_SET_MASK 0x1
+#if __riscv_xlen==64
+#define HENVCFG_ADUE_BIT_OFFSET   61
+#define HENVCFG_ADUE_BIT_WIDTH    1
+#define HENVCFG_ADUE_BIT_MASK     0x2000000000000000
+#define HENVCFG_ADUE_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define HENVCFG_PBMTE_BIT_OFFSET   62
+#define HENVCFG_PBMTE_BIT_WIDTH    1
+#define HENVCFG_PBMTE_BIT_MASK     0x4000000000000000
+#define HENVCFG_PBMTE_ALL_SET_MASK 0x1
+#endif
+#if __riscv_xlen==64
+#define HENVCFG_STCE_BIT_OFFSET   63
+#define HENVCFG_STCE_BIT_WIDTH    1
+#define HENVCFG_STCE_BIT_MASK     0x8000000000000000
+#define HENVCFG_STCE_ALL_SET_MASK 0x1
+#endif
+
+/*******************************************
+ * henvcfgh - HRW - Upper 32 bits of henvcfg, RV32 only. 
+ */
+static inline uint32_t csr_read_henvcfgh(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, henvcfgh" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_henvcfgh(uint_csr32_t value) {
+    __asm__ volatile ("csrw    henvcfgh, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_henvcfgh(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, henvcfgh, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_henvcfgh(uint32_t mask) {
+    __asm__ volatile ("csrrs    zero, henvcfgh, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_henvcfgh(uint32_t mask) {
+    __asm__ volatile ("csrrc    zero, henvcfgh, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_set_bits_henvcfgh(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrs    %0, henvcfgh, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint32_t csr_read_clr_bits_henvcfgh(uint32_t mask) {
+    uint_csr32_t value;
+    __asm__ volatile ("csrrc    %0, henvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* henvcfgh, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HENVCFGH(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, henvcfgh, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* henvcfgh, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HENVCFGH(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, henvcfgh, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* henvcfgh, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HENVCFGH(MASK)               \
+    __asm__ volatile ("csrrci    zero, henvcfgh, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HENVCFGH_ADUE_BIT_OFFSET   29
+#define HENVCFGH_ADUE_BIT_WIDTH    1
+#define HENVCFGH_ADUE_BIT_MASK     0x20000000
+#define HENVCFGH_ADUE_ALL_SET_MASK 0x1
+#define HENVCFGH_PBMTE_BIT_OFFSET   30
+#define HENVCFGH_PBMTE_BIT_WIDTH    1
+#define HENVCFGH_PBMTE_BIT_MASK     0x40000000
+#define HENVCFGH_PBMTE_ALL_SET_MASK 0x1
+#define HENVCFGH_STCE_BIT_OFFSET   31
+#define HENVCFGH_STCE_BIT_WIDTH    1
+#define HENVCFGH_STCE_BIT_MASK     0x80000000
+#define HENVCFGH_STCE_ALL_SET_MASK 0x1
+
+/*******************************************
+ * hvip - HRW - Hypervisor virtual interrupt pending. 
+ */
+static inline uint_xlen_t csr_read_hvip(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, hvip" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_hvip(uint_xlen_t value) {
+    __asm__ volatile ("csrw    hvip, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_hvip(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, hvip, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_hvip(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, hvip, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_hvip(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, hvip, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_hvip(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, hvip, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_hvip(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, hvip, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* hvip, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HVIP(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, hvip, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hvip, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HVIP(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, hvip, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hvip, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HVIP(MASK)               \
+    __asm__ volatile ("csrrci    zero, hvip, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HVIP_VSSI_BIT_OFFSET   2
+#define HVIP_VSSI_BIT_WIDTH    1
+#define HVIP_VSSI_BIT_MASK     0x4
+#define HVIP_VSSI_ALL_SET_MASK 0x1
+#define HVIP_VSTI_BIT_OFFSET   6
+#define HVIP_VSTI_BIT_WIDTH    1
+#define HVIP_VSTI_BIT_MASK     0x40
+#define HVIP_VSTI_ALL_SET_MASK 0x1
+#define HVIP_VSEI_BIT_OFFSET   10
+#define HVIP_VSEI_BIT_WIDTH    1
+#define HVIP_VSEI_BIT_MASK     0x400
+#define HVIP_VSEI_ALL_SET_MASK 0x1
+
+/*******************************************
+ * hvictl - HRW - Hypervisor virtual interrupt control. 
+ */
+static inline uint_xlen_t csr_read_hvictl(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, hvictl" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_hvictl(uint_xlen_t value) {
+    __asm__ volatile ("csrw    hvictl, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_hvictl(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, hvictl, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_hvictl(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, hvictl, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_hvictl(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, hvictl, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_hvictl(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, hvictl, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_hvictl(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, hvictl, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* hvictl, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_HVICTL(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, hvictl, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hvictl, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_HVICTL(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, hvictl, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* hvictl, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_HVICTL(MASK)               \
+    __asm__ volatile ("csrrci    zero, hvictl, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define HVICTL_IPRIO_BIT_OFFSET   0
+#define HVICTL_IPRIO_BIT_WIDTH    8
+#define HVICTL_IPRIO_BIT_MASK     0xff
+#define HVICTL_IPRIO_ALL_SET_MASK 0xff
+#define HVICTL_IPRIOM_BIT_OFFSET   8
+#define HVICTL_IPRIOM_BIT_WIDTH    1
+#define HVICTL_IPRIOM_BIT_MASK     0x100
+#define HVICTL_IPRIOM_ALL_SET_MASK 0x1
+#define HVICTL_DPR_BIT_OFFSET   9
+#define HVICTL_DPR_BIT_WIDTH    1
+#define HVICTL_DPR_BIT_MASK     0x200
+#define HVICTL_DPR_ALL_SET_MASK 0x1
+#define HVICTL_IID_BIT_OFFSET   16
+#define HVICTL_IID_BIT_WIDTH    12
+#define HVICTL_IID_BIT_MASK     0xfff0000
+#define HVICTL_IID_ALL_SET_MASK 0xfff
+#define HVICTL_VTI_BIT_OFFSET   30
+#define HVICTL_VTI_BIT_WIDTH    1
+#define HVICTL_VTI_BIT_MASK     0x40000000
+#define HVICTL_VTI_ALL_SET_MASK 0x1
+
+/*******************************************
+ * hviprio1 - HRW - Hypervisor VS-level interrupt priorities. 
+ */
+static inline uint_xlen_t csr_read_hviprio1(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, hviprio1" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_hviprio1(uint_xlen_t value) {
+    __asm__ volatile ("csrw    hviprio1, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_hviprio1(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, hviprio1, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * hviprio2 - HRW - Hypervisor VS-level interrupt priorities. 
+ */
+static inline uint_xlen_t csr_read_hviprio2(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, hviprio2" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_hviprio2(uint_xlen_t value) {
+    __asm__ volatile ("csrw    hviprio2, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_hviprio2(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, hviprio2, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * hviprio1h - HRW - Upper 32 bits of hviprio1, RV32 only. 
+ */
+static inline uint32_t csr_read_hviprio1h(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, hviprio1h" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_hviprio1h(uint_csr32_t value) {
+    __asm__ volatile ("csrw    hviprio1h, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_hviprio1h(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, hviprio1h, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * hviprio2h - HRW - Upper 32 bits of hviprio2, RV32 only. 
+ */
+static inline uint32_t csr_read_hviprio2h(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, hviprio2h" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_hviprio2h(uint_csr32_t value) {
+    __asm__ volatile ("csrw    hviprio2h, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_hviprio2h(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, hviprio2h, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * mconfigptr - MRO - Pointer to configuration data structure. 
+ */
+static inline uint_xlen_t csr_read_mconfigptr(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, mconfigptr" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+
+/*******************************************
+ * mseccfg - MRW - Machine security configuration. 
+ */
+static inline uint64_t csr_read_mseccfg(void) {
+    uint_csr64_t value;        
+    __asm__ volatile ("csrr    %0, mseccfg" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_mseccfg(uint_csr64_t value) {
+    __asm__ volatile ("csrw    mseccfg, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint64_t csr_read_write_mseccfg(uint64_t new_value) {
+    uint_csr64_t prev_value;
+    __asm__ volatile ("csrrw    %0, mseccfg, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_mseccfg(uint64_t mask) {
+    __asm__ volatile ("csrrs    zero, mseccfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_mseccfg(uint64_t mask) {
+    __asm__ volatile ("csrrc    zero, mseccfg, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint64_t csr_read_set_bits_mseccfg(uint64_t mask) {
+    uint_csr64_t value;
+    __asm__ volatile ("csrrs    %0, mseccfg, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint64_t csr_read_clr_bits_mseccfg(uint64_t mask) {
+    uint_csr64_t value;
+    __asm__ volatile ("csrrc    %0, mseccfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* mseccfg, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_MSECCFG(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, mseccfg, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mseccfg, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_MSECCFG(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, mseccfg, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* mseccfg, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_MSECCFG(MASK)               \
+    __asm__ volatile ("csrrci    zero, mseccfg, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define MSECCFG_MML_BIT_OFFSET   0
+#define MSECCFG_MML_BIT_WIDTH    1
+#define MSECCFG_MML_BIT_MASK     0x1
+#define MSECCFG_MML_ALL_SET_MASK 0x1
+#define MSECCFG_MMWP_BIT_OFFSET   1
+#define MSECCFG_MMWP_BIT_WIDTH    1
+#define MSECCFG_MMWP_BIT_MASK     0x2
+#define MSECCFG_MMWP_ALL_SET_MASK 0x1
+#define MSECCFG_RLB_BIT_OFFSET   2
+#define MSECCFG_RLB_BIT_WIDTH    1
+#define MSECCFG_RLB_BIT_MASK     0x4
+#define MSECCFG_RLB_ALL_SET_MASK 0x1
+
+/*******************************************
+ * mseccfgh - MRW - Upper 32 bits of mseccfg, RV32 only. 
+ */
+static inline uint32_t csr_read_mseccfgh(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, mseccfgh" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_mseccfgh(uint_csr32_t value) {
+    __asm__ volatile ("csrw    mseccfgh, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_mseccfgh(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, mseccfgh, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * stimecmp - SRW - Supervisor timer compare. 
+ */
+static inline uint64_t csr_read_stimecmp(void) {
+    uint_csr64_t value;        
+    __asm__ volatile ("csrr    %0, stimecmp" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_stimecmp(uint_csr64_t value) {
+    __asm__ volatile ("csrw    stimecmp, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint64_t csr_read_write_stimecmp(uint64_t new_value) {
+    uint_csr64_t prev_value;
+    __asm__ volatile ("csrrw    %0, stimecmp, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * stimecmph - SRW - Upper 32 bits of stimecmp, RV32 only. 
+ */
+static inline uint32_t csr_read_stimecmph(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, stimecmph" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_stimecmph(uint_csr32_t value) {
+    __asm__ volatile ("csrw    stimecmph, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_stimecmph(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, stimecmph, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * vstimecmp - HRW - Virtual supervisor timer compare. 
+ */
+static inline uint64_t csr_read_vstimecmp(void) {
+    uint_csr64_t value;        
+    __asm__ volatile ("csrr    %0, vstimecmp" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_vstimecmp(uint_csr64_t value) {
+    __asm__ volatile ("csrw    vstimecmp, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint64_t csr_read_write_vstimecmp(uint64_t new_value) {
+    uint_csr64_t prev_value;
+    __asm__ volatile ("csrrw    %0, vstimecmp, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * vstimecmph - HRW - Upper 32 bits of vstimecmp, RV32 only. 
+ */
+static inline uint32_t csr_read_vstimecmph(void) {
+    uint_csr32_t value;        
+    __asm__ volatile ("csrr    %0, vstimecmph" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_vstimecmph(uint_csr32_t value) {
+    __asm__ volatile ("csrw    vstimecmph, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint32_t csr_read_write_vstimecmph(uint32_t new_value) {
+    uint_csr32_t prev_value;
+    __asm__ volatile ("csrrw    %0, vstimecmph, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * vstart - URW - Vector start position. 
+ */
+static inline uint_xlen_t csr_read_vstart(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, vstart" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_vstart(uint_xlen_t value) {
+    __asm__ volatile ("csrw    vstart, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_vstart(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, vstart, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * vxsat - URW - Fixed-point accrued saturation flag. 
+ */
+static inline uint_xlen_t csr_read_vxsat(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, vxsat" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_vxsat(uint_xlen_t value) {
+    __asm__ volatile ("csrw    vxsat, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_vxsat(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, vxsat, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * vxrm - URW - Fixed-point rounding mode. 
+ */
+static inline uint_xlen_t csr_read_vxrm(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, vxrm" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_vxrm(uint_xlen_t value) {
+    __asm__ volatile ("csrw    vxrm, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_vxrm(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, vxrm, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+
+/*******************************************
+ * vcsr - URW - Vector control and status register. 
+ */
+static inline uint_xlen_t csr_read_vcsr(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, vcsr" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline void csr_write_vcsr(uint_xlen_t value) {
+    __asm__ volatile ("csrw    vcsr, %0" 
+                      : /* output: none */ 
+                      : "r" (value) /* input : from register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_write_vcsr(uint_xlen_t new_value) {
+    uint_xlen_t prev_value;
+    __asm__ volatile ("csrrw    %0, vcsr, %1"  
+                      : "=r" (prev_value) /* output: register %0 */
+                      : "r" (new_value)  /* input : register */
+                      : /* clobbers: none */);
+    return prev_value;
+}
+/* Register CSR bit set and clear instructions */
+static inline void csr_set_bits_vcsr(uint_xlen_t mask) {
+    __asm__ volatile ("csrrs    zero, vcsr, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline void csr_clr_bits_vcsr(uint_xlen_t mask) {
+    __asm__ volatile ("csrrc    zero, vcsr, %0"  
+                      : /* output: none */ 
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+}
+static inline uint_xlen_t csr_read_set_bits_vcsr(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrs    %0, vcsr, %1"  
+                      : "=r" (value) /* output: register %0 */
+                      : "r" (mask)  /* input : register */
+                      : /* clobbers: none */);
+    return value;
+}
+static inline uint_xlen_t csr_read_clr_bits_vcsr(uint_xlen_t mask) {
+    uint_xlen_t value;
+    __asm__ volatile ("csrrc    %0, vcsr, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+    return value;
+}
+/* vcsr, CSR write value via immediate value (only up to 5 bits) */
+#define CSR_WRITE_IMM_VCSR(VALUE)                    \
+    __asm__ volatile ("csrrwi    zero, vcsr, %0"           \
+                      : /* output: none */                         \
+                      : "i" (VALUE)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* vcsr, CSR set bits via immediate value mask (only up to 5 bits) */
+#define CSR_SET_BITS_IMM_VCSR(MASK)                 \
+    __asm__ volatile ("csrrsi    zero, vcsr, %0"          \
+                      : /* output: none */                        \
+                      : "i" (MASK)  /* input : immediate  */      \
+                      : /* clobbers: none */)
+
+/* vcsr, CSR clear bits via immediate value mask (only up to 5 bits) */
+#define CSR_CLR_BITS_IMM_VCSR(MASK)               \
+    __asm__ volatile ("csrrci    zero, vcsr, %0"        \
+                      : /* output: none */                      \
+                      : "i" (MASK)  /* input : immediate */     \
+                      : /* clobbers: none */)
+#define VCSR_VXSAT_BIT_OFFSET   0
+#define VCSR_VXSAT_BIT_WIDTH    1
+#define VCSR_VXSAT_BIT_MASK     0x1
+#define VCSR_VXSAT_ALL_SET_MASK 0x1
+#define VCSR_VXRM_BIT_OFFSET   1
+#define VCSR_VXRM_BIT_WIDTH    2
+#define VCSR_VXRM_BIT_MASK     0x6
+#define VCSR_VXRM_ALL_SET_MASK 0x3
+
+/*******************************************
+ * vl - URO - Vector length. 
+ */
+static inline uint_xlen_t csr_read_vl(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, vl" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+
+/*******************************************
+ * vtype - URO - Vector data type register. 
+ */
+static inline uint_xlen_t csr_read_vtype(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, vtype" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+#define VTYPE_VLMUL_BIT_OFFSET   0
+#define VTYPE_VLMUL_BIT_WIDTH    3
+#define VTYPE_VLMUL_BIT_MASK     0x7
+#define VTYPE_VLMUL_ALL_SET_MASK 0x7
+#define VTYPE_VSEW_BIT_OFFSET   3
+#define VTYPE_VSEW_BIT_WIDTH    3
+#define VTYPE_VSEW_BIT_MASK     0x38
+#define VTYPE_VSEW_ALL_SET_MASK 0x7
+#define VTYPE_VTA_BIT_OFFSET   6
+#define VTYPE_VTA_BIT_WIDTH    1
+#define VTYPE_VTA_BIT_MASK     0x40
+#define VTYPE_VTA_ALL_SET_MASK 0x1
+#define VTYPE_VMA_BIT_OFFSET   7
+#define VTYPE_VMA_BIT_WIDTH    1
+#define VTYPE_VMA_BIT_MASK     0x80
+#define VTYPE_VMA_ALL_SET_MASK 0x1
+#define VTYPE_VILL_BIT_OFFSET   (__riscv_xlen-1)
+#define VTYPE_VILL_BIT_WIDTH    1
+#define VTYPE_VILL_BIT_MASK     (0x1UL << ((__riscv_xlen-1)))
+#define VTYPE_VILL_ALL_SET_MASK 0x1
+
+/*******************************************
+ * vlenb - URO - VLEN/8 (vector register length in bytes). 
+ */
+static inline uint_xlen_t csr_read_vlenb(void) {
+    uint_xlen_t value;        
+    __asm__ volatile ("csrr    %0, vlenb" 
+                      : "=r" (value)  /* output : register */
+                      : /* input : none */
+                      : /* clobbers: none */);
+    return value;
+}
+
 
 #endif // #define RISCV_CSR_H
\ No newline at end of file
@@ -381,6 +381,37 @@ namespace riscv {
             }
             
         }; /* misa_ops */
+        /** Parameter data for fields in misa */
+        namespace misa_data {
+            /** Parameter data for extensions */
+            struct extensions {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 26;
+                static constexpr uint_xlen_t BIT_MASK   = 0x3ffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3ffffff;
+            };
+#if __riscv_xlen==32
+            /** Parameter data for mxl */
+            struct mxl {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 30;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0xc0000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for mxl */
+            struct mxl {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 62;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0xc000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+#endif
+        } /* misa_data */
 
         // ----------------------------------------------------------------
         // mvendorid - MRO - Machine Vendor ID 
@@ -402,6 +433,25 @@ namespace riscv {
             
             
         }; /* mvendorid_ops */
+        /** Parameter data for fields in mvendorid */
+        namespace mvendorid_data {
+            /** Parameter data for offset */
+            struct offset {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 0;
+                static constexpr uint_csr32_t BIT_WIDTH  = 7;
+                static constexpr uint_csr32_t BIT_MASK   = 0x7f;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x7f;
+            };
+            /** Parameter data for bank */
+            struct bank {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 7;
+                static constexpr uint_csr32_t BIT_WIDTH  = 25;
+                static constexpr uint_csr32_t BIT_MASK   = 0xffffff80;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1ffffff;
+            };
+        } /* mvendorid_data */
 
         // ----------------------------------------------------------------
         // marchid - MRO - Machine Architecture ID 
@@ -604,9 +654,9 @@ namespace riscv {
             /** Parameter data for sie */
             struct sie {
                 using datatype = uint_xlen_t;
-                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
                 static constexpr uint_xlen_t BIT_WIDTH  = 1;
-                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2;
                 static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
             };
             /** Parameter data for mpie */
@@ -649,6 +699,182 @@ namespace riscv {
                 static constexpr uint_xlen_t BIT_MASK   = 0x100;
                 static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
             };
+            /** Parameter data for ube */
+            struct ube {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vs */
+            struct vs {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 9;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x600;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+            /** Parameter data for fs */
+            struct fs {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x6000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+            /** Parameter data for xs */
+            struct xs {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 15;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x18000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+            /** Parameter data for sum */
+            struct sum {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 18;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for mxr */
+            struct mxr {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 19;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for tvm */
+            struct tvm {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 20;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x100000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for tw */
+            struct tw {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 21;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x200000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for tsr */
+            struct tsr {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 22;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for spelp */
+            struct spelp {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 23;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x800000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sdt */
+            struct sdt {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 24;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#if __riscv_xlen==64
+            /** Parameter data for uxl */
+            struct uxl {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 32;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x300000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for sxl */
+            struct sxl {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 34;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0xc00000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for sbe */
+            struct sbe {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 36;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for mbe */
+            struct mbe {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 37;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for gva */
+            struct gva {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 38;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for mpv */
+            struct mpv {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 39;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for mpelp */
+            struct mpelp {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 41;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x20000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for mdt */
+            struct mdt {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 42;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+            /** Parameter data for sd */
+            struct sd {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = (__riscv_xlen-1);
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = (0x1UL << ((__riscv_xlen-1)));
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
         } /* mstatus_data */
 
         // ----------------------------------------------------------------
@@ -776,6 +1002,57 @@ namespace riscv {
             }
             
         }; /* mstatush_ops */
+        /** Parameter data for fields in mstatush */
+        namespace mstatush_data {
+            /** Parameter data for sbe */
+            struct sbe {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 4;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x10;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for mbe */
+            struct mbe {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 5;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x20;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for gva */
+            struct gva {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for mpv */
+            struct mpv {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 7;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for mpelp */
+            struct mpelp {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 9;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x200;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for mdt */
+            struct mdt {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 10;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* mstatush_data */
 
         // ----------------------------------------------------------------
         // mtvec - MRW - Machine Trap Vector Base Address 
@@ -909,8 +1186,8 @@ namespace riscv {
                 using datatype = uint_xlen_t;
                 static constexpr uint_xlen_t BIT_OFFSET = 2;
                 static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-1)-(2) + 1);
-                static constexpr uint_xlen_t BIT_MASK   = ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (2));
-                static constexpr uint_xlen_t ALL_SET_MASK = ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (0));
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (2));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (0));
             };
             /** Parameter data for mode */
             struct mode {
@@ -1047,6 +1324,153 @@ namespace riscv {
             }
             
         }; /* medeleg_ops */
+        /** Parameter data for fields in medeleg */
+        namespace medeleg_data {
+            /** Parameter data for instruction_misaligned */
+            struct instruction_misaligned {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for instruction_fault */
+            struct instruction_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for illegal_instruction */
+            struct illegal_instruction {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for breakpoint */
+            struct breakpoint {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 3;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for load_misaligned */
+            struct load_misaligned {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 4;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x10;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for load_fault */
+            struct load_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 5;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x20;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for store_misaligned */
+            struct store_misaligned {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for store_fault */
+            struct store_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 7;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for ecall_u */
+            struct ecall_u {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 8;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x100;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for ecall_s */
+            struct ecall_s {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 9;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x200;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for ecall_vs */
+            struct ecall_vs {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 10;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for instruction_page_fault */
+            struct instruction_page_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 12;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for load_page_fault */
+            struct load_page_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for store_page_fault */
+            struct store_page_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 15;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for instruction_guest_page_fault */
+            struct instruction_guest_page_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 20;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x100000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for load_guest_page_fault */
+            struct load_guest_page_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 21;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x200000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for virtual_instruction */
+            struct virtual_instruction {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 22;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for store_guest_page_fault */
+            struct store_guest_page_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 23;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x800000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* medeleg_data */
 
         // ----------------------------------------------------------------
         // mideleg - MRW - Machine Interrupt Delegation 
@@ -1173,27 +1597,94 @@ namespace riscv {
             }
             
         }; /* mideleg_ops */
-
-        // ----------------------------------------------------------------
-        // mip - MRW - Machine Interrupt Pending 
-        //
-        /** Machine Interrupt Pending assembler operations */
-        struct mip_ops  {
-            using datatype = uint_xlen_t;
-            static constexpr priv_t priv = MRW; 
-            
-            /** Read mip */
-            static uint_xlen_t read(void) {
-                uint_xlen_t value;        
-                __asm__ volatile ("csrr    %0, mip" 
-                                  : "=r" (value)  /* output : register */
-                                  : /* input : none */
-                                  : /* clobbers: none */);
-                return value;
-            }
-            
-            
-            /** Write mip */
+        /** Parameter data for fields in mideleg */
+        namespace mideleg_data {
+            /** Parameter data for ssi */
+            struct ssi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vssi */
+            struct vssi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sti */
+            struct sti {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 5;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x20;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsti */
+            struct vsti {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sei */
+            struct sei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 9;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x200;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsei */
+            struct vsei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 10;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sgei */
+            struct sgei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 12;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for lcofi */
+            struct lcofi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* mideleg_data */
+
+        // ----------------------------------------------------------------
+        // mip - MRW - Machine Interrupt Pending 
+        //
+        /** Machine Interrupt Pending assembler operations */
+        struct mip_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read mip */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, mip" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write mip */
             static void write(uint_xlen_t value) {
                 __asm__ volatile ("csrw    mip, %0" 
                                   : /* output: none */ 
@@ -1377,9 +1868,17 @@ namespace riscv {
             struct platform_defined {
                 using datatype = uint_xlen_t;
                 static constexpr uint_xlen_t BIT_OFFSET = 16;
-                static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen)-(16) + 1);
-                static constexpr uint_xlen_t BIT_MASK   = ((1UL<<(((__riscv_xlen)-(16) + 1)-1)) << (16));
-                static constexpr uint_xlen_t ALL_SET_MASK = ((1UL<<(((__riscv_xlen)-(16) + 1)-1)) << (0));
+                static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-1)-(16) + 1);
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (16));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (0));
+            };
+            /** Parameter data for lcofi */
+            struct lcofi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
             };
         } /* mip_data */
 
@@ -1586,9 +2085,17 @@ namespace riscv {
             struct platform_defined {
                 using datatype = uint_xlen_t;
                 static constexpr uint_xlen_t BIT_OFFSET = 16;
-                static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen)-(16) + 1);
-                static constexpr uint_xlen_t BIT_MASK   = ((1UL<<(((__riscv_xlen)-(16) + 1)-1)) << (16));
-                static constexpr uint_xlen_t ALL_SET_MASK = ((1UL<<(((__riscv_xlen)-(16) + 1)-1)) << (0));
+                static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-1)-(16) + 1);
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (16));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (0));
+            };
+            /** Parameter data for lcofi */
+            struct lcofi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
             };
         } /* mie_data */
 
@@ -2256,6 +2763,89 @@ namespace riscv {
             }
             
         }; /* mhpmevent3_ops */
+        /** Parameter data for fields in mhpmevent3 */
+        namespace mhpmevent3_data {
+#if __riscv_xlen==32
+            /** Parameter data for event */
+            struct event {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 32;
+                static constexpr uint_xlen_t BIT_MASK   = 0xffffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xffffffff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for event */
+            struct event {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 56;
+                static constexpr uint_xlen_t BIT_MASK   = 0xffffffffffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xffffffffffffff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for of */
+            struct of {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 63;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 62;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 61;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 60;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 59;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x800000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 58;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+        } /* mhpmevent3_data */
 
         // ----------------------------------------------------------------
         // mcounteren - MRW - Counter Enable 
@@ -2423,12 +3013,12 @@ namespace riscv {
         //
         /** Counter Enable assembler operations */
         struct scounteren_ops  {
-            using datatype = uint_xlen_t;
+            using datatype = uint_csr32_t;
             static constexpr priv_t priv = SRW; 
             
             /** Read scounteren */
-            static uint_xlen_t read(void) {
-                uint_xlen_t value;        
+            static uint32_t read(void) {
+                uint_csr32_t value;        
                 __asm__ volatile ("csrr    %0, scounteren" 
                                   : "=r" (value)  /* output : register */
                                   : /* input : none */
@@ -2438,22 +3028,22 @@ namespace riscv {
             
             
             /** Write scounteren */
-            static void write(uint_xlen_t value) {
+            static void write(uint_csr32_t value) {
                 __asm__ volatile ("csrw    scounteren, %0" 
                                   : /* output: none */ 
                                   : "r" (value) /* input : from register */
                                   : /* clobbers: none */);
             }
             /** Write immediate value to scounteren */
-            static void write_imm(uint_xlen_t value) {
+            static void write_imm(uint_csr32_t value) {
                 __asm__ volatile ("csrwi    scounteren, %0" 
                                   : /* output: none */ 
                                   : "i" (value) /* input : from immediate */
                                   : /* clobbers: none */);
             }
             /** Read and then write to scounteren */
-            static uint_xlen_t read_write(uint_xlen_t new_value) {
-                uint_xlen_t prev_value;
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
                 __asm__ volatile ("csrrw    %0, scounteren, %1"  
                                   : "=r" (prev_value) /* output: register %0 */
                                   : "r" (new_value)  /* input : register */
@@ -2461,8 +3051,8 @@ namespace riscv {
                 return prev_value;
             }
             /** Read and then write immediate value to scounteren */
-            static uint_xlen_t read_write_imm(const uint8_t new_value) {
-                uint_xlen_t prev_value;
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
                 __asm__ volatile ("csrrwi    %0, scounteren, %1"  
                                   : "=r" (prev_value) /* output: register %0 */
                                   : "i" (new_value)  /* input : register */
@@ -2474,15 +3064,15 @@ namespace riscv {
             // Register CSR bit set and clear instructions
 
             /** Atomic modify and set bits for scounteren */
-            static void set_bits(uint_xlen_t mask) {
+            static void set_bits(uint_csr32_t mask) {
                 __asm__ volatile ("csrrs    zero, scounteren, %0"  
                                   : /* output: none */ 
                                   : "r" (mask)  /* input : register */
                                   : /* clobbers: none */);
             }
             /** Atomic read and then and set bits for scounteren */
-            static uint32_t read_set_bits(uint_xlen_t mask) {
-                uint_xlen_t value;
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
                 __asm__ volatile ("csrrs    %0, scounteren, %1"  
                                   : "=r" (value) /* output: register %0 */
                                   : "r" (mask)  /* input : register */
@@ -2490,15 +3080,15 @@ namespace riscv {
                 return value;
             }
             /** Atomic modify and clear bits for scounteren */
-            static void clr_bits(uint_xlen_t mask) {
+            static void clr_bits(uint_csr32_t mask) {
                 __asm__ volatile ("csrrc    zero, scounteren, %0"  
                                   : /* output: none */ 
                                   : "r" (mask)  /* input : register */
                                   : /* clobbers: none */);
             }
             /** Atomic read and then and clear bits for scounteren */
-            static uint32_t read_clr_bits(uint_xlen_t mask) {
-                uint_xlen_t value;
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
                 __asm__ volatile ("csrrc    %0, scounteren, %1"  
                                   : "=r" (value) /* output: register %0 */
                                   : "r" (mask)  /* input : register */
@@ -2517,8 +3107,8 @@ namespace riscv {
                                   : /* clobbers: none */);
             }
             /** Atomic read and then and set bits from immediate for scounteren */
-            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
-                uint_xlen_t value;
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
                 __asm__ volatile ("csrrsi    %0, scounteren, %1"  
                                   : "=r" (value) /* output: register %0 */
                                   : "i" (mask)  /* input : register */
@@ -2533,8 +3123,8 @@ namespace riscv {
                                   : /* clobbers: none */);
             }
             /** Atomic read and then and clear bits from immediate for scounteren */
-            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
-                uint_xlen_t value;
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
                 __asm__ volatile ("csrrci    %0, scounteren, %1"  
                                   : "=r" (value) /* output: register %0 */
                                   : "i" (mask)  /* input : register */
@@ -2543,6 +3133,41 @@ namespace riscv {
             }
             
         }; /* scounteren_ops */
+        /** Parameter data for fields in scounteren */
+        namespace scounteren_data {
+            /** Parameter data for cy */
+            struct cy {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 0;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x1;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for tm */
+            struct tm {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 1;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x2;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for ir */
+            struct ir {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 2;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x4;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for hpm */
+            struct hpm {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 3;
+                static constexpr uint_csr32_t BIT_WIDTH  = 29;
+                static constexpr uint_csr32_t BIT_MASK   = 0xfffffff8;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1fffffff;
+            };
+        } /* scounteren_data */
 
         // ----------------------------------------------------------------
         // mscratch - MRW - Machine Mode Scratch Register 
@@ -2936,8 +3561,8 @@ namespace riscv {
                 using datatype = uint_xlen_t;
                 static constexpr uint_xlen_t BIT_OFFSET = 0;
                 static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-2)-(0) + 1);
-                static constexpr uint_xlen_t BIT_MASK   = ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0));
-                static constexpr uint_xlen_t ALL_SET_MASK = ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0));
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0));
             };
         } /* mcause_data */
 
@@ -3459,8 +4084,8 @@ namespace riscv {
                 using datatype = uint_xlen_t;
                 static constexpr uint_xlen_t BIT_OFFSET = 0;
                 static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-2)-(0) + 1);
-                static constexpr uint_xlen_t BIT_MASK   = ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0));
-                static constexpr uint_xlen_t ALL_SET_MASK = ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0));
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0));
             };
         } /* scause_data */
 
@@ -3594,9 +4219,9 @@ namespace riscv {
             /** Parameter data for sie */
             struct sie {
                 using datatype = uint_xlen_t;
-                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
                 static constexpr uint_xlen_t BIT_WIDTH  = 1;
-                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2;
                 static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
             };
             /** Parameter data for spie */
@@ -3615,6 +4240,88 @@ namespace riscv {
                 static constexpr uint_xlen_t BIT_MASK   = 0x100;
                 static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
             };
+            /** Parameter data for ube */
+            struct ube {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vs */
+            struct vs {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 9;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x600;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+            /** Parameter data for fs */
+            struct fs {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x6000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+            /** Parameter data for xs */
+            struct xs {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 15;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x18000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+            /** Parameter data for sum */
+            struct sum {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 18;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for mxr */
+            struct mxr {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 19;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for spelp */
+            struct spelp {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 23;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x800000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sdt */
+            struct sdt {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 24;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#if __riscv_xlen==64
+            /** Parameter data for uxl */
+            struct uxl {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 32;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x300000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+#endif
+            /** Parameter data for sd */
+            struct sd {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = (__riscv_xlen-1);
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = (0x1UL << ((__riscv_xlen-1)));
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
         } /* sstatus_data */
 
         // ----------------------------------------------------------------
@@ -3749,8 +4456,8 @@ namespace riscv {
                 using datatype = uint_xlen_t;
                 static constexpr uint_xlen_t BIT_OFFSET = 2;
                 static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-1)-(2) + 1);
-                static constexpr uint_xlen_t BIT_MASK   = ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (2));
-                static constexpr uint_xlen_t ALL_SET_MASK = ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (0));
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (2));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (0));
             };
             /** Parameter data for mode */
             struct mode {
@@ -4189,6 +4896,14 @@ namespace riscv {
                 static constexpr uint_xlen_t BIT_MASK   = 0x100;
                 static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
             };
+            /** Parameter data for lcofi */
+            struct lcofi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
         } /* sip_data */
 
         // ----------------------------------------------------------------
@@ -4366,6 +5081,14 @@ namespace riscv {
                 static constexpr uint_xlen_t BIT_MASK   = 0x100;
                 static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
             };
+            /** Parameter data for lcofi */
+            struct lcofi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
         } /* sie_data */
 
         // ----------------------------------------------------------------
@@ -5211,8 +5934,8 @@ namespace riscv {
                 using datatype = uint_xlen_t;
                 static constexpr uint_xlen_t BIT_OFFSET = 0;
                 static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-2)-(0) + 1);
-                static constexpr uint_xlen_t BIT_MASK   = ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0));
-                static constexpr uint_xlen_t ALL_SET_MASK = ((1UL<<(((__riscv_xlen-2)-(0) + 1)-1)) << (0));
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-2)-(0) + 1))) << (0));
             };
         } /* ucause_data */
 
@@ -5348,8 +6071,8 @@ namespace riscv {
                 using datatype = uint_xlen_t;
                 static constexpr uint_xlen_t BIT_OFFSET = 2;
                 static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-1)-(2) + 1);
-                static constexpr uint_xlen_t BIT_MASK   = ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (2));
-                static constexpr uint_xlen_t ALL_SET_MASK = ((1UL<<(((__riscv_xlen-1)-(2) + 1)-1)) << (0));
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (2));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (0));
             };
             /** Parameter data for mode */
             struct mode {
@@ -5612,23 +6335,66 @@ namespace riscv {
             }
             
         }; /* fflags_ops */
-
-        // ----------------------------------------------------------------
-        // frm - URW - Floating-Point Dynamic Rounding Mode. 
-        //
-        /** Floating-Point Dynamic Rounding Mode. assembler operations */
-        struct frm_ops  {
-            using datatype = uint_xlen_t;
-            static constexpr priv_t priv = URW; 
-            
-            /** Read frm */
-            static uint_xlen_t read(void) {
-                uint_xlen_t value;        
-                __asm__ volatile ("csrr    %0, frm" 
-                                  : "=r" (value)  /* output : register */
-                                  : /* input : none */
-                                  : /* clobbers: none */);
-                return value;
+        /** Parameter data for fields in fflags */
+        namespace fflags_data {
+            /** Parameter data for nx */
+            struct nx {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for uf */
+            struct uf {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for of */
+            struct of {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for dz */
+            struct dz {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 3;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for nv */
+            struct nv {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 4;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x10;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* fflags_data */
+
+        // ----------------------------------------------------------------
+        // frm - URW - Floating-Point Dynamic Rounding Mode. 
+        //
+        /** Floating-Point Dynamic Rounding Mode. assembler operations */
+        struct frm_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = URW; 
+            
+            /** Read frm */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, frm" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
             }
             
             
@@ -5864,6 +6630,57 @@ namespace riscv {
             }
             
         }; /* fcsr_ops */
+        /** Parameter data for fields in fcsr */
+        namespace fcsr_data {
+            /** Parameter data for nx */
+            struct nx {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for uf */
+            struct uf {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for of */
+            struct of {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for dz */
+            struct dz {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 3;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for nv */
+            struct nv {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 4;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x10;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for frm */
+            struct frm {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 5;
+                static constexpr uint_xlen_t BIT_WIDTH  = 3;
+                static constexpr uint_xlen_t BIT_MASK   = 0xe0;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x7;
+            };
+        } /* fcsr_data */
 
         // ----------------------------------------------------------------
         // cycle - URO - Cycle counter for RDCYCLE instruction. 
@@ -6368,6 +7185,69 @@ namespace riscv {
             }
             
         }; /* satp_ops */
+        /** Parameter data for fields in satp */
+        namespace satp_data {
+#if __riscv_xlen==32
+            /** Parameter data for mode */
+            struct mode {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 31;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for mode */
+            struct mode {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 60;
+                static constexpr uint_xlen_t BIT_WIDTH  = 4;
+                static constexpr uint_xlen_t BIT_MASK   = 0xf000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xf;
+            };
+#endif
+#if __riscv_xlen==32
+            /** Parameter data for asid */
+            struct asid {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 22;
+                static constexpr uint_xlen_t BIT_WIDTH  = 9;
+                static constexpr uint_xlen_t BIT_MASK   = 0x7fc00000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1ff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for asid */
+            struct asid {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 44;
+                static constexpr uint_xlen_t BIT_WIDTH  = 16;
+                static constexpr uint_xlen_t BIT_MASK   = 0xffff00000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xffff;
+            };
+#endif
+#if __riscv_xlen==32
+            /** Parameter data for ppn */
+            struct ppn {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 22;
+                static constexpr uint_xlen_t BIT_MASK   = 0x3fffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3fffff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for ppn */
+            struct ppn {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 44;
+                static constexpr uint_xlen_t BIT_MASK   = 0xfffffffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xfffffffffff;
+            };
+#endif
+        } /* satp_data */
 
         // ----------------------------------------------------------------
         // hstatus - HRW - Hypervisor status register. 
@@ -6494,6 +7374,91 @@ namespace riscv {
             }
             
         }; /* hstatus_ops */
+        /** Parameter data for fields in hstatus */
+        namespace hstatus_data {
+            /** Parameter data for vsbe */
+            struct vsbe {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 5;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x20;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for gva */
+            struct gva {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for spv */
+            struct spv {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 7;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for spvp */
+            struct spvp {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 8;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x100;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for hu */
+            struct hu {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 9;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x200;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vgein */
+            struct vgein {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 12;
+                static constexpr uint_xlen_t BIT_WIDTH  = 6;
+                static constexpr uint_xlen_t BIT_MASK   = 0x3f000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3f;
+            };
+            /** Parameter data for vtvm */
+            struct vtvm {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 20;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x100000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vtw */
+            struct vtw {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 21;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x200000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vtsr */
+            struct vtsr {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 22;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#if __riscv_xlen==64
+            /** Parameter data for vsxl */
+            struct vsxl {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 32;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x300000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+#endif
+        } /* hstatus_data */
 
         // ----------------------------------------------------------------
         // hedeleg - HRW - Hypervisor exception delegation register. 
@@ -6620,6 +7585,105 @@ namespace riscv {
             }
             
         }; /* hedeleg_ops */
+        /** Parameter data for fields in hedeleg */
+        namespace hedeleg_data {
+            /** Parameter data for instruction_misaligned */
+            struct instruction_misaligned {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for instruction_fault */
+            struct instruction_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for illegal_instruction */
+            struct illegal_instruction {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for breakpoint */
+            struct breakpoint {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 3;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for load_misaligned */
+            struct load_misaligned {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 4;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x10;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for load_fault */
+            struct load_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 5;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x20;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for store_misaligned */
+            struct store_misaligned {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for store_fault */
+            struct store_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 7;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for ecall_u */
+            struct ecall_u {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 8;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x100;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for instruction_page_fault */
+            struct instruction_page_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 12;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for load_page_fault */
+            struct load_page_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for store_page_fault */
+            struct store_page_fault {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 15;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* hedeleg_data */
 
         // ----------------------------------------------------------------
         // hideleg - HRW - Hypervisor interrupt delegation register. 
@@ -6746,6 +7810,41 @@ namespace riscv {
             }
             
         }; /* hideleg_ops */
+        /** Parameter data for fields in hideleg */
+        namespace hideleg_data {
+            /** Parameter data for vssi */
+            struct vssi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsti */
+            struct vsti {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsei */
+            struct vsei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 10;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for lcofi */
+            struct lcofi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 13;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* hideleg_data */
 
         // ----------------------------------------------------------------
         // hcounteren - HRW - Hypervisor counter enable. 
@@ -6998,27 +8097,90 @@ namespace riscv {
             }
             
         }; /* hgatp_ops */
-
-        // ----------------------------------------------------------------
-        // htimedelta - HRW - Delta for VS/VU-mode timer. 
-        //
-        /** Delta for VS/VU-mode timer. assembler operations */
-        struct htimedelta_ops  {
-            using datatype = uint_xlen_t;
-            static constexpr priv_t priv = HRW; 
-            
-            /** Read htimedelta */
-            static uint_xlen_t read(void) {
-                uint_xlen_t value;        
-                __asm__ volatile ("csrr    %0, htimedelta" 
-                                  : "=r" (value)  /* output : register */
-                                  : /* input : none */
-                                  : /* clobbers: none */);
-                return value;
-            }
-            
-            
-            /** Write htimedelta */
+        /** Parameter data for fields in hgatp */
+        namespace hgatp_data {
+#if __riscv_xlen==32
+            /** Parameter data for mode */
+            struct mode {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 31;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for mode */
+            struct mode {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 60;
+                static constexpr uint_xlen_t BIT_WIDTH  = 4;
+                static constexpr uint_xlen_t BIT_MASK   = 0xf000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xf;
+            };
+#endif
+#if __riscv_xlen==32
+            /** Parameter data for vmid */
+            struct vmid {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 22;
+                static constexpr uint_xlen_t BIT_WIDTH  = 7;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1fc00000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x7f;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vmid */
+            struct vmid {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 44;
+                static constexpr uint_xlen_t BIT_WIDTH  = 14;
+                static constexpr uint_xlen_t BIT_MASK   = 0x3fff00000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3fff;
+            };
+#endif
+#if __riscv_xlen==32
+            /** Parameter data for ppn */
+            struct ppn {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 22;
+                static constexpr uint_xlen_t BIT_MASK   = 0x3fffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3fffff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for ppn */
+            struct ppn {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 44;
+                static constexpr uint_xlen_t BIT_MASK   = 0xfffffffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xfffffffffff;
+            };
+#endif
+        } /* hgatp_data */
+
+        // ----------------------------------------------------------------
+        // htimedelta - HRW - Delta for VS/VU-mode timer. 
+        //
+        /** Delta for VS/VU-mode timer. assembler operations */
+        struct htimedelta_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read htimedelta */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, htimedelta" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write htimedelta */
             static void write(uint_xlen_t value) {
                 __asm__ volatile ("csrw    htimedelta, %0" 
                                   : /* output: none */ 
@@ -8384,6 +9546,69 @@ namespace riscv {
             }
             
         }; /* vsatp_ops */
+        /** Parameter data for fields in vsatp */
+        namespace vsatp_data {
+#if __riscv_xlen==32
+            /** Parameter data for mode */
+            struct mode {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 31;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for mode */
+            struct mode {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 60;
+                static constexpr uint_xlen_t BIT_WIDTH  = 4;
+                static constexpr uint_xlen_t BIT_MASK   = 0xf000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xf;
+            };
+#endif
+#if __riscv_xlen==32
+            /** Parameter data for asid */
+            struct asid {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 22;
+                static constexpr uint_xlen_t BIT_WIDTH  = 9;
+                static constexpr uint_xlen_t BIT_MASK   = 0x7fc00000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1ff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for asid */
+            struct asid {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 44;
+                static constexpr uint_xlen_t BIT_WIDTH  = 16;
+                static constexpr uint_xlen_t BIT_MASK   = 0xffff00000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xffff;
+            };
+#endif
+#if __riscv_xlen==32
+            /** Parameter data for ppn */
+            struct ppn {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 22;
+                static constexpr uint_xlen_t BIT_MASK   = 0x3fffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3fffff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for ppn */
+            struct ppn {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 44;
+                static constexpr uint_xlen_t BIT_MASK   = 0xfffffffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xfffffffffff;
+            };
+#endif
+        } /* vsatp_data */
 
         // ----------------------------------------------------------------
         // mbase - MRW - Base register. 
@@ -11030,6 +12255,89 @@ namespace riscv {
             }
             
         }; /* mhpmevent4_ops */
+        /** Parameter data for fields in mhpmevent4 */
+        namespace mhpmevent4_data {
+#if __riscv_xlen==32
+            /** Parameter data for event */
+            struct event {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 32;
+                static constexpr uint_xlen_t BIT_MASK   = 0xffffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xffffffff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for event */
+            struct event {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 56;
+                static constexpr uint_xlen_t BIT_MASK   = 0xffffffffffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xffffffffffffff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for of */
+            struct of {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 63;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 62;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 61;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 60;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 59;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x800000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 58;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+        } /* mhpmevent4_data */
 
         // ----------------------------------------------------------------
         // mhpmevent31 - MRW - Machine performance-monitoring event selector. 
@@ -11156,6 +12464,89 @@ namespace riscv {
             }
             
         }; /* mhpmevent31_ops */
+        /** Parameter data for fields in mhpmevent31 */
+        namespace mhpmevent31_data {
+#if __riscv_xlen==32
+            /** Parameter data for event */
+            struct event {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 32;
+                static constexpr uint_xlen_t BIT_MASK   = 0xffffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xffffffff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for event */
+            struct event {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 56;
+                static constexpr uint_xlen_t BIT_MASK   = 0xffffffffffffff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xffffffffffffff;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for of */
+            struct of {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 63;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x8000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 62;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 61;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 60;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 59;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x800000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 58;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+        } /* mhpmevent31_data */
 
         // ----------------------------------------------------------------
         // tselect - MRW - Debug/Trace trigger register select. 
@@ -12290,6 +13681,41 @@ namespace riscv {
             }
             
         }; /* hie_ops */
+        /** Parameter data for fields in hie */
+        namespace hie_data {
+            /** Parameter data for vssi */
+            struct vssi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsti */
+            struct vsti {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsei */
+            struct vsei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 10;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sgei */
+            struct sgei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 12;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* hie_data */
 
         // ----------------------------------------------------------------
         // hgeie - HRW - Hypervisor guest external interrupt-enable register. 
@@ -12416,6 +13842,17 @@ namespace riscv {
             }
             
         }; /* hgeie_ops */
+        /** Parameter data for fields in hgeie */
+        namespace hgeie_data {
+            /** Parameter data for gei */
+            struct gei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
+                static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-1)-(1) + 1);
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (1));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (0));
+            };
+        } /* hgeie_data */
 
         // ----------------------------------------------------------------
         // htval - HRW - Hypervisor bad guest physical address. 
@@ -12668,6 +14105,41 @@ namespace riscv {
             }
             
         }; /* hip_ops */
+        /** Parameter data for fields in hip */
+        namespace hip_data {
+            /** Parameter data for vssi */
+            struct vssi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsti */
+            struct vsti {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsei */
+            struct vsei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 10;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sgei */
+            struct sgei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 12;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* hip_data */
 
         // ----------------------------------------------------------------
         // htinst - HRW - Hypervisor trap instruction (transformed). 
@@ -12815,6 +14287,17 @@ namespace riscv {
             
             
         }; /* hgeip_ops */
+        /** Parameter data for fields in hgeip */
+        namespace hgeip_data {
+            /** Parameter data for gei */
+            struct gei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
+                static constexpr uint_xlen_t BIT_WIDTH  = ((__riscv_xlen-1)-(1) + 1);
+                static constexpr uint_xlen_t BIT_MASK   = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (1));
+                static constexpr uint_xlen_t ALL_SET_MASK = ((~0UL >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (0));
+            };
+        } /* hgeip_data */
 
         // ----------------------------------------------------------------
         // mtinst - MRW - Machine trap instruction (transformed). 
@@ -13067,32 +14550,4244 @@ namespace riscv {
             }
             
         }; /* mtval2_ops */
-    } /* csr */
-} /* riscv */
 
+        // ----------------------------------------------------------------
+        // mhpmevent3h - MRW - Upper 32 bits of mhpmevent3, RV32 only. 
+        //
+        /** Upper 32 bits of mhpmevent3, RV32 only. assembler operations */
+        struct mhpmevent3h_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read mhpmevent3h */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, mhpmevent3h" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write mhpmevent3h */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    mhpmevent3h, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to mhpmevent3h */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    mhpmevent3h, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to mhpmevent3h */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, mhpmevent3h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to mhpmevent3h */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, mhpmevent3h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
 
-// ------------------------------------------------------------------------
-// Register and field interface classes.
-namespace riscv {
-    namespace csr {
-        /* Machine ISA */
-        template<class OPS> class misa_reg : public read_write_reg<OPS>
-        {
-        };
-        using misa = misa_reg<riscv::csr::misa_ops>;
-        /* Machine Vendor ID */
-        template<class OPS> class mvendorid_reg : public read_only_reg<OPS>
-        {
-        };
-        using mvendorid = mvendorid_reg<riscv::csr::mvendorid_ops>;
-        /* Machine Architecture ID */
-        template<class OPS> class marchid_reg : public read_only_reg<OPS>
-        {
-        };
-        using marchid = marchid_reg<riscv::csr::marchid_ops>;
-        /* Machine Implementation ID */
-        template<class OPS> class mimpid_reg : public read_only_reg<OPS>
-        {
+            /** Atomic modify and set bits for mhpmevent3h */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, mhpmevent3h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for mhpmevent3h */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, mhpmevent3h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for mhpmevent3h */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, mhpmevent3h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for mhpmevent3h */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, mhpmevent3h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for mhpmevent3h */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, mhpmevent3h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for mhpmevent3h */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, mhpmevent3h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for mhpmevent3h */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, mhpmevent3h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for mhpmevent3h */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, mhpmevent3h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* mhpmevent3h_ops */
+        /** Parameter data for fields in mhpmevent3h */
+        namespace mhpmevent3h_data {
+            /** Parameter data for of */
+            struct of {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 31;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x80000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 30;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x40000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 29;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x20000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 28;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x10000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 27;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x8000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 26;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x4000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+        } /* mhpmevent3h_data */
+
+        // ----------------------------------------------------------------
+        // mhpmevent4h - MRW - Upper 32 bits of mhpmevent4, RV32 only. 
+        //
+        /** Upper 32 bits of mhpmevent4, RV32 only. assembler operations */
+        struct mhpmevent4h_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read mhpmevent4h */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, mhpmevent4h" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write mhpmevent4h */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    mhpmevent4h, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to mhpmevent4h */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    mhpmevent4h, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to mhpmevent4h */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, mhpmevent4h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to mhpmevent4h */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, mhpmevent4h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for mhpmevent4h */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, mhpmevent4h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for mhpmevent4h */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, mhpmevent4h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for mhpmevent4h */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, mhpmevent4h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for mhpmevent4h */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, mhpmevent4h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for mhpmevent4h */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, mhpmevent4h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for mhpmevent4h */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, mhpmevent4h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for mhpmevent4h */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, mhpmevent4h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for mhpmevent4h */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, mhpmevent4h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* mhpmevent4h_ops */
+        /** Parameter data for fields in mhpmevent4h */
+        namespace mhpmevent4h_data {
+            /** Parameter data for of */
+            struct of {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 31;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x80000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 30;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x40000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 29;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x20000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 28;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x10000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 27;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x8000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 26;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x4000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+        } /* mhpmevent4h_data */
+
+        // ----------------------------------------------------------------
+        // mhpmevent31h - MRW - Upper 32 bits of mhpmevent31, RV32 only. 
+        //
+        /** Upper 32 bits of mhpmevent31, RV32 only. assembler operations */
+        struct mhpmevent31h_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read mhpmevent31h */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, mhpmevent31h" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write mhpmevent31h */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    mhpmevent31h, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to mhpmevent31h */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    mhpmevent31h, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to mhpmevent31h */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, mhpmevent31h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to mhpmevent31h */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, mhpmevent31h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for mhpmevent31h */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, mhpmevent31h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for mhpmevent31h */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, mhpmevent31h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for mhpmevent31h */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, mhpmevent31h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for mhpmevent31h */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, mhpmevent31h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for mhpmevent31h */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, mhpmevent31h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for mhpmevent31h */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, mhpmevent31h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for mhpmevent31h */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, mhpmevent31h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for mhpmevent31h */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, mhpmevent31h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* mhpmevent31h_ops */
+        /** Parameter data for fields in mhpmevent31h */
+        namespace mhpmevent31h_data {
+            /** Parameter data for of */
+            struct of {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 31;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x80000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 30;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x40000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 29;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x20000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 28;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x10000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 27;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x8000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 26;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x4000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+        } /* mhpmevent31h_data */
+
+        // ----------------------------------------------------------------
+        // scountovf - SRO - Supervisor count overflow. 
+        //
+        /** Supervisor count overflow. assembler operations */
+        struct scountovf_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = SRO; 
+            
+            /** Read scountovf */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, scountovf" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+        }; /* scountovf_ops */
+        /** Parameter data for fields in scountovf */
+        namespace scountovf_data {
+            /** Parameter data for hpm */
+            struct hpm {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 3;
+                static constexpr uint_csr32_t BIT_WIDTH  = 29;
+                static constexpr uint_csr32_t BIT_MASK   = 0xfffffff8;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1fffffff;
+            };
+        } /* scountovf_data */
+
+        // ----------------------------------------------------------------
+        // mcyclecfg - MRW - Machine cycle counter configuration. 
+        //
+        /** Machine cycle counter configuration. assembler operations */
+        struct mcyclecfg_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read mcyclecfg */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, mcyclecfg" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write mcyclecfg */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    mcyclecfg, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to mcyclecfg */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    mcyclecfg, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to mcyclecfg */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, mcyclecfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to mcyclecfg */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, mcyclecfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for mcyclecfg */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, mcyclecfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for mcyclecfg */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, mcyclecfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for mcyclecfg */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, mcyclecfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for mcyclecfg */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, mcyclecfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for mcyclecfg */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, mcyclecfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for mcyclecfg */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, mcyclecfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for mcyclecfg */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, mcyclecfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for mcyclecfg */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, mcyclecfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* mcyclecfg_ops */
+        /** Parameter data for fields in mcyclecfg */
+        namespace mcyclecfg_data {
+#if __riscv_xlen==64
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 62;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 61;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 60;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 59;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x800000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 58;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+        } /* mcyclecfg_data */
+
+        // ----------------------------------------------------------------
+        // mcyclecfgh - MRW - Upper 32 bits of mcyclecfg, RV32 only. 
+        //
+        /** Upper 32 bits of mcyclecfg, RV32 only. assembler operations */
+        struct mcyclecfgh_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read mcyclecfgh */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, mcyclecfgh" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write mcyclecfgh */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    mcyclecfgh, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to mcyclecfgh */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    mcyclecfgh, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to mcyclecfgh */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, mcyclecfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to mcyclecfgh */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, mcyclecfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for mcyclecfgh */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, mcyclecfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for mcyclecfgh */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, mcyclecfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for mcyclecfgh */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, mcyclecfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for mcyclecfgh */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, mcyclecfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for mcyclecfgh */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, mcyclecfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for mcyclecfgh */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, mcyclecfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for mcyclecfgh */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, mcyclecfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for mcyclecfgh */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, mcyclecfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* mcyclecfgh_ops */
+        /** Parameter data for fields in mcyclecfgh */
+        namespace mcyclecfgh_data {
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 30;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x40000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 29;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x20000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 28;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x10000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 27;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x8000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 26;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x4000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+        } /* mcyclecfgh_data */
+
+        // ----------------------------------------------------------------
+        // minstretcfg - MRW - Machine instret counter configuration. 
+        //
+        /** Machine instret counter configuration. assembler operations */
+        struct minstretcfg_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read minstretcfg */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, minstretcfg" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write minstretcfg */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    minstretcfg, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to minstretcfg */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    minstretcfg, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to minstretcfg */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, minstretcfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to minstretcfg */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, minstretcfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for minstretcfg */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, minstretcfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for minstretcfg */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, minstretcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for minstretcfg */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, minstretcfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for minstretcfg */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, minstretcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for minstretcfg */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, minstretcfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for minstretcfg */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, minstretcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for minstretcfg */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, minstretcfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for minstretcfg */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, minstretcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* minstretcfg_ops */
+        /** Parameter data for fields in minstretcfg */
+        namespace minstretcfg_data {
+#if __riscv_xlen==64
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 62;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 61;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x2000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 60;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1000000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 59;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x800000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 58;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400000000000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+#endif
+        } /* minstretcfg_data */
+
+        // ----------------------------------------------------------------
+        // minstretcfgh - MRW - Upper 32 bits of minstretcfg, RV32 only. 
+        //
+        /** Upper 32 bits of minstretcfg, RV32 only. assembler operations */
+        struct minstretcfgh_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read minstretcfgh */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, minstretcfgh" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write minstretcfgh */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    minstretcfgh, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to minstretcfgh */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    minstretcfgh, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to minstretcfgh */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, minstretcfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to minstretcfgh */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, minstretcfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for minstretcfgh */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, minstretcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for minstretcfgh */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, minstretcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for minstretcfgh */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, minstretcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for minstretcfgh */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, minstretcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for minstretcfgh */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, minstretcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for minstretcfgh */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, minstretcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for minstretcfgh */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, minstretcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for minstretcfgh */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, minstretcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* minstretcfgh_ops */
+        /** Parameter data for fields in minstretcfgh */
+        namespace minstretcfgh_data {
+            /** Parameter data for minh */
+            struct minh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 30;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x40000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for sinh */
+            struct sinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 29;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x20000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for uinh */
+            struct uinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 28;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x10000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsinh */
+            struct vsinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 27;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x8000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vuinh */
+            struct vuinh {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 26;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x4000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+        } /* minstretcfgh_data */
+
+        // ----------------------------------------------------------------
+        // menvcfg - MRW - Machine environment configuration. 
+        //
+        /** Machine environment configuration. assembler operations */
+        struct menvcfg_ops  {
+            using datatype = uint_csr64_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read menvcfg */
+            static uint64_t read(void) {
+                uint_csr64_t value;        
+                __asm__ volatile ("csrr    %0, menvcfg" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write menvcfg */
+            static void write(uint_csr64_t value) {
+                __asm__ volatile ("csrw    menvcfg, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to menvcfg */
+            static void write_imm(uint_csr64_t value) {
+                __asm__ volatile ("csrwi    menvcfg, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to menvcfg */
+            static uint64_t read_write(uint_csr64_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrw    %0, menvcfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to menvcfg */
+            static uint64_t read_write_imm(const uint8_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrwi    %0, menvcfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for menvcfg */
+            static void set_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrs    zero, menvcfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for menvcfg */
+            static uint32_t read_set_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrs    %0, menvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for menvcfg */
+            static void clr_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrc    zero, menvcfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for menvcfg */
+            static uint32_t read_clr_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrc    %0, menvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for menvcfg */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, menvcfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for menvcfg */
+            static uint64_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrsi    %0, menvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for menvcfg */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, menvcfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for menvcfg */
+            static uint64_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrci    %0, menvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* menvcfg_ops */
+        /** Parameter data for fields in menvcfg */
+        namespace menvcfg_data {
+            /** Parameter data for fiom */
+            struct fiom {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 0;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x1;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for cbie */
+            struct cbie {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 4;
+                static constexpr uint_csr64_t BIT_WIDTH  = 2;
+                static constexpr uint_csr64_t BIT_MASK   = 0x30;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x3;
+            };
+            /** Parameter data for cbcfe */
+            struct cbcfe {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 6;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x40;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for cbze */
+            struct cbze {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 7;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x80;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+#if __riscv_xlen==64
+            /** Parameter data for adue */
+            struct adue {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 61;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x2000000000000000;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for pbmte */
+            struct pbmte {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 62;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x4000000000000000;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for stce */
+            struct stce {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 63;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x8000000000000000;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+#endif
+        } /* menvcfg_data */
+
+        // ----------------------------------------------------------------
+        // menvcfgh - MRW - Upper 32 bits of menvcfg, RV32 only. 
+        //
+        /** Upper 32 bits of menvcfg, RV32 only. assembler operations */
+        struct menvcfgh_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read menvcfgh */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, menvcfgh" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write menvcfgh */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    menvcfgh, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to menvcfgh */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    menvcfgh, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to menvcfgh */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, menvcfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to menvcfgh */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, menvcfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for menvcfgh */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, menvcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for menvcfgh */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, menvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for menvcfgh */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, menvcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for menvcfgh */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, menvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for menvcfgh */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, menvcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for menvcfgh */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, menvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for menvcfgh */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, menvcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for menvcfgh */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, menvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* menvcfgh_ops */
+        /** Parameter data for fields in menvcfgh */
+        namespace menvcfgh_data {
+            /** Parameter data for adue */
+            struct adue {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 29;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x20000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for pbmte */
+            struct pbmte {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 30;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x40000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for stce */
+            struct stce {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 31;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x80000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+        } /* menvcfgh_data */
+
+        // ----------------------------------------------------------------
+        // henvcfg - HRW - Hypervisor environment configuration. 
+        //
+        /** Hypervisor environment configuration. assembler operations */
+        struct henvcfg_ops  {
+            using datatype = uint_csr64_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read henvcfg */
+            static uint64_t read(void) {
+                uint_csr64_t value;        
+                __asm__ volatile ("csrr    %0, henvcfg" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write henvcfg */
+            static void write(uint_csr64_t value) {
+                __asm__ volatile ("csrw    henvcfg, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to henvcfg */
+            static void write_imm(uint_csr64_t value) {
+                __asm__ volatile ("csrwi    henvcfg, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to henvcfg */
+            static uint64_t read_write(uint_csr64_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrw    %0, henvcfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to henvcfg */
+            static uint64_t read_write_imm(const uint8_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrwi    %0, henvcfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for henvcfg */
+            static void set_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrs    zero, henvcfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for henvcfg */
+            static uint32_t read_set_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrs    %0, henvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for henvcfg */
+            static void clr_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrc    zero, henvcfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for henvcfg */
+            static uint32_t read_clr_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrc    %0, henvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for henvcfg */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, henvcfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for henvcfg */
+            static uint64_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrsi    %0, henvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for henvcfg */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, henvcfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for henvcfg */
+            static uint64_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrci    %0, henvcfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* henvcfg_ops */
+        /** Parameter data for fields in henvcfg */
+        namespace henvcfg_data {
+            /** Parameter data for fiom */
+            struct fiom {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 0;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x1;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for cbie */
+            struct cbie {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 4;
+                static constexpr uint_csr64_t BIT_WIDTH  = 2;
+                static constexpr uint_csr64_t BIT_MASK   = 0x30;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x3;
+            };
+            /** Parameter data for cbcfe */
+            struct cbcfe {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 6;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x40;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for cbze */
+            struct cbze {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 7;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x80;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+#if __riscv_xlen==64
+            /** Parameter data for adue */
+            struct adue {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 61;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x2000000000000000;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for pbmte */
+            struct pbmte {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 62;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x4000000000000000;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+#endif
+#if __riscv_xlen==64
+            /** Parameter data for stce */
+            struct stce {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 63;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x8000000000000000;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+#endif
+        } /* henvcfg_data */
+
+        // ----------------------------------------------------------------
+        // henvcfgh - HRW - Upper 32 bits of henvcfg, RV32 only. 
+        //
+        /** Upper 32 bits of henvcfg, RV32 only. assembler operations */
+        struct henvcfgh_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read henvcfgh */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, henvcfgh" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write henvcfgh */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    henvcfgh, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to henvcfgh */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    henvcfgh, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to henvcfgh */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, henvcfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to henvcfgh */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, henvcfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for henvcfgh */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, henvcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for henvcfgh */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, henvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for henvcfgh */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, henvcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for henvcfgh */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, henvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for henvcfgh */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, henvcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for henvcfgh */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, henvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for henvcfgh */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, henvcfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for henvcfgh */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, henvcfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* henvcfgh_ops */
+        /** Parameter data for fields in henvcfgh */
+        namespace henvcfgh_data {
+            /** Parameter data for adue */
+            struct adue {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 29;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x20000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for pbmte */
+            struct pbmte {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 30;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x40000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for stce */
+            struct stce {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr32_t BIT_OFFSET = 31;
+                static constexpr uint_csr32_t BIT_WIDTH  = 1;
+                static constexpr uint_csr32_t BIT_MASK   = 0x80000000;
+                static constexpr uint_csr32_t ALL_SET_MASK = 0x1;
+            };
+        } /* henvcfgh_data */
+
+        // ----------------------------------------------------------------
+        // hvip - HRW - Hypervisor virtual interrupt pending. 
+        //
+        /** Hypervisor virtual interrupt pending. assembler operations */
+        struct hvip_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read hvip */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, hvip" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write hvip */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    hvip, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to hvip */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    hvip, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to hvip */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, hvip, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to hvip */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, hvip, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for hvip */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, hvip, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for hvip */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, hvip, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for hvip */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, hvip, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for hvip */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, hvip, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for hvip */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, hvip, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for hvip */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, hvip, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for hvip */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, hvip, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for hvip */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, hvip, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* hvip_ops */
+        /** Parameter data for fields in hvip */
+        namespace hvip_data {
+            /** Parameter data for vssi */
+            struct vssi {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 2;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x4;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsti */
+            struct vsti {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vsei */
+            struct vsei {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 10;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x400;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* hvip_data */
+
+        // ----------------------------------------------------------------
+        // hvictl - HRW - Hypervisor virtual interrupt control. 
+        //
+        /** Hypervisor virtual interrupt control. assembler operations */
+        struct hvictl_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read hvictl */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, hvictl" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write hvictl */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    hvictl, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to hvictl */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    hvictl, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to hvictl */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, hvictl, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to hvictl */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, hvictl, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for hvictl */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, hvictl, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for hvictl */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, hvictl, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for hvictl */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, hvictl, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for hvictl */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, hvictl, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for hvictl */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, hvictl, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for hvictl */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, hvictl, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for hvictl */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, hvictl, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for hvictl */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, hvictl, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* hvictl_ops */
+        /** Parameter data for fields in hvictl */
+        namespace hvictl_data {
+            /** Parameter data for iprio */
+            struct iprio {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 8;
+                static constexpr uint_xlen_t BIT_MASK   = 0xff;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xff;
+            };
+            /** Parameter data for ipriom */
+            struct ipriom {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 8;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x100;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for dpr */
+            struct dpr {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 9;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x200;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for iid */
+            struct iid {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 16;
+                static constexpr uint_xlen_t BIT_WIDTH  = 12;
+                static constexpr uint_xlen_t BIT_MASK   = 0xfff0000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0xfff;
+            };
+            /** Parameter data for vti */
+            struct vti {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 30;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40000000;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* hvictl_data */
+
+        // ----------------------------------------------------------------
+        // hviprio1 - HRW - Hypervisor VS-level interrupt priorities. 
+        //
+        /** Hypervisor VS-level interrupt priorities. assembler operations */
+        struct hviprio1_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read hviprio1 */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, hviprio1" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write hviprio1 */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    hviprio1, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to hviprio1 */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    hviprio1, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to hviprio1 */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, hviprio1, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to hviprio1 */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, hviprio1, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for hviprio1 */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, hviprio1, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for hviprio1 */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, hviprio1, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for hviprio1 */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, hviprio1, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for hviprio1 */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, hviprio1, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for hviprio1 */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, hviprio1, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for hviprio1 */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, hviprio1, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for hviprio1 */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, hviprio1, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for hviprio1 */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, hviprio1, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* hviprio1_ops */
+
+        // ----------------------------------------------------------------
+        // hviprio2 - HRW - Hypervisor VS-level interrupt priorities. 
+        //
+        /** Hypervisor VS-level interrupt priorities. assembler operations */
+        struct hviprio2_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read hviprio2 */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, hviprio2" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write hviprio2 */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    hviprio2, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to hviprio2 */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    hviprio2, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to hviprio2 */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, hviprio2, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to hviprio2 */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, hviprio2, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for hviprio2 */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, hviprio2, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for hviprio2 */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, hviprio2, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for hviprio2 */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, hviprio2, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for hviprio2 */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, hviprio2, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for hviprio2 */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, hviprio2, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for hviprio2 */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, hviprio2, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for hviprio2 */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, hviprio2, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for hviprio2 */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, hviprio2, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* hviprio2_ops */
+
+        // ----------------------------------------------------------------
+        // hviprio1h - HRW - Upper 32 bits of hviprio1, RV32 only. 
+        //
+        /** Upper 32 bits of hviprio1, RV32 only. assembler operations */
+        struct hviprio1h_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read hviprio1h */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, hviprio1h" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write hviprio1h */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    hviprio1h, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to hviprio1h */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    hviprio1h, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to hviprio1h */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, hviprio1h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to hviprio1h */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, hviprio1h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for hviprio1h */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, hviprio1h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for hviprio1h */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, hviprio1h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for hviprio1h */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, hviprio1h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for hviprio1h */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, hviprio1h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for hviprio1h */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, hviprio1h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for hviprio1h */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, hviprio1h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for hviprio1h */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, hviprio1h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for hviprio1h */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, hviprio1h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* hviprio1h_ops */
+
+        // ----------------------------------------------------------------
+        // hviprio2h - HRW - Upper 32 bits of hviprio2, RV32 only. 
+        //
+        /** Upper 32 bits of hviprio2, RV32 only. assembler operations */
+        struct hviprio2h_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read hviprio2h */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, hviprio2h" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write hviprio2h */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    hviprio2h, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to hviprio2h */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    hviprio2h, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to hviprio2h */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, hviprio2h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to hviprio2h */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, hviprio2h, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for hviprio2h */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, hviprio2h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for hviprio2h */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, hviprio2h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for hviprio2h */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, hviprio2h, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for hviprio2h */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, hviprio2h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for hviprio2h */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, hviprio2h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for hviprio2h */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, hviprio2h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for hviprio2h */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, hviprio2h, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for hviprio2h */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, hviprio2h, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* hviprio2h_ops */
+
+        // ----------------------------------------------------------------
+        // mconfigptr - MRO - Pointer to configuration data structure. 
+        //
+        /** Pointer to configuration data structure. assembler operations */
+        struct mconfigptr_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = MRO; 
+            
+            /** Read mconfigptr */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, mconfigptr" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+        }; /* mconfigptr_ops */
+
+        // ----------------------------------------------------------------
+        // mseccfg - MRW - Machine security configuration. 
+        //
+        /** Machine security configuration. assembler operations */
+        struct mseccfg_ops  {
+            using datatype = uint_csr64_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read mseccfg */
+            static uint64_t read(void) {
+                uint_csr64_t value;        
+                __asm__ volatile ("csrr    %0, mseccfg" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write mseccfg */
+            static void write(uint_csr64_t value) {
+                __asm__ volatile ("csrw    mseccfg, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to mseccfg */
+            static void write_imm(uint_csr64_t value) {
+                __asm__ volatile ("csrwi    mseccfg, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to mseccfg */
+            static uint64_t read_write(uint_csr64_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrw    %0, mseccfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to mseccfg */
+            static uint64_t read_write_imm(const uint8_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrwi    %0, mseccfg, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for mseccfg */
+            static void set_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrs    zero, mseccfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for mseccfg */
+            static uint32_t read_set_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrs    %0, mseccfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for mseccfg */
+            static void clr_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrc    zero, mseccfg, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for mseccfg */
+            static uint32_t read_clr_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrc    %0, mseccfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for mseccfg */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, mseccfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for mseccfg */
+            static uint64_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrsi    %0, mseccfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for mseccfg */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, mseccfg, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for mseccfg */
+            static uint64_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrci    %0, mseccfg, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* mseccfg_ops */
+        /** Parameter data for fields in mseccfg */
+        namespace mseccfg_data {
+            /** Parameter data for mml */
+            struct mml {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 0;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x1;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for mmwp */
+            struct mmwp {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 1;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x2;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for rlb */
+            struct rlb {
+                using datatype = uint_xlen_t;
+                static constexpr uint_csr64_t BIT_OFFSET = 2;
+                static constexpr uint_csr64_t BIT_WIDTH  = 1;
+                static constexpr uint_csr64_t BIT_MASK   = 0x4;
+                static constexpr uint_csr64_t ALL_SET_MASK = 0x1;
+            };
+        } /* mseccfg_data */
+
+        // ----------------------------------------------------------------
+        // mseccfgh - MRW - Upper 32 bits of mseccfg, RV32 only. 
+        //
+        /** Upper 32 bits of mseccfg, RV32 only. assembler operations */
+        struct mseccfgh_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = MRW; 
+            
+            /** Read mseccfgh */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, mseccfgh" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write mseccfgh */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    mseccfgh, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to mseccfgh */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    mseccfgh, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to mseccfgh */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, mseccfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to mseccfgh */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, mseccfgh, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for mseccfgh */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, mseccfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for mseccfgh */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, mseccfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for mseccfgh */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, mseccfgh, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for mseccfgh */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, mseccfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for mseccfgh */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, mseccfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for mseccfgh */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, mseccfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for mseccfgh */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, mseccfgh, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for mseccfgh */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, mseccfgh, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* mseccfgh_ops */
+
+        // ----------------------------------------------------------------
+        // stimecmp - SRW - Supervisor timer compare. 
+        //
+        /** Supervisor timer compare. assembler operations */
+        struct stimecmp_ops  {
+            using datatype = uint_csr64_t;
+            static constexpr priv_t priv = SRW; 
+            
+            /** Read stimecmp */
+            static uint64_t read(void) {
+                uint_csr64_t value;        
+                __asm__ volatile ("csrr    %0, stimecmp" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write stimecmp */
+            static void write(uint_csr64_t value) {
+                __asm__ volatile ("csrw    stimecmp, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to stimecmp */
+            static void write_imm(uint_csr64_t value) {
+                __asm__ volatile ("csrwi    stimecmp, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to stimecmp */
+            static uint64_t read_write(uint_csr64_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrw    %0, stimecmp, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to stimecmp */
+            static uint64_t read_write_imm(const uint8_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrwi    %0, stimecmp, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for stimecmp */
+            static void set_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrs    zero, stimecmp, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for stimecmp */
+            static uint32_t read_set_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrs    %0, stimecmp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for stimecmp */
+            static void clr_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrc    zero, stimecmp, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for stimecmp */
+            static uint32_t read_clr_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrc    %0, stimecmp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for stimecmp */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, stimecmp, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for stimecmp */
+            static uint64_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrsi    %0, stimecmp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for stimecmp */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, stimecmp, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for stimecmp */
+            static uint64_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrci    %0, stimecmp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* stimecmp_ops */
+
+        // ----------------------------------------------------------------
+        // stimecmph - SRW - Upper 32 bits of stimecmp, RV32 only. 
+        //
+        /** Upper 32 bits of stimecmp, RV32 only. assembler operations */
+        struct stimecmph_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = SRW; 
+            
+            /** Read stimecmph */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, stimecmph" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write stimecmph */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    stimecmph, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to stimecmph */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    stimecmph, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to stimecmph */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, stimecmph, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to stimecmph */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, stimecmph, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for stimecmph */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, stimecmph, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for stimecmph */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, stimecmph, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for stimecmph */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, stimecmph, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for stimecmph */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, stimecmph, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for stimecmph */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, stimecmph, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for stimecmph */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, stimecmph, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for stimecmph */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, stimecmph, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for stimecmph */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, stimecmph, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* stimecmph_ops */
+
+        // ----------------------------------------------------------------
+        // vstimecmp - HRW - Virtual supervisor timer compare. 
+        //
+        /** Virtual supervisor timer compare. assembler operations */
+        struct vstimecmp_ops  {
+            using datatype = uint_csr64_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read vstimecmp */
+            static uint64_t read(void) {
+                uint_csr64_t value;        
+                __asm__ volatile ("csrr    %0, vstimecmp" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write vstimecmp */
+            static void write(uint_csr64_t value) {
+                __asm__ volatile ("csrw    vstimecmp, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to vstimecmp */
+            static void write_imm(uint_csr64_t value) {
+                __asm__ volatile ("csrwi    vstimecmp, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to vstimecmp */
+            static uint64_t read_write(uint_csr64_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrw    %0, vstimecmp, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to vstimecmp */
+            static uint64_t read_write_imm(const uint8_t new_value) {
+                uint_csr64_t prev_value;
+                __asm__ volatile ("csrrwi    %0, vstimecmp, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for vstimecmp */
+            static void set_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrs    zero, vstimecmp, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for vstimecmp */
+            static uint32_t read_set_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrs    %0, vstimecmp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for vstimecmp */
+            static void clr_bits(uint_csr64_t mask) {
+                __asm__ volatile ("csrrc    zero, vstimecmp, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for vstimecmp */
+            static uint32_t read_clr_bits(uint_csr64_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrc    %0, vstimecmp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for vstimecmp */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, vstimecmp, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for vstimecmp */
+            static uint64_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrsi    %0, vstimecmp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for vstimecmp */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, vstimecmp, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for vstimecmp */
+            static uint64_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr64_t value;
+                __asm__ volatile ("csrrci    %0, vstimecmp, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* vstimecmp_ops */
+
+        // ----------------------------------------------------------------
+        // vstimecmph - HRW - Upper 32 bits of vstimecmp, RV32 only. 
+        //
+        /** Upper 32 bits of vstimecmp, RV32 only. assembler operations */
+        struct vstimecmph_ops  {
+            using datatype = uint_csr32_t;
+            static constexpr priv_t priv = HRW; 
+            
+            /** Read vstimecmph */
+            static uint32_t read(void) {
+                uint_csr32_t value;        
+                __asm__ volatile ("csrr    %0, vstimecmph" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write vstimecmph */
+            static void write(uint_csr32_t value) {
+                __asm__ volatile ("csrw    vstimecmph, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to vstimecmph */
+            static void write_imm(uint_csr32_t value) {
+                __asm__ volatile ("csrwi    vstimecmph, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to vstimecmph */
+            static uint32_t read_write(uint_csr32_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrw    %0, vstimecmph, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to vstimecmph */
+            static uint32_t read_write_imm(const uint8_t new_value) {
+                uint_csr32_t prev_value;
+                __asm__ volatile ("csrrwi    %0, vstimecmph, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for vstimecmph */
+            static void set_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrs    zero, vstimecmph, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for vstimecmph */
+            static uint32_t read_set_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrs    %0, vstimecmph, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for vstimecmph */
+            static void clr_bits(uint_csr32_t mask) {
+                __asm__ volatile ("csrrc    zero, vstimecmph, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for vstimecmph */
+            static uint32_t read_clr_bits(uint_csr32_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrc    %0, vstimecmph, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for vstimecmph */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, vstimecmph, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for vstimecmph */
+            static uint32_t read_set_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrsi    %0, vstimecmph, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for vstimecmph */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, vstimecmph, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for vstimecmph */
+            static uint32_t read_clr_bits_imm(const uint8_t mask) {
+                uint_csr32_t value;
+                __asm__ volatile ("csrrci    %0, vstimecmph, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* vstimecmph_ops */
+
+        // ----------------------------------------------------------------
+        // vstart - URW - Vector start position. 
+        //
+        /** Vector start position. assembler operations */
+        struct vstart_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = URW; 
+            
+            /** Read vstart */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, vstart" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write vstart */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    vstart, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to vstart */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    vstart, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to vstart */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, vstart, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to vstart */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, vstart, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for vstart */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, vstart, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for vstart */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, vstart, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for vstart */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, vstart, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for vstart */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, vstart, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for vstart */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, vstart, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for vstart */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, vstart, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for vstart */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, vstart, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for vstart */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, vstart, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* vstart_ops */
+
+        // ----------------------------------------------------------------
+        // vxsat - URW - Fixed-point accrued saturation flag. 
+        //
+        /** Fixed-point accrued saturation flag. assembler operations */
+        struct vxsat_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = URW; 
+            
+            /** Read vxsat */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, vxsat" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write vxsat */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    vxsat, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to vxsat */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    vxsat, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to vxsat */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, vxsat, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to vxsat */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, vxsat, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for vxsat */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, vxsat, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for vxsat */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, vxsat, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for vxsat */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, vxsat, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for vxsat */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, vxsat, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for vxsat */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, vxsat, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for vxsat */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, vxsat, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for vxsat */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, vxsat, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for vxsat */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, vxsat, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* vxsat_ops */
+
+        // ----------------------------------------------------------------
+        // vxrm - URW - Fixed-point rounding mode. 
+        //
+        /** Fixed-point rounding mode. assembler operations */
+        struct vxrm_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = URW; 
+            
+            /** Read vxrm */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, vxrm" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write vxrm */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    vxrm, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to vxrm */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    vxrm, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to vxrm */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, vxrm, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to vxrm */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, vxrm, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for vxrm */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, vxrm, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for vxrm */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, vxrm, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for vxrm */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, vxrm, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for vxrm */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, vxrm, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for vxrm */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, vxrm, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for vxrm */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, vxrm, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for vxrm */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, vxrm, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for vxrm */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, vxrm, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* vxrm_ops */
+
+        // ----------------------------------------------------------------
+        // vcsr - URW - Vector control and status register. 
+        //
+        /** Vector control and status register. assembler operations */
+        struct vcsr_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = URW; 
+            
+            /** Read vcsr */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, vcsr" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+            /** Write vcsr */
+            static void write(uint_xlen_t value) {
+                __asm__ volatile ("csrw    vcsr, %0" 
+                                  : /* output: none */ 
+                                  : "r" (value) /* input : from register */
+                                  : /* clobbers: none */);
+            }
+            /** Write immediate value to vcsr */
+            static void write_imm(uint_xlen_t value) {
+                __asm__ volatile ("csrwi    vcsr, %0" 
+                                  : /* output: none */ 
+                                  : "i" (value) /* input : from immediate */
+                                  : /* clobbers: none */);
+            }
+            /** Read and then write to vcsr */
+            static uint_xlen_t read_write(uint_xlen_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrw    %0, vcsr, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "r" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+            /** Read and then write immediate value to vcsr */
+            static uint_xlen_t read_write_imm(const uint8_t new_value) {
+                uint_xlen_t prev_value;
+                __asm__ volatile ("csrrwi    %0, vcsr, %1"  
+                                  : "=r" (prev_value) /* output: register %0 */
+                                  : "i" (new_value)  /* input : register */
+                                  : /* clobbers: none */);
+                return prev_value;
+            }
+        
+            // ------------------------------------------
+            // Register CSR bit set and clear instructions
+
+            /** Atomic modify and set bits for vcsr */
+            static void set_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrs    zero, vcsr, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits for vcsr */
+            static uint32_t read_set_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrs    %0, vcsr, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits for vcsr */
+            static void clr_bits(uint_xlen_t mask) {
+                __asm__ volatile ("csrrc    zero, vcsr, %0"  
+                                  : /* output: none */ 
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits for vcsr */
+            static uint32_t read_clr_bits(uint_xlen_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrc    %0, vcsr, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "r" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+        
+            // ------------------------------------------
+            // Immediate value CSR bit set and clear instructions (only up to 5 bits)
+        
+            /** Atomic modify and set bits from immediate for vcsr */
+            static void set_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrsi    zero, vcsr, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and set bits from immediate for vcsr */
+            static uint_xlen_t read_set_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrsi    %0, vcsr, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            /** Atomic modify and clear bits from immediate for vcsr */
+            static void clr_bits_imm(const uint8_t mask) {
+                __asm__ volatile ("csrrci    zero, vcsr, %0"  
+                                  : /* output: none */ 
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+            }
+            /** Atomic read and then and clear bits from immediate for vcsr */
+            static uint_xlen_t read_clr_bits_imm(const uint8_t mask) {
+                uint_xlen_t value;
+                __asm__ volatile ("csrrci    %0, vcsr, %1"  
+                                  : "=r" (value) /* output: register %0 */
+                                  : "i" (mask)  /* input : register */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+        }; /* vcsr_ops */
+        /** Parameter data for fields in vcsr */
+        namespace vcsr_data {
+            /** Parameter data for vxsat */
+            struct vxsat {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x1;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vxrm */
+            struct vxrm {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 1;
+                static constexpr uint_xlen_t BIT_WIDTH  = 2;
+                static constexpr uint_xlen_t BIT_MASK   = 0x6;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x3;
+            };
+        } /* vcsr_data */
+
+        // ----------------------------------------------------------------
+        // vl - URO - Vector length. 
+        //
+        /** Vector length. assembler operations */
+        struct vl_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = URO; 
+            
+            /** Read vl */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, vl" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+        }; /* vl_ops */
+
+        // ----------------------------------------------------------------
+        // vtype - URO - Vector data type register. 
+        //
+        /** Vector data type register. assembler operations */
+        struct vtype_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = URO; 
+            
+            /** Read vtype */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, vtype" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+        }; /* vtype_ops */
+        /** Parameter data for fields in vtype */
+        namespace vtype_data {
+            /** Parameter data for vlmul */
+            struct vlmul {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 0;
+                static constexpr uint_xlen_t BIT_WIDTH  = 3;
+                static constexpr uint_xlen_t BIT_MASK   = 0x7;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x7;
+            };
+            /** Parameter data for vsew */
+            struct vsew {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 3;
+                static constexpr uint_xlen_t BIT_WIDTH  = 3;
+                static constexpr uint_xlen_t BIT_MASK   = 0x38;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x7;
+            };
+            /** Parameter data for vta */
+            struct vta {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 6;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x40;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vma */
+            struct vma {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = 7;
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = 0x80;
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+            /** Parameter data for vill */
+            struct vill {
+                using datatype = uint_xlen_t;
+                static constexpr uint_xlen_t BIT_OFFSET = (__riscv_xlen-1);
+                static constexpr uint_xlen_t BIT_WIDTH  = 1;
+                static constexpr uint_xlen_t BIT_MASK   = (0x1UL << ((__riscv_xlen-1)));
+                static constexpr uint_xlen_t ALL_SET_MASK = 0x1;
+            };
+        } /* vtype_data */
+
+        // ----------------------------------------------------------------
+        // vlenb - URO - VLEN/8 (vector register length in bytes). 
+        //
+        /** VLEN/8 (vector register length in bytes). assembler operations */
+        struct vlenb_ops  {
+            using datatype = uint_xlen_t;
+            static constexpr priv_t priv = URO; 
+            
+            /** Read vlenb */
+            static uint_xlen_t read(void) {
+                uint_xlen_t value;        
+                __asm__ volatile ("csrr    %0, vlenb" 
+                                  : "=r" (value)  /* output : register */
+                                  : /* input : none */
+                                  : /* clobbers: none */);
+                return value;
+            }
+            
+            
+        }; /* vlenb_ops */
+    } /* csr */
+} /* riscv */
+
+
+// ------------------------------------------------------------------------
+// Register and field interface classes.
+namespace riscv {
+    namespace csr {
+        /* Machine ISA */
+        template<class OPS> class misa_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::misa_data::extensions> extensions;
+                read_write_field<OPS, riscv::csr::misa_data::mxl> mxl;
+        };
+        using misa = misa_reg<riscv::csr::misa_ops>;
+        /* Machine Vendor ID */
+        template<class OPS> class mvendorid_reg : public read_only_reg<OPS>
+        {
+            public:
+                read_only_field<OPS, riscv::csr::mvendorid_data::offset> offset;
+                read_only_field<OPS, riscv::csr::mvendorid_data::bank> bank;
+        };
+        using mvendorid = mvendorid_reg<riscv::csr::mvendorid_ops>;
+        /* Machine Architecture ID */
+        template<class OPS> class marchid_reg : public read_only_reg<OPS>
+        {
+        };
+        using marchid = marchid_reg<riscv::csr::marchid_ops>;
+        /* Machine Implementation ID */
+        template<class OPS> class mimpid_reg : public read_only_reg<OPS>
+        {
         };
         using mimpid = mimpid_reg<riscv::csr::mimpid_ops>;
         /* Hardware Thread ID */
@@ -13111,11 +18806,54 @@ namespace riscv {
                 read_write_field<OPS, riscv::csr::mstatus_data::mprv> mprv;
                 read_write_field<OPS, riscv::csr::mstatus_data::mpp> mpp;
                 read_write_field<OPS, riscv::csr::mstatus_data::spp> spp;
+                read_write_field<OPS, riscv::csr::mstatus_data::ube> ube;
+                read_write_field<OPS, riscv::csr::mstatus_data::vs> vs;
+                read_write_field<OPS, riscv::csr::mstatus_data::fs> fs;
+                read_write_field<OPS, riscv::csr::mstatus_data::xs> xs;
+                read_write_field<OPS, riscv::csr::mstatus_data::sum> sum;
+                read_write_field<OPS, riscv::csr::mstatus_data::mxr> mxr;
+                read_write_field<OPS, riscv::csr::mstatus_data::tvm> tvm;
+                read_write_field<OPS, riscv::csr::mstatus_data::tw> tw;
+                read_write_field<OPS, riscv::csr::mstatus_data::tsr> tsr;
+                read_write_field<OPS, riscv::csr::mstatus_data::spelp> spelp;
+                read_write_field<OPS, riscv::csr::mstatus_data::sdt> sdt;
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mstatus_data::uxl> uxl;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mstatus_data::sxl> sxl;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mstatus_data::sbe> sbe;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mstatus_data::mbe> mbe;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mstatus_data::gva> gva;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mstatus_data::mpv> mpv;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mstatus_data::mpelp> mpelp;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mstatus_data::mdt> mdt;
+#endif
+                read_write_field<OPS, riscv::csr::mstatus_data::sd> sd;
         };
         using mstatus = mstatus_reg<riscv::csr::mstatus_ops>;
         /* Additional machine status register, RV32 only. */
         template<class OPS> class mstatush_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::mstatush_data::sbe> sbe;
+                read_write_field<OPS, riscv::csr::mstatush_data::mbe> mbe;
+                read_write_field<OPS, riscv::csr::mstatush_data::gva> gva;
+                read_write_field<OPS, riscv::csr::mstatush_data::mpv> mpv;
+                read_write_field<OPS, riscv::csr::mstatush_data::mpelp> mpelp;
+                read_write_field<OPS, riscv::csr::mstatush_data::mdt> mdt;
         };
         using mstatush = mstatush_reg<riscv::csr::mstatush_ops>;
         /* Machine Trap Vector Base Address */
@@ -13129,11 +18867,39 @@ namespace riscv {
         /* Machine Exception Delegation */
         template<class OPS> class medeleg_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::medeleg_data::instruction_misaligned> instruction_misaligned;
+                read_write_field<OPS, riscv::csr::medeleg_data::instruction_fault> instruction_fault;
+                read_write_field<OPS, riscv::csr::medeleg_data::illegal_instruction> illegal_instruction;
+                read_write_field<OPS, riscv::csr::medeleg_data::breakpoint> breakpoint;
+                read_write_field<OPS, riscv::csr::medeleg_data::load_misaligned> load_misaligned;
+                read_write_field<OPS, riscv::csr::medeleg_data::load_fault> load_fault;
+                read_write_field<OPS, riscv::csr::medeleg_data::store_misaligned> store_misaligned;
+                read_write_field<OPS, riscv::csr::medeleg_data::store_fault> store_fault;
+                read_write_field<OPS, riscv::csr::medeleg_data::ecall_u> ecall_u;
+                read_write_field<OPS, riscv::csr::medeleg_data::ecall_s> ecall_s;
+                read_write_field<OPS, riscv::csr::medeleg_data::ecall_vs> ecall_vs;
+                read_write_field<OPS, riscv::csr::medeleg_data::instruction_page_fault> instruction_page_fault;
+                read_write_field<OPS, riscv::csr::medeleg_data::load_page_fault> load_page_fault;
+                read_write_field<OPS, riscv::csr::medeleg_data::store_page_fault> store_page_fault;
+                read_write_field<OPS, riscv::csr::medeleg_data::instruction_guest_page_fault> instruction_guest_page_fault;
+                read_write_field<OPS, riscv::csr::medeleg_data::load_guest_page_fault> load_guest_page_fault;
+                read_write_field<OPS, riscv::csr::medeleg_data::virtual_instruction> virtual_instruction;
+                read_write_field<OPS, riscv::csr::medeleg_data::store_guest_page_fault> store_guest_page_fault;
         };
         using medeleg = medeleg_reg<riscv::csr::medeleg_ops>;
         /* Machine Interrupt Delegation */
         template<class OPS> class mideleg_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::mideleg_data::ssi> ssi;
+                read_write_field<OPS, riscv::csr::mideleg_data::vssi> vssi;
+                read_write_field<OPS, riscv::csr::mideleg_data::sti> sti;
+                read_write_field<OPS, riscv::csr::mideleg_data::vsti> vsti;
+                read_write_field<OPS, riscv::csr::mideleg_data::sei> sei;
+                read_write_field<OPS, riscv::csr::mideleg_data::vsei> vsei;
+                read_write_field<OPS, riscv::csr::mideleg_data::sgei> sgei;
+                read_write_field<OPS, riscv::csr::mideleg_data::lcofi> lcofi;
         };
         using mideleg = mideleg_reg<riscv::csr::mideleg_ops>;
         /* Machine Interrupt Pending */
@@ -13150,6 +18916,7 @@ namespace riscv {
                 read_write_field<OPS, riscv::csr::mip_data::uti> uti;
                 read_write_field<OPS, riscv::csr::mip_data::uei> uei;
                 read_write_field<OPS, riscv::csr::mip_data::platform_defined> platform_defined;
+                read_write_field<OPS, riscv::csr::mip_data::lcofi> lcofi;
         };
         using mip = mip_reg<riscv::csr::mip_ops>;
         /* Machine Interrupt Enable */
@@ -13166,6 +18933,7 @@ namespace riscv {
                 read_write_field<OPS, riscv::csr::mie_data::uti> uti;
                 read_write_field<OPS, riscv::csr::mie_data::uei> uei;
                 read_write_field<OPS, riscv::csr::mie_data::platform_defined> platform_defined;
+                read_write_field<OPS, riscv::csr::mie_data::lcofi> lcofi;
         };
         using mie = mie_reg<riscv::csr::mie_ops>;
         /* Machine Counter Inhibit */
@@ -13195,6 +18963,26 @@ namespace riscv {
         /* Event Counter Event Select */
         template<class OPS> class mhpmevent3_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::mhpmevent3_data::event> event;
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent3_data::of> of;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent3_data::minh> minh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent3_data::sinh> sinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent3_data::uinh> uinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent3_data::vsinh> vsinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent3_data::vuinh> vuinh;
+#endif
         };
         using mhpmevent3 = mhpmevent3_reg<riscv::csr::mhpmevent3_ops>;
         /* Counter Enable */
@@ -13210,6 +18998,11 @@ namespace riscv {
         /* Counter Enable */
         template<class OPS> class scounteren_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::scounteren_data::cy> cy;
+                read_write_field<OPS, riscv::csr::scounteren_data::tm> tm;
+                read_write_field<OPS, riscv::csr::scounteren_data::ir> ir;
+                read_write_field<OPS, riscv::csr::scounteren_data::hpm> hpm;
         };
         using scounteren = scounteren_reg<riscv::csr::scounteren_ops>;
         /* Machine Mode Scratch Register */
@@ -13260,6 +19053,18 @@ namespace riscv {
                 read_write_field<OPS, riscv::csr::sstatus_data::sie> sie;
                 read_write_field<OPS, riscv::csr::sstatus_data::spie> spie;
                 read_write_field<OPS, riscv::csr::sstatus_data::spp> spp;
+                read_write_field<OPS, riscv::csr::sstatus_data::ube> ube;
+                read_write_field<OPS, riscv::csr::sstatus_data::vs> vs;
+                read_write_field<OPS, riscv::csr::sstatus_data::fs> fs;
+                read_write_field<OPS, riscv::csr::sstatus_data::xs> xs;
+                read_write_field<OPS, riscv::csr::sstatus_data::sum> sum;
+                read_write_field<OPS, riscv::csr::sstatus_data::mxr> mxr;
+                read_write_field<OPS, riscv::csr::sstatus_data::spelp> spelp;
+                read_write_field<OPS, riscv::csr::sstatus_data::sdt> sdt;
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::sstatus_data::uxl> uxl;
+#endif
+                read_write_field<OPS, riscv::csr::sstatus_data::sd> sd;
         };
         using sstatus = sstatus_reg<riscv::csr::sstatus_ops>;
         /* Supervisor Trap Vector Base Address */
@@ -13290,6 +19095,7 @@ namespace riscv {
                 read_write_field<OPS, riscv::csr::sip_data::usi> usi;
                 read_write_field<OPS, riscv::csr::sip_data::uti> uti;
                 read_write_field<OPS, riscv::csr::sip_data::uei> uei;
+                read_write_field<OPS, riscv::csr::sip_data::lcofi> lcofi;
         };
         using sip = sip_reg<riscv::csr::sip_ops>;
         /* Supervisor Interrupt Enable */
@@ -13302,6 +19108,7 @@ namespace riscv {
                 read_write_field<OPS, riscv::csr::sie_data::usi> usi;
                 read_write_field<OPS, riscv::csr::sie_data::uti> uti;
                 read_write_field<OPS, riscv::csr::sie_data::uei> uei;
+                read_write_field<OPS, riscv::csr::sie_data::lcofi> lcofi;
         };
         using sie = sie_reg<riscv::csr::sie_ops>;
         /* User mode restricted view of mstatus */
@@ -13364,6 +19171,12 @@ namespace riscv {
         /* Floating-Point Accrued Exceptions. */
         template<class OPS> class fflags_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::fflags_data::nx> nx;
+                read_write_field<OPS, riscv::csr::fflags_data::uf> uf;
+                read_write_field<OPS, riscv::csr::fflags_data::of> of;
+                read_write_field<OPS, riscv::csr::fflags_data::dz> dz;
+                read_write_field<OPS, riscv::csr::fflags_data::nv> nv;
         };
         using fflags = fflags_reg<riscv::csr::fflags_ops>;
         /* Floating-Point Dynamic Rounding Mode. */
@@ -13374,6 +19187,13 @@ namespace riscv {
         /* Floating-Point Control and Status */
         template<class OPS> class fcsr_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::fcsr_data::nx> nx;
+                read_write_field<OPS, riscv::csr::fcsr_data::uf> uf;
+                read_write_field<OPS, riscv::csr::fcsr_data::of> of;
+                read_write_field<OPS, riscv::csr::fcsr_data::dz> dz;
+                read_write_field<OPS, riscv::csr::fcsr_data::nv> nv;
+                read_write_field<OPS, riscv::csr::fcsr_data::frm> frm;
         };
         using fcsr = fcsr_reg<riscv::csr::fcsr_ops>;
         /* Cycle counter for RDCYCLE instruction. */
@@ -13444,21 +19264,56 @@ namespace riscv {
         /* Supervisor address translation and protection. */
         template<class OPS> class satp_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::satp_data::mode> mode;
+                read_write_field<OPS, riscv::csr::satp_data::asid> asid;
+                read_write_field<OPS, riscv::csr::satp_data::ppn> ppn;
         };
         using satp = satp_reg<riscv::csr::satp_ops>;
         /* Hypervisor status register. */
         template<class OPS> class hstatus_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::hstatus_data::vsbe> vsbe;
+                read_write_field<OPS, riscv::csr::hstatus_data::gva> gva;
+                read_write_field<OPS, riscv::csr::hstatus_data::spv> spv;
+                read_write_field<OPS, riscv::csr::hstatus_data::spvp> spvp;
+                read_write_field<OPS, riscv::csr::hstatus_data::hu> hu;
+                read_write_field<OPS, riscv::csr::hstatus_data::vgein> vgein;
+                read_write_field<OPS, riscv::csr::hstatus_data::vtvm> vtvm;
+                read_write_field<OPS, riscv::csr::hstatus_data::vtw> vtw;
+                read_write_field<OPS, riscv::csr::hstatus_data::vtsr> vtsr;
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::hstatus_data::vsxl> vsxl;
+#endif
         };
         using hstatus = hstatus_reg<riscv::csr::hstatus_ops>;
         /* Hypervisor exception delegation register. */
         template<class OPS> class hedeleg_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::hedeleg_data::instruction_misaligned> instruction_misaligned;
+                read_write_field<OPS, riscv::csr::hedeleg_data::instruction_fault> instruction_fault;
+                read_write_field<OPS, riscv::csr::hedeleg_data::illegal_instruction> illegal_instruction;
+                read_write_field<OPS, riscv::csr::hedeleg_data::breakpoint> breakpoint;
+                read_write_field<OPS, riscv::csr::hedeleg_data::load_misaligned> load_misaligned;
+                read_write_field<OPS, riscv::csr::hedeleg_data::load_fault> load_fault;
+                read_write_field<OPS, riscv::csr::hedeleg_data::store_misaligned> store_misaligned;
+                read_write_field<OPS, riscv::csr::hedeleg_data::store_fault> store_fault;
+                read_write_field<OPS, riscv::csr::hedeleg_data::ecall_u> ecall_u;
+                read_write_field<OPS, riscv::csr::hedeleg_data::instruction_page_fault> instruction_page_fault;
+                read_write_field<OPS, riscv::csr::hedeleg_data::load_page_fault> load_page_fault;
+                read_write_field<OPS, riscv::csr::hedeleg_data::store_page_fault> store_page_fault;
         };
         using hedeleg = hedeleg_reg<riscv::csr::hedeleg_ops>;
         /* Hypervisor interrupt delegation register. */
         template<class OPS> class hideleg_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::hideleg_data::vssi> vssi;
+                read_write_field<OPS, riscv::csr::hideleg_data::vsti> vsti;
+                read_write_field<OPS, riscv::csr::hideleg_data::vsei> vsei;
+                read_write_field<OPS, riscv::csr::hideleg_data::lcofi> lcofi;
         };
         using hideleg = hideleg_reg<riscv::csr::hideleg_ops>;
         /* Hypervisor counter enable. */
@@ -13469,6 +19324,10 @@ namespace riscv {
         /* Hypervisor guest address translation and protection. */
         template<class OPS> class hgatp_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::hgatp_data::mode> mode;
+                read_write_field<OPS, riscv::csr::hgatp_data::vmid> vmid;
+                read_write_field<OPS, riscv::csr::hgatp_data::ppn> ppn;
         };
         using hgatp = hgatp_reg<riscv::csr::hgatp_ops>;
         /* Delta for VS/VU-mode timer. */
@@ -13524,6 +19383,10 @@ namespace riscv {
         /* Virtual supervisor address translation and protection. */
         template<class OPS> class vsatp_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::vsatp_data::mode> mode;
+                read_write_field<OPS, riscv::csr::vsatp_data::asid> asid;
+                read_write_field<OPS, riscv::csr::vsatp_data::ppn> ppn;
         };
         using vsatp = vsatp_reg<riscv::csr::vsatp_ops>;
         /* Base register. */
@@ -13629,11 +19492,51 @@ namespace riscv {
         /* Machine performance-monitoring event selector. */
         template<class OPS> class mhpmevent4_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::mhpmevent4_data::event> event;
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent4_data::of> of;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent4_data::minh> minh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent4_data::sinh> sinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent4_data::uinh> uinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent4_data::vsinh> vsinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent4_data::vuinh> vuinh;
+#endif
         };
         using mhpmevent4 = mhpmevent4_reg<riscv::csr::mhpmevent4_ops>;
         /* Machine performance-monitoring event selector. */
         template<class OPS> class mhpmevent31_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::mhpmevent31_data::event> event;
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent31_data::of> of;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent31_data::minh> minh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent31_data::sinh> sinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent31_data::uinh> uinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent31_data::vsinh> vsinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mhpmevent31_data::vuinh> vuinh;
+#endif
         };
         using mhpmevent31 = mhpmevent31_reg<riscv::csr::mhpmevent31_ops>;
         /* Debug/Trace trigger register select. */
@@ -13679,11 +19582,18 @@ namespace riscv {
         /* Hypervisor interrupt-enable register. */
         template<class OPS> class hie_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::hie_data::vssi> vssi;
+                read_write_field<OPS, riscv::csr::hie_data::vsti> vsti;
+                read_write_field<OPS, riscv::csr::hie_data::vsei> vsei;
+                read_write_field<OPS, riscv::csr::hie_data::sgei> sgei;
         };
         using hie = hie_reg<riscv::csr::hie_ops>;
         /* Hypervisor guest external interrupt-enable register. */
         template<class OPS> class hgeie_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::hgeie_data::gei> gei;
         };
         using hgeie = hgeie_reg<riscv::csr::hgeie_ops>;
         /* Hypervisor bad guest physical address. */
@@ -13694,6 +19604,11 @@ namespace riscv {
         /* Hypervisor interrupt pending. */
         template<class OPS> class hip_reg : public read_write_reg<OPS>
         {
+            public:
+                read_write_field<OPS, riscv::csr::hip_data::vssi> vssi;
+                read_write_field<OPS, riscv::csr::hip_data::vsti> vsti;
+                read_write_field<OPS, riscv::csr::hip_data::vsei> vsei;
+                read_write_field<OPS, riscv::csr::hip_data::sgei> sgei;
         };
         using hip = hip_reg<riscv::csr::hip_ops>;
         /* Hypervisor trap instruction (transformed). */
@@ -13704,6 +19619,8 @@ namespace riscv {
         /* Hypervisor guest external interrupt pending. */
         template<class OPS> class hgeip_reg : public read_only_reg<OPS>
         {
+            public:
+                read_only_field<OPS, riscv::csr::hgeip_data::gei> gei;
         };
         using hgeip = hgeip_reg<riscv::csr::hgeip_ops>;
         /* Machine trap instruction (transformed). */
@@ -13716,6 +19633,292 @@ namespace riscv {
         {
         };
         using mtval2 = mtval2_reg<riscv::csr::mtval2_ops>;
+        /* Upper 32 bits of mhpmevent3, RV32 only. */
+        template<class OPS> class mhpmevent3h_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::mhpmevent3h_data::of> of;
+                read_write_field<OPS, riscv::csr::mhpmevent3h_data::minh> minh;
+                read_write_field<OPS, riscv::csr::mhpmevent3h_data::sinh> sinh;
+                read_write_field<OPS, riscv::csr::mhpmevent3h_data::uinh> uinh;
+                read_write_field<OPS, riscv::csr::mhpmevent3h_data::vsinh> vsinh;
+                read_write_field<OPS, riscv::csr::mhpmevent3h_data::vuinh> vuinh;
+        };
+        using mhpmevent3h = mhpmevent3h_reg<riscv::csr::mhpmevent3h_ops>;
+        /* Upper 32 bits of mhpmevent4, RV32 only. */
+        template<class OPS> class mhpmevent4h_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::mhpmevent4h_data::of> of;
+                read_write_field<OPS, riscv::csr::mhpmevent4h_data::minh> minh;
+                read_write_field<OPS, riscv::csr::mhpmevent4h_data::sinh> sinh;
+                read_write_field<OPS, riscv::csr::mhpmevent4h_data::uinh> uinh;
+                read_write_field<OPS, riscv::csr::mhpmevent4h_data::vsinh> vsinh;
+                read_write_field<OPS, riscv::csr::mhpmevent4h_data::vuinh> vuinh;
+        };
+        using mhpmevent4h = mhpmevent4h_reg<riscv::csr::mhpmevent4h_ops>;
+        /* Upper 32 bits of mhpmevent31, RV32 only. */
+        template<class OPS> class mhpmevent31h_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::mhpmevent31h_data::of> of;
+                read_write_field<OPS, riscv::csr::mhpmevent31h_data::minh> minh;
+                read_write_field<OPS, riscv::csr::mhpmevent31h_data::sinh> sinh;
+                read_write_field<OPS, riscv::csr::mhpmevent31h_data::uinh> uinh;
+                read_write_field<OPS, riscv::csr::mhpmevent31h_data::vsinh> vsinh;
+                read_write_field<OPS, riscv::csr::mhpmevent31h_data::vuinh> vuinh;
+        };
+        using mhpmevent31h = mhpmevent31h_reg<riscv::csr::mhpmevent31h_ops>;
+        /* Supervisor count overflow. */
+        template<class OPS> class scountovf_reg : public read_only_reg<OPS>
+        {
+            public:
+                read_only_field<OPS, riscv::csr::scountovf_data::hpm> hpm;
+        };
+        using scountovf = scountovf_reg<riscv::csr::scountovf_ops>;
+        /* Machine cycle counter configuration. */
+        template<class OPS> class mcyclecfg_reg : public read_write_reg<OPS>
+        {
+            public:
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mcyclecfg_data::minh> minh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mcyclecfg_data::sinh> sinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mcyclecfg_data::uinh> uinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mcyclecfg_data::vsinh> vsinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::mcyclecfg_data::vuinh> vuinh;
+#endif
+        };
+        using mcyclecfg = mcyclecfg_reg<riscv::csr::mcyclecfg_ops>;
+        /* Upper 32 bits of mcyclecfg, RV32 only. */
+        template<class OPS> class mcyclecfgh_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::mcyclecfgh_data::minh> minh;
+                read_write_field<OPS, riscv::csr::mcyclecfgh_data::sinh> sinh;
+                read_write_field<OPS, riscv::csr::mcyclecfgh_data::uinh> uinh;
+                read_write_field<OPS, riscv::csr::mcyclecfgh_data::vsinh> vsinh;
+                read_write_field<OPS, riscv::csr::mcyclecfgh_data::vuinh> vuinh;
+        };
+        using mcyclecfgh = mcyclecfgh_reg<riscv::csr::mcyclecfgh_ops>;
+        /* Machine instret counter configuration. */
+        template<class OPS> class minstretcfg_reg : public read_write_reg<OPS>
+        {
+            public:
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::minstretcfg_data::minh> minh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::minstretcfg_data::sinh> sinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::minstretcfg_data::uinh> uinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::minstretcfg_data::vsinh> vsinh;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::minstretcfg_data::vuinh> vuinh;
+#endif
+        };
+        using minstretcfg = minstretcfg_reg<riscv::csr::minstretcfg_ops>;
+        /* Upper 32 bits of minstretcfg, RV32 only. */
+        template<class OPS> class minstretcfgh_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::minstretcfgh_data::minh> minh;
+                read_write_field<OPS, riscv::csr::minstretcfgh_data::sinh> sinh;
+                read_write_field<OPS, riscv::csr::minstretcfgh_data::uinh> uinh;
+                read_write_field<OPS, riscv::csr::minstretcfgh_data::vsinh> vsinh;
+                read_write_field<OPS, riscv::csr::minstretcfgh_data::vuinh> vuinh;
+        };
+        using minstretcfgh = minstretcfgh_reg<riscv::csr::minstretcfgh_ops>;
+        /* Machine environment configuration. */
+        template<class OPS> class menvcfg_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::menvcfg_data::fiom> fiom;
+                read_write_field<OPS, riscv::csr::menvcfg_data::cbie> cbie;
+                read_write_field<OPS, riscv::csr::menvcfg_data::cbcfe> cbcfe;
+                read_write_field<OPS, riscv::csr::menvcfg_data::cbze> cbze;
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::menvcfg_data::adue> adue;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::menvcfg_data::pbmte> pbmte;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::menvcfg_data::stce> stce;
+#endif
+        };
+        using menvcfg = menvcfg_reg<riscv::csr::menvcfg_ops>;
+        /* Upper 32 bits of menvcfg, RV32 only. */
+        template<class OPS> class menvcfgh_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::menvcfgh_data::adue> adue;
+                read_write_field<OPS, riscv::csr::menvcfgh_data::pbmte> pbmte;
+                read_write_field<OPS, riscv::csr::menvcfgh_data::stce> stce;
+        };
+        using menvcfgh = menvcfgh_reg<riscv::csr::menvcfgh_ops>;
+        /* Hypervisor environment configuration. */
+        template<class OPS> class henvcfg_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::henvcfg_data::fiom> fiom;
+                read_write_field<OPS, riscv::csr::henvcfg_data::cbie> cbie;
+                read_write_field<OPS, riscv::csr::henvcfg_data::cbcfe> cbcfe;
+                read_write_field<OPS, riscv::csr::henvcfg_data::cbze> cbze;
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::henvcfg_data::adue> adue;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::henvcfg_data::pbmte> pbmte;
+#endif
+#if __riscv_xlen==64
+                read_write_field<OPS, riscv::csr::henvcfg_data::stce> stce;
+#endif
+        };
+        using henvcfg = henvcfg_reg<riscv::csr::henvcfg_ops>;
+        /* Upper 32 bits of henvcfg, RV32 only. */
+        template<class OPS> class henvcfgh_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::henvcfgh_data::adue> adue;
+                read_write_field<OPS, riscv::csr::henvcfgh_data::pbmte> pbmte;
+                read_write_field<OPS, riscv::csr::henvcfgh_data::stce> stce;
+        };
+        using henvcfgh = henvcfgh_reg<riscv::csr::henvcfgh_ops>;
+        /* Hypervisor virtual interrupt pending. */
+        template<class OPS> class hvip_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::hvip_data::vssi> vssi;
+                read_write_field<OPS, riscv::csr::hvip_data::vsti> vsti;
+                read_write_field<OPS, riscv::csr::hvip_data::vsei> vsei;
+        };
+        using hvip = hvip_reg<riscv::csr::hvip_ops>;
+        /* Hypervisor virtual interrupt control. */
+        template<class OPS> class hvictl_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::hvictl_data::iprio> iprio;
+                read_write_field<OPS, riscv::csr::hvictl_data::ipriom> ipriom;
+                read_write_field<OPS, riscv::csr::hvictl_data::dpr> dpr;
+                read_write_field<OPS, riscv::csr::hvictl_data::iid> iid;
+                read_write_field<OPS, riscv::csr::hvictl_data::vti> vti;
+        };
+        using hvictl = hvictl_reg<riscv::csr::hvictl_ops>;
+        /* Hypervisor VS-level interrupt priorities. */
+        template<class OPS> class hviprio1_reg : public read_write_reg<OPS>
+        {
+        };
+        using hviprio1 = hviprio1_reg<riscv::csr::hviprio1_ops>;
+        /* Hypervisor VS-level interrupt priorities. */
+        template<class OPS> class hviprio2_reg : public read_write_reg<OPS>
+        {
+        };
+        using hviprio2 = hviprio2_reg<riscv::csr::hviprio2_ops>;
+        /* Upper 32 bits of hviprio1, RV32 only. */
+        template<class OPS> class hviprio1h_reg : public read_write_reg<OPS>
+        {
+        };
+        using hviprio1h = hviprio1h_reg<riscv::csr::hviprio1h_ops>;
+        /* Upper 32 bits of hviprio2, RV32 only. */
+        template<class OPS> class hviprio2h_reg : public read_write_reg<OPS>
+        {
+        };
+        using hviprio2h = hviprio2h_reg<riscv::csr::hviprio2h_ops>;
+        /* Pointer to configuration data structure. */
+        template<class OPS> class mconfigptr_reg : public read_only_reg<OPS>
+        {
+        };
+        using mconfigptr = mconfigptr_reg<riscv::csr::mconfigptr_ops>;
+        /* Machine security configuration. */
+        template<class OPS> class mseccfg_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::mseccfg_data::mml> mml;
+                read_write_field<OPS, riscv::csr::mseccfg_data::mmwp> mmwp;
+                read_write_field<OPS, riscv::csr::mseccfg_data::rlb> rlb;
+        };
+        using mseccfg = mseccfg_reg<riscv::csr::mseccfg_ops>;
+        /* Upper 32 bits of mseccfg, RV32 only. */
+        template<class OPS> class mseccfgh_reg : public read_write_reg<OPS>
+        {
+        };
+        using mseccfgh = mseccfgh_reg<riscv::csr::mseccfgh_ops>;
+        /* Supervisor timer compare. */
+        template<class OPS> class stimecmp_reg : public read_write_reg<OPS>
+        {
+        };
+        using stimecmp = stimecmp_reg<riscv::csr::stimecmp_ops>;
+        /* Upper 32 bits of stimecmp, RV32 only. */
+        template<class OPS> class stimecmph_reg : public read_write_reg<OPS>
+        {
+        };
+        using stimecmph = stimecmph_reg<riscv::csr::stimecmph_ops>;
+        /* Virtual supervisor timer compare. */
+        template<class OPS> class vstimecmp_reg : public read_write_reg<OPS>
+        {
+        };
+        using vstimecmp = vstimecmp_reg<riscv::csr::vstimecmp_ops>;
+        /* Upper 32 bits of vstimecmp, RV32 only. */
+        template<class OPS> class vstimecmph_reg : public read_write_reg<OPS>
+        {
+        };
+        using vstimecmph = vstimecmph_reg<riscv::csr::vstimecmph_ops>;
+        /* Vector start position. */
+        template<class OPS> class vstart_reg : public read_write_reg<OPS>
+        {
+        };
+        using vstart = vstart_reg<riscv::csr::vstart_ops>;
+        /* Fixed-point accrued saturation flag. */
+        template<class OPS> class vxsat_reg : public read_write_reg<OPS>
+        {
+        };
+        using vxsat = vxsat_reg<riscv::csr::vxsat_ops>;
+        /* Fixed-point rounding mode. */
+        template<class OPS> class vxrm_reg : public read_write_reg<OPS>
+        {
+        };
+        using vxrm = vxrm_reg<riscv::csr::vxrm_ops>;
+        /* Vector control and status register. */
+        template<class OPS> class vcsr_reg : public read_write_reg<OPS>
+        {
+            public:
+                read_write_field<OPS, riscv::csr::vcsr_data::vxsat> vxsat;
+                read_write_field<OPS, riscv::csr::vcsr_data::vxrm> vxrm;
+        };
+        using vcsr = vcsr_reg<riscv::csr::vcsr_ops>;
+        /* Vector length. */
+        template<class OPS> class vl_reg : public read_only_reg<OPS>
+        {
+        };
+        using vl = vl_reg<riscv::csr::vl_ops>;
+        /* Vector data type register. */
+        template<class OPS> class vtype_reg : public read_only_reg<OPS>
+        {
+            public:
+                read_only_field<OPS, riscv::csr::vtype_data::vlmul> vlmul;
+                read_only_field<OPS, riscv::csr::vtype_data::vsew> vsew;
+                read_only_field<OPS, riscv::csr::vtype_data::vta> vta;
+                read_only_field<OPS, riscv::csr::vtype_data::vma> vma;
+                read_only_field<OPS, riscv::csr::vtype_data::vill> vill;
+        };
+        using vtype = vtype_reg<riscv::csr::vtype_ops>;
+        /* VLEN/8 (vector register length in bytes). */
+        template<class OPS> class vlenb_reg : public read_only_reg<OPS>
+        {
+        };
+        using vlenb = vlenb_reg<riscv::csr::vlenb_ops>;
 
         /** Encapsulate all CSRs in a single structure.
            - No storage is required by this class.
@@ -13943,6 +20146,70 @@ namespace riscv {
             riscv::csr::mtinst mtinst;
             /* Machine bad guest physical address. */
             riscv::csr::mtval2 mtval2;
+            /* Upper 32 bits of mhpmevent3, RV32 only. */
+            riscv::csr::mhpmevent3h mhpmevent3h;
+            /* Upper 32 bits of mhpmevent4, RV32 only. */
+            riscv::csr::mhpmevent4h mhpmevent4h;
+            /* Upper 32 bits of mhpmevent31, RV32 only. */
+            riscv::csr::mhpmevent31h mhpmevent31h;
+            /* Supervisor count overflow. */
+            riscv::csr::scountovf scountovf;
+            /* Machine cycle counter configuration. */
+            riscv::csr::mcyclecfg mcyclecfg;
+            /* Upper 32 bits of mcyclecfg, RV32 only. */
+            riscv::csr::mcyclecfgh mcyclecfgh;
+            /* Machine instret counter configuration. */
+            riscv::csr::minstretcfg minstretcfg;
+            /* Upper 32 bits of minstretcfg, RV32 only. */
+            riscv::csr::minstretcfgh minstretcfgh;
+            /* Machine environment configuration. */
+            riscv::csr::menvcfg menvcfg;
+            /* Upper 32 bits of menvcfg, RV32 only. */
+            riscv::csr::menvcfgh menvcfgh;
+            /* Hypervisor environment configuration. */
+            riscv::csr::henvcfg henvcfg;
+            /* Upper 32 bits of henvcfg, RV32 only. */
+            riscv::csr::henvcfgh henvcfgh;
+            /* Hypervisor virtual interrupt pending. */
+            riscv::csr::hvip hvip;
+            /* Hypervisor virtual interrupt control. */
+            riscv::csr::hvictl hvictl;
+            /* Hypervisor VS-level interrupt priorities. */
+            riscv::csr::hviprio1 hviprio1;
+            /* Hypervisor VS-level interrupt priorities. */
+            riscv::csr::hviprio2 hviprio2;
+            /* Upper 32 bits of hviprio1, RV32 only. */
+            riscv::csr::hviprio1h hviprio1h;
+            /* Upper 32 bits of hviprio2, RV32 only. */
+            riscv::csr::hviprio2h hviprio2h;
+            /* Pointer to configuration data structure. */
+            riscv::csr::mconfigptr mconfigptr;
+            /* Machine security configuration. */
+            riscv::csr::mseccfg mseccfg;
+            /* Upper 32 bits of mseccfg, RV32 only. */
+            riscv::csr::mseccfgh mseccfgh;
+            /* Supervisor timer compare. */
+            riscv::csr::stimecmp stimecmp;
+            /* Upper 32 bits of stimecmp, RV32 only. */
+            riscv::csr::stimecmph stimecmph;
+            /* Virtual supervisor timer compare. */
+            riscv::csr::vstimecmp vstimecmp;
+            /* Upper 32 bits of vstimecmp, RV32 only. */
+            riscv::csr::vstimecmph vstimecmph;
+            /* Vector start position. */
+            riscv::csr::vstart vstart;
+            /* Fixed-point accrued saturation flag. */
+            riscv::csr::vxsat vxsat;
+            /* Fixed-point rounding mode. */
+            riscv::csr::vxrm vxrm;
+            /* Vector control and status register. */
+            riscv::csr::vcsr vcsr;
+            /* Vector length. */
+            riscv::csr::vl vl;
+            /* Vector data type register. */
+            riscv::csr::vtype vtype;
+            /* VLEN/8 (vector register length in bytes). */
+            riscv::csr::vlenb vlenb;
         };
 
     } /* csr */
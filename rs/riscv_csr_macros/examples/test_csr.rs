@@ -26,7 +26,7 @@ use riscv_csr_macros::csr_set_bits_mie;
 use riscv_csr_macros::csr_set_bits_imm_mie;
 use riscv_csr_macros::MCAUSE_INTERRUPT_BIT_MASK;
 use riscv_csr_macros::MIE_MTI_BIT_MASK;
-use riscv_csr_macros::MTVEC_BASE_ALL_SET_MASK;
+use riscv_csr_macros::MTVEC_BASE_BIT_MASK;
 use riscv_csr_macros::MTVEC_MODE_ALL_SET_MASK;
 use riscv_csr_macros::UintXlen;
 
@@ -53,7 +53,7 @@ pub fn test_csr() {
     let mut old_mtvec = csr_read_clr_bits_mtvec!(MTVEC_MODE_ALL_SET_MASK);
     if (old_mtvec & MTVEC_MODE_ALL_SET_MASK) != 0 {
         // Remove mode bits
-        old_mtvec = old_mtvec & MTVEC_BASE_ALL_SET_MASK;
+        old_mtvec &= MTVEC_BASE_BIT_MASK;
     }
     _saved_mtvec = old_mtvec;
 }
@@ -0,0 +1,378 @@
+/*
+   Typed access to the address translation and protection registers,
+   satp, vsatp and hgatp.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - The field positions differ between RV32 and RV64.
+*/
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use core::arch::asm;
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::probe;
+use crate::UintXlen;
+use crate::{HGATP_MODE_BIT_MASK, HGATP_MODE_BIT_OFFSET, HGATP_PPN_BIT_MASK, HGATP_VMID_BIT_MASK, HGATP_VMID_BIT_OFFSET};
+use crate::{SATP_ASID_BIT_MASK, SATP_ASID_BIT_OFFSET, SATP_MODE_BIT_MASK, SATP_MODE_BIT_OFFSET, SATP_PPN_BIT_MASK};
+
+/// Translation scheme held in `satp.MODE` and `vsatp.MODE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// No translation or protection.
+    Bare = 0,
+    #[cfg(target_pointer_width = "32")]
+    Sv32 = 1,
+    #[cfg(target_pointer_width = "64")]
+    Sv39 = 8,
+    #[cfg(target_pointer_width = "64")]
+    Sv48 = 9,
+    #[cfg(target_pointer_width = "64")]
+    Sv57 = 10,
+    #[cfg(target_pointer_width = "64")]
+    Sv64 = 11,
+}
+
+impl Mode {
+    /// Every mode defined for this XLEN.
+    #[cfg(target_pointer_width = "32")]
+    pub const ALL: &'static [Mode] = &[Mode::Bare, Mode::Sv32];
+    /// Every mode defined for this XLEN.
+    #[cfg(target_pointer_width = "64")]
+    pub const ALL: &'static [Mode] = &[Mode::Bare, Mode::Sv39, Mode::Sv48, Mode::Sv57, Mode::Sv64];
+
+    /// Decode a field value, reserved encodings return `None`.
+    pub const fn from_bits(value: UintXlen) -> Option<Self> {
+        match value {
+            0 => Some(Mode::Bare),
+            #[cfg(target_pointer_width = "32")]
+            1 => Some(Mode::Sv32),
+            #[cfg(target_pointer_width = "64")]
+            8 => Some(Mode::Sv39),
+            #[cfg(target_pointer_width = "64")]
+            9 => Some(Mode::Sv48),
+            #[cfg(target_pointer_width = "64")]
+            10 => Some(Mode::Sv57),
+            #[cfg(target_pointer_width = "64")]
+            11 => Some(Mode::Sv64),
+            _ => None,
+        }
+    }
+    /// Field value to write.
+    pub const fn bits(self) -> UintXlen {
+        self as UintXlen
+    }
+}
+
+/// Translation scheme for guest physical addresses, held in `hgatp.MODE`.
+///
+/// The guest physical address is widened by 2 bits compared to the matching `Mode`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuestMode {
+    /// No G-stage translation or protection.
+    Bare = 0,
+    #[cfg(target_pointer_width = "32")]
+    Sv32x4 = 1,
+    #[cfg(target_pointer_width = "64")]
+    Sv39x4 = 8,
+    #[cfg(target_pointer_width = "64")]
+    Sv48x4 = 9,
+    #[cfg(target_pointer_width = "64")]
+    Sv57x4 = 10,
+}
+
+impl GuestMode {
+    /// Every mode defined for this XLEN.
+    #[cfg(target_pointer_width = "32")]
+    pub const ALL: &'static [GuestMode] = &[GuestMode::Bare, GuestMode::Sv32x4];
+    /// Every mode defined for this XLEN.
+    #[cfg(target_pointer_width = "64")]
+    pub const ALL: &'static [GuestMode] = &[GuestMode::Bare, GuestMode::Sv39x4, GuestMode::Sv48x4, GuestMode::Sv57x4];
+
+    /// Decode a field value, reserved encodings return `None`.
+    pub const fn from_bits(value: UintXlen) -> Option<Self> {
+        match value {
+            0 => Some(GuestMode::Bare),
+            #[cfg(target_pointer_width = "32")]
+            1 => Some(GuestMode::Sv32x4),
+            #[cfg(target_pointer_width = "64")]
+            8 => Some(GuestMode::Sv39x4),
+            #[cfg(target_pointer_width = "64")]
+            9 => Some(GuestMode::Sv48x4),
+            #[cfg(target_pointer_width = "64")]
+            10 => Some(GuestMode::Sv57x4),
+            _ => None,
+        }
+    }
+    /// Field value to write.
+    pub const fn bits(self) -> UintXlen {
+        self as UintXlen
+    }
+}
+
+/// Set of supported modes, bit N is set if mode encoding N is supported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ModeSet(pub u16);
+
+impl ModeSet {
+    pub const fn contains_bits(self, value: UintXlen) -> bool {
+        value < 16 && (self.0 & (1 << value)) != 0
+    }
+    pub const fn contains(self, mode: Mode) -> bool {
+        self.contains_bits(mode.bits())
+    }
+    pub const fn contains_guest(self, mode: GuestMode) -> bool {
+        self.contains_bits(mode.bits())
+    }
+}
+
+/// `satp` or `vsatp` value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Satp(pub UintXlen);
+
+impl Satp {
+    /// Encode a value, `asid` and `ppn` are truncated to the field width.
+    pub const fn new(mode: Mode, asid: UintXlen, ppn: UintXlen) -> Self {
+        Self(((mode.bits() << SATP_MODE_BIT_OFFSET) & SATP_MODE_BIT_MASK)
+            | ((asid << SATP_ASID_BIT_OFFSET) & SATP_ASID_BIT_MASK)
+            | (ppn & SATP_PPN_BIT_MASK))
+    }
+    pub const fn mode(self) -> Option<Mode> {
+        Mode::from_bits((self.0 & SATP_MODE_BIT_MASK) >> SATP_MODE_BIT_OFFSET)
+    }
+    /// Address space identifier.
+    pub const fn asid(self) -> UintXlen {
+        (self.0 & SATP_ASID_BIT_MASK) >> SATP_ASID_BIT_OFFSET
+    }
+    /// Physical page number of the root page table.
+    pub const fn ppn(self) -> UintXlen {
+        self.0 & SATP_PPN_BIT_MASK
+    }
+}
+
+/// `hgatp` value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hgatp(pub UintXlen);
+
+impl Hgatp {
+    /// Encode a value, `vmid` and `ppn` are truncated to the field width.
+    ///
+    /// The root page table is 16KiB, so the 2 low bits of `ppn` must be zero.
+    pub const fn new(mode: GuestMode, vmid: UintXlen, ppn: UintXlen) -> Self {
+        Self(((mode.bits() << HGATP_MODE_BIT_OFFSET) & HGATP_MODE_BIT_MASK)
+            | ((vmid << HGATP_VMID_BIT_OFFSET) & HGATP_VMID_BIT_MASK)
+            | (ppn & HGATP_PPN_BIT_MASK))
+    }
+    pub const fn mode(self) -> Option<GuestMode> {
+        GuestMode::from_bits((self.0 & HGATP_MODE_BIT_MASK) >> HGATP_MODE_BIT_OFFSET)
+    }
+    /// Virtual machine identifier.
+    pub const fn vmid(self) -> UintXlen {
+        (self.0 & HGATP_VMID_BIT_MASK) >> HGATP_VMID_BIT_OFFSET
+    }
+    /// Physical page number of the root guest page table.
+    pub const fn ppn(self) -> UintXlen {
+        self.0 & HGATP_PPN_BIT_MASK
+    }
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn satp() -> Satp {
+    Satp(crate::csr_read_satp!())
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn vsatp() -> Satp {
+    Satp(crate::csr_read_vsatp!())
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn hgatp() -> Hgatp {
+    Hgatp(crate::csr_read_hgatp!())
+}
+
+/// Switch address space, write `satp` then flush all non-global TLB entries.
+///
+/// # Safety
+/// The new page tables must map the code that is running and its stack.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub unsafe fn switch_satp(value: Satp) {
+    asm!("csrw satp, {0}",
+         "sfence.vma zero, zero",
+         in(reg) value.0,
+         options(nostack));
+}
+
+/// Switch address space, write `satp` then flush only the TLB entries of its ASID.
+///
+/// Use when ASIDs are recycled, stale entries of other address spaces are kept.
+///
+/// # Safety
+/// As for `switch_satp()`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub unsafe fn switch_satp_asid(value: Satp) {
+    asm!("csrw satp, {0}",
+         "sfence.vma zero, {1}",
+         in(reg) value.0,
+         in(reg) value.asid(),
+         options(nostack));
+}
+
+/// Switch the guest address space, write `vsatp` then flush the VS-stage TLB entries.
+///
+/// The flush applies to the VMID in the current `hgatp`.
+///
+/// # Safety
+/// Changes the translation used by the guest.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub unsafe fn switch_vsatp(value: Satp) {
+    asm!(".option push",
+         ".option arch, +h",
+         "csrw vsatp, {0}",
+         "hfence.vvma zero, zero",
+         ".option pop",
+         in(reg) value.0,
+         options(nostack));
+}
+
+/// Switch the guest physical address space, write `hgatp` then flush the G-stage TLB entries.
+///
+/// # Safety
+/// Changes the translation used by the guest.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub unsafe fn switch_hgatp(value: Hgatp) {
+    asm!(".option push",
+         ".option arch, +h",
+         "csrw hgatp, {0}",
+         "hfence.gvma zero, zero",
+         ".option pop",
+         in(reg) value.0,
+         options(nostack));
+}
+
+/// Probe which `satp` modes are supported by writing each mode to the WARL field.
+///
+/// A write with an unsupported mode has no effect. Each mode is written and the
+/// original value restored by consecutive instructions, with the probe trap handler
+/// installed, so an access that traps is skipped and the mode is not supported.
+///
+/// # Safety
+/// Must be called from M-mode with `mstatus.MPRV` clear. Any other mode, or M-mode
+/// with `MPRV` set, would translate its next access with a root page table at address 0.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub unsafe fn probe_satp_modes() -> ModeSet {
+    probe::with_probe_handler(|| {
+        let mut modes = ModeSet(0);
+        for mode in Mode::ALL {
+            if let Some(value) = probe::try_write_read::<{ crate::SATP_CSR_ADDRESS }>(Satp::new(*mode, 0, 0).0) {
+                if Satp(value).mode() == Some(*mode) {
+                    modes.0 |= 1 << mode.bits();
+                }
+            }
+        }
+        asm!("sfence.vma zero, zero", options(nostack));
+        modes
+    })
+}
+
+/// Probe which `hgatp` modes are supported by writing each mode to the WARL field.
+///
+/// As for `probe_satp_modes()`, each mode is written and the original value restored
+/// with the probe trap handler installed, so an access that traps is skipped.
+///
+/// # Safety
+/// Must be called from M-mode while no guest is running.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub unsafe fn probe_hgatp_modes() -> ModeSet {
+    probe::with_probe_handler(|| {
+        let mut modes = ModeSet(0);
+        for mode in GuestMode::ALL {
+            if let Some(value) = probe::try_write_read::<{ crate::HGATP_CSR_ADDRESS }>(Hgatp::new(*mode, 0, 0).0) {
+                if Hgatp(value).mode() == Some(*mode) {
+                    modes.0 |= 1 << mode.bits();
+                }
+            }
+        }
+        // The probe trap handler writes a0 and t0 if the fence traps.
+        asm!(".option push",
+             ".option arch, +h",
+             "hfence.gvma zero, zero",
+             ".option pop",
+             out("a0") _,
+             out("t0") _,
+             options(nostack));
+        modes
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn satp_fields() {
+        let satp = Satp::new(Mode::Bare, 0x12, 0x345);
+        assert_eq!(satp.mode(), Some(Mode::Bare));
+        assert_eq!(satp.asid(), 0x12);
+        assert_eq!(satp.ppn(), 0x345);
+        // Values wider than the field are truncated.
+        let satp = Satp::new(Mode::Bare, UintXlen::MAX, UintXlen::MAX);
+        assert_eq!(satp.asid(), SATP_ASID_BIT_MASK >> SATP_ASID_BIT_OFFSET);
+        assert_eq!(satp.ppn(), SATP_PPN_BIT_MASK);
+        assert_eq!(satp.mode(), Some(Mode::Bare));
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn satp_rv64_layout() {
+        assert_eq!(Satp::new(Mode::Sv39, 1, 0x80000).0, 0x8000_1000_0008_0000);
+        assert_eq!(Satp(0x9000_0000_0000_0000).mode(), Some(Mode::Sv48));
+        // Reserved mode encoding.
+        assert_eq!(Satp(0x1000_0000_0000_0000).mode(), None);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn satp_rv32_layout() {
+        assert_eq!(Satp::new(Mode::Sv32, 1, 0x80000).0, 0x8040_0000 | 0x80000);
+    }
+
+    #[test]
+    fn hgatp_fields() {
+        let hgatp = Hgatp::new(GuestMode::Bare, 0x3, 0x100);
+        assert_eq!(hgatp.mode(), Some(GuestMode::Bare));
+        assert_eq!(hgatp.vmid(), 0x3);
+        assert_eq!(hgatp.ppn(), 0x100);
+        let hgatp = Hgatp::new(GuestMode::Bare, UintXlen::MAX, 0);
+        assert_eq!(hgatp.vmid(), HGATP_VMID_BIT_MASK >> HGATP_VMID_BIT_OFFSET);
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn hgatp_rv64_layout() {
+        assert_eq!(Hgatp::new(GuestMode::Sv39x4, 1, 0x80000).0, 0x8000_1000_0008_0000);
+        assert_eq!(Hgatp::new(GuestMode::Sv48x4, 0, 0).mode(), Some(GuestMode::Sv48x4));
+    }
+
+    #[test]
+    fn mode_set() {
+        let set = ModeSet(1 << Mode::Bare.bits());
+        assert!(set.contains(Mode::Bare));
+        assert!(set.contains_guest(GuestMode::Bare));
+        assert!(!set.contains_bits(16));
+        for mode in Mode::ALL {
+            assert_eq!(Mode::from_bits(mode.bits()), Some(*mode));
+        }
+        for mode in GuestMode::ALL {
+            assert_eq!(GuestMode::from_bits(mode.bits()), Some(*mode));
+        }
+    }
+}
@@ -0,0 +1,326 @@
+/*
+   Monotonic clock, instants and busy-wait delays on the time or mcycle counter.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - The timebase frequency of `time` is platform specific, it is
+   given by the timebase-frequency property of the device tree /cpus node.
+*/
+
+use core::ops::{Add, AddAssign, Sub};
+pub use core::time::Duration;
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[cfg(target_pointer_width = "32")]
+use crate::UintCsr32;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::UintCsr64;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Counter used by a `Clock`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// The `time` CSR, a shadow of the platform `mtime`.
+    Time,
+    /// The `mcycle` counter. M-mode only, and the rate changes with the core clock.
+    Mcycle,
+}
+
+/// Point in time as a count of clock ticks.
+///
+/// Comparisons are made on the wrapping difference, so they are correct
+/// across counter wraparound for instants less than 2^63 ticks apart.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Instant(u64);
+
+impl Instant {
+    pub const fn from_ticks(ticks: u64) -> Self {
+        Self(ticks)
+    }
+    pub const fn ticks(self) -> u64 {
+        self.0
+    }
+    /// Ticks from `earlier` to `self`.
+    pub const fn ticks_since(self, earlier: Instant) -> u64 {
+        self.0.wrapping_sub(earlier.0)
+    }
+    /// `self` is at or after `other`.
+    pub const fn is_at_or_after(self, other: Instant) -> bool {
+        (self.0.wrapping_sub(other.0) as i64) >= 0
+    }
+    pub const fn wrapping_add_ticks(self, ticks: u64) -> Self {
+        Self(self.0.wrapping_add(ticks))
+    }
+}
+
+impl Add<u64> for Instant {
+    type Output = Instant;
+    fn add(self, ticks: u64) -> Instant {
+        self.wrapping_add_ticks(ticks)
+    }
+}
+
+impl AddAssign<u64> for Instant {
+    fn add_assign(&mut self, ticks: u64) {
+        *self = *self + ticks;
+    }
+}
+
+/// Ticks between two instants.
+impl Sub for Instant {
+    type Output = u64;
+    fn sub(self, earlier: Instant) -> u64 {
+        self.ticks_since(earlier)
+    }
+}
+
+/// Clock reading a counter at a fixed frequency.
+///
+/// e.g.
+/// ~~~ignore
+/// let clock = Clock::time(10_000_000);
+/// let deadline = clock.deadline(Duration::from_millis(5));
+/// while !clock.expired(deadline) && !ready() {}
+/// clock.delay_us(100);
+/// ~~~
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Clock {
+    source: Source,
+    frequency: u64,
+}
+
+impl Clock {
+    /// Clock on `time`, with the platform timebase frequency in Hz.
+    pub const fn time(timebase_hz: u64) -> Self {
+        assert!(timebase_hz != 0);
+        Self {
+            source: Source::Time,
+            frequency: timebase_hz,
+        }
+    }
+
+    /// Clock on `mcycle`, with the core clock frequency in Hz.
+    ///
+    /// See `calibrate_mcycle()` if the frequency is not known.
+    pub const fn mcycle(core_hz: u64) -> Self {
+        assert!(core_hz != 0);
+        Self {
+            source: Source::Mcycle,
+            frequency: core_hz,
+        }
+    }
+
+    /// Use `time` if it can be read, otherwise fall back to `mcycle`.
+    ///
+    /// Some harts do not implement `time` and trap reads to M-mode for emulation.
+    /// `core_hz` is only used for the fallback.
+    ///
+    /// # Safety
+    /// Must be called from M-mode, a temporary trap handler is installed in `mtvec`.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    pub unsafe fn detect(timebase_hz: u64, core_hz: impl FnOnce() -> u64) -> Self {
+        if crate::probe::with_probe_handler(|| crate::probe::try_read::<{ crate::TIME_CSR_ADDRESS }>().is_some()) {
+            Self::time(timebase_hz)
+        } else {
+            Self::mcycle(core_hz())
+        }
+    }
+
+    pub const fn source(&self) -> Source {
+        self.source
+    }
+
+    /// Tick frequency in Hz.
+    pub const fn frequency(&self) -> u64 {
+        self.frequency
+    }
+
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn now(&self) -> Instant {
+        match self.source {
+            Source::Time => Instant(crate::timer::now()),
+            Source::Mcycle => Instant(read_mcycle()),
+        }
+    }
+
+    /// Ticks in `duration`, rounded up so delays are never short.
+    ///
+    /// Saturates to `u64::MAX`.
+    pub fn ticks(&self, duration: Duration) -> u64 {
+        duration
+            .as_nanos()
+            .checked_mul(self.frequency as u128)
+            .and_then(|ticks| u64::try_from(ticks.div_ceil(NANOS_PER_SEC)).ok())
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Duration of `ticks`, rounded down.
+    pub fn duration(&self, ticks: u64) -> Duration {
+        let frequency = self.frequency;
+        let nanos = (ticks % frequency) as u128 * NANOS_PER_SEC / frequency as u128;
+        Duration::new(ticks / frequency, nanos as u32)
+    }
+
+    /// Time passed since `earlier`.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn elapsed(&self, earlier: Instant) -> Duration {
+        self.duration(self.now().ticks_since(earlier))
+    }
+
+    /// Instant `timeout` from now.
+    ///
+    /// `expired()` compares instants less than 2^63 ticks apart, so `timeout`
+    /// must be shorter than that. Use `delay()` for longer waits.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn deadline(&self, timeout: Duration) -> Instant {
+        self.now() + self.ticks(timeout)
+    }
+
+    /// The clock has reached `deadline`.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn expired(&self, deadline: Instant) -> bool {
+        self.now().is_at_or_after(deadline)
+    }
+
+    /// Busy-wait until `deadline`.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn wait_until(&self, deadline: Instant) {
+        while !self.expired(deadline) {
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Busy-wait for `ticks`.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn delay_ticks(&self, ticks: u64) {
+        let start = self.now();
+        while self.now().ticks_since(start) < ticks {
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Busy-wait for at least `duration`.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn delay(&self, duration: Duration) {
+        self.delay_ticks(self.ticks(duration));
+    }
+
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn delay_us(&self, us: u32) {
+        self.delay(Duration::from_micros(us as u64));
+    }
+
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn delay_ms(&self, ms: u32) {
+        self.delay(Duration::from_millis(ms as u64));
+    }
+}
+
+/* Read the 64 bit mcycle, on RV32 re-read the high half to detect a carry. */
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+fn read_mcycle() -> u64 {
+    #[cfg(target_pointer_width = "32")]
+    loop {
+        let hi = crate::csr_read_mcycleh!();
+        let lo = crate::csr_read_mcycle!();
+        if hi == crate::csr_read_mcycleh!() {
+            return ((hi as u64) << 32) | lo as u64;
+        }
+    }
+    #[cfg(target_pointer_width = "64")]
+    crate::csr_read_mcycle!()
+}
+
+/// Measure the `mcycle` frequency in Hz against a reference counter.
+///
+/// `reference` reads a counter running at `reference_hz`, e.g. the MMIO `mtime`.
+/// Counts `mcycle` over `reference_ticks` of the reference, a longer span
+/// gives a more accurate result.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub fn calibrate_mcycle(reference_hz: u64, mut reference: impl FnMut() -> u64, reference_ticks: u64) -> u64 {
+    assert!(reference_ticks != 0);
+    // Start on a reference tick edge, so the span is not short by up to one tick.
+    let edge = reference();
+    let start_ref = loop {
+        let now = reference();
+        if now != edge {
+            break now;
+        }
+    };
+    let start = read_mcycle();
+    while reference().wrapping_sub(start_ref) < reference_ticks {}
+    let cycles = read_mcycle().wrapping_sub(start);
+    (cycles as u128 * reference_hz as u128 / reference_ticks as u128) as u64
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal::delay::DelayNs for Clock {
+    fn delay_ns(&mut self, ns: u32) {
+        self.delay(Duration::from_nanos(ns as u64));
+    }
+    fn delay_us(&mut self, us: u32) {
+        Clock::delay_us(self, us);
+    }
+    fn delay_ms(&mut self, ms: u32) {
+        Clock::delay_ms(self, ms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instant_compare() {
+        let a = Instant::from_ticks(100);
+        let b = a + 50;
+        assert!(b.is_at_or_after(a));
+        assert!(!a.is_at_or_after(b));
+        assert!(a.is_at_or_after(a));
+        assert_eq!(b - a, 50);
+    }
+
+    #[test]
+    fn instant_wrap_compare() {
+        let before = Instant::from_ticks(u64::MAX - 10);
+        let after = before + 20;
+        assert_eq!(after.ticks(), 9);
+        assert!(after.is_at_or_after(before));
+        assert!(!before.is_at_or_after(after));
+        assert_eq!(after.ticks_since(before), 20);
+        let mut later = before;
+        later += 11;
+        assert_eq!(later, Instant::from_ticks(0));
+    }
+
+    #[test]
+    fn ticks_round_up() {
+        let clock = Clock::time(10_000_000);
+        assert_eq!(clock.ticks(Duration::from_millis(5)), 50_000);
+        assert_eq!(clock.ticks(Duration::from_nanos(1)), 1);
+        assert_eq!(clock.ticks(Duration::from_nanos(101)), 2);
+        assert_eq!(clock.ticks(Duration::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn duration_rounds_down() {
+        let clock = Clock::mcycle(3);
+        assert_eq!(clock.source(), Source::Mcycle);
+        assert_eq!(clock.duration(4), Duration::new(1, 333_333_333));
+        let clock = Clock::time(10_000_000);
+        assert_eq!(clock.duration(50_000), Duration::from_millis(5));
+    }
+}
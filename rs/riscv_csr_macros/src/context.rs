@@ -0,0 +1,184 @@
+/*
+   Lazy context switching of the F/D and V extension state, driven by the
+   FS and VS fields of mstatus or sstatus.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+*/
+
+use crate::status::{ExtensionState, StatusRegister};
+use crate::UintXlen;
+
+/// Register state of one extension that can be switched lazily.
+pub trait ExtensionContext {
+    /// Read the FS or VS field for this extension.
+    fn state<S: StatusRegister>() -> ExtensionState;
+    /// Write the FS or VS field for this extension.
+    fn set_state<S: StatusRegister>(state: ExtensionState);
+    /// Copy the hart registers to `self`.
+    ///
+    /// # Safety
+    /// The extension must not be `Off`, and the caller must not hold live
+    /// values in the extension registers.
+    unsafe fn save(&mut self);
+    /// Copy `self` to the hart registers.
+    ///
+    /// # Safety
+    /// As for `save()`.
+    unsafe fn restore(&self);
+    /// Reset to the initial state given to a task on first use.
+    fn clear(&mut self);
+}
+
+/// Per task extension context, saved and restored only when needed.
+///
+/// - `switch_out()` saves the registers only if the state is `Dirty`,
+///   then marks the state `Clean`.
+/// - `switch_in()` restores the registers of a task that has used the
+///   extension, or sets the state to `Off` so the first use traps.
+/// - `first_use()` is called from the illegal instruction handler to
+///   enable the extension for the task.
+///
+/// The status register is selected by the `StatusRegister` type argument of
+/// each call, `Mstatus` for M-mode code or `Sstatus` for S-mode code,
+/// e.g. `ctx.switch_out::<Sstatus>()`.
+pub struct LazyContext<C> {
+    pub regs: C,
+    used: bool,
+}
+
+impl<C: ExtensionContext> LazyContext<C> {
+    pub const fn new(regs: C) -> Self {
+        Self { regs, used: false }
+    }
+
+    /// The task has used the extension.
+    pub fn used(&self) -> bool {
+        self.used
+    }
+
+    /// Save the outgoing task's registers if dirty, returns true if they were saved.
+    ///
+    /// # Safety
+    /// See `ExtensionContext::save()`.
+    pub unsafe fn switch_out<S: StatusRegister>(&mut self) -> bool {
+        if C::state::<S>() != ExtensionState::Dirty {
+            return false;
+        }
+        self.regs.save();
+        C::set_state::<S>(ExtensionState::Clean);
+        true
+    }
+
+    /// Load the incoming task's registers, or turn the extension off if the task has not used it.
+    ///
+    /// # Safety
+    /// See `ExtensionContext::restore()`.
+    pub unsafe fn switch_in<S: StatusRegister>(&self) {
+        if self.used {
+            // The registers can only be written when the state is not Off.
+            C::set_state::<S>(ExtensionState::Clean);
+            self.regs.restore();
+            C::set_state::<S>(ExtensionState::Clean);
+        } else {
+            C::set_state::<S>(ExtensionState::Off);
+        }
+    }
+
+    /// Handle the trap taken on first use, returns false if the extension
+    /// was already enabled and the trap has some other cause.
+    ///
+    /// The caller should check the trapped instruction uses the extension.
+    ///
+    /// # Safety
+    /// See `ExtensionContext::restore()`.
+    pub unsafe fn first_use<S: StatusRegister>(&mut self) -> bool {
+        if self.used || C::state::<S>() != ExtensionState::Off {
+            return false;
+        }
+        self.used = true;
+        // Do not leak the previous task's registers.
+        self.regs.clear();
+        C::set_state::<S>(ExtensionState::Initial);
+        self.regs.restore();
+        C::set_state::<S>(ExtensionState::Initial);
+        true
+    }
+}
+
+/// F and D extension registers (FLEN=64) and `fcsr`.
+#[derive(Clone, Debug)]
+#[repr(C)]
+pub struct FpContext {
+    pub f: [u64; 32],
+    pub fcsr: UintXlen,
+}
+
+impl FpContext {
+    pub const fn new() -> Self {
+        Self { f: [0; 32], fcsr: 0 }
+    }
+}
+
+impl Default for FpContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/* Load or store f0-f31, assembled with D enabled so that
+   kernels built for soft-float targets can switch user FP state. */
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+macro_rules! fp_regs_asm {
+    ( $op:literal ) => {
+        concat!(
+            ".option push\n",
+            ".option arch, +d\n",
+            $op, " f0, 0({0})\n", $op, " f1, 8({0})\n",
+            $op, " f2, 16({0})\n", $op, " f3, 24({0})\n",
+            $op, " f4, 32({0})\n", $op, " f5, 40({0})\n",
+            $op, " f6, 48({0})\n", $op, " f7, 56({0})\n",
+            $op, " f8, 64({0})\n", $op, " f9, 72({0})\n",
+            $op, " f10, 80({0})\n", $op, " f11, 88({0})\n",
+            $op, " f12, 96({0})\n", $op, " f13, 104({0})\n",
+            $op, " f14, 112({0})\n", $op, " f15, 120({0})\n",
+            $op, " f16, 128({0})\n", $op, " f17, 136({0})\n",
+            $op, " f18, 144({0})\n", $op, " f19, 152({0})\n",
+            $op, " f20, 160({0})\n", $op, " f21, 168({0})\n",
+            $op, " f22, 176({0})\n", $op, " f23, 184({0})\n",
+            $op, " f24, 192({0})\n", $op, " f25, 200({0})\n",
+            $op, " f26, 208({0})\n", $op, " f27, 216({0})\n",
+            $op, " f28, 224({0})\n", $op, " f29, 232({0})\n",
+            $op, " f30, 240({0})\n", $op, " f31, 248({0})\n",
+            ".option pop",
+        )
+    };
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+impl ExtensionContext for FpContext {
+    #[inline]
+    fn state<S: StatusRegister>() -> ExtensionState {
+        S::fs()
+    }
+    #[inline]
+    fn set_state<S: StatusRegister>(state: ExtensionState) {
+        S::set_fs(state)
+    }
+    /// The F registers are not declared as clobbered, the caller must not use floating point.
+    unsafe fn save(&mut self) {
+        use core::arch::asm;
+        asm!(fp_regs_asm!("fsd"), in(reg) self.f.as_mut_ptr(), options(nostack, preserves_flags));
+        self.fcsr = crate::csr_read_fcsr!();
+    }
+    /// The F registers are not declared as clobbered, the caller must not use floating point.
+    unsafe fn restore(&self) {
+        use core::arch::asm;
+        asm!(fp_regs_asm!("fld"), in(reg) self.f.as_ptr(), options(nostack, preserves_flags, readonly));
+        crate::csr_write_fcsr!(self.fcsr);
+    }
+    fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
@@ -0,0 +1,250 @@
+/*
+   Trap delegation policy for medeleg, mideleg, hedeleg and hideleg.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - Delegation bits are WARL, a hart may hardwire any of them to
+   zero (or one). The policy is read back after writing to find the
+   delegations that were refused.
+*/
+
+use core::ops::BitOr;
+
+use crate::trap::{Exception, Interrupt};
+use crate::UintXlen;
+
+/// Set of exceptions, as the bits of `medeleg`/`hedeleg`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExceptionSet(pub UintXlen);
+
+impl ExceptionSet {
+    pub const EMPTY: ExceptionSet = ExceptionSet(0);
+
+    pub const fn of(exception: Exception) -> Self {
+        Self(exception.mask())
+    }
+    pub const fn contains(self, exception: Exception) -> bool {
+        (self.0 & exception.mask()) != 0
+    }
+    pub const fn with(self, exception: Exception) -> Self {
+        Self(self.0 | exception.mask())
+    }
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// The exceptions in the set, codes not in `Exception` are skipped.
+    pub fn iter(self) -> impl Iterator<Item = Exception> {
+        Exception::ALL.iter().copied().filter(move |e| self.contains(*e))
+    }
+}
+
+impl BitOr for ExceptionSet {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+/// Set of interrupts, as the bits of `mideleg`/`hideleg`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InterruptSet(pub UintXlen);
+
+impl InterruptSet {
+    pub const EMPTY: InterruptSet = InterruptSet(0);
+
+    pub const fn of(interrupt: Interrupt) -> Self {
+        Self(interrupt.mask())
+    }
+    pub const fn contains(self, interrupt: Interrupt) -> bool {
+        (self.0 & interrupt.mask()) != 0
+    }
+    pub const fn with(self, interrupt: Interrupt) -> Self {
+        Self(self.0 | interrupt.mask())
+    }
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// The interrupts in the set, codes not in `Interrupt` are skipped.
+    pub fn iter(self) -> impl Iterator<Item = Interrupt> {
+        Interrupt::ALL.iter().copied().filter(move |i| self.contains(*i))
+    }
+}
+
+impl BitOr for InterruptSet {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+/// Which traps are taken by S-mode (HS-mode) and which by the VS-mode guest.
+///
+/// A trap reaches VS-mode only if it is delegated by both `medeleg`/`mideleg`
+/// and `hedeleg`/`hideleg`, so guest delegations are also added to the S-mode sets.
+///
+/// e.g.
+/// ~~~ignore
+/// let refused = DelegationPolicy::new()
+///     .exception_to_supervisor(Exception::UserEcall)
+///     .exception_to_supervisor(Exception::LoadPageFault)
+///     .interrupt_to_supervisor(Interrupt::SupervisorTimer)
+///     .exception_to_guest(Exception::Breakpoint)
+///     .apply();
+/// if !refused.is_empty() {
+///     // Emulate the refused delegations with redirect_trap_to_supervisor().
+/// }
+/// ~~~
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DelegationPolicy {
+    /// `medeleg`
+    pub supervisor_exceptions: ExceptionSet,
+    /// `mideleg`
+    pub supervisor_interrupts: InterruptSet,
+    /// `hedeleg`
+    pub guest_exceptions: ExceptionSet,
+    /// `hideleg`
+    pub guest_interrupts: InterruptSet,
+}
+
+impl DelegationPolicy {
+    /// Nothing delegated, all traps are taken in M-mode.
+    pub const fn new() -> Self {
+        Self {
+            supervisor_exceptions: ExceptionSet::EMPTY,
+            supervisor_interrupts: InterruptSet::EMPTY,
+            guest_exceptions: ExceptionSet::EMPTY,
+            guest_interrupts: InterruptSet::EMPTY,
+        }
+    }
+
+    /// The exceptions and interrupts usually handled by an S-mode kernel.
+    ///
+    /// Instruction address misaligned, U-mode ecalls, breakpoints, page faults and the
+    /// S-mode interrupts. Misaligned loads and stores stay in M-mode, to be emulated
+    /// with `misaligned::emulate_misaligned()`.
+    pub const fn supervisor_default() -> Self {
+        Self::new()
+            .exception_to_supervisor(Exception::InstructionMisaligned)
+            .exception_to_supervisor(Exception::Breakpoint)
+            .exception_to_supervisor(Exception::UserEcall)
+            .exception_to_supervisor(Exception::InstructionPageFault)
+            .exception_to_supervisor(Exception::LoadPageFault)
+            .exception_to_supervisor(Exception::StorePageFault)
+            .interrupt_to_supervisor(Interrupt::SupervisorSoft)
+            .interrupt_to_supervisor(Interrupt::SupervisorTimer)
+            .interrupt_to_supervisor(Interrupt::SupervisorExternal)
+    }
+
+    pub const fn exception_to_supervisor(mut self, exception: Exception) -> Self {
+        self.supervisor_exceptions = self.supervisor_exceptions.with(exception);
+        self
+    }
+
+    pub const fn interrupt_to_supervisor(mut self, interrupt: Interrupt) -> Self {
+        self.supervisor_interrupts = self.supervisor_interrupts.with(interrupt);
+        self
+    }
+
+    /// Delegate through S-mode to VS-mode.
+    pub const fn exception_to_guest(mut self, exception: Exception) -> Self {
+        self.guest_exceptions = self.guest_exceptions.with(exception);
+        self.exception_to_supervisor(exception)
+    }
+
+    /// Delegate through S-mode to VS-mode.
+    pub const fn interrupt_to_guest(mut self, interrupt: Interrupt) -> Self {
+        self.guest_interrupts = self.guest_interrupts.with(interrupt);
+        self.interrupt_to_supervisor(interrupt)
+    }
+
+    /// Delegations in `self` that are not in `other`.
+    pub const fn difference(&self, other: &Self) -> Self {
+        Self {
+            supervisor_exceptions: self.supervisor_exceptions.difference(other.supervisor_exceptions),
+            supervisor_interrupts: self.supervisor_interrupts.difference(other.supervisor_interrupts),
+            guest_exceptions: self.guest_exceptions.difference(other.guest_exceptions),
+            guest_interrupts: self.guest_interrupts.difference(other.guest_interrupts),
+        }
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.supervisor_exceptions.is_empty()
+            && self.supervisor_interrupts.is_empty()
+            && self.guest_exceptions.is_empty()
+            && self.guest_interrupts.is_empty()
+    }
+
+    /// The guest sets are not empty, the hypervisor registers are used.
+    pub const fn has_guest(&self) -> bool {
+        !(self.guest_exceptions.is_empty() && self.guest_interrupts.is_empty())
+    }
+
+    /// Read `medeleg` and `mideleg`, M-mode only.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn read() -> Self {
+        Self {
+            supervisor_exceptions: ExceptionSet(crate::csr_read_medeleg!()),
+            supervisor_interrupts: InterruptSet(crate::csr_read_mideleg!()),
+            ..Self::new()
+        }
+    }
+
+    /// Read all four delegation registers, M-mode only and the H extension must be present.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn read_with_guest() -> Self {
+        Self {
+            guest_exceptions: ExceptionSet(crate::csr_read_hedeleg!()),
+            guest_interrupts: InterruptSet(crate::csr_read_hideleg!()),
+            ..Self::read()
+        }
+    }
+
+    /// Write the policy, returns the delegations the hart refused.
+    ///
+    /// `hedeleg` and `hideleg` are only written if the guest sets are not
+    /// empty, as they do not exist without the H extension. M-mode only.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    pub fn apply(&self) -> Self {
+        crate::csr_write_medeleg!(self.supervisor_exceptions.0);
+        crate::csr_write_mideleg!(self.supervisor_interrupts.0);
+        if self.has_guest() {
+            self.apply_guest();
+            self.difference(&Self::read_with_guest())
+        } else {
+            self.difference(&Self::read())
+        }
+    }
+
+    /// Write only `hedeleg` and `hideleg`, returns the guest delegations the hart refused.
+    ///
+    /// Used by a hypervisor in HS-mode.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    pub fn apply_guest(&self) -> Self {
+        crate::csr_write_hedeleg!(self.guest_exceptions.0);
+        crate::csr_write_hideleg!(self.guest_interrupts.0);
+        Self {
+            guest_exceptions: self
+                .guest_exceptions
+                .difference(ExceptionSet(crate::csr_read_hedeleg!())),
+            guest_interrupts: self
+                .guest_interrupts
+                .difference(InterruptSet(crate::csr_read_hideleg!())),
+            ..Self::new()
+        }
+    }
+}
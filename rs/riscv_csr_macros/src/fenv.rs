@@ -0,0 +1,182 @@
+/*
+   Floating-point environment access over fcsr, frm and fflags.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+*/
+
+use crate::UintXlen;
+use crate::{FFLAGS_DZ_BIT_MASK, FFLAGS_NV_BIT_MASK, FFLAGS_NX_BIT_MASK, FFLAGS_OF_BIT_MASK, FFLAGS_UF_BIT_MASK};
+
+/// Floating point rounding mode, as held in `frm` and the instruction `rm` field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even.
+    Rne = 0,
+    /// Round towards zero.
+    Rtz = 1,
+    /// Round down, towards negative infinity.
+    Rdn = 2,
+    /// Round up, towards positive infinity.
+    Rup = 3,
+    /// Round to nearest, ties to max magnitude.
+    Rmm = 4,
+    /// Use `frm`. Only valid in the instruction `rm` field, writing it to `frm`
+    /// makes floating point instructions raise an illegal instruction exception.
+    Dyn = 7,
+}
+
+impl RoundingMode {
+    /// Decode a 3 bit field value, the reserved encodings 5 and 6 return `None`.
+    pub const fn from_bits(value: UintXlen) -> Option<Self> {
+        match value & 0x7 {
+            0 => Some(RoundingMode::Rne),
+            1 => Some(RoundingMode::Rtz),
+            2 => Some(RoundingMode::Rdn),
+            3 => Some(RoundingMode::Rup),
+            4 => Some(RoundingMode::Rmm),
+            7 => Some(RoundingMode::Dyn),
+            _ => None,
+        }
+    }
+    /// Field value to write.
+    pub const fn bits(self) -> UintXlen {
+        self as UintXlen
+    }
+}
+
+/// Set of accrued floating point exception flags, as held in `fflags`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FpExceptionFlags(UintXlen);
+
+impl FpExceptionFlags {
+    /// Inexact.
+    pub const NX: Self = Self(FFLAGS_NX_BIT_MASK);
+    /// Underflow.
+    pub const UF: Self = Self(FFLAGS_UF_BIT_MASK);
+    /// Overflow.
+    pub const OF: Self = Self(FFLAGS_OF_BIT_MASK);
+    /// Divide by zero.
+    pub const DZ: Self = Self(FFLAGS_DZ_BIT_MASK);
+    /// Invalid operation.
+    pub const NV: Self = Self(FFLAGS_NV_BIT_MASK);
+    /// No flags.
+    pub const NONE: Self = Self(0);
+    /// All flags.
+    pub const ALL: Self = Self(FFLAGS_NX_BIT_MASK | FFLAGS_UF_BIT_MASK | FFLAGS_OF_BIT_MASK | FFLAGS_DZ_BIT_MASK | FFLAGS_NV_BIT_MASK);
+
+    /// Create from a register value, bits other than the flags are dropped.
+    pub const fn from_bits(value: UintXlen) -> Self {
+        Self(value & Self::ALL.0)
+    }
+    /// Register value.
+    pub const fn bits(self) -> UintXlen {
+        self.0
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// All flags in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+    /// Any flag in `other` is set.
+    pub const fn intersects(self, other: Self) -> bool {
+        (self.0 & other.0) != 0
+    }
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl core::ops::BitOr for FpExceptionFlags {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl core::ops::BitAnd for FpExceptionFlags {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+
+/// Read the dynamic rounding mode from `frm`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn rounding_mode() -> Option<RoundingMode> {
+    RoundingMode::from_bits(crate::csr_read_frm!())
+}
+
+/// Write the dynamic rounding mode to `frm`, returns the previous mode.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn set_rounding_mode(mode: RoundingMode) -> Option<RoundingMode> {
+    RoundingMode::from_bits(crate::csr_read_write_frm!(mode.bits()))
+}
+
+/// Restores the previous value of `frm` when dropped.
+///
+/// e.g.
+/// ~~~ignore
+/// let _guard = RoundingModeGuard::new(RoundingMode::Rtz);
+/// // Dynamic rounding mode instructions round towards zero here.
+/// ~~~
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[must_use]
+pub struct RoundingModeGuard {
+    prev: UintXlen,
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+impl RoundingModeGuard {
+    /// Switch `frm` to `mode` until the guard is dropped.
+    #[inline]
+    pub fn new(mode: RoundingMode) -> Self {
+        Self {
+            prev: crate::csr_read_write_frm!(mode.bits()),
+        }
+    }
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+impl Drop for RoundingModeGuard {
+    #[inline]
+    fn drop(&mut self) {
+        crate::csr_write_frm!(self.prev);
+    }
+}
+
+/// Read the accrued exception flags.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn exception_flags() -> FpExceptionFlags {
+    FpExceptionFlags::from_bits(crate::csr_read_fflags!())
+}
+
+/// Clear the flags in `mask` with a single `csrrc`, returns which of them were set.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn test_and_clear_exceptions(mask: FpExceptionFlags) -> FpExceptionFlags {
+    FpExceptionFlags::from_bits(crate::csr_read_clr_bits_fflags!(mask.bits())).intersection(mask)
+}
+
+/// Clear the flags in `mask`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn clear_exceptions(mask: FpExceptionFlags) {
+    crate::csr_clr_bits_fflags!(mask.bits());
+}
+
+/// Set the flags in `mask`, as if the operations had raised them.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn raise_exceptions(mask: FpExceptionFlags) {
+    crate::csr_set_bits_fflags!(mask.bits());
+}
@@ -0,0 +1,219 @@
+/*
+   Hypervisor (HS-mode) vCPU state switching and guest entry.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - The guest general purpose and FP registers are switched by the
+   caller, see context for the FP state. hgatp is switched with
+   atp::switch_hgatp().
+*/
+
+use crate::status::{hstatus, sstatus, PrivilegeMode};
+#[cfg(target_pointer_width = "32")]
+use crate::UintCsr32;
+use crate::{UintCsr64, UintXlen};
+use crate::{
+    HIP_VSEI_BIT_MASK, HIP_VSSI_BIT_MASK, HIP_VSTI_BIT_MASK, HVIP_VSEI_BIT_MASK, HVIP_VSSI_BIT_MASK, HVIP_VSTI_BIT_MASK,
+};
+
+/* Read a 64 bit register that is split into a high half on RV32. */
+macro_rules! read_csr64 {
+    ( $lo:ident, $hi:ident ) => {{
+        #[cfg(target_pointer_width = "32")]
+        let value = ((crate::$hi!() as u64) << 32) | crate::$lo!() as u64;
+        #[cfg(target_pointer_width = "64")]
+        let value = crate::$lo!() as u64;
+        value
+    }};
+}
+
+macro_rules! write_csr64 {
+    ( $lo:ident, $hi:ident, $value:expr ) => {{
+        let value: u64 = $value;
+        #[cfg(target_pointer_width = "32")]
+        crate::$hi!((value >> 32) as UintCsr32);
+        crate::$lo!(value as UintCsr64);
+    }};
+}
+
+/// VS-level CSRs of one virtual hart, and the HS-level CSRs that configure it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct VsCsrState {
+    pub vsstatus: UintXlen,
+    pub vsie: UintXlen,
+    pub vstvec: UintXlen,
+    pub vsscratch: UintXlen,
+    pub vsepc: UintXlen,
+    pub vscause: UintXlen,
+    pub vstval: UintXlen,
+    pub vsip: UintXlen,
+    pub vsatp: UintXlen,
+    /// Virtual interrupts injected into the guest.
+    pub hvip: UintXlen,
+    pub henvcfg: u64,
+    /// Offset of the guest `time` from the host `time`.
+    pub htimedelta: u64,
+}
+
+impl VsCsrState {
+    pub const fn new() -> Self {
+        Self {
+            vsstatus: 0,
+            vsie: 0,
+            vstvec: 0,
+            vsscratch: 0,
+            vsepc: 0,
+            vscause: 0,
+            vstval: 0,
+            vsip: 0,
+            vsatp: 0,
+            hvip: 0,
+            henvcfg: 0,
+            htimedelta: 0,
+        }
+    }
+
+    /// Copy the hart CSRs to `self`, when switching out a vCPU.
+    #[inline]
+    pub fn save(&mut self) {
+        self.vsstatus = crate::csr_read_vsstatus!();
+        self.vsie = crate::csr_read_vsie!();
+        self.vstvec = crate::csr_read_vstvec!();
+        self.vsscratch = crate::csr_read_vsscratch!();
+        self.vsepc = crate::csr_read_vsepc!();
+        self.vscause = crate::csr_read_vscause!();
+        self.vstval = crate::csr_read_vstval!();
+        self.vsip = crate::csr_read_vsip!();
+        self.vsatp = crate::csr_read_vsatp!();
+        self.hvip = crate::csr_read_hvip!();
+        self.henvcfg = read_csr64!(csr_read_henvcfg, csr_read_henvcfgh);
+        self.htimedelta = read_csr64!(csr_read_htimedelta, csr_read_htimedeltah);
+    }
+
+    /// Copy `self` to the hart CSRs, when switching in a vCPU.
+    ///
+    /// `vsatp` is written without a fence, use `atp::switch_vsatp()`
+    /// if the new value may reuse a VMID and ASID with stale translations.
+    #[inline]
+    pub fn restore(&self) {
+        crate::csr_write_vsstatus!(self.vsstatus);
+        crate::csr_write_vsie!(self.vsie);
+        crate::csr_write_vstvec!(self.vstvec);
+        crate::csr_write_vsscratch!(self.vsscratch);
+        crate::csr_write_vsepc!(self.vsepc);
+        crate::csr_write_vscause!(self.vscause);
+        crate::csr_write_vstval!(self.vstval);
+        crate::csr_write_vsip!(self.vsip);
+        crate::csr_write_vsatp!(self.vsatp);
+        // After vsip, as vsip.SSIP is an alias of hvip.VSSIP.
+        crate::csr_write_hvip!(self.hvip);
+        write_csr64!(csr_write_henvcfg, csr_write_henvcfgh, self.henvcfg);
+        write_csr64!(csr_write_htimedelta, csr_write_htimedeltah, self.htimedelta);
+    }
+}
+
+/// Guest instructions that trap to HS-mode as virtual instruction exceptions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GuestTraps {
+    /// `WFI` in VS-mode, `hstatus.VTW`.
+    pub wfi: bool,
+    /// `SFENCE.VMA`, `SINVAL.VMA` and `satp` access in VS-mode, `hstatus.VTVM`.
+    pub vm: bool,
+}
+
+/// Set up `sret` to enter the guest at `pc` in `mode`.
+///
+/// - `hstatus.SPV` is set, so `sret` enters a virtualized mode.
+/// - `hstatus.SPVP` and `sstatus.SPP` are set to `mode`, VS-mode for
+///   `Supervisor` and VU-mode for `User`.
+/// - `hstatus.VTW` and `hstatus.VTVM` are set from `traps`.
+/// - `sepc` is set to `pc`.
+///
+/// The guest CSRs, `hgatp` and the guest registers must already be loaded,
+/// the caller then executes `sret`.
+///
+/// # Panics
+/// If `mode` is `Machine`.
+#[inline]
+pub fn prepare_guest_entry(pc: UintXlen, mode: PrivilegeMode, traps: GuestTraps) {
+    hstatus::set_spvp(mode);
+    hstatus::set_vtw(traps.wfi);
+    hstatus::set_vtvm(traps.vm);
+    hstatus::set_spv(true);
+    sstatus::set_spp(mode);
+    crate::csr_write_sepc!(pc);
+}
+
+/// The trap being handled was taken from the guest, `hstatus.SPV`.
+#[inline]
+pub fn trapped_from_guest() -> bool {
+    hstatus::spv()
+}
+
+/// Clear `hstatus.SPV`, so `sret` returns to HS-mode or U-mode rather than the guest.
+#[inline]
+pub fn prepare_host_return() {
+    hstatus::set_spv(false);
+}
+
+/// VS-level interrupt that HS-mode can assert for the guest in `hvip`.
+///
+/// e.g. forward a host timer tick to the guest
+/// ~~~ignore
+/// GuestInterrupt::Timer.inject();
+/// ~~~
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuestInterrupt {
+    Software,
+    Timer,
+    External,
+}
+
+impl GuestInterrupt {
+    /// Bit in `hvip`.
+    pub const fn hvip_mask(self) -> UintXlen {
+        match self {
+            GuestInterrupt::Software => HVIP_VSSI_BIT_MASK,
+            GuestInterrupt::Timer => HVIP_VSTI_BIT_MASK,
+            GuestInterrupt::External => HVIP_VSEI_BIT_MASK,
+        }
+    }
+
+    /// Bit in `hip`.
+    pub const fn hip_mask(self) -> UintXlen {
+        match self {
+            GuestInterrupt::Software => HIP_VSSI_BIT_MASK,
+            GuestInterrupt::Timer => HIP_VSTI_BIT_MASK,
+            GuestInterrupt::External => HIP_VSEI_BIT_MASK,
+        }
+    }
+
+    /// Assert the interrupt for the guest.
+    ///
+    /// It is taken in VS-mode if delegated in `hideleg` and enabled by the guest.
+    #[inline]
+    pub fn inject(self) {
+        crate::csr_set_bits_hvip!(self.hvip_mask());
+    }
+
+    /// Deassert an injected interrupt.
+    #[inline]
+    pub fn retract(self) {
+        crate::csr_clr_bits_hvip!(self.hvip_mask());
+    }
+
+    /// The interrupt is asserted in `hvip`.
+    #[inline]
+    pub fn is_injected(self) -> bool {
+        (crate::csr_read_hvip!() & self.hvip_mask()) != 0
+    }
+
+    /// The interrupt is pending for the guest, from `hvip` or another source such as
+    /// `vstimecmp` or the guest external interrupt selected by `hstatus.VGEIN`.
+    #[inline]
+    pub fn is_pending(self) -> bool {
+        (crate::csr_read_hip!() & self.hip_mask()) != 0
+    }
+}
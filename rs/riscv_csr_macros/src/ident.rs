@@ -0,0 +1,243 @@
+/*
+   Hart identification, decoding of mvendorid, marchid and mimpid.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   Sources for the tables:
+   - JEDEC JEP106 manufacturer IDs.
+   - The open source marchid list in the riscv-isa-manual repository.
+*/
+
+use core::fmt;
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::UintCsr32;
+use crate::UintXlen;
+use crate::{MVENDORID_BANK_BIT_MASK, MVENDORID_BANK_BIT_OFFSET, MVENDORID_OFFSET_BIT_MASK};
+
+/// JEDEC manufacturer ID held in `mvendorid`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JedecId {
+    /// Bank number, from 1. This is one more than the count of 0x7f continuation codes.
+    pub bank: u32,
+    /// ID within the bank, without the parity bit.
+    pub offset: u8,
+}
+
+impl JedecId {
+    /// Decode `mvendorid`, returns `None` for 0 (not implemented or non-commercial).
+    #[allow(clippy::unnecessary_cast)]
+    pub const fn from_mvendorid(value: u32) -> Option<Self> {
+        if value == 0 {
+            return None;
+        }
+        let value = value as UintXlen;
+        Some(Self {
+            bank: ((value & MVENDORID_BANK_BIT_MASK) >> MVENDORID_BANK_BIT_OFFSET) as u32 + 1,
+            offset: (value & MVENDORID_OFFSET_BIT_MASK) as u8,
+        })
+    }
+    /// Encode as an `mvendorid` value.
+    #[allow(clippy::unnecessary_cast)]
+    pub const fn mvendorid(self) -> u32 {
+        ((((self.bank - 1) as UintXlen) << MVENDORID_BANK_BIT_OFFSET) & MVENDORID_BANK_BIT_MASK
+            | (self.offset as UintXlen & MVENDORID_OFFSET_BIT_MASK)) as u32
+    }
+}
+
+/* mvendorid, name */
+const VENDORS: &[(u32, &str)] = &[
+    (0x029, "Microchip"),
+    (0x31e, "Andes"),
+    (0x489, "SiFive"),
+    (0x5b7, "T-Head"),
+    (0x602, "OpenHW Group"),
+];
+
+/* Open source marchid (MSB clear), registered in the riscv-isa-manual. */
+const OPEN_SOURCE_ARCHS: &[(UintXlen, &str)] = &[
+    (1, "Rocket"),
+    (2, "BOOM"),
+    (3, "CVA6"),
+    (4, "CV32E40P"),
+    (5, "Spike"),
+    (8, "SCR1"),
+    (11, "SweRV EH1"),
+    (13, "BlackParrot"),
+    (16, "SweRV EL2 (VeeR EL2)"),
+    (17, "SweRV EH2 (VeeR EH2)"),
+    (18, "SERV"),
+    (19, "NEORV32"),
+    (20, "CV32E40X"),
+    (21, "CV32E40S"),
+    (22, "Ibex"),
+    (25, "XiangShan"),
+];
+
+/* SiFive marchid: MSB set, low bits hold the core series. */
+const SIFIVE_SERIES: &[(UintXlen, &str)] = &[
+    (2, "SiFive 2-series"),
+    (3, "SiFive 3-series"),
+    (5, "SiFive 5-series"),
+    (7, "SiFive 7-series"),
+    (8, "SiFive P-series"),
+];
+
+/* T-Head XuanTie marchid: MSB set, low bits hold the core number in hex. */
+const THEAD_CORES: &[(UintXlen, &str)] = &[
+    (0x906, "XuanTie C906"),
+    (0x910, "XuanTie C910"),
+    (0x920, "XuanTie C920"),
+];
+
+const MARCHID_COMMERCIAL: UintXlen = 1 << (crate::__riscv_xlen - 1);
+
+fn lookup<K: PartialEq + Copy>(table: &[(K, &'static str)], key: K) -> Option<&'static str> {
+    table.iter().find(|(k, _)| *k == key).map(|(_, name)| *name)
+}
+
+/// Name of the vendor with this `mvendorid`.
+pub fn vendor_name(mvendorid: u32) -> Option<&'static str> {
+    lookup(VENDORS, mvendorid)
+}
+
+/// Name of the microarchitecture with this `marchid`.
+///
+/// Open source architectures are unique on their own, commercial
+/// values are only meaningful together with `mvendorid`.
+pub fn arch_name(mvendorid: u32, marchid: UintXlen) -> Option<&'static str> {
+    if marchid & MARCHID_COMMERCIAL == 0 {
+        return lookup(OPEN_SOURCE_ARCHS, marchid);
+    }
+    match vendor_name(mvendorid) {
+        Some("SiFive") => lookup(SIFIVE_SERIES, marchid & !MARCHID_COMMERCIAL),
+        Some("T-Head") => lookup(THEAD_CORES, marchid & !MARCHID_COMMERCIAL),
+        _ => None,
+    }
+}
+
+/// Identification registers of one hart.
+///
+/// Renders as a one line summary, e.g.
+/// `hart 0: vendor 0x489 SiFive (bank 10, 0x09), arch 0x8000000000000007 SiFive 7-series, impl 0x...`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HartId {
+    pub mvendorid: u32,
+    pub marchid: UintXlen,
+    pub mimpid: UintXlen,
+    pub mhartid: UintXlen,
+    /// Address of the configuration data structure, `None` if not read.
+    pub mconfigptr: Option<UintXlen>,
+}
+
+impl HartId {
+    /// Read the ID registers other than `mconfigptr`, which is left as `None`.
+    ///
+    /// Safe on all harts, see `read_with_config()` to also read `mconfigptr`.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn read() -> Self {
+        Self {
+            mvendorid: crate::csr_read_mvendorid!(),
+            marchid: crate::csr_read_marchid!(),
+            mimpid: crate::csr_read_mimpid!(),
+            mhartid: crate::csr_read_mhartid!(),
+            mconfigptr: None,
+        }
+    }
+    /// Read all the ID registers, including `mconfigptr`.
+    ///
+    /// NOTE - `mconfigptr` was added in privileged spec 1.12, reading it raises an
+    /// illegal instruction exception on older harts. Only call this on harts known
+    /// to implement it.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    #[inline]
+    pub fn read_with_config() -> Self {
+        Self {
+            mconfigptr: Some(crate::csr_read_mconfigptr!()),
+            ..Self::read()
+        }
+    }
+    pub const fn jedec_id(&self) -> Option<JedecId> {
+        JedecId::from_mvendorid(self.mvendorid)
+    }
+    pub fn vendor_name(&self) -> Option<&'static str> {
+        vendor_name(self.mvendorid)
+    }
+    pub fn arch_name(&self) -> Option<&'static str> {
+        arch_name(self.mvendorid, self.marchid)
+    }
+}
+
+impl fmt::Display for HartId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hart {}: vendor {:#x}", self.mhartid, self.mvendorid)?;
+        if let Some(name) = self.vendor_name() {
+            write!(f, " {}", name)?;
+        }
+        if let Some(id) = self.jedec_id() {
+            write!(f, " (bank {}, {:#04x})", id.bank, id.offset)?;
+        }
+        write!(f, ", arch {:#x}", self.marchid)?;
+        if let Some(name) = self.arch_name() {
+            write!(f, " {}", name)?;
+        }
+        write!(f, ", impl {:#x}", self.mimpid)?;
+        if let Some(ptr) = self.mconfigptr {
+            write!(f, ", config {:#x}", ptr)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jedec_decode() {
+        assert_eq!(JedecId::from_mvendorid(0x489), Some(JedecId { bank: 10, offset: 0x09 }));
+        assert_eq!(JedecId::from_mvendorid(0x5b7), Some(JedecId { bank: 12, offset: 0x37 }));
+        assert_eq!(JedecId::from_mvendorid(0x029), Some(JedecId { bank: 1, offset: 0x29 }));
+        assert_eq!(JedecId::from_mvendorid(0), None);
+    }
+
+    #[test]
+    fn jedec_round_trip() {
+        for mvendorid in [0x029, 0x31e, 0x489, 0x5b7, 0x602] {
+            assert_eq!(JedecId::from_mvendorid(mvendorid).unwrap().mvendorid(), mvendorid);
+        }
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(vendor_name(0x489), Some("SiFive"));
+        assert_eq!(vendor_name(0x123), None);
+        assert_eq!(arch_name(0, 22), Some("Ibex"));
+        assert_eq!(arch_name(0x489, MARCHID_COMMERCIAL | 7), Some("SiFive 7-series"));
+        // Commercial values depend on the vendor.
+        assert_eq!(arch_name(0x31e, MARCHID_COMMERCIAL | 7), None);
+        assert_eq!(arch_name(0x5b7, MARCHID_COMMERCIAL | 0x906), Some("XuanTie C906"));
+        assert_eq!(arch_name(0x5b7, MARCHID_COMMERCIAL | 0x920), Some("XuanTie C920"));
+        assert_eq!(arch_name(0x5b7, MARCHID_COMMERCIAL | 7), None);
+    }
+
+    #[test]
+    fn display() {
+        let id = HartId {
+            mvendorid: 0x489,
+            marchid: MARCHID_COMMERCIAL | 7,
+            mimpid: 0x20181004,
+            mhartid: 1,
+            mconfigptr: None,
+        };
+        assert_eq!(
+            id.to_string(),
+            format!(
+                "hart 1: vendor 0x489 SiFive (bank 10, 0x09), arch {:#x} SiFive 7-series, impl 0x20181004",
+                MARCHID_COMMERCIAL | 7
+            )
+        );
+    }
+}
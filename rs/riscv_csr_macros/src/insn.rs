@@ -0,0 +1,339 @@
+/*
+   Encoding, decoding and emulation of the Zicsr instructions.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - CSR instructions are always 32 bits, there are no compressed forms.
+*/
+
+use crate::trap::TrapFrame;
+use crate::UintXlen;
+
+/* SYSTEM major opcode. */
+const OPCODE_SYSTEM: u32 = 0x73;
+
+/// Operation of a CSR instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CsrOp {
+    /// `csrrw`, write `rs1`.
+    Rw = 1,
+    /// `csrrs`, set the bits in `rs1`.
+    Rs = 2,
+    /// `csrrc`, clear the bits in `rs1`.
+    Rc = 3,
+    /// `csrrwi`, write `uimm`.
+    Rwi = 5,
+    /// `csrrsi`, set the bits in `uimm`.
+    Rsi = 6,
+    /// `csrrci`, clear the bits in `uimm`.
+    Rci = 7,
+}
+
+impl CsrOp {
+    /// Decode the `funct3` field, 0 and 4 are not CSR instructions.
+    pub const fn from_funct3(funct3: u32) -> Option<Self> {
+        match funct3 {
+            1 => Some(CsrOp::Rw),
+            2 => Some(CsrOp::Rs),
+            3 => Some(CsrOp::Rc),
+            5 => Some(CsrOp::Rwi),
+            6 => Some(CsrOp::Rsi),
+            7 => Some(CsrOp::Rci),
+            _ => None,
+        }
+    }
+    pub const fn funct3(self) -> u32 {
+        self as u32
+    }
+    /// The operand is the 5 bit `uimm` rather than `rs1`.
+    pub const fn is_immediate(self) -> bool {
+        (self as u32) & 0x4 != 0
+    }
+}
+
+/// Fields of a CSR instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CsrInstruction {
+    pub op: CsrOp,
+    /// 12 bit CSR address.
+    pub csr: u16,
+    pub rd: u8,
+    /// Source register, or the zero extended immediate for the `i` forms.
+    pub rs1_or_uimm: u8,
+}
+
+impl CsrInstruction {
+    /// Decode a 32 bit instruction, returns `None` if it is not a CSR instruction.
+    ///
+    /// After an illegal instruction trap the encoding is in `mtval`, if the
+    /// hart reports it, otherwise it must be loaded from `mepc`.
+    pub const fn decode(insn: u32) -> Option<Self> {
+        if insn & 0x7f != OPCODE_SYSTEM {
+            return None;
+        }
+        let op = match CsrOp::from_funct3((insn >> 12) & 0x7) {
+            Some(op) => op,
+            None => return None,
+        };
+        Some(Self {
+            op,
+            csr: (insn >> 20) as u16,
+            rd: ((insn >> 7) & 0x1f) as u8,
+            rs1_or_uimm: ((insn >> 15) & 0x1f) as u8,
+        })
+    }
+
+    /// Encode as a 32 bit instruction.
+    ///
+    /// Fails if `csr` is more than 12 bits, or `rd` or `rs1_or_uimm` more than 5 bits.
+    pub const fn encode(&self) -> Result<u32, EncodeError> {
+        if self.csr > 0xfff {
+            return Err(EncodeError::Csr(self.csr));
+        }
+        if self.rd > 31 {
+            return Err(EncodeError::Register(self.rd));
+        }
+        if self.rs1_or_uimm > 31 {
+            return Err(if self.op.is_immediate() {
+                EncodeError::Immediate(self.rs1_or_uimm)
+            } else {
+                EncodeError::Register(self.rs1_or_uimm)
+            });
+        }
+        Ok(((self.csr as u32) << 20)
+            | ((self.rs1_or_uimm as u32) << 15)
+            | (self.op.funct3() << 12)
+            | ((self.rd as u32) << 7)
+            | OPCODE_SYSTEM)
+    }
+
+    /// The instruction reads the CSR, `csrrw`/`csrrwi` with `rd = x0` do not.
+    pub const fn reads(&self) -> bool {
+        match self.op {
+            CsrOp::Rw | CsrOp::Rwi => self.rd != 0,
+            _ => true,
+        }
+    }
+
+    /// The instruction writes the CSR, set and clear with `x0` or 0 do not.
+    pub const fn writes(&self) -> bool {
+        match self.op {
+            CsrOp::Rw | CsrOp::Rwi => true,
+            _ => self.rs1_or_uimm != 0,
+        }
+    }
+
+    /// Value of `rs1` in `frame`, or the immediate.
+    pub fn operand(&self, frame: &TrapFrame) -> UintXlen {
+        if self.op.is_immediate() {
+            self.rs1_or_uimm as UintXlen
+        } else {
+            frame.reg(self.rs1_or_uimm as usize)
+        }
+    }
+
+    /// New CSR value from the `old` value and the operand.
+    pub const fn apply(&self, old: UintXlen, operand: UintXlen) -> UintXlen {
+        match self.op {
+            CsrOp::Rw | CsrOp::Rwi => operand,
+            CsrOp::Rs | CsrOp::Rsi => old | operand,
+            CsrOp::Rc | CsrOp::Rci => old & !operand,
+        }
+    }
+}
+
+/// Operand out of range for `CsrInstruction::encode()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodeError {
+    /// CSR address is more than 12 bits.
+    Csr(u16),
+    /// Register number is more than 31.
+    Register(u8),
+    /// Immediate is more than 5 bits.
+    Immediate(u8),
+}
+
+/* Encode one of the CSR instruction forms. */
+const fn encode(op: CsrOp, rd: u8, csr: u16, rs1_or_uimm: u8) -> Result<u32, EncodeError> {
+    CsrInstruction {
+        op,
+        csr,
+        rd,
+        rs1_or_uimm,
+    }
+    .encode()
+}
+
+/// `csrrw rd, csr, rs1`
+///
+/// e.g.
+/// ~~~ignore
+/// const SWAP_MSCRATCH: u32 = match csrrw(10, MSCRATCH_CSR_ADDRESS, 10) {
+///     Ok(insn) => insn,
+///     Err(_) => panic!(),
+/// };
+/// ~~~
+pub const fn csrrw(rd: u8, csr: u16, rs1: u8) -> Result<u32, EncodeError> {
+    encode(CsrOp::Rw, rd, csr, rs1)
+}
+
+/// `csrrs rd, csr, rs1`
+pub const fn csrrs(rd: u8, csr: u16, rs1: u8) -> Result<u32, EncodeError> {
+    encode(CsrOp::Rs, rd, csr, rs1)
+}
+
+/// `csrrc rd, csr, rs1`
+pub const fn csrrc(rd: u8, csr: u16, rs1: u8) -> Result<u32, EncodeError> {
+    encode(CsrOp::Rc, rd, csr, rs1)
+}
+
+/// `csrrwi rd, csr, uimm`, `uimm` is 0 to 31.
+pub const fn csrrwi(rd: u8, csr: u16, uimm: u8) -> Result<u32, EncodeError> {
+    encode(CsrOp::Rwi, rd, csr, uimm)
+}
+
+/// `csrrsi rd, csr, uimm`, `uimm` is 0 to 31.
+pub const fn csrrsi(rd: u8, csr: u16, uimm: u8) -> Result<u32, EncodeError> {
+    encode(CsrOp::Rsi, rd, csr, uimm)
+}
+
+/// `csrrci rd, csr, uimm`, `uimm` is 0 to 31.
+pub const fn csrrci(rd: u8, csr: u16, uimm: u8) -> Result<u32, EncodeError> {
+    encode(CsrOp::Rci, rd, csr, uimm)
+}
+
+/// `csrr rd, csr`, the pseudo instruction for `csrrs rd, csr, x0`.
+pub const fn csrr(rd: u8, csr: u16) -> Result<u32, EncodeError> {
+    csrrs(rd, csr, 0)
+}
+
+/// `csrw csr, rs1`, the pseudo instruction for `csrrw x0, csr, rs1`.
+pub const fn csrw(csr: u16, rs1: u8) -> Result<u32, EncodeError> {
+    csrrw(0, csr, rs1)
+}
+
+/// Emulate `insn` on a virtual CSR held in `value`.
+///
+/// The old value is written to `rd` in `frame`, `value` is updated if the
+/// instruction writes the CSR, and `frame.mepc` is advanced past the instruction.
+/// The caller checks `insn.writes()` first for read-only CSRs.
+///
+/// e.g. emulating `time` in the illegal instruction handler
+/// ~~~ignore
+/// if let Some(insn) = CsrInstruction::decode(csr_read_mtval!() as u32) {
+///     if insn.csr == TIME_CSR_ADDRESS && !insn.writes() {
+///         let mut time = mtimer.now() as UintXlen;
+///         emulate(frame, &insn, &mut time);
+///     }
+/// }
+/// ~~~
+pub fn emulate(frame: &mut TrapFrame, insn: &CsrInstruction, value: &mut UintXlen) {
+    let old = *value;
+    let operand = insn.operand(frame);
+    if insn.writes() {
+        *value = insn.apply(old, operand);
+    }
+    frame.set_reg(insn.rd as usize, old);
+    frame.mepc = frame.mepc.wrapping_add(4);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_register_forms() {
+        // csrrw a0, mscratch, a0
+        assert_eq!(
+            CsrInstruction::decode(0x34051573),
+            Some(CsrInstruction { op: CsrOp::Rw, csr: 0x340, rd: 10, rs1_or_uimm: 10 })
+        );
+        // csrr a0, mcause
+        assert_eq!(
+            CsrInstruction::decode(0x34202573),
+            Some(CsrInstruction { op: CsrOp::Rs, csr: 0x342, rd: 10, rs1_or_uimm: 0 })
+        );
+        // csrc mie, t0
+        assert_eq!(
+            CsrInstruction::decode(0x3042b073),
+            Some(CsrInstruction { op: CsrOp::Rc, csr: 0x304, rd: 0, rs1_or_uimm: 5 })
+        );
+    }
+
+    #[test]
+    fn decode_immediate_forms() {
+        // csrsi mstatus, 8
+        assert_eq!(
+            CsrInstruction::decode(0x30046073),
+            Some(CsrInstruction { op: CsrOp::Rsi, csr: 0x300, rd: 0, rs1_or_uimm: 8 })
+        );
+        // csrrwi a1, 0xfff, 31
+        assert_eq!(
+            CsrInstruction::decode(0xffffd5f3),
+            Some(CsrInstruction { op: CsrOp::Rwi, csr: 0xfff, rd: 11, rs1_or_uimm: 31 })
+        );
+    }
+
+    #[test]
+    fn decode_rejects_other_instructions() {
+        // ecall, funct3 = 0
+        assert_eq!(CsrInstruction::decode(0x00000073), None);
+        // funct3 = 4 is not a CSR instruction.
+        assert_eq!(CsrInstruction::decode(0x34044573), None);
+        // addi a0, a0, 1
+        assert_eq!(CsrInstruction::decode(0x00150513), None);
+    }
+
+    #[test]
+    fn encode_round_trips() {
+        for insn in [0x34051573, 0x34202573, 0x3042b073, 0x30046073, 0xffffd5f3] {
+            assert_eq!(CsrInstruction::decode(insn).unwrap().encode(), Ok(insn));
+        }
+    }
+
+    #[test]
+    fn encoders() {
+        assert_eq!(csrrw(10, 0x340, 10), Ok(0x34051573));
+        assert_eq!(csrr(10, 0x342), Ok(0x34202573));
+        assert_eq!(csrw(0x305, 5), Ok(0x30529073));
+        assert_eq!(csrrc(0, 0x304, 5), Ok(0x3042b073));
+        assert_eq!(csrrsi(0, 0x300, 8), Ok(0x30046073));
+        assert_eq!(csrrci(0, 0x300, 8), Ok(0x30047073));
+        assert_eq!(csrrwi(11, 0xfff, 31), Ok(0xffffd5f3));
+    }
+
+    #[test]
+    fn encode_range_checks() {
+        assert_eq!(csrrw(0, 0x1000, 0), Err(EncodeError::Csr(0x1000)));
+        assert_eq!(csrrs(32, 0x300, 0), Err(EncodeError::Register(32)));
+        assert_eq!(csrrc(0, 0x300, 32), Err(EncodeError::Register(32)));
+        assert_eq!(csrrsi(0, 0x300, 32), Err(EncodeError::Immediate(32)));
+    }
+
+    #[test]
+    fn reads_writes_and_apply() {
+        let csrw = CsrInstruction::decode(0x30529073).unwrap();
+        assert!(!csrw.reads() && csrw.writes());
+        let csrr = CsrInstruction::decode(0x34202573).unwrap();
+        assert!(csrr.reads() && !csrr.writes());
+        let csrc = CsrInstruction::decode(0x3042b073).unwrap();
+        assert_eq!(csrc.apply(0b1111, 0b0101), 0b1010);
+        let csrsi = CsrInstruction::decode(0x30046073).unwrap();
+        assert_eq!(csrsi.apply(0b0001, 8), 0b1001);
+    }
+
+    #[test]
+    fn emulate_on_frame() {
+        let mut frame = TrapFrame::new();
+        frame.mepc = 0x1000;
+        frame.set_reg(5, 0b0110);
+        let mut value: UintXlen = 0b0011;
+        // csrrs a0, 0x800, t0
+        let insn = CsrInstruction::decode(csrrs(10, 0x800, 5).unwrap()).unwrap();
+        emulate(&mut frame, &insn, &mut value);
+        assert_eq!(frame.reg(10), 0b0011);
+        assert_eq!(value, 0b0111);
+        assert_eq!(frame.mepc, 0x1004);
+    }
+}
@@ -0,0 +1,278 @@
+/*
+   Decoding of misa into the base XLEN and extension set, and conversion
+   to and from ISA strings such as "rv64imafdc".
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+*/
+
+use core::fmt;
+
+use crate::status::Xlen;
+use crate::UintXlen;
+use crate::{MISA_EXTENSIONS_BIT_MASK, MISA_MXL_BIT_MASK, MISA_MXL_BIT_OFFSET};
+
+/// Set of single letter extensions, bit N is letter 'A' + N as in `misa`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Extensions(pub u32);
+
+impl Extensions {
+    pub const NONE: Self = Self(0);
+    pub const A: Self = Self::bit(b'A');
+    pub const B: Self = Self::bit(b'B');
+    pub const C: Self = Self::bit(b'C');
+    pub const D: Self = Self::bit(b'D');
+    pub const E: Self = Self::bit(b'E');
+    pub const F: Self = Self::bit(b'F');
+    pub const H: Self = Self::bit(b'H');
+    pub const I: Self = Self::bit(b'I');
+    pub const M: Self = Self::bit(b'M');
+    pub const Q: Self = Self::bit(b'Q');
+    /// Supervisor mode is implemented.
+    pub const S: Self = Self::bit(b'S');
+    /// User mode is implemented.
+    pub const U: Self = Self::bit(b'U');
+    pub const V: Self = Self::bit(b'V');
+    /// Non-standard extensions are present.
+    pub const X: Self = Self::bit(b'X');
+    /// Shorthand for IMAFD.
+    pub const G: Self = Self(Self::I.0 | Self::M.0 | Self::A.0 | Self::F.0 | Self::D.0);
+
+    /// Order of the letters in an ISA string.
+    /// S, U and X are modes or vendor flags and are not rendered.
+    pub const CANONICAL_ORDER: &'static [u8] = b"IEMAFDQLCBKJTPVHN";
+
+    /* Extension of an upper case letter. */
+    const fn bit(c: u8) -> Self {
+        Self(1 << (c - b'A'))
+    }
+    /// The extension with the letter `c`, upper or lower case.
+    ///
+    /// Returns `None` if `c` is not an ASCII letter.
+    pub const fn letter(c: u8) -> Option<Self> {
+        if c.is_ascii_alphabetic() {
+            Some(Self::bit(c.to_ascii_uppercase()))
+        } else {
+            None
+        }
+    }
+    /// Create from a `misa` value, MXL is dropped.
+    #[allow(clippy::unnecessary_cast)]
+    pub const fn from_bits(value: UintXlen) -> Self {
+        Self((value & MISA_EXTENSIONS_BIT_MASK) as u32)
+    }
+    /// Extension field value.
+    pub const fn bits(self) -> UintXlen {
+        self.0 as UintXlen
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// All extensions in `other` are present.
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+    pub const fn has(self, c: u8) -> bool {
+        match Self::letter(c) {
+            Some(extension) => self.contains(extension),
+            None => false,
+        }
+    }
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl core::ops::BitOr for Extensions {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// Base ISA and extensions, as reported by `misa`.
+///
+/// Renders as an ISA string, e.g. `rv64imafdc`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Isa {
+    pub xlen: Xlen,
+    pub extensions: Extensions,
+}
+
+/// Reason an ISA string could not be parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IsaParseError {
+    /// Does not start with `rv`.
+    Prefix,
+    /// Base width is not 32, 64 or 128.
+    Xlen,
+    /// First letter is not `i`, `e` or `g`.
+    Base,
+    /// Unknown single letter extension.
+    Letter(u8),
+}
+
+impl Isa {
+    /// Decode a `misa` value, returns `None` for 0 (`misa` not implemented).
+    pub const fn from_bits(value: UintXlen) -> Option<Self> {
+        match Xlen::from_bits((value & MISA_MXL_BIT_MASK) >> MISA_MXL_BIT_OFFSET) {
+            Some(xlen) => Some(Self {
+                xlen,
+                extensions: Extensions::from_bits(value),
+            }),
+            None => None,
+        }
+    }
+    /// Encode as a `misa` value.
+    pub const fn bits(self) -> UintXlen {
+        (self.xlen.bits() << MISA_MXL_BIT_OFFSET) | self.extensions.bits()
+    }
+
+    /// Parse an ISA string such as `rv32imac` or `RV64GC_Zicsr_Zifencei`.
+    ///
+    /// Only the single letter extensions held in `misa` are decoded, the
+    /// multi-letter extensions following the first `_` are ignored.
+    /// Version numbers such as `rv32i2p1` are not supported.
+    pub fn parse(s: &str) -> Result<Self, IsaParseError> {
+        let s = s.as_bytes();
+        if s.len() < 2 || !s[..2].eq_ignore_ascii_case(b"rv") {
+            return Err(IsaParseError::Prefix);
+        }
+        let digits = s[2..].iter().take_while(|c| c.is_ascii_digit()).count();
+        let xlen = match &s[2..2 + digits] {
+            b"32" => Xlen::Rv32,
+            b"64" => Xlen::Rv64,
+            b"128" => Xlen::Rv128,
+            _ => return Err(IsaParseError::Xlen),
+        };
+        let letters = s[2 + digits..].split(|c| *c == b'_').next().unwrap_or(&[]);
+        let mut extensions = Extensions::NONE;
+        for (i, c) in letters.iter().enumerate() {
+            let c = c.to_ascii_uppercase();
+            if i == 0 && !matches!(c, b'I' | b'E' | b'G') {
+                return Err(IsaParseError::Base);
+            }
+            extensions = match c {
+                b'G' => extensions | Extensions::G,
+                // Multi-letter extensions without the '_' separator.
+                b'Z' | b'S' | b'X' => break,
+                _ if Extensions::CANONICAL_ORDER.contains(&c) => extensions | Extensions::bit(c),
+                _ => return Err(IsaParseError::Letter(c)),
+            };
+        }
+        if extensions.is_empty() {
+            return Err(IsaParseError::Base);
+        }
+        Ok(Self { xlen, extensions })
+    }
+}
+
+impl fmt::Display for Isa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rv{}", self.xlen.bit_width())?;
+        for c in Extensions::CANONICAL_ORDER {
+            if self.extensions.has(*c) {
+                write!(f, "{}", c.to_ascii_lowercase() as char)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Read `misa`, returns `None` if it is not implemented.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn misa() -> Option<Isa> {
+    Isa::from_bits(crate::csr_read_misa!())
+}
+
+/// Set the extensions in `ext`, returns the extensions now enabled.
+///
+/// `misa` is WARL, so extensions that can not be enabled are left clear.
+/// Check the result with `contains()`.
+///
+/// # Safety
+/// Changes the instructions that are legal for all privilege modes.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub unsafe fn enable(ext: Extensions) -> Extensions {
+    crate::csr_set_bits_misa!(ext.bits());
+    Extensions::from_bits(crate::csr_read_misa!())
+}
+
+/// Clear the extensions in `ext`, returns the extensions still enabled.
+///
+/// `misa` is WARL, so extensions that can not be disabled remain set.
+///
+/// # Safety
+/// Changes the instructions that are legal for all privilege modes,
+/// the caller must not be using the disabled extensions.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub unsafe fn disable(ext: Extensions) -> Extensions {
+    crate::csr_clr_bits_misa!(ext.bits());
+    Extensions::from_bits(crate::csr_read_misa!())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_single_letters() {
+        assert_eq!(
+            Isa::parse("rv32imac"),
+            Ok(Isa { xlen: Xlen::Rv32, extensions: Extensions::I | Extensions::M | Extensions::A | Extensions::C })
+        );
+        assert_eq!(
+            Isa::parse("RV64GC_Zicsr_Zifencei"),
+            Ok(Isa { xlen: Xlen::Rv64, extensions: Extensions::G | Extensions::C })
+        );
+        // Multi-letter extension without the '_' separator.
+        assert_eq!(
+            Isa::parse("rv64imazicsr"),
+            Ok(Isa { xlen: Xlen::Rv64, extensions: Extensions::I | Extensions::M | Extensions::A })
+        );
+        assert_eq!(Isa::parse("rv128e").map(|isa| isa.xlen), Ok(Xlen::Rv128));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Isa::parse("r"), Err(IsaParseError::Prefix));
+        assert_eq!(Isa::parse("x86"), Err(IsaParseError::Prefix));
+        assert_eq!(Isa::parse("rv16i"), Err(IsaParseError::Xlen));
+        assert_eq!(Isa::parse("rv32mac"), Err(IsaParseError::Base));
+        assert_eq!(Isa::parse("rv32"), Err(IsaParseError::Base));
+        assert_eq!(Isa::parse("rv32i_zicsr").map(|isa| isa.extensions), Ok(Extensions::I));
+        assert_eq!(Isa::parse("rv32iw"), Err(IsaParseError::Letter(b'W')));
+    }
+
+    #[test]
+    fn display_in_canonical_order() {
+        let isa = Isa {
+            xlen: Xlen::Rv64,
+            extensions: Extensions::C | Extensions::G | Extensions::V | Extensions::H,
+        };
+        assert_eq!(isa.to_string(), "rv64imafdcvh");
+        assert_eq!(Isa::parse(&isa.to_string()), Ok(isa));
+    }
+
+    #[test]
+    fn misa_bits_round_trip() {
+        let isa = Isa::parse("rv64imac").unwrap();
+        assert_eq!(Isa::from_bits(isa.bits()), Some(isa));
+        assert_eq!(Isa::from_bits(0), None);
+    }
+
+    #[test]
+    fn letters() {
+        assert_eq!(Extensions::letter(b'a'), Some(Extensions::A));
+        assert_eq!(Extensions::letter(b'Z').map(|e| e.bits()), Some(1 << 25));
+        assert_eq!(Extensions::letter(b'0'), None);
+        assert!(Extensions::G.has(b'd') && !Extensions::G.has(b'c'));
+    }
+}
@@ -0,0 +1,243 @@
+/*
+   Cycle and instruction count measurement of code regions.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - The counters are read through cycle, instret and hpmcounterN,
+   below M-mode they must be enabled in mcounteren/scounteren.
+*/
+
+use core::hint::black_box;
+
+use crate::perf::{self, CounterIndex};
+use crate::UintXlen;
+
+/// Maximum number of `hpmcounter` values in a `Measurement`.
+pub const MAX_EVENTS: usize = 4;
+
+/// Counter increments over a region.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Measurement {
+    pub cycles: u64,
+    pub instret: u64,
+    /// Increments of the `hpmcounter`s selected in the `Harness`, in the same order.
+    pub events: [u64; MAX_EVENTS],
+}
+
+impl Measurement {
+    /// Instructions retired per cycle.
+    pub fn ipc(&self) -> f32 {
+        if self.cycles == 0 {
+            return 0.0;
+        }
+        self.instret as f32 / self.cycles as f32
+    }
+
+    /* Counters are 64 bit, so a wrapping difference is correct across one wrap. */
+    fn wrapping_sub(&self, earlier: &Self) -> Self {
+        let mut result = Self {
+            cycles: self.cycles.wrapping_sub(earlier.cycles),
+            instret: self.instret.wrapping_sub(earlier.instret),
+            events: [0; MAX_EVENTS],
+        };
+        for i in 0..MAX_EVENTS {
+            result.events[i] = self.events[i].wrapping_sub(earlier.events[i]);
+        }
+        result
+    }
+
+    fn saturating_sub(&self, other: &Self) -> Self {
+        let mut result = Self {
+            cycles: self.cycles.saturating_sub(other.cycles),
+            instret: self.instret.saturating_sub(other.instret),
+            events: [0; MAX_EVENTS],
+        };
+        for i in 0..MAX_EVENTS {
+            result.events[i] = self.events[i].saturating_sub(other.events[i]);
+        }
+        result
+    }
+}
+
+/// Minimum, median and maximum of repeated measurements.
+///
+/// Each counter is ordered independently, so e.g. `min.cycles` and
+/// `min.instret` may come from different runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Measurement,
+    pub median: Measurement,
+    pub max: Measurement,
+}
+
+fn min_median_max(values: &mut [u64]) -> (u64, u64, u64) {
+    values.sort_unstable();
+    (values[0], values[values.len() / 2], values[values.len() - 1])
+}
+
+/* Read a 64 bit counter, on RV32 re-read the high half to detect a carry. */
+#[cfg(target_pointer_width = "32")]
+macro_rules! read_counter64 {
+    ( $lo:ident, $hi:ident ) => {
+        loop {
+            let hi = crate::$hi!();
+            let lo = crate::$lo!();
+            if hi == crate::$hi!() {
+                break ((hi as u64) << 32) | lo as u64;
+            }
+        }
+    };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! read_counter64 {
+    ( $lo:ident, $hi:ident ) => {
+        crate::$lo!()
+    };
+}
+
+/// Measures regions with a fixed set of counters.
+///
+/// The overhead of reading the counters is measured on creation and
+/// subtracted from each result.
+pub struct Harness {
+    events: [CounterIndex; MAX_EVENTS],
+    num_events: usize,
+    overhead: Measurement,
+}
+
+impl Harness {
+    /// Runs of the empty region used to find the overhead, the minimum is used.
+    const CALIBRATION_RUNS: usize = 8;
+
+    /// Measure `cycle` and `instret`.
+    pub fn new() -> Self {
+        Self::with_events(&[])
+    }
+
+    /// Also measure the `hpmcounter`s in `events`, at most `MAX_EVENTS`.
+    ///
+    /// The counters should already be programmed and running, e.g. with `perf::PerfMonitor`.
+    pub fn with_events(events: &[CounterIndex]) -> Self {
+        assert!(events.len() <= MAX_EVENTS);
+        let mut harness = Self {
+            events: [0; MAX_EVENTS],
+            num_events: events.len(),
+            overhead: Measurement::default(),
+        };
+        harness.events[..events.len()].copy_from_slice(events);
+        harness.overhead = harness.calibrate();
+        harness
+    }
+
+    /// Counter increments of an empty region.
+    pub fn overhead(&self) -> Measurement {
+        self.overhead
+    }
+
+    /* cycle is read last at the start and first at the end, so it is
+       closest to the region. None of the CSR read asm is `nomem`, so the
+       compiler does not move the region's memory accesses across them. */
+    #[inline(always)]
+    fn start(&self) -> Measurement {
+        let mut m = Measurement::default();
+        for i in 0..self.num_events {
+            m.events[i] = perf::read_user_counter(self.events[i]);
+        }
+        m.instret = read_counter64!(csr_read_instret, csr_read_instreth);
+        m.cycles = read_counter64!(csr_read_cycle, csr_read_cycleh);
+        m
+    }
+
+    #[inline(always)]
+    fn end(&self) -> Measurement {
+        let cycles = read_counter64!(csr_read_cycle, csr_read_cycleh);
+        let instret = read_counter64!(csr_read_instret, csr_read_instreth);
+        let mut m = Measurement {
+            cycles,
+            instret,
+            events: [0; MAX_EVENTS],
+        };
+        for i in 0..self.num_events {
+            m.events[i] = perf::read_user_counter(self.events[i]);
+        }
+        m
+    }
+
+    #[inline(always)]
+    fn measure_raw<R>(&self, f: impl FnOnce() -> R) -> (R, Measurement) {
+        let start = self.start();
+        let result = black_box(f());
+        let end = self.end();
+        (result, end.wrapping_sub(&start))
+    }
+
+    fn calibrate(&self) -> Measurement {
+        let mut min = self.measure_raw(|| ()).1;
+        for _ in 1..Self::CALIBRATION_RUNS {
+            let m = self.measure_raw(|| ()).1;
+            min.cycles = min.cycles.min(m.cycles);
+            min.instret = min.instret.min(m.instret);
+            for i in 0..MAX_EVENTS {
+                min.events[i] = min.events[i].min(m.events[i]);
+            }
+        }
+        min
+    }
+
+    /// Run `f` once, returns its result and the counter increments less the overhead.
+    #[inline]
+    pub fn measure<R>(&self, f: impl FnOnce() -> R) -> (R, Measurement) {
+        let (result, m) = self.measure_raw(f);
+        (result, m.saturating_sub(&self.overhead))
+    }
+
+    /// Run `f` `N` times and return the statistics of the measurements.
+    pub fn repeat<const N: usize>(&self, mut f: impl FnMut()) -> Stats {
+        assert!(N > 0);
+        let mut cycles = [0u64; N];
+        let mut instret = [0u64; N];
+        let mut events = [[0u64; N]; MAX_EVENTS];
+        for run in 0..N {
+            let m = self.measure(&mut f).1;
+            cycles[run] = m.cycles;
+            instret[run] = m.instret;
+            for (values, value) in events.iter_mut().zip(m.events) {
+                values[run] = value;
+            }
+        }
+        let mut stats = Stats::default();
+        (stats.min.cycles, stats.median.cycles, stats.max.cycles) = min_median_max(&mut cycles);
+        (stats.min.instret, stats.median.instret, stats.max.instret) = min_median_max(&mut instret);
+        for (i, values) in events.iter_mut().enumerate() {
+            (stats.min.events[i], stats.median.events[i], stats.max.events[i]) = min_median_max(values);
+        }
+        stats
+    }
+}
+
+impl Default for Harness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Measure the `cycle` and `instret` increments of running `f` once.
+///
+/// Calibrates a new `Harness` on each call, keep a `Harness` to measure many regions.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Measurement) {
+    Harness::new().measure(f)
+}
+
+/// Measure a block of code, returns `(result, Measurement)`.
+///
+/// e.g.
+/// ~~~ignore
+/// let (sum, m) = measure!({ a + b });
+/// ~~~
+#[macro_export]
+macro_rules! measure {
+    ( $body:block ) => {
+        $crate::measure::measure(|| $body)
+    };
+}
@@ -0,0 +1,492 @@
+/*
+   Emulation of misaligned integer loads and stores in M-mode.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - The instruction and the data are accessed with mstatus.MPRV set,
+   so they are translated and checked as the mode that took the trap.
+*/
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::mprv;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::status::PrivilegeMode;
+use crate::trap::AccessFault;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::trap::Exception;
+#[cfg(any(test, target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::trap::TrapFrame;
+use crate::UintXlen;
+
+/// Integer load or store decoded from the trapped instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryAccess {
+    /// Access size in bytes, 1, 2, 4 or 8.
+    pub width: u8,
+    /// Sign extend a load.
+    pub signed: bool,
+    pub store: bool,
+    /// `rd` for loads, `rs2` for stores.
+    pub reg: u8,
+    /// Instruction length in bytes, 2 for compressed instructions.
+    pub len: u8,
+}
+
+impl MemoryAccess {
+    /// Decode an integer load or store, including the compressed forms.
+    ///
+    /// Only the low 16 bits of `insn` are used for compressed instructions.
+    /// Floating point and RV64 only forms on RV32 return `None`.
+    pub const fn decode(insn: u32) -> Option<Self> {
+        if insn & 0x3 == 0x3 {
+            Self::decode_32(insn)
+        } else {
+            Self::decode_16(insn & 0xffff)
+        }
+    }
+
+    const fn decode_32(insn: u32) -> Option<Self> {
+        let funct3 = (insn >> 12) & 0x7;
+        let rd = ((insn >> 7) & 0x1f) as u8;
+        let rs2 = ((insn >> 20) & 0x1f) as u8;
+        let (width, signed) = match funct3 {
+            0 => (1, true),
+            1 => (2, true),
+            2 => (4, true),
+            3 if crate::__riscv_xlen == 64 => (8, true),
+            4 => (1, false),
+            5 => (2, false),
+            6 if crate::__riscv_xlen == 64 => (4, false),
+            _ => return None,
+        };
+        match insn & 0x7f {
+            // LOAD
+            0x03 => Some(Self {
+                width,
+                signed,
+                store: false,
+                reg: rd,
+                len: 4,
+            }),
+            // STORE, only the signed encodings are defined.
+            0x23 if signed => Some(Self {
+                width,
+                signed: false,
+                store: true,
+                reg: rs2,
+                len: 4,
+            }),
+            _ => None,
+        }
+    }
+
+    const fn decode_16(insn: u32) -> Option<Self> {
+        let funct3 = (insn >> 13) & 0x7;
+        // rd' or rs2' in quadrant 0.
+        let reg_c = (((insn >> 2) & 0x7) + 8) as u8;
+        let rd = ((insn >> 7) & 0x1f) as u8;
+        let rs2 = ((insn >> 2) & 0x1f) as u8;
+        let rv64 = crate::__riscv_xlen == 64;
+        let (width, store, reg) = match (insn & 0x3, funct3) {
+            // c.lw, c.ld
+            (0, 2) => (4, false, reg_c),
+            (0, 3) if rv64 => (8, false, reg_c),
+            // c.sw, c.sd
+            (0, 6) => (4, true, reg_c),
+            (0, 7) if rv64 => (8, true, reg_c),
+            // c.lwsp, c.ldsp, rd = x0 is reserved.
+            (2, 2) if rd != 0 => (4, false, rd),
+            (2, 3) if rv64 && rd != 0 => (8, false, rd),
+            // c.swsp, c.sdsp
+            (2, 6) => (4, true, rs2),
+            (2, 7) if rv64 => (8, true, rs2),
+            _ => return None,
+        };
+        Some(Self {
+            width,
+            signed: !store,
+            store,
+            reg,
+            len: 2,
+        })
+    }
+}
+
+/// Memory holding the trapped instruction and the data it accesses.
+///
+/// `mprv::LowerMemory` accesses it as the mode that took the trap.
+pub trait Memory {
+    /// Read the instruction at `pc`, 16 or 32 bits.
+    fn read_instruction(&self, pc: UintXlen) -> Result<u32, AccessFault>;
+    fn read_u8(&self, addr: UintXlen) -> Result<u8, AccessFault>;
+    fn write_u8(&self, addr: UintXlen, value: u8) -> Result<(), AccessFault>;
+}
+
+/// Reason a misaligned access was not emulated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MisalignedErrorKind {
+    /// The instruction could not be read from `mepc`.
+    Fetch,
+    /// Not an integer load or store, e.g. a floating point access.
+    Unsupported(u32),
+    /// The trap was taken from VS-mode or VU-mode.
+    Virtualized,
+    /// A byte of the access raised a page or access fault.
+    Access,
+}
+
+/// A misaligned access that was not emulated, and the trap to pass on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MisalignedError {
+    pub kind: MisalignedErrorKind,
+    /// `mcause` to pass on. For `Access` this is the load or store fault, for
+    /// `Fetch` the instruction page or access fault, for the other kinds the
+    /// original misaligned access trap.
+    pub cause: UintXlen,
+    /// `mtval` to pass on, the faulting address.
+    pub tval: UintXlen,
+}
+
+/* Emulate the access at `addr` by the instruction at `frame.mepc`.
+   On error returns the `mtval` to pass on, the address of the fetch or data
+   fault, or `addr` if nothing faulted. */
+#[cfg(any(test, target_arch = "riscv32", target_arch = "riscv64"))]
+#[allow(clippy::unnecessary_cast)]
+fn emulate<M: Memory>(frame: &mut TrapFrame, mem: &M, addr: UintXlen) -> Result<(), (MisalignedErrorKind, UintXlen)> {
+    let insn = mem
+        .read_instruction(frame.mepc)
+        .map_err(|fault| (MisalignedErrorKind::Fetch, fault.addr))?;
+    let op = MemoryAccess::decode(insn).ok_or((MisalignedErrorKind::Unsupported(insn), addr))?;
+    let width = op.width as usize;
+    let fault = |fault: AccessFault| (MisalignedErrorKind::Access, fault.addr);
+
+    if op.store {
+        let value = frame.reg(op.reg as usize) as u64;
+        for i in 0..width {
+            mem.write_u8(addr.wrapping_add(i as UintXlen), (value >> (8 * i)) as u8)
+                .map_err(fault)?;
+        }
+    } else {
+        let mut value: u64 = 0;
+        for i in 0..width {
+            let byte = mem.read_u8(addr.wrapping_add(i as UintXlen)).map_err(fault)?;
+            value |= (byte as u64) << (8 * i);
+        }
+        if op.signed && width < 8 {
+            let shift = 64 - 8 * width as u32;
+            value = (((value << shift) as i64) >> shift) as u64;
+        }
+        frame.set_reg(op.reg as usize, value as UintXlen);
+    }
+    frame.mepc = frame.mepc.wrapping_add(op.len as UintXlen);
+    Ok(())
+}
+
+/// Emulate the misaligned load or store that raised the trap in `frame`.
+///
+/// Called from the M-mode handler for `mcause` 4 (load) or 6 (store), with the
+/// address in `mtval`. The access is done a byte at a time, a load writes `rd`
+/// in `frame`, and `frame.mepc` is advanced past the instruction.
+///
+/// On error `frame` is not changed, but a store that faults part way has
+/// already written the bytes before the fault. The trap can be passed on with
+/// the `cause` and `tval` of the error, the restarted store then writes all bytes.
+///
+/// e.g. in the M-mode trap handler
+/// ~~~ignore
+/// if let Err(error) = unsafe { emulate_misaligned(frame) } {
+///     redirect_trap_to_supervisor(frame, error.cause, error.tval);
+/// }
+/// ~~~
+///
+/// # Safety
+/// Must be called from the M-mode trap handler with `frame` holding the trapped
+/// `mepc` and `mstatus`. `mepc`, `mcause` and `mtval` are overwritten if an access faults.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub unsafe fn emulate_misaligned(frame: &mut TrapFrame) -> Result<(), MisalignedError> {
+    let cause = crate::csr_read_mcause!();
+    let addr = crate::csr_read_mtval!();
+    if frame.previous_virtualized() {
+        return Err(MisalignedError {
+            kind: MisalignedErrorKind::Virtualized,
+            cause,
+            tval: addr,
+        });
+    }
+    let mode = frame.previous_mode().unwrap_or(PrivilegeMode::Machine);
+    let result = mprv::with_mprv(mode, |mem| emulate(frame, mem, addr));
+    result.map_err(|(kind, tval)| {
+        let cause = match kind {
+            // The fault taken by the byte access.
+            MisalignedErrorKind::Access => crate::csr_read_mcause!(),
+            // The instruction is read with a load, pass on the matching fetch fault.
+            MisalignedErrorKind::Fetch if crate::csr_read_mcause!() == Exception::LoadPageFault.code() => {
+                Exception::InstructionPageFault.code()
+            }
+            MisalignedErrorKind::Fetch => Exception::InstructionFault.code(),
+            _ => cause,
+        };
+        MisalignedError { kind, cause, tval }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::RefCell;
+
+    fn access(width: u8, signed: bool, store: bool, reg: u8, len: u8) -> Option<MemoryAccess> {
+        Some(MemoryAccess { width, signed, store, reg, len })
+    }
+
+    #[test]
+    fn decode_32_loads() {
+        // lw a0, 0(a1)
+        assert_eq!(MemoryAccess::decode_32(0x0005a503), access(4, true, false, 10, 4));
+        // lhu t0, 2(sp)
+        assert_eq!(MemoryAccess::decode_32(0x00215283), access(2, false, false, 5, 4));
+        // lh a0, 0(a1)
+        assert_eq!(MemoryAccess::decode_32(0x00059503), access(2, true, false, 10, 4));
+        // ld a0, 0(a1), RV64 only
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(MemoryAccess::decode_32(0x0005b503), access(8, true, false, 10, 4));
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(MemoryAccess::decode_32(0x0005b503), None);
+    }
+
+    #[test]
+    fn decode_32_stores() {
+        // sh a1, 0(a0)
+        assert_eq!(MemoryAccess::decode_32(0x00b51023), access(2, false, true, 11, 4));
+        // sw a1, 0(a0)
+        assert_eq!(MemoryAccess::decode_32(0x00b52023), access(4, false, true, 11, 4));
+    }
+
+    #[test]
+    fn decode_32_rejects_other_instructions() {
+        // flw fa0, 0(a1)
+        assert_eq!(MemoryAccess::decode_32(0x0005a507), None);
+        // addi a0, a0, 1
+        assert_eq!(MemoryAccess::decode_32(0x00150513), None);
+    }
+
+    #[test]
+    fn decode_16() {
+        // c.lw a0, 0(a1)
+        assert_eq!(MemoryAccess::decode_16(0x4188), access(4, true, false, 10, 2));
+        // c.sw a0, 0(a1)
+        assert_eq!(MemoryAccess::decode_16(0xc188), access(4, false, true, 10, 2));
+        // c.lwsp a0, 0(sp)
+        assert_eq!(MemoryAccess::decode_16(0x4502), access(4, true, false, 10, 2));
+        // c.swsp a1, 0(sp)
+        assert_eq!(MemoryAccess::decode_16(0xc02e), access(4, false, true, 11, 2));
+        // c.lwsp with rd = x0 is reserved.
+        assert_eq!(MemoryAccess::decode_16(0x4002), None);
+        // c.addi a0, 1
+        assert_eq!(MemoryAccess::decode_16(0x0505), None);
+    }
+
+    #[test]
+    fn decode_selects_length() {
+        assert_eq!(MemoryAccess::decode(0x0005a503), MemoryAccess::decode_32(0x0005a503));
+        // The upper half of a 32 bit fetch is ignored for a compressed instruction.
+        assert_eq!(MemoryAccess::decode(0xffff4188), MemoryAccess::decode_16(0x4188));
+    }
+
+    const BASE: UintXlen = 0x1000;
+    const DATA: UintXlen = BASE + 0x21;
+
+    /* 64 bytes at BASE with the instruction at BASE, an access to `fault` faults. */
+    struct TestMemory {
+        bytes: RefCell<[u8; 64]>,
+        fault: Option<UintXlen>,
+    }
+
+    impl TestMemory {
+        fn new(insn: u32) -> Self {
+            let mut bytes = [0; 64];
+            bytes[..4].copy_from_slice(&insn.to_le_bytes());
+            Self {
+                bytes: RefCell::new(bytes),
+                fault: None,
+            }
+        }
+        fn offset(&self, addr: UintXlen) -> Result<usize, AccessFault> {
+            if self.fault == Some(addr) {
+                Err(AccessFault { addr })
+            } else {
+                Ok((addr - BASE) as usize)
+            }
+        }
+        fn data(&self, len: usize) -> Vec<u8> {
+            let start = (DATA - BASE) as usize;
+            self.bytes.borrow()[start..start + len].to_vec()
+        }
+        fn set_data(&self, data: &[u8]) {
+            let start = (DATA - BASE) as usize;
+            self.bytes.borrow_mut()[start..start + data.len()].copy_from_slice(data);
+        }
+    }
+
+    impl Memory for TestMemory {
+        fn read_instruction(&self, pc: UintXlen) -> Result<u32, AccessFault> {
+            let halfword = |addr| -> Result<u32, AccessFault> {
+                let i = self.offset(addr)?;
+                let bytes = self.bytes.borrow();
+                Ok(u16::from_le_bytes([bytes[i], bytes[i + 1]]) as u32)
+            };
+            let low = halfword(pc)?;
+            if low & 0x3 != 0x3 {
+                return Ok(low);
+            }
+            Ok(low | (halfword(pc + 2)? << 16))
+        }
+        fn read_u8(&self, addr: UintXlen) -> Result<u8, AccessFault> {
+            let i = self.offset(addr)?;
+            Ok(self.bytes.borrow()[i])
+        }
+        fn write_u8(&self, addr: UintXlen, value: u8) -> Result<(), AccessFault> {
+            let i = self.offset(addr)?;
+            self.bytes.borrow_mut()[i] = value;
+            Ok(())
+        }
+    }
+
+    fn trapped_frame() -> TrapFrame {
+        let mut frame = TrapFrame::new();
+        frame.mepc = BASE;
+        frame
+    }
+
+    #[test]
+    fn emulate_loads() {
+        // lw a0, 0(a1)
+        let mem = TestMemory::new(0x0005a503);
+        mem.set_data(&[0x78, 0x56, 0x34, 0x12]);
+        let mut frame = trapped_frame();
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(frame.reg(10), 0x12345678);
+        assert_eq!(frame.mepc, BASE + 4);
+
+        // lhu a0, 0(a1)
+        let mem = TestMemory::new(0x0005d503);
+        mem.set_data(&[0xff, 0x80]);
+        let mut frame = trapped_frame();
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(frame.reg(10), 0x80ff);
+    }
+
+    #[test]
+    fn emulate_sign_extension() {
+        // lh a0, 0(a1)
+        let mem = TestMemory::new(0x00059503);
+        mem.set_data(&[0xff, 0x80]);
+        let mut frame = trapped_frame();
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(frame.reg(10), 0x80ff_u16 as i16 as UintXlen);
+
+        // lw a0, 0(a1), sign extended to 64 bits on RV64.
+        let mem = TestMemory::new(0x0005a503);
+        mem.set_data(&[0x01, 0x02, 0x03, 0x80]);
+        let mut frame = trapped_frame();
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(frame.reg(10), 0x80030201_u32 as i32 as UintXlen);
+    }
+
+    #[test]
+    fn emulate_stores() {
+        // sw a1, 0(a0)
+        let mem = TestMemory::new(0x00b52023);
+        let mut frame = trapped_frame();
+        frame.set_reg(11, 0x12345678);
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(mem.data(5), [0x78, 0x56, 0x34, 0x12, 0x00]);
+        assert_eq!(frame.mepc, BASE + 4);
+
+        // sh a1, 0(a0)
+        let mem = TestMemory::new(0x00b51023);
+        let mut frame = trapped_frame();
+        frame.set_reg(11, 0x12345678);
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(mem.data(3), [0x78, 0x56, 0x00]);
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn emulate_doublewords() {
+        // ld a0, 0(a1)
+        let mem = TestMemory::new(0x0005b503);
+        mem.set_data(&[1, 2, 3, 4, 5, 6, 7, 0x80]);
+        let mut frame = trapped_frame();
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(frame.reg(10), 0x8007060504030201);
+
+        // sd a1, 0(a0)
+        let mem = TestMemory::new(0x00b53023);
+        let mut frame = trapped_frame();
+        frame.set_reg(11, 0x0807060504030201);
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(mem.data(8), [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn emulate_compressed() {
+        // c.lw a0, 0(a1)
+        let mem = TestMemory::new(0x4188);
+        mem.set_data(&[0x01, 0x02, 0x03, 0x84]);
+        let mut frame = trapped_frame();
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(frame.reg(10), 0x84030201_u32 as i32 as UintXlen);
+        assert_eq!(frame.mepc, BASE + 2);
+
+        // c.sw a0, 0(a1)
+        let mem = TestMemory::new(0xc188);
+        let mut frame = trapped_frame();
+        frame.set_reg(10, 0x12345678);
+        assert_eq!(emulate(&mut frame, &mem, DATA), Ok(()));
+        assert_eq!(mem.data(4), [0x78, 0x56, 0x34, 0x12]);
+        assert_eq!(frame.mepc, BASE + 2);
+    }
+
+    #[test]
+    fn emulate_fetch_fault() {
+        // The upper half of lw a0, 0(a1) is not readable.
+        let mut mem = TestMemory::new(0x0005a503);
+        mem.fault = Some(BASE + 2);
+        let mut frame = trapped_frame();
+        assert_eq!(emulate(&mut frame, &mem, DATA), Err((MisalignedErrorKind::Fetch, BASE + 2)));
+        assert_eq!(frame, trapped_frame());
+    }
+
+    #[test]
+    fn emulate_access_fault() {
+        // lw a0, 0(a1)
+        let mut mem = TestMemory::new(0x0005a503);
+        mem.fault = Some(DATA + 2);
+        let mut frame = trapped_frame();
+        assert_eq!(emulate(&mut frame, &mem, DATA), Err((MisalignedErrorKind::Access, DATA + 2)));
+        assert_eq!(frame, trapped_frame());
+
+        // sw a1, 0(a0), the bytes before the fault are written.
+        let mut mem = TestMemory::new(0x00b52023);
+        mem.fault = Some(DATA + 2);
+        let mut frame = trapped_frame();
+        frame.set_reg(11, 0x12345678);
+        assert_eq!(emulate(&mut frame, &mem, DATA), Err((MisalignedErrorKind::Access, DATA + 2)));
+        assert_eq!(mem.data(2), [0x78, 0x56]);
+        assert_eq!(frame.mepc, BASE);
+    }
+
+    #[test]
+    fn emulate_unsupported() {
+        // flw fa0, 0(a1)
+        let mem = TestMemory::new(0x0005a507);
+        let mut frame = trapped_frame();
+        assert_eq!(
+            emulate(&mut frame, &mem, DATA),
+            Err((MisalignedErrorKind::Unsupported(0x0005a507), DATA))
+        );
+        assert_eq!(frame, trapped_frame());
+    }
+}
@@ -0,0 +1,192 @@
+/*
+   M-mode access to the memory of a lower privilege mode with mstatus.MPRV.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - MPRV is only set for the duration of each access instruction,
+   with the probe trap handler installed in mtvec. A fault is returned
+   as an error and mstatus is always restored, so the M-mode trap handler
+   does not need to know about these accesses.
+*/
+
+use core::arch::asm;
+
+use crate::status::PrivilegeMode;
+use crate::trap::AccessFault;
+use crate::UintXlen;
+use crate::{MSTATUS_MIE_BIT_MASK, MSTATUS_MPP_BIT_MASK, MSTATUS_MPP_BIT_OFFSET, MSTATUS_MPRV_BIT_MASK, MSTATUS_MXR_BIT_MASK};
+#[cfg(target_pointer_width = "32")]
+use crate::MSTATUSH_MPV_BIT_MASK;
+
+/* mstatus.MPV, on RV32 it is in mstatush. */
+#[cfg(target_pointer_width = "64")]
+const MSTATUS_MPV: UintXlen = crate::MSTATUS_MPV_BIT_MASK;
+#[cfg(target_pointer_width = "32")]
+const MSTATUS_MPV: UintXlen = 0;
+
+/* Run one access instruction with `mstatus` set to `access` and the probe
+   trap handler in `mtvec`, then restore both. The access is assembled as
+   a 4 byte instruction, so the probe handler can skip it. */
+macro_rules! mprv_asm {
+    ( $insn:literal, $access:expr, $addr:expr, $($value:tt)* ) => {{
+        let trapped: usize;
+        asm!(".option push",
+             ".option norvc",
+             "csrrw {tvec}, mtvec, {handler}",
+             "csrrw {org}, mstatus, {access}",
+             $insn,
+             "csrw mstatus, {org}",
+             "csrw mtvec, {tvec}",
+             ".option pop",
+             tvec = out(reg) _,
+             handler = in(reg) crate::probe::trap_handler(),
+             org = out(reg) _,
+             access = in(reg) $access,
+             addr = in(reg) $addr,
+             $($value)*,
+             inout("a0") 0usize => trapped,
+             out("t0") _,
+             options(nostack));
+        trapped == 0
+    }};
+}
+
+/// Memory as seen by a lower privilege mode, given to the `with_mprv()` closure.
+///
+/// Loads and stores are translated and checked as that mode.
+pub struct LowerMemory {
+    /* mstatus during an access. */
+    access: UintXlen,
+}
+
+impl LowerMemory {
+    /// Privilege mode the accesses are made as.
+    pub fn mode(&self) -> Option<PrivilegeMode> {
+        PrivilegeMode::from_bits((self.access & MSTATUS_MPP_BIT_MASK) >> MSTATUS_MPP_BIT_OFFSET)
+    }
+
+    #[inline]
+    pub fn read_u8(&self, addr: UintXlen) -> Result<u8, AccessFault> {
+        let value: usize;
+        if unsafe { mprv_asm!("lbu {value}, 0({addr})", self.access, addr, value = out(reg) value) } {
+            Ok(value as u8)
+        } else {
+            Err(AccessFault { addr })
+        }
+    }
+
+    #[inline]
+    pub fn write_u8(&self, addr: UintXlen, value: u8) -> Result<(), AccessFault> {
+        if unsafe { mprv_asm!("sb {value}, 0({addr})", self.access, addr, value = in(reg) value as usize) } {
+            Ok(())
+        } else {
+            Err(AccessFault { addr })
+        }
+    }
+
+    /* Halfword load, `addr` must be 2 byte aligned. */
+    #[inline]
+    fn read_u16(&self, access: UintXlen, addr: UintXlen) -> Result<u16, AccessFault> {
+        let value: usize;
+        if unsafe { mprv_asm!("lhu {value}, 0({addr})", access, addr, value = out(reg) value) } {
+            Ok(value as u16)
+        } else {
+            Err(AccessFault { addr })
+        }
+    }
+
+    /// Read the instruction at `pc`, 16 or 32 bits.
+    ///
+    /// `mstatus.MXR` is set so execute-only pages can be read.
+    pub fn read_instruction(&self, pc: UintXlen) -> Result<u32, AccessFault> {
+        let access = self.access | MSTATUS_MXR_BIT_MASK;
+        let low = self.read_u16(access, pc)? as u32;
+        if low & 0x3 != 0x3 {
+            return Ok(low);
+        }
+        let high = self.read_u16(access, pc.wrapping_add(2))? as u32;
+        Ok(low | (high << 16))
+    }
+
+    /// Copy `dst.len()` bytes from `src`.
+    pub fn copy_from(&self, dst: &mut [u8], src: UintXlen) -> Result<(), AccessFault> {
+        for (i, byte) in dst.iter_mut().enumerate() {
+            *byte = self.read_u8(src.wrapping_add(i as UintXlen))?;
+        }
+        Ok(())
+    }
+
+    /// Copy `src` to `dst`.
+    pub fn copy_to(&self, dst: UintXlen, src: &[u8]) -> Result<(), AccessFault> {
+        for (i, byte) in src.iter().enumerate() {
+            self.write_u8(dst.wrapping_add(i as UintXlen), *byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::misaligned::Memory for LowerMemory {
+    #[inline]
+    fn read_instruction(&self, pc: UintXlen) -> Result<u32, AccessFault> {
+        LowerMemory::read_instruction(self, pc)
+    }
+    #[inline]
+    fn read_u8(&self, addr: UintXlen) -> Result<u8, AccessFault> {
+        LowerMemory::read_u8(self, addr)
+    }
+    #[inline]
+    fn write_u8(&self, addr: UintXlen, value: u8) -> Result<(), AccessFault> {
+        LowerMemory::write_u8(self, addr, value)
+    }
+}
+
+/// Run `f` with access to memory as seen by `mode`.
+///
+/// `mode` is HS-mode or U-mode, `MPV` is cleared while `f` runs so the accesses
+/// never use the guest two-stage translation, even after a trap from VS-mode or VU-mode.
+/// M-mode interrupts are disabled while `f` runs. `mstatus` and `mtvec` are
+/// restored after each access, including one that faults, so `f` may return
+/// early on an `AccessFault` and any other trap in `f` goes to the normal handler.
+/// A fault overwrites `mepc`, `mcause` and `mtval`.
+///
+/// e.g. in an SBI call handler
+/// ~~~ignore
+/// let mut buf = [0u8; 64];
+/// let result = unsafe {
+///     with_mprv(PrivilegeMode::Supervisor, |mem| mem.copy_from(&mut buf, frame.reg(10)))
+/// };
+/// ~~~
+///
+/// # Safety
+/// Must be called from M-mode. With `mode` set to `Machine` the accesses are not checked.
+pub unsafe fn with_mprv<R>(mode: PrivilegeMode, f: impl FnOnce(&LowerMemory) -> R) -> R {
+    let org = crate::csr_read_clr_bits_mstatus!(MSTATUS_MIE_BIT_MASK);
+    let access = (org & !(MSTATUS_MIE_BIT_MASK | MSTATUS_MPP_BIT_MASK | MSTATUS_MPV))
+        | (mode.bits() << MSTATUS_MPP_BIT_OFFSET)
+        | MSTATUS_MPRV_BIT_MASK;
+    // Also restored after a fault, which clears MPV.
+    #[cfg(target_pointer_width = "32")]
+    let org_mstatush = crate::csr_read_clr_bits_mstatush!(MSTATUSH_MPV_BIT_MASK);
+    let result = f(&LowerMemory { access });
+    #[cfg(target_pointer_width = "32")]
+    crate::csr_set_bits_mstatush!(org_mstatush & MSTATUSH_MPV_BIT_MASK);
+    crate::csr_set_bits_mstatus!(org & MSTATUS_MIE_BIT_MASK);
+    result
+}
+
+/// Copy `dst.len()` bytes from `src` in the address space of `mode`.
+///
+/// # Safety
+/// See `with_mprv()`.
+pub unsafe fn copy_from_lower(mode: PrivilegeMode, dst: &mut [u8], src: UintXlen) -> Result<(), AccessFault> {
+    with_mprv(mode, |mem| mem.copy_from(dst, src))
+}
+
+/// Copy `src` to `dst` in the address space of `mode`.
+///
+/// # Safety
+/// See `with_mprv()`.
+pub unsafe fn copy_to_lower(mode: PrivilegeMode, dst: UintXlen, src: &[u8]) -> Result<(), AccessFault> {
+    with_mprv(mode, |mem| mem.copy_to(dst, src))
+}
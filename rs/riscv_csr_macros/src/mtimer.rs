@@ -0,0 +1,173 @@
+/*
+   Memory mapped machine timer (CLINT or ACLINT MTIMER) and the M-mode timer interrupt.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - The base address and layout are platform specific, they are
+   given by the clint or aclint-mtimer node of the device tree.
+*/
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::UintXlen;
+use crate::{MTIMECMP_MMIO_OFFSET, MTIMECMP_MMIO_STRIDE, MTIME_MMIO_OFFSET};
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::{MIE_MTI_BIT_MASK, MIP_MTI_BIT_MASK};
+
+/// Compare value that never expires.
+pub const NEVER: u64 = u64::MAX;
+
+/// Platform `mtime` and per-hart `mtimecmp` registers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mtimer {
+    mtime: usize,
+    mtimecmp: usize,
+    stride: usize,
+}
+
+impl Mtimer {
+    /// Timer with `mtime` at `mtime`, and `mtimecmp` for hart N at `mtimecmp + N * stride`.
+    ///
+    /// # Safety
+    /// The addresses must map the timer registers.
+    pub const unsafe fn new(mtime: usize, mtimecmp: usize, stride: usize) -> Self {
+        Self {
+            mtime,
+            mtimecmp,
+            stride,
+        }
+    }
+
+    /// Timer in a SiFive compatible CLINT at `base`, e.g. 0x200_0000.
+    ///
+    /// # Safety
+    /// `base` must be the address of the CLINT.
+    pub const unsafe fn clint(base: usize) -> Self {
+        Self::new(base + MTIME_MMIO_OFFSET, base + MTIMECMP_MMIO_OFFSET, MTIMECMP_MMIO_STRIDE)
+    }
+
+    /// Timer in an ACLINT MTIMER device, `mtimecmp` at `base` and `mtime` at `base + 0x7ff8`.
+    ///
+    /// # Safety
+    /// `base` must be the address of the MTIMER device.
+    pub const unsafe fn aclint(base: usize) -> Self {
+        Self::new(base + 0x7ff8, base, MTIMECMP_MMIO_STRIDE)
+    }
+
+    /// Read `mtime`.
+    #[inline]
+    pub fn now(&self) -> u64 {
+        crate::mmio_read_mtime!(self.mtime)
+    }
+
+    /// Write `mtime`, this changes the time seen by all harts.
+    #[inline]
+    pub fn set_now(&self, value: u64) {
+        crate::mmio_write_mtime!(self.mtime, value);
+    }
+
+    #[inline]
+    fn mtimecmp_addr(&self, hart: usize) -> usize {
+        self.mtimecmp + hart * self.stride
+    }
+
+    /// Read `mtimecmp` of `hart`.
+    #[inline]
+    pub fn compare(&self, hart: usize) -> u64 {
+        crate::mmio_read_mtimecmp!(self.mtimecmp_addr(hart))
+    }
+
+    /// Write `mtimecmp` of `hart`.
+    #[inline]
+    pub fn set_compare(&self, hart: usize, value: u64) {
+        crate::mmio_write_mtimecmp!(self.mtimecmp_addr(hart), value);
+    }
+}
+
+/// M-mode timer interrupt of one hart.
+///
+/// The interrupt is pending while `mtime >= mtimecmp`,
+/// it is cleared by writing a later deadline or cancelling.
+///
+/// e.g. a periodic tick
+/// ~~~ignore
+/// let timer = MachineTimer::current_hart(unsafe { Mtimer::clint(0x200_0000) });
+/// timer.set_timeout(PERIOD);
+/// // In the M-mode trap handler, for mcause interrupt 7:
+/// timer.advance(PERIOD);
+/// ~~~
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MachineTimer {
+    mtimer: Mtimer,
+    hart: usize,
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+impl MachineTimer {
+    pub const fn new(mtimer: Mtimer, hart: usize) -> Self {
+        Self { mtimer, hart }
+    }
+
+    /// Timer of the hart given by `mhartid`.
+    #[inline]
+    pub fn current_hart(mtimer: Mtimer) -> Self {
+        Self::new(mtimer, crate::csr_read_mhartid!() as usize)
+    }
+
+    pub const fn mtimer(&self) -> &Mtimer {
+        &self.mtimer
+    }
+
+    pub const fn hart(&self) -> usize {
+        self.hart
+    }
+
+    #[inline]
+    pub fn now(&self) -> u64 {
+        self.mtimer.now()
+    }
+
+    /// Raise the timer interrupt when `mtime` reaches `deadline` and enable it, `mie.MTIE`.
+    #[inline]
+    pub fn set_deadline(&self, deadline: u64) {
+        self.mtimer.set_compare(self.hart, deadline);
+        crate::csr_set_bits_mie!(MIE_MTI_BIT_MASK);
+    }
+
+    /// Raise the timer interrupt `ticks` from now, returns the deadline.
+    #[inline]
+    pub fn set_timeout(&self, ticks: u64) -> u64 {
+        let deadline = self.now().saturating_add(ticks);
+        self.set_deadline(deadline);
+        deadline
+    }
+
+    /// Move the deadline `ticks` past the current one, returns the new deadline.
+    ///
+    /// Used in the interrupt handler for a periodic tick without drift.
+    #[inline]
+    pub fn advance(&self, ticks: u64) -> u64 {
+        let deadline = self.deadline().saturating_add(ticks);
+        self.set_deadline(deadline);
+        deadline
+    }
+
+    #[inline]
+    pub fn deadline(&self) -> u64 {
+        self.mtimer.compare(self.hart)
+    }
+
+    /// Disable the timer interrupt and disarm the timer.
+    #[inline]
+    pub fn cancel(&self) {
+        crate::csr_clr_bits_mie!(MIE_MTI_BIT_MASK);
+        self.mtimer.set_compare(self.hart, NEVER);
+    }
+
+    /// The timer interrupt is pending, `mip.MTIP`.
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        (crate::csr_read_mip!() & MIP_MTI_BIT_MASK) != 0
+    }
+}
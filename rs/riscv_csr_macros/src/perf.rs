@@ -0,0 +1,600 @@
+/*
+   Performance monitoring over the mhpmcounter, mhpmevent, mcountinhibit,
+   mcounteren and scounteren registers.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - The counters and event selectors are M-mode registers.
+   Event selector values are implementation defined.
+*/
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use core::arch::asm;
+
+use crate::status::PrivilegeMode;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::UintCsr32;
+use crate::UintXlen;
+
+/// Index of a counter, 0 = `mcycle`, 2 = `minstret`, 3..31 = `mhpmcounterN`.
+///
+/// Index 1 is `time`, it can only be read with `read_user_counter()`.
+pub type CounterIndex = u32;
+
+/// Set of counters, bit N is counter N as in `mcountinhibit` and `mcounteren`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CounterMask(pub u32);
+
+impl CounterMask {
+    pub const NONE: Self = Self(0);
+    /// `mcycle`.
+    pub const CY: Self = Self(0x1);
+    /// `time`, only for `mcounteren` and `scounteren`.
+    pub const TM: Self = Self(0x2);
+    /// `minstret`.
+    pub const IR: Self = Self(0x4);
+    /// `mhpmcounter3` to `mhpmcounter31`.
+    pub const HPM: Self = Self(0xffff_fff8);
+
+    pub const fn counter(index: CounterIndex) -> Self {
+        Self(1 << index)
+    }
+    pub const fn contains(self, index: CounterIndex) -> bool {
+        index < 32 && (self.0 & (1 << index)) != 0
+    }
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// Iterate over the counter indices in the set.
+    pub fn iter(self) -> impl Iterator<Item = CounterIndex> {
+        (0..32).filter(move |i| self.contains(*i))
+    }
+}
+
+impl core::ops::BitOr for CounterMask {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// Event selector encoding, implemented per vendor.
+pub trait EventEncoding {
+    /// Value to write to `mhpmeventN`.
+    fn selector(&self) -> UintXlen;
+}
+
+/// Event selector value written as is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RawEvent(pub UintXlen);
+
+impl EventEncoding for RawEvent {
+    fn selector(&self) -> UintXlen {
+        self.0
+    }
+}
+
+/// SiFive event encoding, as used by the E3, S5, U5 and U7 series cores.
+///
+/// `mhpmevent[7:0]` selects the event class, the bits above select events
+/// in that class. The counter increments when any selected event occurs.
+pub mod sifive {
+    use super::EventEncoding;
+    use crate::UintXlen;
+
+    /// Events in one class, the value is the mask bit position.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct SiFiveEvent {
+        pub class: u8,
+        pub mask: UintXlen,
+    }
+
+    impl SiFiveEvent {
+        /// Count the events of `self` or `other`, both must be in the same class.
+        pub const fn or(self, other: Self) -> Self {
+            Self {
+                class: self.class,
+                mask: self.mask | other.mask,
+            }
+        }
+    }
+
+    impl EventEncoding for SiFiveEvent {
+        fn selector(&self) -> UintXlen {
+            self.class as UintXlen | self.mask
+        }
+    }
+
+    const fn event(class: u8, bit: u32) -> SiFiveEvent {
+        SiFiveEvent { class, mask: 1 << bit }
+    }
+
+    /* Class 0, instruction commit events. */
+    pub const EXCEPTION_TAKEN: SiFiveEvent = event(0, 8);
+    pub const INT_LOAD_RETIRED: SiFiveEvent = event(0, 9);
+    pub const INT_STORE_RETIRED: SiFiveEvent = event(0, 10);
+    pub const ATOMIC_RETIRED: SiFiveEvent = event(0, 11);
+    pub const SYSTEM_RETIRED: SiFiveEvent = event(0, 12);
+    pub const INT_ARITH_RETIRED: SiFiveEvent = event(0, 13);
+    pub const BRANCH_RETIRED: SiFiveEvent = event(0, 14);
+    pub const JAL_RETIRED: SiFiveEvent = event(0, 15);
+    pub const JALR_RETIRED: SiFiveEvent = event(0, 16);
+    pub const INT_MUL_RETIRED: SiFiveEvent = event(0, 17);
+    pub const INT_DIV_RETIRED: SiFiveEvent = event(0, 18);
+    /* Class 1, microarchitectural events. */
+    pub const LOAD_USE_INTERLOCK: SiFiveEvent = event(1, 8);
+    pub const LONG_LATENCY_INTERLOCK: SiFiveEvent = event(1, 9);
+    pub const CSR_READ_INTERLOCK: SiFiveEvent = event(1, 10);
+    pub const ICACHE_BUSY: SiFiveEvent = event(1, 11);
+    pub const DCACHE_BUSY: SiFiveEvent = event(1, 12);
+    pub const BRANCH_DIRECTION_MISPREDICT: SiFiveEvent = event(1, 13);
+    pub const BRANCH_TARGET_MISPREDICT: SiFiveEvent = event(1, 14);
+    pub const PIPELINE_FLUSH_CSR: SiFiveEvent = event(1, 15);
+    pub const PIPELINE_FLUSH_OTHER: SiFiveEvent = event(1, 16);
+    pub const INT_MUL_INTERLOCK: SiFiveEvent = event(1, 17);
+    /* Class 2, memory system events. */
+    pub const ICACHE_MISS: SiFiveEvent = event(2, 8);
+    pub const DCACHE_MISS: SiFiveEvent = event(2, 9);
+    pub const DCACHE_WRITEBACK: SiFiveEvent = event(2, 10);
+    pub const ITLB_MISS: SiFiveEvent = event(2, 11);
+    pub const DTLB_MISS: SiFiveEvent = event(2, 12);
+}
+
+/* Access a counter CSR by index, the CSR number must be an immediate.
+   Not `nomem`, so like the generated CSR macros the reads are compiler
+   barriers and memory accesses are not moved across them. */
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline(always)]
+fn csr_read<const CSR: u16>() -> UintXlen {
+    let value: UintXlen;
+    unsafe {
+        asm!("csrr {0}, {csr}", out(reg) value, csr = const CSR, options(nostack));
+    }
+    value
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline(always)]
+fn csr_write<const CSR: u16>(value: UintXlen) {
+    unsafe {
+        asm!("csrw {csr}, {0}", in(reg) value, csr = const CSR, options(nostack));
+    }
+}
+
+/* Expand $f::<BASE + N>($args) for a runtime counter index N. */
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+macro_rules! by_index {
+    ( $index:expr, $base:literal, $f:ident $args:tt ) => {
+        by_index!(@arms $index, $base, $f $args;
+                  0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                  16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31)
+    };
+    ( @arms $index:expr, $base:literal, $f:ident $args:tt; $($n:literal)* ) => {
+        match $index {
+            $( $n => $f::<{ $base + $n }> $args, )*
+            _ => panic!("counter index out of range"),
+        }
+    };
+}
+
+/// Read a counter, 0 = `mcycle`, 2 = `minstret`, 3..31 = `mhpmcounterN`.
+///
+/// There is no M-mode counter 1, `time` is only read through `read_user_counter()`.
+/// On RV32 the high half is re-read to detect a carry between the two reads.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn read_counter(index: CounterIndex) -> u64 {
+    assert!(index != 1 && index < 32);
+    #[cfg(target_pointer_width = "32")]
+    loop {
+        let hi = by_index!(index, 0xb80, csr_read());
+        let lo = by_index!(index, 0xb00, csr_read());
+        if hi == by_index!(index, 0xb80, csr_read()) {
+            return ((hi as u64) << 32) | lo as u64;
+        }
+    }
+    #[cfg(target_pointer_width = "64")]
+    by_index!(index, 0xb00, csr_read())
+}
+
+/// Read a counter through its unprivileged shadow, `cycle`, `time`, `instret` or `hpmcounterN`.
+///
+/// Index 1 is `time`. Below M-mode the counter must be enabled in `mcounteren` and,
+/// for U-mode, `scounteren`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn read_user_counter(index: CounterIndex) -> u64 {
+    assert!(index < 32);
+    #[cfg(target_pointer_width = "32")]
+    loop {
+        let hi = by_index!(index, 0xc80, csr_read());
+        let lo = by_index!(index, 0xc00, csr_read());
+        if hi == by_index!(index, 0xc80, csr_read()) {
+            return ((hi as u64) << 32) | lo as u64;
+        }
+    }
+    #[cfg(target_pointer_width = "64")]
+    by_index!(index, 0xc00, csr_read())
+}
+
+/// Write a counter, 0 = `mcycle`, 2 = `minstret`, 3..31 = `mhpmcounterN`.
+///
+/// The counter should be inhibited on RV32.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn write_counter(index: CounterIndex, value: u64) {
+    assert!(index != 1 && index < 32);
+    #[cfg(target_pointer_width = "32")]
+    {
+        // Clear the low half first so it does not carry into the new high half.
+        by_index!(index, 0xb00, csr_write(0));
+        by_index!(index, 0xb80, csr_write((value >> 32) as UintXlen));
+        by_index!(index, 0xb00, csr_write(value as UintXlen));
+    }
+    #[cfg(target_pointer_width = "64")]
+    by_index!(index, 0xb00, csr_write(value))
+}
+
+/// Write the event selector `mhpmeventN`, for N = 3..31.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn write_event(index: CounterIndex, selector: UintXlen) {
+    assert!(index >= 3);
+    by_index!(index, 0x320, csr_write(selector))
+}
+
+/// Read the event selector `mhpmeventN`, for N = 3..31.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn read_event(index: CounterIndex) -> UintXlen {
+    assert!(index >= 3);
+    by_index!(index, 0x320, csr_read())
+}
+
+/// Overflow flag and privilege mode inhibit bits of `mhpmeventN` (Sscofpmf).
+///
+/// On RV64 these are `mhpmeventN[63:58]`, on RV32 `mhpmeventNh[31:26]`.
+/// The same inhibit bits are used by `mcyclecfg` and `minstretcfg` (Smcntrpmf),
+/// see `write_mode_filter()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EventFlags(pub u8);
+
+impl EventFlags {
+    pub const NONE: Self = Self(0);
+    /// Do not count in VU-mode.
+    pub const VUINH: Self = Self(1 << 0);
+    /// Do not count in VS-mode.
+    pub const VSINH: Self = Self(1 << 1);
+    /// Do not count in U-mode.
+    pub const UINH: Self = Self(1 << 2);
+    /// Do not count in S/HS-mode.
+    pub const SINH: Self = Self(1 << 3);
+    /// Do not count in M-mode.
+    pub const MINH: Self = Self(1 << 4);
+    /// Counter has overflowed, no overflow interrupt is raised while set.
+    pub const OF: Self = Self(1 << 5);
+    /// All inhibit bits.
+    pub const ALL_INH: Self = Self(0x1f);
+
+    /// Inhibit counting in all modes other than `mode`, VS-mode and VU-mode are inhibited.
+    pub const fn only_in(mode: PrivilegeMode) -> Self {
+        let count = match mode {
+            PrivilegeMode::User => Self::UINH,
+            PrivilegeMode::Supervisor => Self::SINH,
+            PrivilegeMode::Machine => Self::MINH,
+        };
+        Self::ALL_INH.difference(count)
+    }
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl core::ops::BitOr for EventFlags {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[cfg(target_pointer_width = "64")]
+const EVENT_FLAGS_OFFSET: isize = crate::MHPMEVENT3_VUINH_BIT_OFFSET;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[cfg(target_pointer_width = "32")]
+const EVENT_FLAGS_OFFSET: isize = crate::MHPMEVENT3H_VUINH_BIT_OFFSET;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+const EVENT_FLAGS_MASK: UintXlen = (EventFlags::OF.0 as UintXlen | EventFlags::ALL_INH.0 as UintXlen) << EVENT_FLAGS_OFFSET;
+
+/// Read the overflow and inhibit bits of `mhpmeventN`, for N = 3..31.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn read_event_flags(index: CounterIndex) -> EventFlags {
+    assert!(index >= 3);
+    #[cfg(target_pointer_width = "64")]
+    let value = by_index!(index, 0x320, csr_read());
+    #[cfg(target_pointer_width = "32")]
+    let value = by_index!(index, 0x720, csr_read());
+    EventFlags(((value & EVENT_FLAGS_MASK) >> EVENT_FLAGS_OFFSET) as u8)
+}
+
+/// Write the overflow and inhibit bits of `mhpmeventN`, for N = 3..31.
+///
+/// NOTE - not atomic, read-modify-write of the event selector.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn write_event_flags(index: CounterIndex, flags: EventFlags) {
+    assert!(index >= 3);
+    let bits = ((flags.0 as UintXlen) << EVENT_FLAGS_OFFSET) & EVENT_FLAGS_MASK;
+    #[cfg(target_pointer_width = "64")]
+    {
+        let value = by_index!(index, 0x320, csr_read());
+        by_index!(index, 0x320, csr_write((value & !EVENT_FLAGS_MASK) | bits));
+    }
+    #[cfg(target_pointer_width = "32")]
+    {
+        let value = by_index!(index, 0x720, csr_read());
+        by_index!(index, 0x720, csr_write((value & !EVENT_FLAGS_MASK) | bits));
+    }
+}
+
+/// Counter with a privilege mode filter in `mcyclecfg` or `minstretcfg` (Smcntrpmf).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilteredCounter {
+    Cycle,
+    Instret,
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+const MODE_FILTER_MASK: UintXlen = (EventFlags::ALL_INH.0 as UintXlen) << EVENT_FLAGS_OFFSET;
+
+/* mcyclecfg/minstretcfg on RV64, the h registers on RV32 */
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[cfg(target_pointer_width = "64")]
+fn read_counter_cfg(counter: FilteredCounter) -> UintXlen {
+    match counter {
+        FilteredCounter::Cycle => crate::csr_read_mcyclecfg!(),
+        FilteredCounter::Instret => crate::csr_read_minstretcfg!(),
+    }
+}
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[cfg(target_pointer_width = "64")]
+fn write_counter_cfg(counter: FilteredCounter, value: UintXlen) {
+    match counter {
+        FilteredCounter::Cycle => crate::csr_write_mcyclecfg!(value),
+        FilteredCounter::Instret => crate::csr_write_minstretcfg!(value),
+    }
+}
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[cfg(target_pointer_width = "32")]
+fn read_counter_cfg(counter: FilteredCounter) -> UintXlen {
+    match counter {
+        FilteredCounter::Cycle => crate::csr_read_mcyclecfgh!(),
+        FilteredCounter::Instret => crate::csr_read_minstretcfgh!(),
+    }
+}
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[cfg(target_pointer_width = "32")]
+fn write_counter_cfg(counter: FilteredCounter, value: UintXlen) {
+    match counter {
+        FilteredCounter::Cycle => crate::csr_write_mcyclecfgh!(value),
+        FilteredCounter::Instret => crate::csr_write_minstretcfgh!(value),
+    }
+}
+
+/// Read the privilege mode inhibit bits of `mcycle` or `minstret`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn read_mode_filter(counter: FilteredCounter) -> EventFlags {
+    EventFlags(((read_counter_cfg(counter) & MODE_FILTER_MASK) >> EVENT_FLAGS_OFFSET) as u8)
+}
+
+/// Write the privilege mode inhibit bits of `mcycle` or `minstret`, `OF` is ignored.
+///
+/// NOTE - not atomic, read-modify-write.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn write_mode_filter(counter: FilteredCounter, flags: EventFlags) {
+    let value = read_counter_cfg(counter) & !MODE_FILTER_MASK;
+    write_counter_cfg(counter, value | (((flags.0 as UintXlen) << EVENT_FLAGS_OFFSET) & MODE_FILTER_MASK));
+}
+
+/// Count cycles or instructions retired only while in `mode`.
+///
+/// e.g. `count_only_in(FilteredCounter::Cycle, PrivilegeMode::Supervisor)` counts kernel time.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn count_only_in(counter: FilteredCounter, mode: PrivilegeMode) {
+    write_mode_filter(counter, EventFlags::only_in(mode));
+}
+
+/// Counters with the overflow flag set, from `scountovf`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn overflowed() -> CounterMask {
+    CounterMask(crate::csr_read_scountovf!() & crate::SCOUNTOVF_HPM_BIT_MASK as UintCsr32)
+}
+
+/// Stop the counters in `mask` with a single `csrrs` on `mcountinhibit`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn stop(mask: CounterMask) {
+    crate::csr_set_bits_mcountinhibit!(mask.0);
+}
+
+/// Start the counters in `mask` with a single `csrrc` on `mcountinhibit`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn start(mask: CounterMask) {
+    crate::csr_clr_bits_mcountinhibit!(mask.0);
+}
+
+/// Counters that are currently stopped.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn stopped() -> CounterMask {
+    CounterMask(crate::csr_read_mcountinhibit!())
+}
+
+/// Allow S-mode to read the counters in `mask` through `cycle`, `time`, `instret` and `hpmcounterN`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn expose_to_supervisor(mask: CounterMask) {
+    crate::csr_set_bits_mcounteren!(mask.0);
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn hide_from_supervisor(mask: CounterMask) {
+    crate::csr_clr_bits_mcounteren!(mask.0);
+}
+
+/// Allow U-mode to read the counters in `mask`, they must also be exposed to S-mode.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn expose_to_user(mask: CounterMask) {
+    crate::csr_set_bits_scounteren!(mask.0);
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn hide_from_user(mask: CounterMask) {
+    crate::csr_clr_bits_scounteren!(mask.0);
+}
+
+/// Values of a set of counters, all read while the counters were stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub mask: CounterMask,
+    pub values: [u64; 32],
+}
+
+impl Snapshot {
+    /// Value of counter `index`, `None` if it is not in the snapshot.
+    pub const fn get(&self, index: CounterIndex) -> Option<u64> {
+        if self.mask.contains(index) {
+            Some(self.values[index as usize])
+        } else {
+            None
+        }
+    }
+    /// Counter increments from `earlier` to `self`, for counters in both snapshots.
+    pub fn delta(&self, earlier: &Snapshot) -> Snapshot {
+        let mut result = Snapshot {
+            mask: CounterMask(self.mask.0 & earlier.mask.0),
+            values: [0; 32],
+        };
+        for i in result.mask.iter() {
+            result.values[i as usize] = self.values[i as usize].wrapping_sub(earlier.values[i as usize]);
+        }
+        result
+    }
+}
+
+/// Read the counters in `mask` as one consistent set.
+///
+/// The counters are stopped while they are read, the counters that
+/// were running are restarted with a single write. `time` can not be
+/// stopped and is left out.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub fn snapshot(mask: CounterMask) -> Snapshot {
+    let mask = CounterMask(mask.0 & !CounterMask::TM.0);
+    let was_stopped = CounterMask(crate::csr_read_set_bits_mcountinhibit!(mask.0));
+    let mut result = Snapshot { mask, values: [0; 32] };
+    for i in mask.iter() {
+        result.values[i as usize] = read_counter(i);
+    }
+    start(CounterMask(mask.0 & !was_stopped.0));
+    result
+}
+
+/// Allocator for the `mhpmcounter` registers.
+///
+/// e.g.
+/// ~~~ignore
+/// let mut pmu = PerfMonitor::new(CounterMask::HPM);
+/// let misses = pmu.allocate(&sifive::DCACHE_MISS).unwrap();
+/// pmu.start_all();
+/// // ...
+/// pmu.stop_all();
+/// let s = pmu.snapshot();
+/// ~~~
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub struct PerfMonitor {
+    available: CounterMask,
+    allocated: CounterMask,
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+impl PerfMonitor {
+    /// Manage the counters in `available`, e.g. from `probe::HartCapabilities::hpm_counters`.
+    ///
+    /// Only counters 3..31 are allocated, `mcycle` and `minstret` are always free running.
+    pub const fn new(available: CounterMask) -> Self {
+        Self {
+            available: CounterMask(available.0 & CounterMask::HPM.0),
+            allocated: CounterMask::NONE,
+        }
+    }
+
+    /// Counters in use.
+    pub const fn allocated(&self) -> CounterMask {
+        self.allocated
+    }
+
+    /// Allocate a free counter, program it to count `event` and clear it.
+    ///
+    /// The counter is left stopped. Returns `None` if no counter is free.
+    pub fn allocate<E: EventEncoding>(&mut self, event: &E) -> Option<CounterIndex> {
+        let index = CounterMask(self.available.0 & !self.allocated.0).iter().next()?;
+        self.allocated.0 |= 1 << index;
+        stop(CounterMask::counter(index));
+        write_event(index, event.selector());
+        write_counter(index, 0);
+        Some(index)
+    }
+
+    /// Stop a counter and return it to the free set.
+    pub fn free(&mut self, index: CounterIndex) {
+        if self.allocated.contains(index) {
+            stop(CounterMask::counter(index));
+            write_event(index, 0);
+            self.allocated.0 &= !(1 << index);
+        }
+    }
+
+    /// Start all allocated counters together.
+    #[inline]
+    pub fn start_all(&self) {
+        start(self.allocated);
+    }
+
+    /// Stop all allocated counters together.
+    #[inline]
+    pub fn stop_all(&self) {
+        stop(self.allocated);
+    }
+
+    /// Clear all allocated counters, they should be stopped.
+    pub fn reset_all(&self) {
+        for i in self.allocated.iter() {
+            write_counter(i, 0);
+        }
+    }
+
+    /// Read all allocated counters, with `mcycle` and `minstret`.
+    pub fn snapshot(&self) -> Snapshot {
+        snapshot(self.allocated | CounterMask::CY | CounterMask::IR)
+    }
+}
@@ -0,0 +1,315 @@
+/*
+   Runtime probing of the optional CSRs and WARL fields of a hart.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - Probing is done in M-mode with a temporary trap handler
+   installed in mtvec. Accesses that raise an exception are skipped.
+*/
+
+use core::arch::{asm, global_asm};
+
+use crate::atp::{self, ModeSet};
+use crate::UintXlen;
+use crate::{MSTATUS_MIE_BIT_MASK, MTVEC_MODE_BIT_MASK};
+
+/* Temporary trap handler. Skips the trapping CSR instruction (always 4 bytes)
+   and sets a0 to flag the exception. Only t0 and a0 are modified, the
+   probe sequences declare both. */
+global_asm!(
+    ".pushsection .text.riscv_csr_probe_trap, \"ax\", @progbits",
+    ".balign 4",
+    ".global riscv_csr_probe_trap",
+    "riscv_csr_probe_trap:",
+    "csrr t0, mepc",
+    "addi t0, t0, 4",
+    "csrw mepc, t0",
+    "li a0, 1",
+    "mret",
+    ".popsection",
+);
+
+extern "C" {
+    fn riscv_csr_probe_trap();
+}
+
+/// Address of the probe trap handler, for a direct mode `mtvec`.
+#[inline(always)]
+pub(crate) fn trap_handler() -> UintXlen {
+    riscv_csr_probe_trap as unsafe extern "C" fn() as usize as UintXlen
+}
+
+/// Read a CSR by address, returns `None` if the read raised an exception.
+///
+/// # Safety
+/// Only valid while the probe trap handler is installed.
+#[inline(always)]
+pub(crate) unsafe fn try_read<const CSR: u16>() -> Option<UintXlen> {
+    let value: UintXlen;
+    let trapped: usize;
+    asm!("csrr {0}, {csr}",
+         out(reg) value,
+         csr = const CSR,
+         inout("a0") 0usize => trapped,
+         out("t0") _,
+         options(nostack));
+    if trapped == 0 {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Write `value` to a CSR, read back and restore the original value.
+/// Returns `None` if the CSR access raised an exception.
+///
+/// # Safety
+/// Only valid while the probe trap handler is installed, the
+/// written value must have no side effects on the running code.
+#[inline(always)]
+pub(crate) unsafe fn try_write_read<const CSR: u16>(value: UintXlen) -> Option<UintXlen> {
+    let readback: UintXlen;
+    let trapped: usize;
+    asm!("csrr {0}, {csr}",
+         "csrw {csr}, {1}",
+         "csrr {1}, {csr}",
+         "csrw {csr}, {0}",
+         out(reg) _,
+         inout(reg) value => readback,
+         csr = const CSR,
+         inout("a0") 0usize => trapped,
+         out("t0") _,
+         options(nostack));
+    if trapped == 0 {
+        Some(readback)
+    } else {
+        None
+    }
+}
+
+/// Run `f` with the probe trap handler installed and M-mode interrupts disabled.
+///
+/// # Safety
+/// Must be called from M-mode. Any trap raised by `f` is skipped.
+pub(crate) unsafe fn with_probe_handler<R>(f: impl FnOnce() -> R) -> R {
+    let org_mstatus = crate::csr_read_clr_bits_mstatus!(MSTATUS_MIE_BIT_MASK);
+    let org_mtvec = crate::csr_read_write_mtvec!(trap_handler());
+    let result = f();
+    crate::csr_write_mtvec!(org_mtvec);
+    crate::csr_set_bits_mstatus!(org_mstatus & MSTATUS_MIE_BIT_MASK);
+    result
+}
+
+/// Optional CSRs that are probed, the value is the bit in `CsrSet`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptionalCsr {
+    /// S-mode address translation.
+    Satp = 0,
+    /// S-mode, trap delegation.
+    Medeleg = 1,
+    Mideleg = 2,
+    Scounteren = 3,
+    Mcountinhibit = 4,
+    Menvcfg = 5,
+    Mseccfg = 6,
+    Mconfigptr = 7,
+    /// Hypervisor extension.
+    Hstatus = 8,
+    Mtval2 = 9,
+    Mtinst = 10,
+    /// Sstc extension.
+    Stimecmp = 11,
+    /// Debug triggers.
+    Tselect = 12,
+    /// Memory mapped `mtime` shadow, often emulated by M-mode software.
+    Time = 13,
+}
+
+impl OptionalCsr {
+    pub const ALL: &'static [OptionalCsr] = &[
+        OptionalCsr::Satp,
+        OptionalCsr::Medeleg,
+        OptionalCsr::Mideleg,
+        OptionalCsr::Scounteren,
+        OptionalCsr::Mcountinhibit,
+        OptionalCsr::Menvcfg,
+        OptionalCsr::Mseccfg,
+        OptionalCsr::Mconfigptr,
+        OptionalCsr::Hstatus,
+        OptionalCsr::Mtval2,
+        OptionalCsr::Mtinst,
+        OptionalCsr::Stimecmp,
+        OptionalCsr::Tselect,
+        OptionalCsr::Time,
+    ];
+
+    /// CSR address.
+    pub const fn address(self) -> u16 {
+        match self {
+            OptionalCsr::Satp => crate::SATP_CSR_ADDRESS,
+            OptionalCsr::Medeleg => crate::MEDELEG_CSR_ADDRESS,
+            OptionalCsr::Mideleg => crate::MIDELEG_CSR_ADDRESS,
+            OptionalCsr::Scounteren => crate::SCOUNTEREN_CSR_ADDRESS,
+            OptionalCsr::Mcountinhibit => crate::MCOUNTINHIBIT_CSR_ADDRESS,
+            OptionalCsr::Menvcfg => crate::MENVCFG_CSR_ADDRESS,
+            OptionalCsr::Mseccfg => crate::MSECCFG_CSR_ADDRESS,
+            OptionalCsr::Mconfigptr => crate::MCONFIGPTR_CSR_ADDRESS,
+            OptionalCsr::Hstatus => crate::HSTATUS_CSR_ADDRESS,
+            OptionalCsr::Mtval2 => crate::MTVAL2_CSR_ADDRESS,
+            OptionalCsr::Mtinst => crate::MTINST_CSR_ADDRESS,
+            OptionalCsr::Stimecmp => crate::STIMECMP_CSR_ADDRESS,
+            OptionalCsr::Tselect => crate::TSELECT_CSR_ADDRESS,
+            OptionalCsr::Time => crate::TIME_CSR_ADDRESS,
+        }
+    }
+
+    /// Returns true if reading the CSR does not raise an exception.
+    ///
+    /// # Safety
+    /// Only valid while the probe trap handler is installed.
+    unsafe fn readable(self) -> bool {
+        match self {
+            OptionalCsr::Satp => try_read::<{ crate::SATP_CSR_ADDRESS }>(),
+            OptionalCsr::Medeleg => try_read::<{ crate::MEDELEG_CSR_ADDRESS }>(),
+            OptionalCsr::Mideleg => try_read::<{ crate::MIDELEG_CSR_ADDRESS }>(),
+            OptionalCsr::Scounteren => try_read::<{ crate::SCOUNTEREN_CSR_ADDRESS }>(),
+            OptionalCsr::Mcountinhibit => try_read::<{ crate::MCOUNTINHIBIT_CSR_ADDRESS }>(),
+            OptionalCsr::Menvcfg => try_read::<{ crate::MENVCFG_CSR_ADDRESS }>(),
+            OptionalCsr::Mseccfg => try_read::<{ crate::MSECCFG_CSR_ADDRESS }>(),
+            OptionalCsr::Mconfigptr => try_read::<{ crate::MCONFIGPTR_CSR_ADDRESS }>(),
+            OptionalCsr::Hstatus => try_read::<{ crate::HSTATUS_CSR_ADDRESS }>(),
+            OptionalCsr::Mtval2 => try_read::<{ crate::MTVAL2_CSR_ADDRESS }>(),
+            OptionalCsr::Mtinst => try_read::<{ crate::MTINST_CSR_ADDRESS }>(),
+            OptionalCsr::Stimecmp => try_read::<{ crate::STIMECMP_CSR_ADDRESS }>(),
+            OptionalCsr::Tselect => try_read::<{ crate::TSELECT_CSR_ADDRESS }>(),
+            OptionalCsr::Time => try_read::<{ crate::TIME_CSR_ADDRESS }>(),
+        }
+        .is_some()
+    }
+}
+
+/// Set of implemented optional CSRs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CsrSet(pub u32);
+
+impl CsrSet {
+    pub const fn contains(self, csr: OptionalCsr) -> bool {
+        (self.0 & (1 << csr as u32)) != 0
+    }
+}
+
+/// Trap vector modes accepted by the `mtvec.MODE` WARL field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MtvecModes {
+    pub direct: bool,
+    pub vectored: bool,
+}
+
+/// Result of `probe()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HartCapabilities {
+    /// Optional CSRs that can be read.
+    pub csrs: CsrSet,
+    /// Bit N is set if `mhpmcounterN` is writable (N = 3..31).
+    pub hpm_counters: u32,
+    pub mtvec_modes: MtvecModes,
+    /// Modes accepted by `satp.MODE`, empty if there is no `satp`.
+    pub satp_modes: ModeSet,
+    /// Modes accepted by `hgatp.MODE`, empty without the hypervisor extension.
+    pub hgatp_modes: ModeSet,
+    /// Value of `misa`, 0 if not implemented.
+    pub misa: UintXlen,
+    /// Extension bits of `misa` that could be toggled.
+    ///
+    /// `C`, `E` and `I` are not tested as changing them affects instruction fetch.
+    pub misa_writable: UintXlen,
+}
+
+impl HartCapabilities {
+    pub const fn has(&self, csr: OptionalCsr) -> bool {
+        self.csrs.contains(csr)
+    }
+    pub const fn has_hpm_counter(&self, n: u32) -> bool {
+        n < 32 && (self.hpm_counters & (1 << n)) != 0
+    }
+}
+
+/* Write all ones to mhpmcounterN, set bit N if any bit sticks. */
+macro_rules! probe_hpm_counters {
+    ( $( $n:literal => $csr:literal ),* ) => {{
+        let mut counters: u32 = 0;
+        $(
+            if let Some(value) = try_write_read::<$csr>(UintXlen::MAX) {
+                if value != 0 {
+                    counters |= 1 << $n;
+                }
+            }
+        )*
+        counters
+    }};
+}
+
+/// Extension bits in `misa` that are not probed.
+const MISA_UNPROBED: UintXlen = (1 << (b'C' - b'A')) | (1 << (b'E' - b'A')) | (1 << (b'I' - b'A'));
+
+/// Probe the optional CSRs and WARL fields of this hart.
+///
+/// All CSRs are restored to their original values.
+///
+/// # Safety
+/// Must be called from M-mode with no other harts sharing the trap vector.
+/// The hpm counters are written, so any running count is lost.
+pub unsafe fn probe() -> HartCapabilities {
+    with_probe_handler(|| {
+        let mut caps = HartCapabilities::default();
+        for csr in OptionalCsr::ALL {
+            if csr.readable() {
+                caps.csrs.0 |= 1 << *csr as u32;
+            }
+        }
+
+        caps.hpm_counters = probe_hpm_counters!(
+            3 => 0xb03, 4 => 0xb04, 5 => 0xb05, 6 => 0xb06, 7 => 0xb07,
+            8 => 0xb08, 9 => 0xb09, 10 => 0xb0a, 11 => 0xb0b, 12 => 0xb0c,
+            13 => 0xb0d, 14 => 0xb0e, 15 => 0xb0f, 16 => 0xb10, 17 => 0xb11,
+            18 => 0xb12, 19 => 0xb13, 20 => 0xb14, 21 => 0xb15, 22 => 0xb16,
+            23 => 0xb17, 24 => 0xb18, 25 => 0xb19, 26 => 0xb1a, 27 => 0xb1b,
+            28 => 0xb1c, 29 => 0xb1d, 30 => 0xb1e, 31 => 0xb1f);
+
+        // Exceptions always go to the base address, so the handler stays valid.
+        let handler = crate::csr_read_mtvec!() & !MTVEC_MODE_BIT_MASK;
+        for mode in 0..2 {
+            if let Some(value) = try_write_read::<{ crate::MTVEC_CSR_ADDRESS }>(handler | mode) {
+                let accepted = (value & MTVEC_MODE_BIT_MASK) == mode;
+                match mode {
+                    0 => caps.mtvec_modes.direct = accepted,
+                    _ => caps.mtvec_modes.vectored = accepted,
+                }
+            }
+        }
+
+        if caps.has(OptionalCsr::Satp) {
+            caps.satp_modes = atp::probe_satp_modes();
+        }
+        if caps.has(OptionalCsr::Hstatus) {
+            caps.hgatp_modes = atp::probe_hgatp_modes();
+        }
+
+        caps.misa = try_read::<{ crate::MISA_CSR_ADDRESS }>().unwrap_or(0);
+        if caps.misa != 0 {
+            for bit in 0..26 {
+                let mask: UintXlen = 1 << bit;
+                if mask & MISA_UNPROBED != 0 {
+                    continue;
+                }
+                if let Some(value) = try_write_read::<{ crate::MISA_CSR_ADDRESS }>(caps.misa ^ mask) {
+                    if (value ^ caps.misa) & mask != 0 {
+                        caps.misa_writable |= mask;
+                    }
+                }
+            }
+        }
+        caps
+    })
+}
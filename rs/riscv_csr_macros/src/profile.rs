@@ -0,0 +1,147 @@
+/*
+   Sampling profiler driven by the local counter overflow interrupt (LCOFI)
+   of the Sscofpmf extension.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - Reloading the counter needs M-mode. With LCOFI delegated to
+   S-mode the reload must be done by M-mode firmware (e.g. SBI PMU).
+*/
+
+use crate::perf::{self, CounterIndex, CounterMask, EventEncoding, EventFlags};
+use crate::UintXlen;
+use crate::{MIE_LCOFI_BIT_MASK, MIP_LCOFI_BIT_MASK, SIE_LCOFI_BIT_MASK, SIP_LCOFI_BIT_MASK};
+
+/// Histogram of the interrupted PC, sampled every `period` events.
+///
+/// Bucket `i` counts samples with `base + (i << shift) <= pc < base + ((i + 1) << shift)`.
+///
+/// e.g.
+/// ~~~ignore
+/// static mut BUCKETS: [u32; 1024] = [0; 1024];
+/// let mut profiler = Profiler::new(3, 10_000, text_start, 4, unsafe { &mut BUCKETS }).unwrap();
+/// profiler.arm_machine(&RawEvent(0), EventFlags::MINH);
+/// // In the M-mode trap handler, for mcause interrupt 13:
+/// profiler.handle_machine();
+/// ~~~
+pub struct Profiler<'a> {
+    counter: CounterIndex,
+    period: u64,
+    base: usize,
+    shift: u32,
+    buckets: &'a mut [u32],
+    /// Samples outside the bucket range.
+    dropped: u32,
+}
+
+impl<'a> Profiler<'a> {
+    /// Sample with `mhpmcounterN` every `period` events.
+    ///
+    /// Returns `None` unless `counter` is 3..31.
+    pub fn new(counter: CounterIndex, period: u64, base: usize, shift: u32, buckets: &'a mut [u32]) -> Option<Self> {
+        if !(3..=31).contains(&counter) {
+            return None;
+        }
+        Some(Self {
+            counter,
+            period,
+            base,
+            shift,
+            buckets,
+            dropped: 0,
+        })
+    }
+
+    pub fn buckets(&self) -> &[u32] {
+        self.buckets
+    }
+
+    /// Samples outside the bucket range.
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+
+    /// Clear the histogram.
+    pub fn reset(&mut self) {
+        self.buckets.fill(0);
+        self.dropped = 0;
+    }
+
+    /// Add one sample to the histogram.
+    pub fn record(&mut self, pc: usize) {
+        let index = pc.wrapping_sub(self.base) >> self.shift;
+        match self.buckets.get_mut(index) {
+            Some(count) => *count = count.saturating_add(1),
+            None => self.dropped = self.dropped.saturating_add(1),
+        }
+    }
+
+    /// Load the counter so it overflows after `period` events and clear the overflow flag.
+    ///
+    /// M-mode only.
+    pub fn rearm(&self) {
+        perf::write_counter(self.counter, 0u64.wrapping_sub(self.period));
+        let flags = perf::read_event_flags(self.counter);
+        perf::write_event_flags(self.counter, flags.difference(EventFlags::OF));
+    }
+
+    /* Program the event and start the counter, the interrupt is enabled by the caller. */
+    fn arm<E: EventEncoding>(&self, event: &E, inhibit: EventFlags) {
+        let mask = CounterMask::counter(self.counter);
+        perf::stop(mask);
+        perf::write_event(self.counter, event.selector());
+        perf::write_event_flags(self.counter, inhibit.difference(EventFlags::OF));
+        perf::write_counter(self.counter, 0u64.wrapping_sub(self.period));
+        perf::start(mask);
+    }
+
+    /// Start sampling `event`, with the overflow interrupt taken in M-mode.
+    ///
+    /// `inhibit` selects the privilege modes that are not counted.
+    pub fn arm_machine<E: EventEncoding>(&self, event: &E, inhibit: EventFlags) {
+        self.arm(event, inhibit);
+        crate::csr_set_bits_mie!(MIE_LCOFI_BIT_MASK);
+    }
+
+    /// Start sampling `event`, with the overflow interrupt delegated to S-mode.
+    ///
+    /// Called from M-mode, LCOFI must also be set in `mideleg`.
+    pub fn arm_supervisor<E: EventEncoding>(&self, event: &E, inhibit: EventFlags) {
+        self.arm(event, inhibit);
+        crate::csr_set_bits_sie!(SIE_LCOFI_BIT_MASK);
+    }
+
+    /// Stop sampling and disable the overflow interrupt.
+    pub fn disarm(&self) {
+        perf::stop(CounterMask::counter(self.counter));
+        crate::csr_clr_bits_mie!(MIE_LCOFI_BIT_MASK);
+    }
+
+    /// M-mode LCOFI handler, records `mepc` and re-arms the counter.
+    ///
+    /// Returns false if this counter has not overflowed.
+    pub fn handle_machine(&mut self) -> bool {
+        if !perf::read_event_flags(self.counter).contains(EventFlags::OF) {
+            return false;
+        }
+        self.record(crate::csr_read_mepc!() as usize);
+        self.rearm();
+        crate::csr_clr_bits_mip!(MIP_LCOFI_BIT_MASK);
+        true
+    }
+
+    /// S-mode LCOFI handler, records `sepc` and clears the pending interrupt.
+    ///
+    /// No further overflow interrupt is raised until M-mode firmware
+    /// reloads the counter and clears its overflow flag.
+    /// Returns false if this counter has not overflowed.
+    pub fn handle_supervisor(&mut self) -> bool {
+        if !perf::overflowed().contains(self.counter) {
+            return false;
+        }
+        self.record(crate::csr_read_sepc!() as usize);
+        crate::csr_clr_bits_sip!(SIP_LCOFI_BIT_MASK);
+        true
+    }
+}
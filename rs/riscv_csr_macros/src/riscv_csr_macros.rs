@@ -6,7 +6,7 @@
 
 */
 
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
 #[cfg(target_pointer_width = "32")]
 pub type UintXlen = u32;
@@ -29,9 +29,43 @@ const __riscv_xlen : isize = 32;
 #[cfg(target_pointer_width = "64")]
 const __riscv_xlen : isize = 64;
 
+/* Typed access built on the register macros below.
+   Code that executes CSR or other RISC-V instructions is only built for
+   RISC-V targets, the rest can be unit tested on the host. */
+pub mod status;
+pub mod fenv;
+pub mod context;
+pub mod vector;
+pub mod atp;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod probe;
+pub mod isa;
+pub mod ident;
+pub mod perf;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod measure;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod profile;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod timer;
+pub mod clock;
+pub mod mtimer;
+pub mod trap;
+pub mod deleg;
+pub mod insn;
+pub mod misaligned;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod mprv;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod uaccess;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod hyp;
+
 /*******************************************
  * misa - MRW - Machine ISA 
  */
+/* misa: CSR address, as used in the csr field of the CSR instructions. */
+pub const MISA_CSR_ADDRESS: u16 = 0x301;
 /* misa: CSR Whole register access */
 /* misa: CSR read.
 e.g.
@@ -57,9 +91,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_misa {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    misa, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    misa, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -71,10 +108,11 @@ e.g.
 macro_rules! csr_read_write_misa {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, misa, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, misa, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -82,10 +120,121 @@ macro_rules! csr_read_write_misa {
 }
 
 /* misa: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_misa!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_misa {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, misa, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_misa!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_misa {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, misa, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_misa!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_misa {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, misa, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_misa!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_misa {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, misa, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* misa: CSR Field Modifications - via immediate */
+/* misa, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_misa!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_misa {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, misa, ", stringify!($value)));
+        }
+    );
+}
+
+/* misa, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_misa!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_misa {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, misa, ", stringify!($value)));
+        }
+    );
+}
+/* misa, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_misa!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_misa {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, misa, ", stringify!($value)));
+        }
+    );
+}
 
 /*******************************************
  * mvendorid - MRO - Machine Vendor ID 
  */
+/* mvendorid: CSR address, as used in the csr field of the CSR instructions. */
+pub const MVENDORID_CSR_ADDRESS: u16 = 0xf11;
 /* mvendorid: CSR Whole register access */
 /* mvendorid: CSR read.
 e.g.
@@ -110,6 +259,8 @@ macro_rules! csr_read_mvendorid {
 /*******************************************
  * marchid - MRO - Machine Architecture ID 
  */
+/* marchid: CSR address, as used in the csr field of the CSR instructions. */
+pub const MARCHID_CSR_ADDRESS: u16 = 0xf12;
 /* marchid: CSR Whole register access */
 /* marchid: CSR read.
 e.g.
@@ -134,6 +285,8 @@ macro_rules! csr_read_marchid {
 /*******************************************
  * mimpid - MRO - Machine Implementation ID 
  */
+/* mimpid: CSR address, as used in the csr field of the CSR instructions. */
+pub const MIMPID_CSR_ADDRESS: u16 = 0xf13;
 /* mimpid: CSR Whole register access */
 /* mimpid: CSR read.
 e.g.
@@ -158,6 +311,8 @@ macro_rules! csr_read_mimpid {
 /*******************************************
  * mhartid - MRO - Hardware Thread ID 
  */
+/* mhartid: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHARTID_CSR_ADDRESS: u16 = 0xf14;
 /* mhartid: CSR Whole register access */
 /* mhartid: CSR read.
 e.g.
@@ -182,6 +337,8 @@ macro_rules! csr_read_mhartid {
 /*******************************************
  * mstatus - MRW - Machine Status 
  */
+/* mstatus: CSR address, as used in the csr field of the CSR instructions. */
+pub const MSTATUS_CSR_ADDRESS: u16 = 0x300;
 /* mstatus: CSR Whole register access */
 /* mstatus: CSR read.
 e.g.
@@ -207,9 +364,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mstatus {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mstatus, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mstatus, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -221,10 +381,11 @@ e.g.
 macro_rules! csr_read_write_mstatus {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mstatus, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mstatus, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -239,9 +400,12 @@ csr_set_bits_mstatus!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_mstatus {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, mstatus, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mstatus, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -252,9 +416,12 @@ csr_clr_bits_mstatus!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_mstatus {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, mstatus, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mstatus, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -266,10 +433,11 @@ let org_value_ = csr_read_set_bits_mstatus!(0x0F0F0F)
 macro_rules! csr_read_set_bits_mstatus {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, mstatus, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, mstatus, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -283,10 +451,11 @@ let org_value_ = csr_read_clr_bits_mstatus!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_mstatus {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, mstatus, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, mstatus, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -299,7 +468,7 @@ csr_write_imm_mstatus!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_mstatus {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, mstatus, ", stringify!($value)));
@@ -313,8 +482,8 @@ csr_set_bits_imm_mstatus!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_mstatus {
-    ( MSTATUS_MIE_BIT_MASK) => { csr_set_bits_imm_mstatus!(0x8)};
-    ( MSTATUS_SIE_BIT_MASK) => { csr_set_bits_imm_mstatus!(0x4)};
+    ( MSTATUS_MIE_BIT_MASK) => { $crate::csr_set_bits_imm_mstatus!(0x8)};
+    ( MSTATUS_SIE_BIT_MASK) => { $crate::csr_set_bits_imm_mstatus!(0x2)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -328,9 +497,9 @@ csr_clr_bits_imm_mstatus!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_mstatus {
-    ( MSTATUS_MIE_BIT_MASK) => { csr_clr_bits_imm_mstatus!(0x8)};
-    ( MSTATUS_SIE_BIT_MASK) => { csr_clr_bits_imm_mstatus!(0x4)};
-    ( $value:ident ) => (
+    ( MSTATUS_MIE_BIT_MASK) => { $crate::csr_clr_bits_imm_mstatus!(0x8)};
+    ( MSTATUS_SIE_BIT_MASK) => { $crate::csr_clr_bits_imm_mstatus!(0x2)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, mstatus, ", stringify!($value)));
@@ -341,6 +510,8 @@ macro_rules! csr_clr_bits_imm_mstatus {
 /*******************************************
  * mstatush - MRW - Additional machine status register, RV32 only. 
  */
+/* mstatush: CSR address, as used in the csr field of the CSR instructions. */
+pub const MSTATUSH_CSR_ADDRESS: u16 = 0x310;
 /* mstatush: CSR Whole register access */
 /* mstatush: CSR read.
 e.g.
@@ -366,9 +537,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mstatush {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mstatush, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mstatush, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -380,10 +554,11 @@ e.g.
 macro_rules! csr_read_write_mstatush {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mstatush, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mstatush, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -391,10 +566,123 @@ macro_rules! csr_read_write_mstatush {
 }
 
 /* mstatush: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mstatush!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mstatush {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mstatush, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mstatush!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_mstatush {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mstatush, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_mstatush!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_mstatush {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mstatush, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_mstatush!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_mstatush {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, mstatush, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mstatush: CSR Field Modifications - via immediate */
+/* mstatush, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mstatush!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mstatush {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mstatush, ", stringify!($value)));
+        }
+    );
+}
+
+/* mstatush, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mstatush!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_mstatush {
+    ( MSTATUSH_SBE_BIT_MASK) => { $crate::csr_set_bits_imm_mstatush!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mstatush, ", stringify!($value)));
+        }
+    );
+}
+/* mstatush, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mstatush!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mstatush {
+    ( MSTATUSH_SBE_BIT_MASK) => { $crate::csr_clr_bits_imm_mstatush!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mstatush, ", stringify!($value)));
+        }
+    );
+}
 
 /*******************************************
  * mtvec - MRW - Machine Trap Vector Base Address 
  */
+/* mtvec: CSR address, as used in the csr field of the CSR instructions. */
+pub const MTVEC_CSR_ADDRESS: u16 = 0x305;
 /* mtvec: CSR Whole register access */
 /* mtvec: CSR read.
 e.g.
@@ -420,9 +708,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mtvec {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mtvec, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mtvec, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -434,10 +725,11 @@ e.g.
 macro_rules! csr_read_write_mtvec {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mtvec, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mtvec, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -452,9 +744,12 @@ csr_set_bits_mtvec!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_mtvec {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, mtvec, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mtvec, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -465,9 +760,12 @@ csr_clr_bits_mtvec!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_mtvec {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, mtvec, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mtvec, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -479,10 +777,11 @@ let org_value_ = csr_read_set_bits_mtvec!(0x0F0F0F)
 macro_rules! csr_read_set_bits_mtvec {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, mtvec, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, mtvec, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -496,10 +795,11 @@ let org_value_ = csr_read_clr_bits_mtvec!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_mtvec {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, mtvec, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, mtvec, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -512,7 +812,7 @@ csr_write_imm_mtvec!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_mtvec {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, mtvec, ", stringify!($value)));
@@ -526,7 +826,7 @@ csr_set_bits_imm_mtvec!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_mtvec {
-    ( MTVEC_MODE_BIT_MASK) => { csr_set_bits_imm_mtvec!(0x3)};
+    ( MTVEC_MODE_BIT_MASK) => { $crate::csr_set_bits_imm_mtvec!(0x3)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -540,8 +840,8 @@ csr_clr_bits_imm_mtvec!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_mtvec {
-    ( MTVEC_MODE_BIT_MASK) => { csr_clr_bits_imm_mtvec!(0x3)};
-    ( $value:ident ) => (
+    ( MTVEC_MODE_BIT_MASK) => { $crate::csr_clr_bits_imm_mtvec!(0x3)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, mtvec, ", stringify!($value)));
@@ -552,6 +852,8 @@ macro_rules! csr_clr_bits_imm_mtvec {
 /*******************************************
  * medeleg - MRW - Machine Exception Delegation 
  */
+/* medeleg: CSR address, as used in the csr field of the CSR instructions. */
+pub const MEDELEG_CSR_ADDRESS: u16 = 0x302;
 /* medeleg: CSR Whole register access */
 /* medeleg: CSR read.
 e.g.
@@ -577,9 +879,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_medeleg {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    medeleg, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    medeleg, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -591,10 +896,11 @@ e.g.
 macro_rules! csr_read_write_medeleg {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, medeleg, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, medeleg, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -602,64 +908,304 @@ macro_rules! csr_read_write_medeleg {
 }
 
 /* medeleg: CSR Field Modifications - via register */
-
-/*******************************************
- * mideleg - MRW - Machine Interrupt Delegation 
- */
-/* mideleg: CSR Whole register access */
-/* mideleg: CSR read.
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_mideleg!();
- */
+csr_set_bits_medeleg!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_mideleg {
-    ( ) => (
+macro_rules! csr_set_bits_medeleg {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mideleg" , out(reg) tmp_value);
+                asm!("csrrs    zero, medeleg, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* mideleg: CSR write 
+/* Register CSR bit clear instructions
 e.g.
-    csr_write_mideleg!(0x1234567);
-*/
+csr_clr_bits_medeleg!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_write_mideleg {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mideleg, {0}" , in(reg) $x);
+macro_rules! csr_clr_bits_medeleg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, medeleg, {0}", in(reg) value);
+            }
         }
     );
 }
-/* mideleg: CSR Read and Write 
+/* Register CSR read and then bit set instructions
 e.g.
-    let v_ = csr_read_write_mideleg!(0x1234567);
+let org_value_ = csr_read_set_bits_medeleg!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_read_write_mideleg {
-    ( $x:expr ) => (
+macro_rules! csr_read_set_bits_medeleg {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mideleg, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrs    {0}, medeleg, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_medeleg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_medeleg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, medeleg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* medeleg: CSR Field Modifications - via immediate */
+/* medeleg, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_medeleg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_medeleg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, medeleg, ", stringify!($value)));
+        }
+    );
+}
+
+/* medeleg, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_medeleg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_medeleg {
+    ( MEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK) => { $crate::csr_set_bits_imm_medeleg!(0x1)};
+    ( MEDELEG_INSTRUCTION_FAULT_BIT_MASK) => { $crate::csr_set_bits_imm_medeleg!(0x2)};
+    ( MEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK) => { $crate::csr_set_bits_imm_medeleg!(0x4)};
+    ( MEDELEG_BREAKPOINT_BIT_MASK) => { $crate::csr_set_bits_imm_medeleg!(0x8)};
+    ( MEDELEG_LOAD_MISALIGNED_BIT_MASK) => { $crate::csr_set_bits_imm_medeleg!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, medeleg, ", stringify!($value)));
+        }
+    );
+}
+/* medeleg, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_medeleg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_medeleg {
+    ( MEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK) => { $crate::csr_clr_bits_imm_medeleg!(0x1)};
+    ( MEDELEG_INSTRUCTION_FAULT_BIT_MASK) => { $crate::csr_clr_bits_imm_medeleg!(0x2)};
+    ( MEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK) => { $crate::csr_clr_bits_imm_medeleg!(0x4)};
+    ( MEDELEG_BREAKPOINT_BIT_MASK) => { $crate::csr_clr_bits_imm_medeleg!(0x8)};
+    ( MEDELEG_LOAD_MISALIGNED_BIT_MASK) => { $crate::csr_clr_bits_imm_medeleg!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, medeleg, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * mideleg - MRW - Machine Interrupt Delegation 
+ */
+/* mideleg: CSR address, as used in the csr field of the CSR instructions. */
+pub const MIDELEG_CSR_ADDRESS: u16 = 0x303;
+/* mideleg: CSR Whole register access */
+/* mideleg: CSR read.
+e.g.
+    let _v = csr_read_mideleg!();
+ */
+#[macro_export]
+macro_rules! csr_read_mideleg {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mideleg" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mideleg: CSR write 
+e.g.
+    csr_write_mideleg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mideleg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mideleg, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mideleg: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mideleg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mideleg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mideleg, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
 /* mideleg: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mideleg!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mideleg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mideleg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mideleg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_mideleg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mideleg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_mideleg!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_mideleg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mideleg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_mideleg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_mideleg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, mideleg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mideleg: CSR Field Modifications - via immediate */
+/* mideleg, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mideleg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mideleg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mideleg, ", stringify!($value)));
+        }
+    );
+}
+
+/* mideleg, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mideleg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_mideleg {
+    ( MIDELEG_SSI_BIT_MASK) => { $crate::csr_set_bits_imm_mideleg!(0x2)};
+    ( MIDELEG_VSSI_BIT_MASK) => { $crate::csr_set_bits_imm_mideleg!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mideleg, ", stringify!($value)));
+        }
+    );
+}
+/* mideleg, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mideleg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mideleg {
+    ( MIDELEG_SSI_BIT_MASK) => { $crate::csr_clr_bits_imm_mideleg!(0x2)};
+    ( MIDELEG_VSSI_BIT_MASK) => { $crate::csr_clr_bits_imm_mideleg!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mideleg, ", stringify!($value)));
+        }
+    );
+}
 
 /*******************************************
  * mip - MRW - Machine Interrupt Pending 
  */
+/* mip: CSR address, as used in the csr field of the CSR instructions. */
+pub const MIP_CSR_ADDRESS: u16 = 0x344;
 /* mip: CSR Whole register access */
 /* mip: CSR read.
 e.g.
@@ -685,9 +1231,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mip {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mip, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mip, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -699,10 +1248,11 @@ e.g.
 macro_rules! csr_read_write_mip {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mip, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mip, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -717,9 +1267,12 @@ csr_set_bits_mip!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_mip {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, mip, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mip, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -730,9 +1283,12 @@ csr_clr_bits_mip!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_mip {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, mip, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mip, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -744,10 +1300,11 @@ let org_value_ = csr_read_set_bits_mip!(0x0F0F0F)
 macro_rules! csr_read_set_bits_mip {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, mip, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, mip, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -761,10 +1318,11 @@ let org_value_ = csr_read_clr_bits_mip!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_mip {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, mip, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, mip, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -777,7 +1335,7 @@ csr_write_imm_mip!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_mip {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, mip, ", stringify!($value)));
@@ -791,10 +1349,10 @@ csr_set_bits_imm_mip!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_mip {
-    ( MIP_MSI_BIT_MASK) => { csr_set_bits_imm_mip!(0x8)};
-    ( MIP_SSI_BIT_MASK) => { csr_set_bits_imm_mip!(0x2)};
-    ( MIP_USI_BIT_MASK) => { csr_set_bits_imm_mip!(0x1)};
-    ( MIP_UTI_BIT_MASK) => { csr_set_bits_imm_mip!(0x10)};
+    ( MIP_MSI_BIT_MASK) => { $crate::csr_set_bits_imm_mip!(0x8)};
+    ( MIP_SSI_BIT_MASK) => { $crate::csr_set_bits_imm_mip!(0x2)};
+    ( MIP_USI_BIT_MASK) => { $crate::csr_set_bits_imm_mip!(0x1)};
+    ( MIP_UTI_BIT_MASK) => { $crate::csr_set_bits_imm_mip!(0x10)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -808,11 +1366,11 @@ csr_clr_bits_imm_mip!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_mip {
-    ( MIP_MSI_BIT_MASK) => { csr_clr_bits_imm_mip!(0x8)};
-    ( MIP_SSI_BIT_MASK) => { csr_clr_bits_imm_mip!(0x2)};
-    ( MIP_USI_BIT_MASK) => { csr_clr_bits_imm_mip!(0x1)};
-    ( MIP_UTI_BIT_MASK) => { csr_clr_bits_imm_mip!(0x10)};
-    ( $value:ident ) => (
+    ( MIP_MSI_BIT_MASK) => { $crate::csr_clr_bits_imm_mip!(0x8)};
+    ( MIP_SSI_BIT_MASK) => { $crate::csr_clr_bits_imm_mip!(0x2)};
+    ( MIP_USI_BIT_MASK) => { $crate::csr_clr_bits_imm_mip!(0x1)};
+    ( MIP_UTI_BIT_MASK) => { $crate::csr_clr_bits_imm_mip!(0x10)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, mip, ", stringify!($value)));
@@ -823,6 +1381,8 @@ macro_rules! csr_clr_bits_imm_mip {
 /*******************************************
  * mie - MRW - Machine Interrupt Enable 
  */
+/* mie: CSR address, as used in the csr field of the CSR instructions. */
+pub const MIE_CSR_ADDRESS: u16 = 0x304;
 /* mie: CSR Whole register access */
 /* mie: CSR read.
 e.g.
@@ -848,9 +1408,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mie {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mie, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mie, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -862,10 +1425,11 @@ e.g.
 macro_rules! csr_read_write_mie {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mie, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mie, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -880,9 +1444,12 @@ csr_set_bits_mie!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_mie {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, mie, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mie, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -893,9 +1460,12 @@ csr_clr_bits_mie!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_mie {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, mie, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mie, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -907,10 +1477,11 @@ let org_value_ = csr_read_set_bits_mie!(0x0F0F0F)
 macro_rules! csr_read_set_bits_mie {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, mie, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, mie, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -924,10 +1495,11 @@ let org_value_ = csr_read_clr_bits_mie!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_mie {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, mie, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, mie, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -940,7 +1512,7 @@ csr_write_imm_mie!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_mie {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, mie, ", stringify!($value)));
@@ -954,10 +1526,10 @@ csr_set_bits_imm_mie!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_mie {
-    ( MIE_MSI_BIT_MASK) => { csr_set_bits_imm_mie!(0x8)};
-    ( MIE_SSI_BIT_MASK) => { csr_set_bits_imm_mie!(0x2)};
-    ( MIE_USI_BIT_MASK) => { csr_set_bits_imm_mie!(0x1)};
-    ( MIE_UTI_BIT_MASK) => { csr_set_bits_imm_mie!(0x10)};
+    ( MIE_MSI_BIT_MASK) => { $crate::csr_set_bits_imm_mie!(0x8)};
+    ( MIE_SSI_BIT_MASK) => { $crate::csr_set_bits_imm_mie!(0x2)};
+    ( MIE_USI_BIT_MASK) => { $crate::csr_set_bits_imm_mie!(0x1)};
+    ( MIE_UTI_BIT_MASK) => { $crate::csr_set_bits_imm_mie!(0x10)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -971,11 +1543,11 @@ csr_clr_bits_imm_mie!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_mie {
-    ( MIE_MSI_BIT_MASK) => { csr_clr_bits_imm_mie!(0x8)};
-    ( MIE_SSI_BIT_MASK) => { csr_clr_bits_imm_mie!(0x2)};
-    ( MIE_USI_BIT_MASK) => { csr_clr_bits_imm_mie!(0x1)};
-    ( MIE_UTI_BIT_MASK) => { csr_clr_bits_imm_mie!(0x10)};
-    ( $value:ident ) => (
+    ( MIE_MSI_BIT_MASK) => { $crate::csr_clr_bits_imm_mie!(0x8)};
+    ( MIE_SSI_BIT_MASK) => { $crate::csr_clr_bits_imm_mie!(0x2)};
+    ( MIE_USI_BIT_MASK) => { $crate::csr_clr_bits_imm_mie!(0x1)};
+    ( MIE_UTI_BIT_MASK) => { $crate::csr_clr_bits_imm_mie!(0x10)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, mie, ", stringify!($value)));
@@ -984,68 +1556,193 @@ macro_rules! csr_clr_bits_imm_mie {
 }
 
 /*******************************************
- * mcountinhibit - MRW - Machine Counter Inhibit 
+ * mtime (memory mapped) - Machine Timer
  */
-/* mcountinhibit: CSR Whole register access */
-/* mcountinhibit: CSR read.
+/* mtime: Offset from the CLINT base address. */
+pub const MTIME_MMIO_OFFSET: usize = 0xbff8;
+/* mtime: 64 bit volatile read.
+   On RV32 the high word is re-read to detect a carry from the low word.
 e.g.
-    let _v = csr_read_mcountinhibit!();
+    let _v = mmio_read_mtime!(0x2000000 + MTIME_MMIO_OFFSET);
  */
 #[macro_export]
-macro_rules! csr_read_mcountinhibit {
-    ( ) => (
+macro_rules! mmio_read_mtime {
+    ( $addr:expr ) => (
         {
-            let tmp_value: UintCsr32;
+            let addr: usize = $addr;
             unsafe {
-                use core::arch::asm;
-                asm!("csrr    {0}, mcountinhibit" , out(reg) tmp_value);
+                #[cfg(target_pointer_width = "32")]
+                let value = loop {
+                    let hi = core::ptr::read_volatile((addr + 4) as *const u32);
+                    let lo = core::ptr::read_volatile(addr as *const u32);
+                    if hi == core::ptr::read_volatile((addr + 4) as *const u32) {
+                        break ((hi as u64) << 32) | lo as u64;
+                    }
+                };
+                #[cfg(target_pointer_width = "64")]
+                let value = core::ptr::read_volatile(addr as *const u64);
+                value
             }
-            tmp_value
         }
     );
 }
-/* mcountinhibit: CSR write 
+/* mtime: 64 bit volatile write.
+   On RV32 the low word is first cleared, so a tick between the word
+   writes does not carry into the new high word.
 e.g.
-    csr_write_mcountinhibit!(0x1234567);
-*/
+    mmio_write_mtime!(0x2000000 + MTIME_MMIO_OFFSET, 0x1234567);
+ */
 #[macro_export]
-macro_rules! csr_write_mcountinhibit {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mcountinhibit, {0}" , in(reg) $x);
+macro_rules! mmio_write_mtime {
+    ( $addr:expr, $x:expr ) => (
+        {
+            let addr: usize = $addr;
+            let value: u64 = $x;
+            unsafe {
+                #[cfg(target_pointer_width = "32")]
+                {
+                    core::ptr::write_volatile(addr as *mut u32, 0);
+                    core::ptr::write_volatile((addr + 4) as *mut u32, (value >> 32) as u32);
+                    core::ptr::write_volatile(addr as *mut u32, value as u32);
+                }
+                #[cfg(target_pointer_width = "64")]
+                core::ptr::write_volatile(addr as *mut u64, value);
+            }
         }
     );
 }
-/* mcountinhibit: CSR Read and Write 
+
+/*******************************************
+ * mtimecmp (memory mapped) - Machine Timer Compare
+ */
+/* mtimecmp: Offset from the CLINT base address. */
+pub const MTIMECMP_MMIO_OFFSET: usize = 0x4000;
+/* mtimecmp: Address step between harts. */
+pub const MTIMECMP_MMIO_STRIDE: usize = 8;
+/* mtimecmp: 64 bit volatile read.
+   On RV32 the high word is re-read to detect a carry from the low word.
 e.g.
-    let v_ = csr_read_write_mcountinhibit!(0x1234567);
-*/
+    let _v = mmio_read_mtimecmp!(0x2000000 + MTIMECMP_MMIO_OFFSET);
+ */
 #[macro_export]
-macro_rules! csr_read_write_mcountinhibit {
-    ( $x:expr ) => (
+macro_rules! mmio_read_mtimecmp {
+    ( $addr:expr ) => (
         {
-            let tmp_value: UintCsr32;
+            let addr: usize = $addr;
             unsafe {
-                use core::arch::asm;
-                asm!("csrrw    {0}, mcountinhibit, {1}" , out(reg) tmp_value, in(reg) $x);
+                #[cfg(target_pointer_width = "32")]
+                let value = loop {
+                    let hi = core::ptr::read_volatile((addr + 4) as *const u32);
+                    let lo = core::ptr::read_volatile(addr as *const u32);
+                    if hi == core::ptr::read_volatile((addr + 4) as *const u32) {
+                        break ((hi as u64) << 32) | lo as u64;
+                    }
+                };
+                #[cfg(target_pointer_width = "64")]
+                let value = core::ptr::read_volatile(addr as *const u64);
+                value
             }
-            tmp_value
         }
     );
 }
-
-/* mcountinhibit: CSR Field Modifications - via register */
-/* Register CSR bit set instructions.
+/* mtimecmp: 64 bit volatile write.
+   On RV32 the low word is first set to all ones, so the register never
+   holds a value earlier than both the old and new value.
 e.g.
-csr_set_bits_mcountinhibit!(0x0F0F0F);
-*/
+    mmio_write_mtimecmp!(0x2000000 + MTIMECMP_MMIO_OFFSET, 0x1234567);
+ */
 #[macro_export]
-macro_rules! csr_set_bits_mcountinhibit {
-    ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, mcountinhibit, {0}", in(reg) $mask);
+macro_rules! mmio_write_mtimecmp {
+    ( $addr:expr, $x:expr ) => (
+        {
+            let addr: usize = $addr;
+            let value: u64 = $x;
+            unsafe {
+                #[cfg(target_pointer_width = "32")]
+                {
+                    core::ptr::write_volatile(addr as *mut u32, u32::MAX);
+                    core::ptr::write_volatile((addr + 4) as *mut u32, (value >> 32) as u32);
+                    core::ptr::write_volatile(addr as *mut u32, value as u32);
+                }
+                #[cfg(target_pointer_width = "64")]
+                core::ptr::write_volatile(addr as *mut u64, value);
+            }
+        }
+    );
+}
+
+/*******************************************
+ * mcountinhibit - MRW - Machine Counter Inhibit 
+ */
+/* mcountinhibit: CSR address, as used in the csr field of the CSR instructions. */
+pub const MCOUNTINHIBIT_CSR_ADDRESS: u16 = 0x320;
+/* mcountinhibit: CSR Whole register access */
+/* mcountinhibit: CSR read.
+e.g.
+    let _v = csr_read_mcountinhibit!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcountinhibit {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mcountinhibit" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mcountinhibit: CSR write 
+e.g.
+    csr_write_mcountinhibit!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mcountinhibit {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mcountinhibit, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mcountinhibit: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mcountinhibit!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mcountinhibit {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mcountinhibit, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mcountinhibit: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mcountinhibit!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mcountinhibit {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mcountinhibit, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -1056,9 +1753,12 @@ csr_clr_bits_mcountinhibit!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_mcountinhibit {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, mcountinhibit, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mcountinhibit, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -1070,10 +1770,11 @@ let org_value_ = csr_read_set_bits_mcountinhibit!(0x0F0F0F)
 macro_rules! csr_read_set_bits_mcountinhibit {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, mcountinhibit, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, mcountinhibit, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1087,10 +1788,11 @@ let org_value_ = csr_read_clr_bits_mcountinhibit!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_mcountinhibit {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, mcountinhibit, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, mcountinhibit, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1103,7 +1805,7 @@ csr_write_imm_mcountinhibit!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_mcountinhibit {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, mcountinhibit, ", stringify!($value)));
@@ -1117,8 +1819,8 @@ csr_set_bits_imm_mcountinhibit!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_mcountinhibit {
-    ( MCOUNTINHIBIT_CY_BIT_MASK) => { csr_set_bits_imm_mcountinhibit!(0x1)};
-    ( MCOUNTINHIBIT_IR_BIT_MASK) => { csr_set_bits_imm_mcountinhibit!(0x4)};
+    ( MCOUNTINHIBIT_CY_BIT_MASK) => { $crate::csr_set_bits_imm_mcountinhibit!(0x1)};
+    ( MCOUNTINHIBIT_IR_BIT_MASK) => { $crate::csr_set_bits_imm_mcountinhibit!(0x4)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -1132,9 +1834,9 @@ csr_clr_bits_imm_mcountinhibit!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_mcountinhibit {
-    ( MCOUNTINHIBIT_CY_BIT_MASK) => { csr_clr_bits_imm_mcountinhibit!(0x1)};
-    ( MCOUNTINHIBIT_IR_BIT_MASK) => { csr_clr_bits_imm_mcountinhibit!(0x4)};
-    ( $value:ident ) => (
+    ( MCOUNTINHIBIT_CY_BIT_MASK) => { $crate::csr_clr_bits_imm_mcountinhibit!(0x1)};
+    ( MCOUNTINHIBIT_IR_BIT_MASK) => { $crate::csr_clr_bits_imm_mcountinhibit!(0x4)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, mcountinhibit, ", stringify!($value)));
@@ -1145,6 +1847,8 @@ macro_rules! csr_clr_bits_imm_mcountinhibit {
 /*******************************************
  * mcycle - MRW - Clock Cycles Executed Counter 
  */
+/* mcycle: CSR address, as used in the csr field of the CSR instructions. */
+pub const MCYCLE_CSR_ADDRESS: u16 = 0xb00;
 /* mcycle: CSR Whole register access */
 /* mcycle: CSR read.
 e.g.
@@ -1170,9 +1874,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mcycle {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mcycle, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mcycle, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1184,10 +1891,11 @@ e.g.
 macro_rules! csr_read_write_mcycle {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintCsr64;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mcycle, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mcycle, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1199,6 +1907,8 @@ macro_rules! csr_read_write_mcycle {
 /*******************************************
  * minstret - MRW - Number of Instructions Retired Counter 
  */
+/* minstret: CSR address, as used in the csr field of the CSR instructions. */
+pub const MINSTRET_CSR_ADDRESS: u16 = 0xb02;
 /* minstret: CSR Whole register access */
 /* minstret: CSR read.
 e.g.
@@ -1224,9 +1934,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_minstret {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    minstret, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    minstret, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1238,10 +1951,11 @@ e.g.
 macro_rules! csr_read_write_minstret {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintCsr64;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, minstret, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, minstret, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1253,6 +1967,8 @@ macro_rules! csr_read_write_minstret {
 /*******************************************
  * mhpmcounter3 - MRW - Event Counters 
  */
+/* mhpmcounter3: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMCOUNTER3_CSR_ADDRESS: u16 = 0xb03;
 /* mhpmcounter3: CSR Whole register access */
 /* mhpmcounter3: CSR read.
 e.g.
@@ -1278,9 +1994,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mhpmcounter3 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mhpmcounter3, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmcounter3, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1292,10 +2011,11 @@ e.g.
 macro_rules! csr_read_write_mhpmcounter3 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintCsr64;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mhpmcounter3, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mhpmcounter3, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1307,6 +2027,8 @@ macro_rules! csr_read_write_mhpmcounter3 {
 /*******************************************
  * mhpmevent3 - MRW - Event Counter Event Select 
  */
+/* mhpmevent3: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMEVENT3_CSR_ADDRESS: u16 = 0x323;
 /* mhpmevent3: CSR Whole register access */
 /* mhpmevent3: CSR read.
 e.g.
@@ -1332,9 +2054,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mhpmevent3 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mhpmevent3, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmevent3, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1346,10 +2071,11 @@ e.g.
 macro_rules! csr_read_write_mhpmevent3 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mhpmevent3, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mhpmevent3, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1357,10 +2083,121 @@ macro_rules! csr_read_write_mhpmevent3 {
 }
 
 /* mhpmevent3: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mhpmevent3!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mhpmevent3 {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mhpmevent3, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mhpmevent3!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_mhpmevent3 {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mhpmevent3, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_mhpmevent3!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_mhpmevent3 {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mhpmevent3, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_mhpmevent3!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_mhpmevent3 {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, mhpmevent3, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mhpmevent3: CSR Field Modifications - via immediate */
+/* mhpmevent3, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mhpmevent3!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mhpmevent3 {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mhpmevent3, ", stringify!($value)));
+        }
+    );
+}
+
+/* mhpmevent3, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mhpmevent3!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_mhpmevent3 {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mhpmevent3, ", stringify!($value)));
+        }
+    );
+}
+/* mhpmevent3, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mhpmevent3!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mhpmevent3 {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mhpmevent3, ", stringify!($value)));
+        }
+    );
+}
 
 /*******************************************
  * mcounteren - MRW - Counter Enable 
  */
+/* mcounteren: CSR address, as used in the csr field of the CSR instructions. */
+pub const MCOUNTEREN_CSR_ADDRESS: u16 = 0x306;
 /* mcounteren: CSR Whole register access */
 /* mcounteren: CSR read.
 e.g.
@@ -1386,9 +2223,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mcounteren {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mcounteren, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mcounteren, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1400,10 +2240,11 @@ e.g.
 macro_rules! csr_read_write_mcounteren {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mcounteren, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mcounteren, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1418,9 +2259,12 @@ csr_set_bits_mcounteren!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_mcounteren {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, mcounteren, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mcounteren, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -1431,9 +2275,12 @@ csr_clr_bits_mcounteren!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_mcounteren {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, mcounteren, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mcounteren, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -1445,10 +2292,11 @@ let org_value_ = csr_read_set_bits_mcounteren!(0x0F0F0F)
 macro_rules! csr_read_set_bits_mcounteren {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, mcounteren, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, mcounteren, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1462,10 +2310,11 @@ let org_value_ = csr_read_clr_bits_mcounteren!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_mcounteren {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, mcounteren, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, mcounteren, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1478,7 +2327,7 @@ csr_write_imm_mcounteren!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_mcounteren {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, mcounteren, ", stringify!($value)));
@@ -1492,9 +2341,9 @@ csr_set_bits_imm_mcounteren!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_mcounteren {
-    ( MCOUNTEREN_CY_BIT_MASK) => { csr_set_bits_imm_mcounteren!(0x1)};
-    ( MCOUNTEREN_TM_BIT_MASK) => { csr_set_bits_imm_mcounteren!(0x2)};
-    ( MCOUNTEREN_IR_BIT_MASK) => { csr_set_bits_imm_mcounteren!(0x4)};
+    ( MCOUNTEREN_CY_BIT_MASK) => { $crate::csr_set_bits_imm_mcounteren!(0x1)};
+    ( MCOUNTEREN_TM_BIT_MASK) => { $crate::csr_set_bits_imm_mcounteren!(0x2)};
+    ( MCOUNTEREN_IR_BIT_MASK) => { $crate::csr_set_bits_imm_mcounteren!(0x4)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -1508,10 +2357,10 @@ csr_clr_bits_imm_mcounteren!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_mcounteren {
-    ( MCOUNTEREN_CY_BIT_MASK) => { csr_clr_bits_imm_mcounteren!(0x1)};
-    ( MCOUNTEREN_TM_BIT_MASK) => { csr_clr_bits_imm_mcounteren!(0x2)};
-    ( MCOUNTEREN_IR_BIT_MASK) => { csr_clr_bits_imm_mcounteren!(0x4)};
-    ( $value:ident ) => (
+    ( MCOUNTEREN_CY_BIT_MASK) => { $crate::csr_clr_bits_imm_mcounteren!(0x1)};
+    ( MCOUNTEREN_TM_BIT_MASK) => { $crate::csr_clr_bits_imm_mcounteren!(0x2)};
+    ( MCOUNTEREN_IR_BIT_MASK) => { $crate::csr_clr_bits_imm_mcounteren!(0x4)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, mcounteren, ", stringify!($value)));
@@ -1522,6 +2371,8 @@ macro_rules! csr_clr_bits_imm_mcounteren {
 /*******************************************
  * scounteren - SRW - Counter Enable 
  */
+/* scounteren: CSR address, as used in the csr field of the CSR instructions. */
+pub const SCOUNTEREN_CSR_ADDRESS: u16 = 0x106;
 /* scounteren: CSR Whole register access */
 /* scounteren: CSR read.
 e.g.
@@ -1531,7 +2382,7 @@ e.g.
 macro_rules! csr_read_scounteren {
     ( ) => (
         {
-            let tmp_value: UintXlen;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
                 asm!("csrr    {0}, scounteren" , out(reg) tmp_value);
@@ -1547,9 +2398,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_scounteren {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    scounteren, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    scounteren, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1561,10 +2415,11 @@ e.g.
 macro_rules! csr_read_write_scounteren {
     ( $x:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $x;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, scounteren, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, scounteren, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1572,64 +2427,187 @@ macro_rules! csr_read_write_scounteren {
 }
 
 /* scounteren: CSR Field Modifications - via register */
-
-/*******************************************
- * mscratch - MRW - Machine Mode Scratch Register 
- */
-/* mscratch: CSR Whole register access */
-/* mscratch: CSR read.
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_mscratch!();
- */
+csr_set_bits_scounteren!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_mscratch {
-    ( ) => (
+macro_rules! csr_set_bits_scounteren {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mscratch" , out(reg) tmp_value);
+                asm!("csrrs    zero, scounteren, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* mscratch: CSR write 
+/* Register CSR bit clear instructions
 e.g.
-    csr_write_mscratch!(0x1234567);
-*/
+csr_clr_bits_scounteren!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_write_mscratch {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mscratch, {0}" , in(reg) $x);
+macro_rules! csr_clr_bits_scounteren {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, scounteren, {0}", in(reg) value);
+            }
         }
     );
 }
-/* mscratch: CSR Read and Write 
+/* Register CSR read and then bit set instructions
 e.g.
-    let v_ = csr_read_write_mscratch!(0x1234567);
+let org_value_ = csr_read_set_bits_scounteren!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_read_write_mscratch {
-    ( $x:expr ) => (
+macro_rules! csr_read_set_bits_scounteren {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mscratch, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrs    {0}, scounteren, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-
-/* mscratch: CSR Field Modifications - via register */
-
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_scounteren!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_scounteren {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, scounteren, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* scounteren: CSR Field Modifications - via immediate */
+/* scounteren, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_scounteren!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_scounteren {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, scounteren, ", stringify!($value)));
+        }
+    );
+}
+
+/* scounteren, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_scounteren!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_scounteren {
+    ( SCOUNTEREN_CY_BIT_MASK) => { $crate::csr_set_bits_imm_scounteren!(0x1)};
+    ( SCOUNTEREN_TM_BIT_MASK) => { $crate::csr_set_bits_imm_scounteren!(0x2)};
+    ( SCOUNTEREN_IR_BIT_MASK) => { $crate::csr_set_bits_imm_scounteren!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, scounteren, ", stringify!($value)));
+        }
+    );
+}
+/* scounteren, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_scounteren!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_scounteren {
+    ( SCOUNTEREN_CY_BIT_MASK) => { $crate::csr_clr_bits_imm_scounteren!(0x1)};
+    ( SCOUNTEREN_TM_BIT_MASK) => { $crate::csr_clr_bits_imm_scounteren!(0x2)};
+    ( SCOUNTEREN_IR_BIT_MASK) => { $crate::csr_clr_bits_imm_scounteren!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, scounteren, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * mscratch - MRW - Machine Mode Scratch Register 
+ */
+/* mscratch: CSR address, as used in the csr field of the CSR instructions. */
+pub const MSCRATCH_CSR_ADDRESS: u16 = 0x340;
+/* mscratch: CSR Whole register access */
+/* mscratch: CSR read.
+e.g.
+    let _v = csr_read_mscratch!();
+ */
+#[macro_export]
+macro_rules! csr_read_mscratch {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mscratch" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mscratch: CSR write 
+e.g.
+    csr_write_mscratch!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mscratch {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mscratch, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mscratch: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mscratch!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mscratch {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mscratch, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mscratch: CSR Field Modifications - via register */
+
 /*******************************************
  * mepc - MRW - Machine Exception Program Counter 
  */
+/* mepc: CSR address, as used in the csr field of the CSR instructions. */
+pub const MEPC_CSR_ADDRESS: u16 = 0x341;
 /* mepc: CSR Whole register access */
 /* mepc: CSR read.
 e.g.
@@ -1655,9 +2633,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mepc {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mepc, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mepc, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1669,10 +2650,11 @@ e.g.
 macro_rules! csr_read_write_mepc {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mepc, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mepc, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1684,6 +2666,8 @@ macro_rules! csr_read_write_mepc {
 /*******************************************
  * mcause - MRW - Machine Exception Cause 
  */
+/* mcause: CSR address, as used in the csr field of the CSR instructions. */
+pub const MCAUSE_CSR_ADDRESS: u16 = 0x342;
 /* mcause: CSR Whole register access */
 /* mcause: CSR read.
 e.g.
@@ -1709,9 +2693,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mcause {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mcause, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mcause, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1723,10 +2710,11 @@ e.g.
 macro_rules! csr_read_write_mcause {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mcause, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mcause, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1741,9 +2729,12 @@ csr_set_bits_mcause!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_mcause {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, mcause, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mcause, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -1754,9 +2745,12 @@ csr_clr_bits_mcause!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_mcause {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, mcause, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mcause, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -1768,10 +2762,11 @@ let org_value_ = csr_read_set_bits_mcause!(0x0F0F0F)
 macro_rules! csr_read_set_bits_mcause {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, mcause, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, mcause, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1785,10 +2780,11 @@ let org_value_ = csr_read_clr_bits_mcause!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_mcause {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, mcause, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, mcause, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1801,7 +2797,7 @@ csr_write_imm_mcause!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_mcause {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, mcause, ", stringify!($value)));
@@ -1828,7 +2824,7 @@ csr_clr_bits_imm_mcause!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_mcause {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, mcause, ", stringify!($value)));
@@ -1839,6 +2835,8 @@ macro_rules! csr_clr_bits_imm_mcause {
 /*******************************************
  * mtval - MRW - Machine Trap Value 
  */
+/* mtval: CSR address, as used in the csr field of the CSR instructions. */
+pub const MTVAL_CSR_ADDRESS: u16 = 0x343;
 /* mtval: CSR Whole register access */
 /* mtval: CSR read.
 e.g.
@@ -1864,9 +2862,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_mtval {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mtval, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mtval, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1878,10 +2879,11 @@ e.g.
 macro_rules! csr_read_write_mtval {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mtval, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mtval, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1893,6 +2895,8 @@ macro_rules! csr_read_write_mtval {
 /*******************************************
  * sscratch - SRW - Supervisor Mode Scratch Register 
  */
+/* sscratch: CSR address, as used in the csr field of the CSR instructions. */
+pub const SSCRATCH_CSR_ADDRESS: u16 = 0x140;
 /* sscratch: CSR Whole register access */
 /* sscratch: CSR read.
 e.g.
@@ -1918,9 +2922,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_sscratch {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    sscratch, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    sscratch, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1932,10 +2939,11 @@ e.g.
 macro_rules! csr_read_write_sscratch {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, sscratch, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, sscratch, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -1947,6 +2955,8 @@ macro_rules! csr_read_write_sscratch {
 /*******************************************
  * sepc - SRW - Supervisor Exception Program Counter 
  */
+/* sepc: CSR address, as used in the csr field of the CSR instructions. */
+pub const SEPC_CSR_ADDRESS: u16 = 0x141;
 /* sepc: CSR Whole register access */
 /* sepc: CSR read.
 e.g.
@@ -1972,9 +2982,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_sepc {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    sepc, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    sepc, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -1986,10 +2999,11 @@ e.g.
 macro_rules! csr_read_write_sepc {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, sepc, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, sepc, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2001,6 +3015,8 @@ macro_rules! csr_read_write_sepc {
 /*******************************************
  * scause - SRW - Supervisor Exception Cause 
  */
+/* scause: CSR address, as used in the csr field of the CSR instructions. */
+pub const SCAUSE_CSR_ADDRESS: u16 = 0x142;
 /* scause: CSR Whole register access */
 /* scause: CSR read.
 e.g.
@@ -2026,9 +3042,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_scause {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    scause, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    scause, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -2040,10 +3059,11 @@ e.g.
 macro_rules! csr_read_write_scause {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, scause, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, scause, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2058,9 +3078,12 @@ csr_set_bits_scause!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_scause {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, scause, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, scause, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2071,9 +3094,12 @@ csr_clr_bits_scause!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_scause {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, scause, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, scause, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2085,10 +3111,11 @@ let org_value_ = csr_read_set_bits_scause!(0x0F0F0F)
 macro_rules! csr_read_set_bits_scause {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, scause, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, scause, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2102,10 +3129,11 @@ let org_value_ = csr_read_clr_bits_scause!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_scause {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, scause, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, scause, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2118,7 +3146,7 @@ csr_write_imm_scause!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_scause {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, scause, ", stringify!($value)));
@@ -2145,7 +3173,7 @@ csr_clr_bits_imm_scause!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_scause {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, scause, ", stringify!($value)));
@@ -2156,6 +3184,8 @@ macro_rules! csr_clr_bits_imm_scause {
 /*******************************************
  * sstatus - SRW - Supervisor Status 
  */
+/* sstatus: CSR address, as used in the csr field of the CSR instructions. */
+pub const SSTATUS_CSR_ADDRESS: u16 = 0x100;
 /* sstatus: CSR Whole register access */
 /* sstatus: CSR read.
 e.g.
@@ -2181,9 +3211,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_sstatus {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    sstatus, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    sstatus, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -2195,10 +3228,11 @@ e.g.
 macro_rules! csr_read_write_sstatus {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, sstatus, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, sstatus, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2213,9 +3247,12 @@ csr_set_bits_sstatus!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_sstatus {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, sstatus, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, sstatus, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2226,9 +3263,12 @@ csr_clr_bits_sstatus!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_sstatus {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, sstatus, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, sstatus, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2240,10 +3280,11 @@ let org_value_ = csr_read_set_bits_sstatus!(0x0F0F0F)
 macro_rules! csr_read_set_bits_sstatus {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, sstatus, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, sstatus, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2257,10 +3298,11 @@ let org_value_ = csr_read_clr_bits_sstatus!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_sstatus {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, sstatus, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, sstatus, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2273,7 +3315,7 @@ csr_write_imm_sstatus!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_sstatus {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, sstatus, ", stringify!($value)));
@@ -2287,7 +3329,7 @@ csr_set_bits_imm_sstatus!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_sstatus {
-    ( SSTATUS_SIE_BIT_MASK) => { csr_set_bits_imm_sstatus!(0x4)};
+    ( SSTATUS_SIE_BIT_MASK) => { $crate::csr_set_bits_imm_sstatus!(0x2)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -2301,8 +3343,8 @@ csr_clr_bits_imm_sstatus!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_sstatus {
-    ( SSTATUS_SIE_BIT_MASK) => { csr_clr_bits_imm_sstatus!(0x4)};
-    ( $value:ident ) => (
+    ( SSTATUS_SIE_BIT_MASK) => { $crate::csr_clr_bits_imm_sstatus!(0x2)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, sstatus, ", stringify!($value)));
@@ -2313,6 +3355,8 @@ macro_rules! csr_clr_bits_imm_sstatus {
 /*******************************************
  * stvec - SRW - Supervisor Trap Vector Base Address 
  */
+/* stvec: CSR address, as used in the csr field of the CSR instructions. */
+pub const STVEC_CSR_ADDRESS: u16 = 0x105;
 /* stvec: CSR Whole register access */
 /* stvec: CSR read.
 e.g.
@@ -2338,9 +3382,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_stvec {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    stvec, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    stvec, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -2352,10 +3399,11 @@ e.g.
 macro_rules! csr_read_write_stvec {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, stvec, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, stvec, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2370,9 +3418,12 @@ csr_set_bits_stvec!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_stvec {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, stvec, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, stvec, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2383,9 +3434,12 @@ csr_clr_bits_stvec!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_stvec {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, stvec, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, stvec, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2397,10 +3451,11 @@ let org_value_ = csr_read_set_bits_stvec!(0x0F0F0F)
 macro_rules! csr_read_set_bits_stvec {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, stvec, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, stvec, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2414,10 +3469,11 @@ let org_value_ = csr_read_clr_bits_stvec!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_stvec {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, stvec, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, stvec, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2430,7 +3486,7 @@ csr_write_imm_stvec!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_stvec {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, stvec, ", stringify!($value)));
@@ -2444,7 +3500,7 @@ csr_set_bits_imm_stvec!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_stvec {
-    ( STVEC_MODE_BIT_MASK) => { csr_set_bits_imm_stvec!(0x3)};
+    ( STVEC_MODE_BIT_MASK) => { $crate::csr_set_bits_imm_stvec!(0x3)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -2458,8 +3514,8 @@ csr_clr_bits_imm_stvec!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_stvec {
-    ( STVEC_MODE_BIT_MASK) => { csr_clr_bits_imm_stvec!(0x3)};
-    ( $value:ident ) => (
+    ( STVEC_MODE_BIT_MASK) => { $crate::csr_clr_bits_imm_stvec!(0x3)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, stvec, ", stringify!($value)));
@@ -2470,6 +3526,8 @@ macro_rules! csr_clr_bits_imm_stvec {
 /*******************************************
  * sideleg - SRW - Supervisor Interrupt Delegation 
  */
+/* sideleg: CSR address, as used in the csr field of the CSR instructions. */
+pub const SIDELEG_CSR_ADDRESS: u16 = 0x103;
 /* sideleg: CSR Whole register access */
 /* sideleg: CSR read.
 e.g.
@@ -2495,9 +3553,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_sideleg {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    sideleg, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    sideleg, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -2509,10 +3570,11 @@ e.g.
 macro_rules! csr_read_write_sideleg {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, sideleg, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, sideleg, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2524,6 +3586,8 @@ macro_rules! csr_read_write_sideleg {
 /*******************************************
  * sedeleg - SRW - Supervisor Exception Delegation 
  */
+/* sedeleg: CSR address, as used in the csr field of the CSR instructions. */
+pub const SEDELEG_CSR_ADDRESS: u16 = 0x102;
 /* sedeleg: CSR Whole register access */
 /* sedeleg: CSR read.
 e.g.
@@ -2549,9 +3613,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_sedeleg {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    sedeleg, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    sedeleg, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -2563,10 +3630,11 @@ e.g.
 macro_rules! csr_read_write_sedeleg {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, sedeleg, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, sedeleg, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2578,6 +3646,8 @@ macro_rules! csr_read_write_sedeleg {
 /*******************************************
  * sip - SRW - Supervisor Interrupt Pending 
  */
+/* sip: CSR address, as used in the csr field of the CSR instructions. */
+pub const SIP_CSR_ADDRESS: u16 = 0x144;
 /* sip: CSR Whole register access */
 /* sip: CSR read.
 e.g.
@@ -2603,9 +3673,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_sip {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    sip, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    sip, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -2617,10 +3690,11 @@ e.g.
 macro_rules! csr_read_write_sip {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, sip, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, sip, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2635,9 +3709,12 @@ csr_set_bits_sip!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_sip {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, sip, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, sip, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2648,9 +3725,12 @@ csr_clr_bits_sip!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_sip {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, sip, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, sip, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2662,10 +3742,11 @@ let org_value_ = csr_read_set_bits_sip!(0x0F0F0F)
 macro_rules! csr_read_set_bits_sip {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, sip, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, sip, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2679,10 +3760,11 @@ let org_value_ = csr_read_clr_bits_sip!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_sip {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, sip, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, sip, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2695,7 +3777,7 @@ csr_write_imm_sip!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_sip {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, sip, ", stringify!($value)));
@@ -2709,9 +3791,9 @@ csr_set_bits_imm_sip!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_sip {
-    ( SIP_SSI_BIT_MASK) => { csr_set_bits_imm_sip!(0x2)};
-    ( SIP_USI_BIT_MASK) => { csr_set_bits_imm_sip!(0x1)};
-    ( SIP_UTI_BIT_MASK) => { csr_set_bits_imm_sip!(0x10)};
+    ( SIP_SSI_BIT_MASK) => { $crate::csr_set_bits_imm_sip!(0x2)};
+    ( SIP_USI_BIT_MASK) => { $crate::csr_set_bits_imm_sip!(0x1)};
+    ( SIP_UTI_BIT_MASK) => { $crate::csr_set_bits_imm_sip!(0x10)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -2725,10 +3807,10 @@ csr_clr_bits_imm_sip!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_sip {
-    ( SIP_SSI_BIT_MASK) => { csr_clr_bits_imm_sip!(0x2)};
-    ( SIP_USI_BIT_MASK) => { csr_clr_bits_imm_sip!(0x1)};
-    ( SIP_UTI_BIT_MASK) => { csr_clr_bits_imm_sip!(0x10)};
-    ( $value:ident ) => (
+    ( SIP_SSI_BIT_MASK) => { $crate::csr_clr_bits_imm_sip!(0x2)};
+    ( SIP_USI_BIT_MASK) => { $crate::csr_clr_bits_imm_sip!(0x1)};
+    ( SIP_UTI_BIT_MASK) => { $crate::csr_clr_bits_imm_sip!(0x10)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, sip, ", stringify!($value)));
@@ -2739,6 +3821,8 @@ macro_rules! csr_clr_bits_imm_sip {
 /*******************************************
  * sie - SRW - Supervisor Interrupt Enable 
  */
+/* sie: CSR address, as used in the csr field of the CSR instructions. */
+pub const SIE_CSR_ADDRESS: u16 = 0x104;
 /* sie: CSR Whole register access */
 /* sie: CSR read.
 e.g.
@@ -2764,9 +3848,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_sie {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    sie, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    sie, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -2778,10 +3865,11 @@ e.g.
 macro_rules! csr_read_write_sie {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, sie, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, sie, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2796,9 +3884,12 @@ csr_set_bits_sie!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_sie {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, sie, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, sie, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2809,9 +3900,12 @@ csr_clr_bits_sie!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_sie {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, sie, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, sie, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2823,10 +3917,11 @@ let org_value_ = csr_read_set_bits_sie!(0x0F0F0F)
 macro_rules! csr_read_set_bits_sie {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, sie, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, sie, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2840,10 +3935,11 @@ let org_value_ = csr_read_clr_bits_sie!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_sie {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, sie, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, sie, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2856,7 +3952,7 @@ csr_write_imm_sie!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_sie {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, sie, ", stringify!($value)));
@@ -2870,9 +3966,9 @@ csr_set_bits_imm_sie!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_sie {
-    ( SIE_SSI_BIT_MASK) => { csr_set_bits_imm_sie!(0x2)};
-    ( SIE_USI_BIT_MASK) => { csr_set_bits_imm_sie!(0x1)};
-    ( SIE_UTI_BIT_MASK) => { csr_set_bits_imm_sie!(0x10)};
+    ( SIE_SSI_BIT_MASK) => { $crate::csr_set_bits_imm_sie!(0x2)};
+    ( SIE_USI_BIT_MASK) => { $crate::csr_set_bits_imm_sie!(0x1)};
+    ( SIE_UTI_BIT_MASK) => { $crate::csr_set_bits_imm_sie!(0x10)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -2886,10 +3982,10 @@ csr_clr_bits_imm_sie!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_sie {
-    ( SIE_SSI_BIT_MASK) => { csr_clr_bits_imm_sie!(0x2)};
-    ( SIE_USI_BIT_MASK) => { csr_clr_bits_imm_sie!(0x1)};
-    ( SIE_UTI_BIT_MASK) => { csr_clr_bits_imm_sie!(0x10)};
-    ( $value:ident ) => (
+    ( SIE_SSI_BIT_MASK) => { $crate::csr_clr_bits_imm_sie!(0x2)};
+    ( SIE_USI_BIT_MASK) => { $crate::csr_clr_bits_imm_sie!(0x1)};
+    ( SIE_UTI_BIT_MASK) => { $crate::csr_clr_bits_imm_sie!(0x10)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, sie, ", stringify!($value)));
@@ -2900,6 +3996,8 @@ macro_rules! csr_clr_bits_imm_sie {
 /*******************************************
  * ustatus - URW - User mode restricted view of mstatus 
  */
+/* ustatus: CSR address, as used in the csr field of the CSR instructions. */
+pub const USTATUS_CSR_ADDRESS: u16 = 0x000;
 /* ustatus: CSR Whole register access */
 /* ustatus: CSR read.
 e.g.
@@ -2925,10 +4023,13 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_ustatus {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    ustatus, {0}" , in(reg) $x);
-        }
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    ustatus, {0}" , in(reg) value);
+            }
+        }
     );
 }
 /* ustatus: CSR Read and Write 
@@ -2939,10 +4040,11 @@ e.g.
 macro_rules! csr_read_write_ustatus {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, ustatus, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, ustatus, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -2957,9 +4059,12 @@ csr_set_bits_ustatus!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_ustatus {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, ustatus, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, ustatus, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2970,9 +4075,12 @@ csr_clr_bits_ustatus!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_ustatus {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, ustatus, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, ustatus, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -2984,10 +4092,11 @@ let org_value_ = csr_read_set_bits_ustatus!(0x0F0F0F)
 macro_rules! csr_read_set_bits_ustatus {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, ustatus, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, ustatus, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3001,10 +4110,11 @@ let org_value_ = csr_read_clr_bits_ustatus!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_ustatus {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, ustatus, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, ustatus, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3017,7 +4127,7 @@ csr_write_imm_ustatus!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_ustatus {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, ustatus, ", stringify!($value)));
@@ -3031,8 +4141,8 @@ csr_set_bits_imm_ustatus!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_ustatus {
-    ( USTATUS_UIE_BIT_MASK) => { csr_set_bits_imm_ustatus!(0x2)};
-    ( USTATUS_UPIE_BIT_MASK) => { csr_set_bits_imm_ustatus!(0x8)};
+    ( USTATUS_UIE_BIT_MASK) => { $crate::csr_set_bits_imm_ustatus!(0x2)};
+    ( USTATUS_UPIE_BIT_MASK) => { $crate::csr_set_bits_imm_ustatus!(0x8)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -3046,9 +4156,9 @@ csr_clr_bits_imm_ustatus!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_ustatus {
-    ( USTATUS_UIE_BIT_MASK) => { csr_clr_bits_imm_ustatus!(0x2)};
-    ( USTATUS_UPIE_BIT_MASK) => { csr_clr_bits_imm_ustatus!(0x8)};
-    ( $value:ident ) => (
+    ( USTATUS_UIE_BIT_MASK) => { $crate::csr_clr_bits_imm_ustatus!(0x2)};
+    ( USTATUS_UPIE_BIT_MASK) => { $crate::csr_clr_bits_imm_ustatus!(0x8)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, ustatus, ", stringify!($value)));
@@ -3059,6 +4169,8 @@ macro_rules! csr_clr_bits_imm_ustatus {
 /*******************************************
  * uip - URW - User Interrupt Pending 
  */
+/* uip: CSR address, as used in the csr field of the CSR instructions. */
+pub const UIP_CSR_ADDRESS: u16 = 0x044;
 /* uip: CSR Whole register access */
 /* uip: CSR read.
 e.g.
@@ -3084,9 +4196,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_uip {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    uip, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    uip, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3098,10 +4213,11 @@ e.g.
 macro_rules! csr_read_write_uip {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, uip, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, uip, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3116,9 +4232,12 @@ csr_set_bits_uip!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_uip {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, uip, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, uip, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -3129,9 +4248,12 @@ csr_clr_bits_uip!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_uip {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, uip, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, uip, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -3143,10 +4265,11 @@ let org_value_ = csr_read_set_bits_uip!(0x0F0F0F)
 macro_rules! csr_read_set_bits_uip {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, uip, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, uip, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3160,10 +4283,11 @@ let org_value_ = csr_read_clr_bits_uip!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_uip {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, uip, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, uip, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3176,7 +4300,7 @@ csr_write_imm_uip!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_uip {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, uip, ", stringify!($value)));
@@ -3190,8 +4314,8 @@ csr_set_bits_imm_uip!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_uip {
-    ( UIP_USI_BIT_MASK) => { csr_set_bits_imm_uip!(0x1)};
-    ( UIP_UTI_BIT_MASK) => { csr_set_bits_imm_uip!(0x10)};
+    ( UIP_USI_BIT_MASK) => { $crate::csr_set_bits_imm_uip!(0x1)};
+    ( UIP_UTI_BIT_MASK) => { $crate::csr_set_bits_imm_uip!(0x10)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -3205,9 +4329,9 @@ csr_clr_bits_imm_uip!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_uip {
-    ( UIP_USI_BIT_MASK) => { csr_clr_bits_imm_uip!(0x1)};
-    ( UIP_UTI_BIT_MASK) => { csr_clr_bits_imm_uip!(0x10)};
-    ( $value:ident ) => (
+    ( UIP_USI_BIT_MASK) => { $crate::csr_clr_bits_imm_uip!(0x1)};
+    ( UIP_UTI_BIT_MASK) => { $crate::csr_clr_bits_imm_uip!(0x10)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, uip, ", stringify!($value)));
@@ -3218,6 +4342,8 @@ macro_rules! csr_clr_bits_imm_uip {
 /*******************************************
  * uie - URW - User Interrupt Enable 
  */
+/* uie: CSR address, as used in the csr field of the CSR instructions. */
+pub const UIE_CSR_ADDRESS: u16 = 0x004;
 /* uie: CSR Whole register access */
 /* uie: CSR read.
 e.g.
@@ -3243,9 +4369,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_uie {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    uie, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    uie, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3257,10 +4386,11 @@ e.g.
 macro_rules! csr_read_write_uie {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, uie, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, uie, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3275,9 +4405,12 @@ csr_set_bits_uie!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_uie {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, uie, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, uie, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -3288,9 +4421,12 @@ csr_clr_bits_uie!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_uie {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, uie, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, uie, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -3302,10 +4438,11 @@ let org_value_ = csr_read_set_bits_uie!(0x0F0F0F)
 macro_rules! csr_read_set_bits_uie {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, uie, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, uie, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3319,10 +4456,11 @@ let org_value_ = csr_read_clr_bits_uie!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_uie {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, uie, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, uie, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3335,7 +4473,7 @@ csr_write_imm_uie!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_uie {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, uie, ", stringify!($value)));
@@ -3349,8 +4487,8 @@ csr_set_bits_imm_uie!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_uie {
-    ( UIE_USI_BIT_MASK) => { csr_set_bits_imm_uie!(0x1)};
-    ( UIE_UTI_BIT_MASK) => { csr_set_bits_imm_uie!(0x10)};
+    ( UIE_USI_BIT_MASK) => { $crate::csr_set_bits_imm_uie!(0x1)};
+    ( UIE_UTI_BIT_MASK) => { $crate::csr_set_bits_imm_uie!(0x10)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -3364,9 +4502,9 @@ csr_clr_bits_imm_uie!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_uie {
-    ( UIE_USI_BIT_MASK) => { csr_clr_bits_imm_uie!(0x1)};
-    ( UIE_UTI_BIT_MASK) => { csr_clr_bits_imm_uie!(0x10)};
-    ( $value:ident ) => (
+    ( UIE_USI_BIT_MASK) => { $crate::csr_clr_bits_imm_uie!(0x1)};
+    ( UIE_UTI_BIT_MASK) => { $crate::csr_clr_bits_imm_uie!(0x10)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, uie, ", stringify!($value)));
@@ -3377,6 +4515,8 @@ macro_rules! csr_clr_bits_imm_uie {
 /*******************************************
  * uscratch - URW - User Mode Scratch Register 
  */
+/* uscratch: CSR address, as used in the csr field of the CSR instructions. */
+pub const USCRATCH_CSR_ADDRESS: u16 = 0x040;
 /* uscratch: CSR Whole register access */
 /* uscratch: CSR read.
 e.g.
@@ -3402,9 +4542,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_uscratch {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    uscratch, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    uscratch, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3416,10 +4559,11 @@ e.g.
 macro_rules! csr_read_write_uscratch {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, uscratch, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, uscratch, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3431,6 +4575,8 @@ macro_rules! csr_read_write_uscratch {
 /*******************************************
  * uepc - URW - User Exception Program Counter 
  */
+/* uepc: CSR address, as used in the csr field of the CSR instructions. */
+pub const UEPC_CSR_ADDRESS: u16 = 0x041;
 /* uepc: CSR Whole register access */
 /* uepc: CSR read.
 e.g.
@@ -3456,9 +4602,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_uepc {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    uepc, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    uepc, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3470,10 +4619,11 @@ e.g.
 macro_rules! csr_read_write_uepc {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, uepc, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, uepc, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3485,6 +4635,8 @@ macro_rules! csr_read_write_uepc {
 /*******************************************
  * ucause - URW - User Exception Cause 
  */
+/* ucause: CSR address, as used in the csr field of the CSR instructions. */
+pub const UCAUSE_CSR_ADDRESS: u16 = 0x042;
 /* ucause: CSR Whole register access */
 /* ucause: CSR read.
 e.g.
@@ -3510,9 +4662,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_ucause {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    ucause, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    ucause, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3524,10 +4679,11 @@ e.g.
 macro_rules! csr_read_write_ucause {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, ucause, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, ucause, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3542,9 +4698,12 @@ csr_set_bits_ucause!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_ucause {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, ucause, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, ucause, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -3555,9 +4714,12 @@ csr_clr_bits_ucause!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_ucause {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, ucause, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, ucause, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -3569,10 +4731,11 @@ let org_value_ = csr_read_set_bits_ucause!(0x0F0F0F)
 macro_rules! csr_read_set_bits_ucause {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, ucause, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, ucause, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3586,10 +4749,11 @@ let org_value_ = csr_read_clr_bits_ucause!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_ucause {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, ucause, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, ucause, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3602,7 +4766,7 @@ csr_write_imm_ucause!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_ucause {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, ucause, ", stringify!($value)));
@@ -3629,7 +4793,7 @@ csr_clr_bits_imm_ucause!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_ucause {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, ucause, ", stringify!($value)));
@@ -3640,6 +4804,8 @@ macro_rules! csr_clr_bits_imm_ucause {
 /*******************************************
  * utvec - URW - User Trap Vector Base Address 
  */
+/* utvec: CSR address, as used in the csr field of the CSR instructions. */
+pub const UTVEC_CSR_ADDRESS: u16 = 0x005;
 /* utvec: CSR Whole register access */
 /* utvec: CSR read.
 e.g.
@@ -3665,9 +4831,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_utvec {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    utvec, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    utvec, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3679,10 +4848,11 @@ e.g.
 macro_rules! csr_read_write_utvec {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, utvec, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, utvec, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3697,9 +4867,12 @@ csr_set_bits_utvec!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_utvec {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, utvec, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, utvec, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -3710,9 +4883,12 @@ csr_clr_bits_utvec!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_utvec {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, utvec, {0}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, utvec, {0}", in(reg) value);
+            }
         }
     );
 }
@@ -3724,10 +4900,11 @@ let org_value_ = csr_read_set_bits_utvec!(0x0F0F0F)
 macro_rules! csr_read_set_bits_utvec {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {0}, utvec, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {0}, utvec, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3741,10 +4918,11 @@ let org_value_ = csr_read_clr_bits_utvec!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_utvec {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {0}, utvec, {1}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {0}, utvec, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3757,7 +4935,7 @@ csr_write_imm_utvec!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_utvec {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, utvec, ", stringify!($value)));
@@ -3771,7 +4949,7 @@ csr_set_bits_imm_utvec!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_set_bits_imm_utvec {
-    ( UTVEC_MODE_BIT_MASK) => { csr_set_bits_imm_utvec!(0x3)};
+    ( UTVEC_MODE_BIT_MASK) => { $crate::csr_set_bits_imm_utvec!(0x3)};
     ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
@@ -3785,8 +4963,8 @@ csr_clr_bits_imm_utvec!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_clr_bits_imm_utvec {
-    ( UTVEC_MODE_BIT_MASK) => { csr_clr_bits_imm_utvec!(0x3)};
-    ( $value:ident ) => (
+    ( UTVEC_MODE_BIT_MASK) => { $crate::csr_clr_bits_imm_utvec!(0x3)};
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, utvec, ", stringify!($value)));
@@ -3797,6 +4975,8 @@ macro_rules! csr_clr_bits_imm_utvec {
 /*******************************************
  * utval - URW - User Trap Value 
  */
+/* utval: CSR address, as used in the csr field of the CSR instructions. */
+pub const UTVAL_CSR_ADDRESS: u16 = 0x043;
 /* utval: CSR Whole register access */
 /* utval: CSR read.
 e.g.
@@ -3822,9 +5002,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_utval {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    utval, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    utval, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3836,10 +5019,11 @@ e.g.
 macro_rules! csr_read_write_utval {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, utval, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, utval, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3851,6 +5035,8 @@ macro_rules! csr_read_write_utval {
 /*******************************************
  * fflags - URW - Floating-Point Accrued Exceptions. 
  */
+/* fflags: CSR address, as used in the csr field of the CSR instructions. */
+pub const FFLAGS_CSR_ADDRESS: u16 = 0x001;
 /* fflags: CSR Whole register access */
 /* fflags: CSR read.
 e.g.
@@ -3876,9 +5062,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_fflags {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    fflags, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    fflags, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3890,10 +5079,11 @@ e.g.
 macro_rules! csr_read_write_fflags {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, fflags, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, fflags, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3901,10 +5091,131 @@ macro_rules! csr_read_write_fflags {
 }
 
 /* fflags: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_fflags!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_fflags {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, fflags, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_fflags!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_fflags {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, fflags, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_fflags!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_fflags {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, fflags, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_fflags!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_fflags {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, fflags, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* fflags: CSR Field Modifications - via immediate */
+/* fflags, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_fflags!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_fflags {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, fflags, ", stringify!($value)));
+        }
+    );
+}
+
+/* fflags, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_fflags!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_fflags {
+    ( FFLAGS_NX_BIT_MASK) => { $crate::csr_set_bits_imm_fflags!(0x1)};
+    ( FFLAGS_UF_BIT_MASK) => { $crate::csr_set_bits_imm_fflags!(0x2)};
+    ( FFLAGS_OF_BIT_MASK) => { $crate::csr_set_bits_imm_fflags!(0x4)};
+    ( FFLAGS_DZ_BIT_MASK) => { $crate::csr_set_bits_imm_fflags!(0x8)};
+    ( FFLAGS_NV_BIT_MASK) => { $crate::csr_set_bits_imm_fflags!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, fflags, ", stringify!($value)));
+        }
+    );
+}
+/* fflags, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_fflags!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_fflags {
+    ( FFLAGS_NX_BIT_MASK) => { $crate::csr_clr_bits_imm_fflags!(0x1)};
+    ( FFLAGS_UF_BIT_MASK) => { $crate::csr_clr_bits_imm_fflags!(0x2)};
+    ( FFLAGS_OF_BIT_MASK) => { $crate::csr_clr_bits_imm_fflags!(0x4)};
+    ( FFLAGS_DZ_BIT_MASK) => { $crate::csr_clr_bits_imm_fflags!(0x8)};
+    ( FFLAGS_NV_BIT_MASK) => { $crate::csr_clr_bits_imm_fflags!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, fflags, ", stringify!($value)));
+        }
+    );
+}
 
 /*******************************************
  * frm - URW - Floating-Point Dynamic Rounding Mode. 
  */
+/* frm: CSR address, as used in the csr field of the CSR instructions. */
+pub const FRM_CSR_ADDRESS: u16 = 0x002;
 /* frm: CSR Whole register access */
 /* frm: CSR read.
 e.g.
@@ -3930,9 +5241,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_frm {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    frm, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    frm, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3944,10 +5258,11 @@ e.g.
 macro_rules! csr_read_write_frm {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, frm, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, frm, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -3959,6 +5274,8 @@ macro_rules! csr_read_write_frm {
 /*******************************************
  * fcsr - URW - Floating-Point Control and Status 
  */
+/* fcsr: CSR address, as used in the csr field of the CSR instructions. */
+pub const FCSR_CSR_ADDRESS: u16 = 0x003;
 /* fcsr: CSR Whole register access */
 /* fcsr: CSR read.
 e.g.
@@ -3984,9 +5301,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_fcsr {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    fcsr, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    fcsr, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -3998,10 +5318,11 @@ e.g.
 macro_rules! csr_read_write_fcsr {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, fcsr, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, fcsr, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -4009,60 +5330,185 @@ macro_rules! csr_read_write_fcsr {
 }
 
 /* fcsr: CSR Field Modifications - via register */
-
-/*******************************************
- * cycle - URO - Cycle counter for RDCYCLE instruction. 
- */
-/* cycle: CSR Whole register access */
-/* cycle: CSR read.
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_cycle!();
- */
+csr_set_bits_fcsr!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_cycle {
-    ( ) => (
+macro_rules! csr_set_bits_fcsr {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, cycle" , out(reg) tmp_value);
+                asm!("csrrs    zero, fcsr, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-
-/* cycle: CSR Field Modifications - via register */
-
-/*******************************************
- * time - URO - Timer for RDTIME instruction. 
- */
-/* time: CSR Whole register access */
-/* time: CSR read.
+/* Register CSR bit clear instructions
 e.g.
-    let _v = csr_read_time!();
+csr_clr_bits_fcsr!(0x0F0F0F)
  */
 #[macro_export]
-macro_rules! csr_read_time {
-    ( ) => (
+macro_rules! csr_clr_bits_fcsr {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, time" , out(reg) tmp_value);
+                asm!("csrrc    zero, fcsr, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-
-/* time: CSR Field Modifications - via register */
-
-/*******************************************
- * instret - URO - Instructions-retired counter for RDINSTRET instruction. 
- */
-/* instret: CSR Whole register access */
-/* instret: CSR read.
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_fcsr!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_fcsr {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, fcsr, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_fcsr!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_fcsr {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, fcsr, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* fcsr: CSR Field Modifications - via immediate */
+/* fcsr, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_fcsr!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_fcsr {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, fcsr, ", stringify!($value)));
+        }
+    );
+}
+
+/* fcsr, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_fcsr!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_fcsr {
+    ( FCSR_NX_BIT_MASK) => { $crate::csr_set_bits_imm_fcsr!(0x1)};
+    ( FCSR_UF_BIT_MASK) => { $crate::csr_set_bits_imm_fcsr!(0x2)};
+    ( FCSR_OF_BIT_MASK) => { $crate::csr_set_bits_imm_fcsr!(0x4)};
+    ( FCSR_DZ_BIT_MASK) => { $crate::csr_set_bits_imm_fcsr!(0x8)};
+    ( FCSR_NV_BIT_MASK) => { $crate::csr_set_bits_imm_fcsr!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, fcsr, ", stringify!($value)));
+        }
+    );
+}
+/* fcsr, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_fcsr!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_fcsr {
+    ( FCSR_NX_BIT_MASK) => { $crate::csr_clr_bits_imm_fcsr!(0x1)};
+    ( FCSR_UF_BIT_MASK) => { $crate::csr_clr_bits_imm_fcsr!(0x2)};
+    ( FCSR_OF_BIT_MASK) => { $crate::csr_clr_bits_imm_fcsr!(0x4)};
+    ( FCSR_DZ_BIT_MASK) => { $crate::csr_clr_bits_imm_fcsr!(0x8)};
+    ( FCSR_NV_BIT_MASK) => { $crate::csr_clr_bits_imm_fcsr!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, fcsr, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * cycle - URO - Cycle counter for RDCYCLE instruction. 
+ */
+/* cycle: CSR address, as used in the csr field of the CSR instructions. */
+pub const CYCLE_CSR_ADDRESS: u16 = 0xc00;
+/* cycle: CSR Whole register access */
+/* cycle: CSR read.
+e.g.
+    let _v = csr_read_cycle!();
+ */
+#[macro_export]
+macro_rules! csr_read_cycle {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, cycle" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* cycle: CSR Field Modifications - via register */
+
+/*******************************************
+ * time - URO - Timer for RDTIME instruction. 
+ */
+/* time: CSR address, as used in the csr field of the CSR instructions. */
+pub const TIME_CSR_ADDRESS: u16 = 0xc01;
+/* time: CSR Whole register access */
+/* time: CSR read.
+e.g.
+    let _v = csr_read_time!();
+ */
+#[macro_export]
+macro_rules! csr_read_time {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, time" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* time: CSR Field Modifications - via register */
+
+/*******************************************
+ * instret - URO - Instructions-retired counter for RDINSTRET instruction. 
+ */
+/* instret: CSR address, as used in the csr field of the CSR instructions. */
+pub const INSTRET_CSR_ADDRESS: u16 = 0xc02;
+/* instret: CSR Whole register access */
+/* instret: CSR read.
 e.g.
     let _v = csr_read_instret!();
  */
@@ -4085,6 +5531,8 @@ macro_rules! csr_read_instret {
 /*******************************************
  * hpmcounter3 - URO - Performance-monitoring counter. 
  */
+/* hpmcounter3: CSR address, as used in the csr field of the CSR instructions. */
+pub const HPMCOUNTER3_CSR_ADDRESS: u16 = 0xc03;
 /* hpmcounter3: CSR Whole register access */
 /* hpmcounter3: CSR read.
 e.g.
@@ -4109,6 +5557,8 @@ macro_rules! csr_read_hpmcounter3 {
 /*******************************************
  * hpmcounter4 - URO - Performance-monitoring counter. 
  */
+/* hpmcounter4: CSR address, as used in the csr field of the CSR instructions. */
+pub const HPMCOUNTER4_CSR_ADDRESS: u16 = 0xc04;
 /* hpmcounter4: CSR Whole register access */
 /* hpmcounter4: CSR read.
 e.g.
@@ -4133,6 +5583,8 @@ macro_rules! csr_read_hpmcounter4 {
 /*******************************************
  * hpmcounter31 - URO - Performance-monitoring counter. 
  */
+/* hpmcounter31: CSR address, as used in the csr field of the CSR instructions. */
+pub const HPMCOUNTER31_CSR_ADDRESS: u16 = 0xc1f;
 /* hpmcounter31: CSR Whole register access */
 /* hpmcounter31: CSR read.
 e.g.
@@ -4157,6 +5609,8 @@ macro_rules! csr_read_hpmcounter31 {
 /*******************************************
  * cycleh - URO - Upper 32 bits of  cycle, RV32I only. 
  */
+/* cycleh: CSR address, as used in the csr field of the CSR instructions. */
+pub const CYCLEH_CSR_ADDRESS: u16 = 0xc80;
 /* cycleh: CSR Whole register access */
 /* cycleh: CSR read.
 e.g.
@@ -4181,6 +5635,8 @@ macro_rules! csr_read_cycleh {
 /*******************************************
  * timeh - URO - Upper 32 bits of  time, RV32I only. 
  */
+/* timeh: CSR address, as used in the csr field of the CSR instructions. */
+pub const TIMEH_CSR_ADDRESS: u16 = 0xc81;
 /* timeh: CSR Whole register access */
 /* timeh: CSR read.
 e.g.
@@ -4205,6 +5661,8 @@ macro_rules! csr_read_timeh {
 /*******************************************
  * instreth - URO - Upper 32 bits of  instret, RV32I only. 
  */
+/* instreth: CSR address, as used in the csr field of the CSR instructions. */
+pub const INSTRETH_CSR_ADDRESS: u16 = 0xc82;
 /* instreth: CSR Whole register access */
 /* instreth: CSR read.
 e.g.
@@ -4229,6 +5687,8 @@ macro_rules! csr_read_instreth {
 /*******************************************
  * hpmcounter3h - URO - Upper 32 bits of  hpmcounter3, RV32I only. 
  */
+/* hpmcounter3h: CSR address, as used in the csr field of the CSR instructions. */
+pub const HPMCOUNTER3H_CSR_ADDRESS: u16 = 0xc83;
 /* hpmcounter3h: CSR Whole register access */
 /* hpmcounter3h: CSR read.
 e.g.
@@ -4253,6 +5713,8 @@ macro_rules! csr_read_hpmcounter3h {
 /*******************************************
  * hpmcounter4h - URO - Upper 32 bits of  hpmcounter4, RV32I only. 
  */
+/* hpmcounter4h: CSR address, as used in the csr field of the CSR instructions. */
+pub const HPMCOUNTER4H_CSR_ADDRESS: u16 = 0xc84;
 /* hpmcounter4h: CSR Whole register access */
 /* hpmcounter4h: CSR read.
 e.g.
@@ -4277,6 +5739,8 @@ macro_rules! csr_read_hpmcounter4h {
 /*******************************************
  * hpmcounter31h - URO - Upper 32 bits of  hpmcounter31, RV32I only. 
  */
+/* hpmcounter31h: CSR address, as used in the csr field of the CSR instructions. */
+pub const HPMCOUNTER31H_CSR_ADDRESS: u16 = 0xc9f;
 /* hpmcounter31h: CSR Whole register access */
 /* hpmcounter31h: CSR read.
 e.g.
@@ -4301,6 +5765,8 @@ macro_rules! csr_read_hpmcounter31h {
 /*******************************************
  * stval - SRW - Supervisor bad address or instruction. 
  */
+/* stval: CSR address, as used in the csr field of the CSR instructions. */
+pub const STVAL_CSR_ADDRESS: u16 = 0x143;
 /* stval: CSR Whole register access */
 /* stval: CSR read.
 e.g.
@@ -4326,9 +5792,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_stval {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    stval, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    stval, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -4340,10 +5809,11 @@ e.g.
 macro_rules! csr_read_write_stval {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, stval, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, stval, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -4355,6 +5825,8 @@ macro_rules! csr_read_write_stval {
 /*******************************************
  * satp - SRW - Supervisor address translation and protection. 
  */
+/* satp: CSR address, as used in the csr field of the CSR instructions. */
+pub const SATP_CSR_ADDRESS: u16 = 0x180;
 /* satp: CSR Whole register access */
 /* satp: CSR read.
 e.g.
@@ -4380,9 +5852,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_satp {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    satp, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    satp, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -4394,10 +5869,11 @@ e.g.
 macro_rules! csr_read_write_satp {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, satp, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, satp, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -4405,3233 +5881,17231 @@ macro_rules! csr_read_write_satp {
 }
 
 /* satp: CSR Field Modifications - via register */
-
-/*******************************************
- * hstatus - HRW - Hypervisor status register. 
- */
-/* hstatus: CSR Whole register access */
-/* hstatus: CSR read.
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_hstatus!();
- */
+csr_set_bits_satp!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_hstatus {
-    ( ) => (
+macro_rules! csr_set_bits_satp {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, hstatus" , out(reg) tmp_value);
+                asm!("csrrs    zero, satp, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* hstatus: CSR write 
+/* Register CSR bit clear instructions
 e.g.
-    csr_write_hstatus!(0x1234567);
-*/
+csr_clr_bits_satp!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_write_hstatus {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    hstatus, {0}" , in(reg) $x);
+macro_rules! csr_clr_bits_satp {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, satp, {0}", in(reg) value);
+            }
         }
     );
 }
-/* hstatus: CSR Read and Write 
+/* Register CSR read and then bit set instructions
 e.g.
-    let v_ = csr_read_write_hstatus!(0x1234567);
+let org_value_ = csr_read_set_bits_satp!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_read_write_hstatus {
-    ( $x:expr ) => (
+macro_rules! csr_read_set_bits_satp {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, hstatus, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrs    {0}, satp, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-
-/* hstatus: CSR Field Modifications - via register */
-
-/*******************************************
- * hedeleg - HRW - Hypervisor exception delegation register. 
- */
-/* hedeleg: CSR Whole register access */
-/* hedeleg: CSR read.
+/* Register CSR read and then bit clear instructions
 e.g.
-    let _v = csr_read_hedeleg!();
+let org_value_ = csr_read_clr_bits_satp!(0x0F0F0F)
  */
 #[macro_export]
-macro_rules! csr_read_hedeleg {
-    ( ) => (
+macro_rules! csr_read_clr_bits_satp {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, hedeleg" , out(reg) tmp_value);
+                asm!("csrrc    {0}, satp, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-/* hedeleg: CSR write 
+/* satp: CSR Field Modifications - via immediate */
+/* satp, CSR write value via immediate value (only up to 5 bits).
 e.g.
-    csr_write_hedeleg!(0x1234567);
-*/
+csr_write_imm_satp!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_write_hedeleg {
-    ( $x:expr ) => (
+macro_rules! csr_write_imm_satp {
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
-            asm!("csrw    hedeleg, {0}" , in(reg) $x);
+            asm!(concat!("csrrwi    zero, satp, ", stringify!($value)));
         }
     );
 }
-/* hedeleg: CSR Read and Write 
+
+/* satp, CSR set bits via immediate value mask (only up to 5 bits).
 e.g.
-    let v_ = csr_read_write_hedeleg!(0x1234567);
-*/
+csr_set_bits_imm_satp!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_read_write_hedeleg {
-    ( $x:expr ) => (
-        {
-            let tmp_value: UintXlen;
-            unsafe {
-                use core::arch::asm;
-                asm!("csrrw    {0}, hedeleg, {1}" , out(reg) tmp_value, in(reg) $x);
-            }
-            tmp_value
+macro_rules! csr_set_bits_imm_satp {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, satp, ", stringify!($value)));
+        }
+    );
+}
+/* satp, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_satp!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_satp {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, satp, ", stringify!($value)));
         }
     );
 }
-
-/* hedeleg: CSR Field Modifications - via register */
 
 /*******************************************
- * hideleg - HRW - Hypervisor interrupt delegation register. 
+ * hstatus - HRW - Hypervisor status register. 
  */
-/* hideleg: CSR Whole register access */
-/* hideleg: CSR read.
+/* hstatus: CSR address, as used in the csr field of the CSR instructions. */
+pub const HSTATUS_CSR_ADDRESS: u16 = 0x600;
+/* hstatus: CSR Whole register access */
+/* hstatus: CSR read.
 e.g.
-    let _v = csr_read_hideleg!();
+    let _v = csr_read_hstatus!();
  */
 #[macro_export]
-macro_rules! csr_read_hideleg {
+macro_rules! csr_read_hstatus {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, hideleg" , out(reg) tmp_value);
+                asm!("csrr    {0}, hstatus" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* hideleg: CSR write 
+/* hstatus: CSR write 
 e.g.
-    csr_write_hideleg!(0x1234567);
+    csr_write_hstatus!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_hideleg {
+macro_rules! csr_write_hstatus {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    hideleg, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hstatus, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* hideleg: CSR Read and Write 
+/* hstatus: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_hideleg!(0x1234567);
+    let v_ = csr_read_write_hstatus!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_hideleg {
+macro_rules! csr_read_write_hstatus {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, hideleg, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, hstatus, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* hideleg: CSR Field Modifications - via register */
-
-/*******************************************
- * hcounteren - HRW - Hypervisor counter enable. 
- */
-/* hcounteren: CSR Whole register access */
-/* hcounteren: CSR read.
+/* hstatus: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_hcounteren!();
- */
+csr_set_bits_hstatus!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_hcounteren {
-    ( ) => (
+macro_rules! csr_set_bits_hstatus {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, hcounteren" , out(reg) tmp_value);
+                asm!("csrrs    zero, hstatus, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* hcounteren: CSR write 
+/* Register CSR bit clear instructions
 e.g.
-    csr_write_hcounteren!(0x1234567);
-*/
+csr_clr_bits_hstatus!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_write_hcounteren {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    hcounteren, {0}" , in(reg) $x);
+macro_rules! csr_clr_bits_hstatus {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, hstatus, {0}", in(reg) value);
+            }
         }
     );
 }
-/* hcounteren: CSR Read and Write 
+/* Register CSR read and then bit set instructions
 e.g.
-    let v_ = csr_read_write_hcounteren!(0x1234567);
+let org_value_ = csr_read_set_bits_hstatus!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_read_write_hcounteren {
-    ( $x:expr ) => (
+macro_rules! csr_read_set_bits_hstatus {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, hcounteren, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrs    {0}, hstatus, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-
-/* hcounteren: CSR Field Modifications - via register */
-
-/*******************************************
- * hgatp - HRW - Hypervisor guest address translation and protection. 
- */
-/* hgatp: CSR Whole register access */
-/* hgatp: CSR read.
+/* Register CSR read and then bit clear instructions
 e.g.
-    let _v = csr_read_hgatp!();
+let org_value_ = csr_read_clr_bits_hstatus!(0x0F0F0F)
  */
 #[macro_export]
-macro_rules! csr_read_hgatp {
-    ( ) => (
+macro_rules! csr_read_clr_bits_hstatus {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, hgatp" , out(reg) tmp_value);
+                asm!("csrrc    {0}, hstatus, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-/* hgatp: CSR write 
+/* hstatus: CSR Field Modifications - via immediate */
+/* hstatus, CSR write value via immediate value (only up to 5 bits).
 e.g.
-    csr_write_hgatp!(0x1234567);
-*/
+csr_write_imm_hstatus!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_write_hgatp {
-    ( $x:expr ) => (
+macro_rules! csr_write_imm_hstatus {
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
-            asm!("csrw    hgatp, {0}" , in(reg) $x);
+            asm!(concat!("csrrwi    zero, hstatus, ", stringify!($value)));
         }
     );
 }
-/* hgatp: CSR Read and Write 
+
+/* hstatus, CSR set bits via immediate value mask (only up to 5 bits).
 e.g.
-    let v_ = csr_read_write_hgatp!(0x1234567);
-*/
+csr_set_bits_imm_hstatus!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_read_write_hgatp {
-    ( $x:expr ) => (
-        {
-            let tmp_value: UintXlen;
-            unsafe {
-                use core::arch::asm;
-                asm!("csrrw    {0}, hgatp, {1}" , out(reg) tmp_value, in(reg) $x);
-            }
-            tmp_value
+macro_rules! csr_set_bits_imm_hstatus {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, hstatus, ", stringify!($value)));
+        }
+    );
+}
+/* hstatus, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_hstatus!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_hstatus {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, hstatus, ", stringify!($value)));
         }
     );
 }
-
-/* hgatp: CSR Field Modifications - via register */
 
 /*******************************************
- * htimedelta - HRW - Delta for VS/VU-mode timer. 
+ * hedeleg - HRW - Hypervisor exception delegation register. 
  */
-/* htimedelta: CSR Whole register access */
-/* htimedelta: CSR read.
+/* hedeleg: CSR address, as used in the csr field of the CSR instructions. */
+pub const HEDELEG_CSR_ADDRESS: u16 = 0x602;
+/* hedeleg: CSR Whole register access */
+/* hedeleg: CSR read.
 e.g.
-    let _v = csr_read_htimedelta!();
+    let _v = csr_read_hedeleg!();
  */
 #[macro_export]
-macro_rules! csr_read_htimedelta {
+macro_rules! csr_read_hedeleg {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, htimedelta" , out(reg) tmp_value);
+                asm!("csrr    {0}, hedeleg" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* htimedelta: CSR write 
+/* hedeleg: CSR write 
 e.g.
-    csr_write_htimedelta!(0x1234567);
+    csr_write_hedeleg!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_htimedelta {
+macro_rules! csr_write_hedeleg {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    htimedelta, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hedeleg, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* htimedelta: CSR Read and Write 
+/* hedeleg: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_htimedelta!(0x1234567);
+    let v_ = csr_read_write_hedeleg!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_htimedelta {
+macro_rules! csr_read_write_hedeleg {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, htimedelta, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, hedeleg, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* htimedelta: CSR Field Modifications - via register */
-
-/*******************************************
- * htimedeltah - HRW - Upper 32 bits of  htimedelta, RV32I only. 
- */
-/* htimedeltah: CSR Whole register access */
-/* htimedeltah: CSR read.
+/* hedeleg: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_htimedeltah!();
- */
+csr_set_bits_hedeleg!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_htimedeltah {
-    ( ) => (
+macro_rules! csr_set_bits_hedeleg {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, htimedeltah" , out(reg) tmp_value);
+                asm!("csrrs    zero, hedeleg, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* htimedeltah: CSR write 
+/* Register CSR bit clear instructions
 e.g.
-    csr_write_htimedeltah!(0x1234567);
-*/
+csr_clr_bits_hedeleg!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_write_htimedeltah {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    htimedeltah, {0}" , in(reg) $x);
+macro_rules! csr_clr_bits_hedeleg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, hedeleg, {0}", in(reg) value);
+            }
         }
     );
 }
-/* htimedeltah: CSR Read and Write 
+/* Register CSR read and then bit set instructions
 e.g.
-    let v_ = csr_read_write_htimedeltah!(0x1234567);
+let org_value_ = csr_read_set_bits_hedeleg!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_read_write_htimedeltah {
-    ( $x:expr ) => (
+macro_rules! csr_read_set_bits_hedeleg {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, htimedeltah, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrs    {0}, hedeleg, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-
-/* htimedeltah: CSR Field Modifications - via register */
-
-/*******************************************
- * vsstatus - HRW - Virtual supervisor status register. 
- */
-/* vsstatus: CSR Whole register access */
-/* vsstatus: CSR read.
+/* Register CSR read and then bit clear instructions
 e.g.
-    let _v = csr_read_vsstatus!();
+let org_value_ = csr_read_clr_bits_hedeleg!(0x0F0F0F)
  */
 #[macro_export]
-macro_rules! csr_read_vsstatus {
-    ( ) => (
+macro_rules! csr_read_clr_bits_hedeleg {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, vsstatus" , out(reg) tmp_value);
+                asm!("csrrc    {0}, hedeleg, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-/* vsstatus: CSR write 
+/* hedeleg: CSR Field Modifications - via immediate */
+/* hedeleg, CSR write value via immediate value (only up to 5 bits).
 e.g.
-    csr_write_vsstatus!(0x1234567);
-*/
+csr_write_imm_hedeleg!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_write_vsstatus {
-    ( $x:expr ) => (
+macro_rules! csr_write_imm_hedeleg {
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
-            asm!("csrw    vsstatus, {0}" , in(reg) $x);
+            asm!(concat!("csrrwi    zero, hedeleg, ", stringify!($value)));
         }
     );
 }
-/* vsstatus: CSR Read and Write 
+
+/* hedeleg, CSR set bits via immediate value mask (only up to 5 bits).
 e.g.
-    let v_ = csr_read_write_vsstatus!(0x1234567);
-*/
+csr_set_bits_imm_hedeleg!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_read_write_vsstatus {
-    ( $x:expr ) => (
-        {
-            let tmp_value: UintXlen;
-            unsafe {
-                use core::arch::asm;
-                asm!("csrrw    {0}, vsstatus, {1}" , out(reg) tmp_value, in(reg) $x);
-            }
-            tmp_value
+macro_rules! csr_set_bits_imm_hedeleg {
+    ( HEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK) => { $crate::csr_set_bits_imm_hedeleg!(0x1)};
+    ( HEDELEG_INSTRUCTION_FAULT_BIT_MASK) => { $crate::csr_set_bits_imm_hedeleg!(0x2)};
+    ( HEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK) => { $crate::csr_set_bits_imm_hedeleg!(0x4)};
+    ( HEDELEG_BREAKPOINT_BIT_MASK) => { $crate::csr_set_bits_imm_hedeleg!(0x8)};
+    ( HEDELEG_LOAD_MISALIGNED_BIT_MASK) => { $crate::csr_set_bits_imm_hedeleg!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, hedeleg, ", stringify!($value)));
+        }
+    );
+}
+/* hedeleg, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_hedeleg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_hedeleg {
+    ( HEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK) => { $crate::csr_clr_bits_imm_hedeleg!(0x1)};
+    ( HEDELEG_INSTRUCTION_FAULT_BIT_MASK) => { $crate::csr_clr_bits_imm_hedeleg!(0x2)};
+    ( HEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK) => { $crate::csr_clr_bits_imm_hedeleg!(0x4)};
+    ( HEDELEG_BREAKPOINT_BIT_MASK) => { $crate::csr_clr_bits_imm_hedeleg!(0x8)};
+    ( HEDELEG_LOAD_MISALIGNED_BIT_MASK) => { $crate::csr_clr_bits_imm_hedeleg!(0x10)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, hedeleg, ", stringify!($value)));
         }
     );
 }
-
-/* vsstatus: CSR Field Modifications - via register */
 
 /*******************************************
- * vsie - HRW - Virtual supervisor interrupt-enable register. 
+ * hideleg - HRW - Hypervisor interrupt delegation register. 
  */
-/* vsie: CSR Whole register access */
-/* vsie: CSR read.
+/* hideleg: CSR address, as used in the csr field of the CSR instructions. */
+pub const HIDELEG_CSR_ADDRESS: u16 = 0x603;
+/* hideleg: CSR Whole register access */
+/* hideleg: CSR read.
 e.g.
-    let _v = csr_read_vsie!();
+    let _v = csr_read_hideleg!();
  */
 #[macro_export]
-macro_rules! csr_read_vsie {
+macro_rules! csr_read_hideleg {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, vsie" , out(reg) tmp_value);
+                asm!("csrr    {0}, hideleg" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* vsie: CSR write 
+/* hideleg: CSR write 
 e.g.
-    csr_write_vsie!(0x1234567);
+    csr_write_hideleg!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_vsie {
+macro_rules! csr_write_hideleg {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    vsie, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hideleg, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* vsie: CSR Read and Write 
+/* hideleg: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_vsie!(0x1234567);
+    let v_ = csr_read_write_hideleg!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_vsie {
+macro_rules! csr_read_write_hideleg {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, vsie, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, hideleg, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* vsie: CSR Field Modifications - via register */
-
-/*******************************************
- * vstvec - HRW - Virtual supervisor trap handler base address. 
- */
-/* vstvec: CSR Whole register access */
-/* vstvec: CSR read.
+/* hideleg: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_vstvec!();
- */
+csr_set_bits_hideleg!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_vstvec {
-    ( ) => (
+macro_rules! csr_set_bits_hideleg {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, vstvec" , out(reg) tmp_value);
+                asm!("csrrs    zero, hideleg, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* vstvec: CSR write 
+/* Register CSR bit clear instructions
 e.g.
-    csr_write_vstvec!(0x1234567);
-*/
+csr_clr_bits_hideleg!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_write_vstvec {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    vstvec, {0}" , in(reg) $x);
+macro_rules! csr_clr_bits_hideleg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, hideleg, {0}", in(reg) value);
+            }
         }
     );
 }
-/* vstvec: CSR Read and Write 
+/* Register CSR read and then bit set instructions
 e.g.
-    let v_ = csr_read_write_vstvec!(0x1234567);
+let org_value_ = csr_read_set_bits_hideleg!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_read_write_vstvec {
-    ( $x:expr ) => (
+macro_rules! csr_read_set_bits_hideleg {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, vstvec, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrs    {0}, hideleg, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-
-/* vstvec: CSR Field Modifications - via register */
-
-/*******************************************
- * vsscratch - HRW - Virtual supervisor scratch register. 
- */
-/* vsscratch: CSR Whole register access */
-/* vsscratch: CSR read.
-e.g.
-    let _v = csr_read_vsscratch!();
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_hideleg!(0x0F0F0F)
  */
 #[macro_export]
-macro_rules! csr_read_vsscratch {
-    ( ) => (
+macro_rules! csr_read_clr_bits_hideleg {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, vsscratch" , out(reg) tmp_value);
+                asm!("csrrc    {0}, hideleg, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-/* vsscratch: CSR write 
+/* hideleg: CSR Field Modifications - via immediate */
+/* hideleg, CSR write value via immediate value (only up to 5 bits).
 e.g.
-    csr_write_vsscratch!(0x1234567);
-*/
+csr_write_imm_hideleg!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_write_vsscratch {
-    ( $x:expr ) => (
+macro_rules! csr_write_imm_hideleg {
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
-            asm!("csrw    vsscratch, {0}" , in(reg) $x);
+            asm!(concat!("csrrwi    zero, hideleg, ", stringify!($value)));
         }
     );
 }
-/* vsscratch: CSR Read and Write 
+
+/* hideleg, CSR set bits via immediate value mask (only up to 5 bits).
 e.g.
-    let v_ = csr_read_write_vsscratch!(0x1234567);
-*/
+csr_set_bits_imm_hideleg!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_read_write_vsscratch {
-    ( $x:expr ) => (
-        {
-            let tmp_value: UintXlen;
-            unsafe {
-                use core::arch::asm;
-                asm!("csrrw    {0}, vsscratch, {1}" , out(reg) tmp_value, in(reg) $x);
-            }
-            tmp_value
+macro_rules! csr_set_bits_imm_hideleg {
+    ( HIDELEG_VSSI_BIT_MASK) => { $crate::csr_set_bits_imm_hideleg!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, hideleg, ", stringify!($value)));
+        }
+    );
+}
+/* hideleg, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_hideleg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_hideleg {
+    ( HIDELEG_VSSI_BIT_MASK) => { $crate::csr_clr_bits_imm_hideleg!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, hideleg, ", stringify!($value)));
         }
     );
 }
-
-/* vsscratch: CSR Field Modifications - via register */
 
 /*******************************************
- * vsepc - HRW - Virtual supervisor exception program counter. 
+ * hcounteren - HRW - Hypervisor counter enable. 
  */
-/* vsepc: CSR Whole register access */
-/* vsepc: CSR read.
+/* hcounteren: CSR address, as used in the csr field of the CSR instructions. */
+pub const HCOUNTEREN_CSR_ADDRESS: u16 = 0x606;
+/* hcounteren: CSR Whole register access */
+/* hcounteren: CSR read.
 e.g.
-    let _v = csr_read_vsepc!();
+    let _v = csr_read_hcounteren!();
  */
 #[macro_export]
-macro_rules! csr_read_vsepc {
+macro_rules! csr_read_hcounteren {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, vsepc" , out(reg) tmp_value);
+                asm!("csrr    {0}, hcounteren" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* vsepc: CSR write 
+/* hcounteren: CSR write 
 e.g.
-    csr_write_vsepc!(0x1234567);
+    csr_write_hcounteren!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_vsepc {
+macro_rules! csr_write_hcounteren {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    vsepc, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hcounteren, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* vsepc: CSR Read and Write 
+/* hcounteren: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_vsepc!(0x1234567);
+    let v_ = csr_read_write_hcounteren!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_vsepc {
+macro_rules! csr_read_write_hcounteren {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, vsepc, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, hcounteren, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* vsepc: CSR Field Modifications - via register */
+/* hcounteren: CSR Field Modifications - via register */
 
 /*******************************************
- * vscause - HRW - Virtual supervisor trap cause. 
+ * hgatp - HRW - Hypervisor guest address translation and protection. 
  */
-/* vscause: CSR Whole register access */
-/* vscause: CSR read.
+/* hgatp: CSR address, as used in the csr field of the CSR instructions. */
+pub const HGATP_CSR_ADDRESS: u16 = 0x680;
+/* hgatp: CSR Whole register access */
+/* hgatp: CSR read.
 e.g.
-    let _v = csr_read_vscause!();
+    let _v = csr_read_hgatp!();
  */
 #[macro_export]
-macro_rules! csr_read_vscause {
+macro_rules! csr_read_hgatp {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, vscause" , out(reg) tmp_value);
+                asm!("csrr    {0}, hgatp" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* vscause: CSR write 
+/* hgatp: CSR write 
 e.g.
-    csr_write_vscause!(0x1234567);
+    csr_write_hgatp!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_vscause {
+macro_rules! csr_write_hgatp {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    vscause, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hgatp, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* vscause: CSR Read and Write 
+/* hgatp: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_vscause!(0x1234567);
+    let v_ = csr_read_write_hgatp!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_vscause {
+macro_rules! csr_read_write_hgatp {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, vscause, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, hgatp, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* vscause: CSR Field Modifications - via register */
-
-/*******************************************
- * vstval - HRW - Virtual supervisor bad address or instruction. 
- */
-/* vstval: CSR Whole register access */
-/* vstval: CSR read.
+/* hgatp: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_vstval!();
- */
+csr_set_bits_hgatp!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_vstval {
-    ( ) => (
+macro_rules! csr_set_bits_hgatp {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, vstval" , out(reg) tmp_value);
+                asm!("csrrs    zero, hgatp, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* vstval: CSR write 
+/* Register CSR bit clear instructions
 e.g.
-    csr_write_vstval!(0x1234567);
-*/
+csr_clr_bits_hgatp!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_write_vstval {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    vstval, {0}" , in(reg) $x);
+macro_rules! csr_clr_bits_hgatp {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, hgatp, {0}", in(reg) value);
+            }
         }
     );
 }
-/* vstval: CSR Read and Write 
+/* Register CSR read and then bit set instructions
 e.g.
-    let v_ = csr_read_write_vstval!(0x1234567);
+let org_value_ = csr_read_set_bits_hgatp!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_read_write_vstval {
-    ( $x:expr ) => (
+macro_rules! csr_read_set_bits_hgatp {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, vstval, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrs    {0}, hgatp, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-
-/* vstval: CSR Field Modifications - via register */
-
-/*******************************************
- * vsip - HRW - Virtual supervisor interrupt pending. 
- */
-/* vsip: CSR Whole register access */
-/* vsip: CSR read.
+/* Register CSR read and then bit clear instructions
 e.g.
-    let _v = csr_read_vsip!();
+let org_value_ = csr_read_clr_bits_hgatp!(0x0F0F0F)
  */
 #[macro_export]
-macro_rules! csr_read_vsip {
-    ( ) => (
+macro_rules! csr_read_clr_bits_hgatp {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, vsip" , out(reg) tmp_value);
+                asm!("csrrc    {0}, hgatp, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-/* vsip: CSR write 
+/* hgatp: CSR Field Modifications - via immediate */
+/* hgatp, CSR write value via immediate value (only up to 5 bits).
 e.g.
-    csr_write_vsip!(0x1234567);
-*/
+csr_write_imm_hgatp!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_write_vsip {
-    ( $x:expr ) => (
+macro_rules! csr_write_imm_hgatp {
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
-            asm!("csrw    vsip, {0}" , in(reg) $x);
+            asm!(concat!("csrrwi    zero, hgatp, ", stringify!($value)));
         }
     );
 }
-/* vsip: CSR Read and Write 
+
+/* hgatp, CSR set bits via immediate value mask (only up to 5 bits).
 e.g.
-    let v_ = csr_read_write_vsip!(0x1234567);
-*/
+csr_set_bits_imm_hgatp!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_read_write_vsip {
-    ( $x:expr ) => (
-        {
-            let tmp_value: UintXlen;
-            unsafe {
-                use core::arch::asm;
-                asm!("csrrw    {0}, vsip, {1}" , out(reg) tmp_value, in(reg) $x);
-            }
-            tmp_value
+macro_rules! csr_set_bits_imm_hgatp {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, hgatp, ", stringify!($value)));
+        }
+    );
+}
+/* hgatp, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_hgatp!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_hgatp {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, hgatp, ", stringify!($value)));
         }
     );
 }
-
-/* vsip: CSR Field Modifications - via register */
 
 /*******************************************
- * vsatp - HRW - Virtual supervisor address translation and protection. 
+ * htimedelta - HRW - Delta for VS/VU-mode timer. 
  */
-/* vsatp: CSR Whole register access */
-/* vsatp: CSR read.
+/* htimedelta: CSR address, as used in the csr field of the CSR instructions. */
+pub const HTIMEDELTA_CSR_ADDRESS: u16 = 0x605;
+/* htimedelta: CSR Whole register access */
+/* htimedelta: CSR read.
 e.g.
-    let _v = csr_read_vsatp!();
+    let _v = csr_read_htimedelta!();
  */
 #[macro_export]
-macro_rules! csr_read_vsatp {
+macro_rules! csr_read_htimedelta {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, vsatp" , out(reg) tmp_value);
+                asm!("csrr    {0}, htimedelta" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* vsatp: CSR write 
+/* htimedelta: CSR write 
 e.g.
-    csr_write_vsatp!(0x1234567);
+    csr_write_htimedelta!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_vsatp {
+macro_rules! csr_write_htimedelta {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    vsatp, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    htimedelta, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* vsatp: CSR Read and Write 
+/* htimedelta: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_vsatp!(0x1234567);
+    let v_ = csr_read_write_htimedelta!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_vsatp {
+macro_rules! csr_read_write_htimedelta {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, vsatp, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, htimedelta, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* vsatp: CSR Field Modifications - via register */
+/* htimedelta: CSR Field Modifications - via register */
 
 /*******************************************
- * mbase - MRW - Base register. 
+ * htimedeltah - HRW - Upper 32 bits of  htimedelta, RV32I only. 
  */
-/* mbase: CSR Whole register access */
-/* mbase: CSR read.
+/* htimedeltah: CSR address, as used in the csr field of the CSR instructions. */
+pub const HTIMEDELTAH_CSR_ADDRESS: u16 = 0x615;
+/* htimedeltah: CSR Whole register access */
+/* htimedeltah: CSR read.
 e.g.
-    let _v = csr_read_mbase!();
+    let _v = csr_read_htimedeltah!();
  */
 #[macro_export]
-macro_rules! csr_read_mbase {
+macro_rules! csr_read_htimedeltah {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mbase" , out(reg) tmp_value);
+                asm!("csrr    {0}, htimedeltah" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mbase: CSR write 
+/* htimedeltah: CSR write 
 e.g.
-    csr_write_mbase!(0x1234567);
+    csr_write_htimedeltah!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mbase {
+macro_rules! csr_write_htimedeltah {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mbase, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    htimedeltah, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mbase: CSR Read and Write 
+/* htimedeltah: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mbase!(0x1234567);
+    let v_ = csr_read_write_htimedeltah!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mbase {
+macro_rules! csr_read_write_htimedeltah {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mbase, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, htimedeltah, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mbase: CSR Field Modifications - via register */
+/* htimedeltah: CSR Field Modifications - via register */
 
 /*******************************************
- * mbound - MRW - Bound register. 
+ * vsstatus - HRW - Virtual supervisor status register. 
  */
-/* mbound: CSR Whole register access */
-/* mbound: CSR read.
+/* vsstatus: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSSTATUS_CSR_ADDRESS: u16 = 0x200;
+/* vsstatus: CSR Whole register access */
+/* vsstatus: CSR read.
 e.g.
-    let _v = csr_read_mbound!();
+    let _v = csr_read_vsstatus!();
  */
 #[macro_export]
-macro_rules! csr_read_mbound {
+macro_rules! csr_read_vsstatus {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mbound" , out(reg) tmp_value);
+                asm!("csrr    {0}, vsstatus" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mbound: CSR write 
+/* vsstatus: CSR write 
 e.g.
-    csr_write_mbound!(0x1234567);
+    csr_write_vsstatus!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mbound {
+macro_rules! csr_write_vsstatus {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mbound, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vsstatus, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mbound: CSR Read and Write 
+/* vsstatus: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mbound!(0x1234567);
+    let v_ = csr_read_write_vsstatus!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mbound {
+macro_rules! csr_read_write_vsstatus {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mbound, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, vsstatus, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mbound: CSR Field Modifications - via register */
+/* vsstatus: CSR Field Modifications - via register */
 
 /*******************************************
- * mibase - MRW - Instruction base register. 
+ * vsie - HRW - Virtual supervisor interrupt-enable register. 
  */
-/* mibase: CSR Whole register access */
-/* mibase: CSR read.
+/* vsie: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSIE_CSR_ADDRESS: u16 = 0x204;
+/* vsie: CSR Whole register access */
+/* vsie: CSR read.
 e.g.
-    let _v = csr_read_mibase!();
+    let _v = csr_read_vsie!();
  */
 #[macro_export]
-macro_rules! csr_read_mibase {
+macro_rules! csr_read_vsie {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mibase" , out(reg) tmp_value);
+                asm!("csrr    {0}, vsie" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mibase: CSR write 
+/* vsie: CSR write 
 e.g.
-    csr_write_mibase!(0x1234567);
+    csr_write_vsie!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mibase {
+macro_rules! csr_write_vsie {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mibase, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vsie, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mibase: CSR Read and Write 
+/* vsie: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mibase!(0x1234567);
+    let v_ = csr_read_write_vsie!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mibase {
+macro_rules! csr_read_write_vsie {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mibase, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, vsie, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mibase: CSR Field Modifications - via register */
+/* vsie: CSR Field Modifications - via register */
 
 /*******************************************
- * mibound - MRW - Instruction bound register. 
+ * vstvec - HRW - Virtual supervisor trap handler base address. 
  */
-/* mibound: CSR Whole register access */
-/* mibound: CSR read.
+/* vstvec: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSTVEC_CSR_ADDRESS: u16 = 0x205;
+/* vstvec: CSR Whole register access */
+/* vstvec: CSR read.
 e.g.
-    let _v = csr_read_mibound!();
+    let _v = csr_read_vstvec!();
  */
 #[macro_export]
-macro_rules! csr_read_mibound {
+macro_rules! csr_read_vstvec {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mibound" , out(reg) tmp_value);
+                asm!("csrr    {0}, vstvec" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mibound: CSR write 
+/* vstvec: CSR write 
 e.g.
-    csr_write_mibound!(0x1234567);
+    csr_write_vstvec!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mibound {
+macro_rules! csr_write_vstvec {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mibound, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vstvec, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mibound: CSR Read and Write 
+/* vstvec: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mibound!(0x1234567);
+    let v_ = csr_read_write_vstvec!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mibound {
+macro_rules! csr_read_write_vstvec {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mibound, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, vstvec, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mibound: CSR Field Modifications - via register */
+/* vstvec: CSR Field Modifications - via register */
 
 /*******************************************
- * mdbase - MRW - Data base register. 
+ * vsscratch - HRW - Virtual supervisor scratch register. 
  */
-/* mdbase: CSR Whole register access */
-/* mdbase: CSR read.
+/* vsscratch: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSSCRATCH_CSR_ADDRESS: u16 = 0x240;
+/* vsscratch: CSR Whole register access */
+/* vsscratch: CSR read.
 e.g.
-    let _v = csr_read_mdbase!();
+    let _v = csr_read_vsscratch!();
  */
 #[macro_export]
-macro_rules! csr_read_mdbase {
+macro_rules! csr_read_vsscratch {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mdbase" , out(reg) tmp_value);
+                asm!("csrr    {0}, vsscratch" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mdbase: CSR write 
+/* vsscratch: CSR write 
 e.g.
-    csr_write_mdbase!(0x1234567);
+    csr_write_vsscratch!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mdbase {
+macro_rules! csr_write_vsscratch {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mdbase, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vsscratch, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mdbase: CSR Read and Write 
+/* vsscratch: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mdbase!(0x1234567);
+    let v_ = csr_read_write_vsscratch!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mdbase {
+macro_rules! csr_read_write_vsscratch {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mdbase, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, vsscratch, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mdbase: CSR Field Modifications - via register */
+/* vsscratch: CSR Field Modifications - via register */
 
 /*******************************************
- * mdbound - MRW - Data bound register. 
+ * vsepc - HRW - Virtual supervisor exception program counter. 
  */
-/* mdbound: CSR Whole register access */
-/* mdbound: CSR read.
+/* vsepc: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSEPC_CSR_ADDRESS: u16 = 0x241;
+/* vsepc: CSR Whole register access */
+/* vsepc: CSR read.
 e.g.
-    let _v = csr_read_mdbound!();
+    let _v = csr_read_vsepc!();
  */
 #[macro_export]
-macro_rules! csr_read_mdbound {
+macro_rules! csr_read_vsepc {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mdbound" , out(reg) tmp_value);
+                asm!("csrr    {0}, vsepc" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mdbound: CSR write 
+/* vsepc: CSR write 
 e.g.
-    csr_write_mdbound!(0x1234567);
+    csr_write_vsepc!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mdbound {
+macro_rules! csr_write_vsepc {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mdbound, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vsepc, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mdbound: CSR Read and Write 
+/* vsepc: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mdbound!(0x1234567);
+    let v_ = csr_read_write_vsepc!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mdbound {
+macro_rules! csr_read_write_vsepc {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mdbound, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, vsepc, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mdbound: CSR Field Modifications - via register */
+/* vsepc: CSR Field Modifications - via register */
 
 /*******************************************
- * pmpcfg0 - MRW - Physical memory protection configuration. 
+ * vscause - HRW - Virtual supervisor trap cause. 
  */
-/* pmpcfg0: CSR Whole register access */
-/* pmpcfg0: CSR read.
+/* vscause: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSCAUSE_CSR_ADDRESS: u16 = 0x242;
+/* vscause: CSR Whole register access */
+/* vscause: CSR read.
 e.g.
-    let _v = csr_read_pmpcfg0!();
+    let _v = csr_read_vscause!();
  */
 #[macro_export]
-macro_rules! csr_read_pmpcfg0 {
+macro_rules! csr_read_vscause {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, pmpcfg0" , out(reg) tmp_value);
+                asm!("csrr    {0}, vscause" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* pmpcfg0: CSR write 
+/* vscause: CSR write 
 e.g.
-    csr_write_pmpcfg0!(0x1234567);
+    csr_write_vscause!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_pmpcfg0 {
+macro_rules! csr_write_vscause {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    pmpcfg0, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vscause, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* pmpcfg0: CSR Read and Write 
+/* vscause: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_pmpcfg0!(0x1234567);
+    let v_ = csr_read_write_vscause!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_pmpcfg0 {
+macro_rules! csr_read_write_vscause {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, pmpcfg0, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, vscause, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* pmpcfg0: CSR Field Modifications - via register */
+/* vscause: CSR Field Modifications - via register */
 
 /*******************************************
- * pmpcfg1 - MRW - Physical memory protection configuration, RV32 only. 
+ * vstval - HRW - Virtual supervisor bad address or instruction. 
  */
-/* pmpcfg1: CSR Whole register access */
-/* pmpcfg1: CSR read.
+/* vstval: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSTVAL_CSR_ADDRESS: u16 = 0x243;
+/* vstval: CSR Whole register access */
+/* vstval: CSR read.
 e.g.
-    let _v = csr_read_pmpcfg1!();
+    let _v = csr_read_vstval!();
  */
 #[macro_export]
-macro_rules! csr_read_pmpcfg1 {
+macro_rules! csr_read_vstval {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, pmpcfg1" , out(reg) tmp_value);
+                asm!("csrr    {0}, vstval" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* pmpcfg1: CSR write 
+/* vstval: CSR write 
 e.g.
-    csr_write_pmpcfg1!(0x1234567);
+    csr_write_vstval!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_pmpcfg1 {
+macro_rules! csr_write_vstval {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    pmpcfg1, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vstval, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* pmpcfg1: CSR Read and Write 
+/* vstval: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_pmpcfg1!(0x1234567);
+    let v_ = csr_read_write_vstval!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_pmpcfg1 {
+macro_rules! csr_read_write_vstval {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, pmpcfg1, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, vstval, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* pmpcfg1: CSR Field Modifications - via register */
+/* vstval: CSR Field Modifications - via register */
 
 /*******************************************
- * pmpcfg2 - MRW - Physical memory protection configuration. 
+ * vsip - HRW - Virtual supervisor interrupt pending. 
  */
-/* pmpcfg2: CSR Whole register access */
-/* pmpcfg2: CSR read.
+/* vsip: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSIP_CSR_ADDRESS: u16 = 0x244;
+/* vsip: CSR Whole register access */
+/* vsip: CSR read.
 e.g.
-    let _v = csr_read_pmpcfg2!();
+    let _v = csr_read_vsip!();
  */
 #[macro_export]
-macro_rules! csr_read_pmpcfg2 {
+macro_rules! csr_read_vsip {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, pmpcfg2" , out(reg) tmp_value);
+                asm!("csrr    {0}, vsip" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* pmpcfg2: CSR write 
+/* vsip: CSR write 
 e.g.
-    csr_write_pmpcfg2!(0x1234567);
+    csr_write_vsip!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_pmpcfg2 {
+macro_rules! csr_write_vsip {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    pmpcfg2, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vsip, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* pmpcfg2: CSR Read and Write 
+/* vsip: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_pmpcfg2!(0x1234567);
+    let v_ = csr_read_write_vsip!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_pmpcfg2 {
+macro_rules! csr_read_write_vsip {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, pmpcfg2, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, vsip, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* pmpcfg2: CSR Field Modifications - via register */
+/* vsip: CSR Field Modifications - via register */
 
 /*******************************************
- * pmpcfg3 - MRW - Physical memory protection configuration, RV32 only. 
+ * vsatp - HRW - Virtual supervisor address translation and protection. 
  */
-/* pmpcfg3: CSR Whole register access */
-/* pmpcfg3: CSR read.
+/* vsatp: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSATP_CSR_ADDRESS: u16 = 0x280;
+/* vsatp: CSR Whole register access */
+/* vsatp: CSR read.
 e.g.
-    let _v = csr_read_pmpcfg3!();
+    let _v = csr_read_vsatp!();
  */
 #[macro_export]
-macro_rules! csr_read_pmpcfg3 {
+macro_rules! csr_read_vsatp {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, pmpcfg3" , out(reg) tmp_value);
+                asm!("csrr    {0}, vsatp" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* pmpcfg3: CSR write 
+/* vsatp: CSR write 
 e.g.
-    csr_write_pmpcfg3!(0x1234567);
+    csr_write_vsatp!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_pmpcfg3 {
+macro_rules! csr_write_vsatp {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    pmpcfg3, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vsatp, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* pmpcfg3: CSR Read and Write 
+/* vsatp: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_pmpcfg3!(0x1234567);
+    let v_ = csr_read_write_vsatp!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_pmpcfg3 {
+macro_rules! csr_read_write_vsatp {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, pmpcfg3, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, vsatp, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* pmpcfg3: CSR Field Modifications - via register */
-
-/*******************************************
- * pmpaddr0 - MRW - Physical memory protection address register. 
- */
-/* pmpaddr0: CSR Whole register access */
-/* pmpaddr0: CSR read.
+/* vsatp: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_pmpaddr0!();
- */
+csr_set_bits_vsatp!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_pmpaddr0 {
-    ( ) => (
+macro_rules! csr_set_bits_vsatp {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, pmpaddr0" , out(reg) tmp_value);
+                asm!("csrrs    zero, vsatp, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* pmpaddr0: CSR write 
+/* Register CSR bit clear instructions
 e.g.
-    csr_write_pmpaddr0!(0x1234567);
-*/
+csr_clr_bits_vsatp!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_write_pmpaddr0 {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    pmpaddr0, {0}" , in(reg) $x);
+macro_rules! csr_clr_bits_vsatp {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, vsatp, {0}", in(reg) value);
+            }
         }
     );
 }
-/* pmpaddr0: CSR Read and Write 
+/* Register CSR read and then bit set instructions
 e.g.
-    let v_ = csr_read_write_pmpaddr0!(0x1234567);
+let org_value_ = csr_read_set_bits_vsatp!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_read_write_pmpaddr0 {
-    ( $x:expr ) => (
+macro_rules! csr_read_set_bits_vsatp {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, pmpaddr0, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrs    {0}, vsatp, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-
-/* pmpaddr0: CSR Field Modifications - via register */
-
-/*******************************************
- * pmpaddr1 - MRW - Physical memory protection address register. 
- */
-/* pmpaddr1: CSR Whole register access */
-/* pmpaddr1: CSR read.
+/* Register CSR read and then bit clear instructions
 e.g.
-    let _v = csr_read_pmpaddr1!();
+let org_value_ = csr_read_clr_bits_vsatp!(0x0F0F0F)
  */
 #[macro_export]
-macro_rules! csr_read_pmpaddr1 {
-    ( ) => (
+macro_rules! csr_read_clr_bits_vsatp {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, pmpaddr1" , out(reg) tmp_value);
+                asm!("csrrc    {0}, vsatp, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-/* pmpaddr1: CSR write 
+/* vsatp: CSR Field Modifications - via immediate */
+/* vsatp, CSR write value via immediate value (only up to 5 bits).
 e.g.
-    csr_write_pmpaddr1!(0x1234567);
-*/
+csr_write_imm_vsatp!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_write_pmpaddr1 {
-    ( $x:expr ) => (
+macro_rules! csr_write_imm_vsatp {
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
-            asm!("csrw    pmpaddr1, {0}" , in(reg) $x);
+            asm!(concat!("csrrwi    zero, vsatp, ", stringify!($value)));
         }
     );
 }
-/* pmpaddr1: CSR Read and Write 
+
+/* vsatp, CSR set bits via immediate value mask (only up to 5 bits).
 e.g.
-    let v_ = csr_read_write_pmpaddr1!(0x1234567);
-*/
+csr_set_bits_imm_vsatp!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_read_write_pmpaddr1 {
-    ( $x:expr ) => (
-        {
-            let tmp_value: UintXlen;
-            unsafe {
-                use core::arch::asm;
-                asm!("csrrw    {0}, pmpaddr1, {1}" , out(reg) tmp_value, in(reg) $x);
-            }
-            tmp_value
+macro_rules! csr_set_bits_imm_vsatp {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, vsatp, ", stringify!($value)));
+        }
+    );
+}
+/* vsatp, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_vsatp!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_vsatp {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, vsatp, ", stringify!($value)));
         }
     );
 }
-
-/* pmpaddr1: CSR Field Modifications - via register */
 
 /*******************************************
- * pmpaddr15 - MRW - Physical memory protection address register. 
+ * mbase - MRW - Base register. 
  */
-/* pmpaddr15: CSR Whole register access */
-/* pmpaddr15: CSR read.
+/* mbase: CSR address, as used in the csr field of the CSR instructions. */
+pub const MBASE_CSR_ADDRESS: u16 = 0x380;
+/* mbase: CSR Whole register access */
+/* mbase: CSR read.
 e.g.
-    let _v = csr_read_pmpaddr15!();
+    let _v = csr_read_mbase!();
  */
 #[macro_export]
-macro_rules! csr_read_pmpaddr15 {
+macro_rules! csr_read_mbase {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, pmpaddr15" , out(reg) tmp_value);
+                asm!("csrr    {0}, mbase" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* pmpaddr15: CSR write 
+/* mbase: CSR write 
 e.g.
-    csr_write_pmpaddr15!(0x1234567);
+    csr_write_mbase!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_pmpaddr15 {
+macro_rules! csr_write_mbase {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    pmpaddr15, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mbase, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* pmpaddr15: CSR Read and Write 
+/* mbase: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_pmpaddr15!(0x1234567);
+    let v_ = csr_read_write_mbase!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_pmpaddr15 {
+macro_rules! csr_read_write_mbase {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, pmpaddr15, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mbase, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* pmpaddr15: CSR Field Modifications - via register */
+/* mbase: CSR Field Modifications - via register */
 
 /*******************************************
- * mhpmcounter4 - MRW - Machine performance-monitoring counter. 
+ * mbound - MRW - Bound register. 
  */
-/* mhpmcounter4: CSR Whole register access */
-/* mhpmcounter4: CSR read.
+/* mbound: CSR address, as used in the csr field of the CSR instructions. */
+pub const MBOUND_CSR_ADDRESS: u16 = 0x381;
+/* mbound: CSR Whole register access */
+/* mbound: CSR read.
 e.g.
-    let _v = csr_read_mhpmcounter4!();
+    let _v = csr_read_mbound!();
  */
 #[macro_export]
-macro_rules! csr_read_mhpmcounter4 {
+macro_rules! csr_read_mbound {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mhpmcounter4" , out(reg) tmp_value);
+                asm!("csrr    {0}, mbound" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mhpmcounter4: CSR write 
+/* mbound: CSR write 
 e.g.
-    csr_write_mhpmcounter4!(0x1234567);
+    csr_write_mbound!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mhpmcounter4 {
+macro_rules! csr_write_mbound {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mhpmcounter4, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mbound, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mhpmcounter4: CSR Read and Write 
+/* mbound: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mhpmcounter4!(0x1234567);
+    let v_ = csr_read_write_mbound!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mhpmcounter4 {
+macro_rules! csr_read_write_mbound {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mhpmcounter4, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mbound, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mhpmcounter4: CSR Field Modifications - via register */
+/* mbound: CSR Field Modifications - via register */
 
 /*******************************************
- * mhpmcounter31 - MRW - Machine performance-monitoring counter. 
+ * mibase - MRW - Instruction base register. 
  */
-/* mhpmcounter31: CSR Whole register access */
-/* mhpmcounter31: CSR read.
+/* mibase: CSR address, as used in the csr field of the CSR instructions. */
+pub const MIBASE_CSR_ADDRESS: u16 = 0x382;
+/* mibase: CSR Whole register access */
+/* mibase: CSR read.
 e.g.
-    let _v = csr_read_mhpmcounter31!();
+    let _v = csr_read_mibase!();
  */
 #[macro_export]
-macro_rules! csr_read_mhpmcounter31 {
+macro_rules! csr_read_mibase {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mhpmcounter31" , out(reg) tmp_value);
+                asm!("csrr    {0}, mibase" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mhpmcounter31: CSR write 
+/* mibase: CSR write 
 e.g.
-    csr_write_mhpmcounter31!(0x1234567);
+    csr_write_mibase!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mhpmcounter31 {
+macro_rules! csr_write_mibase {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mhpmcounter31, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mibase, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mhpmcounter31: CSR Read and Write 
+/* mibase: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mhpmcounter31!(0x1234567);
+    let v_ = csr_read_write_mibase!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mhpmcounter31 {
+macro_rules! csr_read_write_mibase {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mhpmcounter31, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mibase, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mhpmcounter31: CSR Field Modifications - via register */
+/* mibase: CSR Field Modifications - via register */
 
 /*******************************************
- * mcycleh - MRW - Upper 32 bits of  mcycle, RV32I only. 
+ * mibound - MRW - Instruction bound register. 
  */
-/* mcycleh: CSR Whole register access */
-/* mcycleh: CSR read.
+/* mibound: CSR address, as used in the csr field of the CSR instructions. */
+pub const MIBOUND_CSR_ADDRESS: u16 = 0x383;
+/* mibound: CSR Whole register access */
+/* mibound: CSR read.
 e.g.
-    let _v = csr_read_mcycleh!();
+    let _v = csr_read_mibound!();
  */
 #[macro_export]
-macro_rules! csr_read_mcycleh {
+macro_rules! csr_read_mibound {
     ( ) => (
         {
-            let tmp_value: UintCsr32;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mcycleh" , out(reg) tmp_value);
+                asm!("csrr    {0}, mibound" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mcycleh: CSR write 
+/* mibound: CSR write 
 e.g.
-    csr_write_mcycleh!(0x1234567);
+    csr_write_mibound!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mcycleh {
+macro_rules! csr_write_mibound {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mcycleh, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mibound, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mcycleh: CSR Read and Write 
+/* mibound: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mcycleh!(0x1234567);
+    let v_ = csr_read_write_mibound!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mcycleh {
+macro_rules! csr_read_write_mibound {
     ( $x:expr ) => (
         {
-            let tmp_value: UintCsr32;
+            let value = $x;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mcycleh, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mibound, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mcycleh: CSR Field Modifications - via register */
+/* mibound: CSR Field Modifications - via register */
 
 /*******************************************
- * minstreth - MRW - Upper 32 bits of  minstret, RV32I only. 
+ * mdbase - MRW - Data base register. 
  */
-/* minstreth: CSR Whole register access */
-/* minstreth: CSR read.
+/* mdbase: CSR address, as used in the csr field of the CSR instructions. */
+pub const MDBASE_CSR_ADDRESS: u16 = 0x384;
+/* mdbase: CSR Whole register access */
+/* mdbase: CSR read.
 e.g.
-    let _v = csr_read_minstreth!();
+    let _v = csr_read_mdbase!();
  */
 #[macro_export]
-macro_rules! csr_read_minstreth {
+macro_rules! csr_read_mdbase {
     ( ) => (
         {
-            let tmp_value: UintCsr32;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, minstreth" , out(reg) tmp_value);
+                asm!("csrr    {0}, mdbase" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* minstreth: CSR write 
+/* mdbase: CSR write 
 e.g.
-    csr_write_minstreth!(0x1234567);
+    csr_write_mdbase!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_minstreth {
+macro_rules! csr_write_mdbase {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    minstreth, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mdbase, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* minstreth: CSR Read and Write 
+/* mdbase: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_minstreth!(0x1234567);
+    let v_ = csr_read_write_mdbase!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_minstreth {
+macro_rules! csr_read_write_mdbase {
     ( $x:expr ) => (
         {
-            let tmp_value: UintCsr32;
+            let value = $x;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, minstreth, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mdbase, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* minstreth: CSR Field Modifications - via register */
+/* mdbase: CSR Field Modifications - via register */
 
 /*******************************************
- * mhpmcounter3h - MRW - Upper 32 bits of  mhpmcounter3, RV32I only. 
+ * mdbound - MRW - Data bound register. 
  */
-/* mhpmcounter3h: CSR Whole register access */
-/* mhpmcounter3h: CSR read.
+/* mdbound: CSR address, as used in the csr field of the CSR instructions. */
+pub const MDBOUND_CSR_ADDRESS: u16 = 0x385;
+/* mdbound: CSR Whole register access */
+/* mdbound: CSR read.
 e.g.
-    let _v = csr_read_mhpmcounter3h!();
+    let _v = csr_read_mdbound!();
  */
 #[macro_export]
-macro_rules! csr_read_mhpmcounter3h {
+macro_rules! csr_read_mdbound {
     ( ) => (
         {
-            let tmp_value: UintCsr32;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mhpmcounter3h" , out(reg) tmp_value);
+                asm!("csrr    {0}, mdbound" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mhpmcounter3h: CSR write 
+/* mdbound: CSR write 
 e.g.
-    csr_write_mhpmcounter3h!(0x1234567);
+    csr_write_mdbound!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mhpmcounter3h {
+macro_rules! csr_write_mdbound {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mhpmcounter3h, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mdbound, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mhpmcounter3h: CSR Read and Write 
+/* mdbound: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mhpmcounter3h!(0x1234567);
+    let v_ = csr_read_write_mdbound!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mhpmcounter3h {
+macro_rules! csr_read_write_mdbound {
     ( $x:expr ) => (
         {
-            let tmp_value: UintCsr32;
+            let value = $x;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mhpmcounter3h, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mdbound, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mhpmcounter3h: CSR Field Modifications - via register */
+/* mdbound: CSR Field Modifications - via register */
 
 /*******************************************
- * mhpmcounter4h - MRW - Upper 32 bits of  mhpmcounter4, RV32I only. 
+ * pmpcfg0 - MRW - Physical memory protection configuration. 
  */
-/* mhpmcounter4h: CSR Whole register access */
-/* mhpmcounter4h: CSR read.
+/* pmpcfg0: CSR address, as used in the csr field of the CSR instructions. */
+pub const PMPCFG0_CSR_ADDRESS: u16 = 0x3a0;
+/* pmpcfg0: CSR Whole register access */
+/* pmpcfg0: CSR read.
 e.g.
-    let _v = csr_read_mhpmcounter4h!();
+    let _v = csr_read_pmpcfg0!();
  */
 #[macro_export]
-macro_rules! csr_read_mhpmcounter4h {
+macro_rules! csr_read_pmpcfg0 {
     ( ) => (
         {
-            let tmp_value: UintCsr32;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mhpmcounter4h" , out(reg) tmp_value);
+                asm!("csrr    {0}, pmpcfg0" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mhpmcounter4h: CSR write 
+/* pmpcfg0: CSR write 
 e.g.
-    csr_write_mhpmcounter4h!(0x1234567);
+    csr_write_pmpcfg0!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mhpmcounter4h {
+macro_rules! csr_write_pmpcfg0 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mhpmcounter4h, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    pmpcfg0, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mhpmcounter4h: CSR Read and Write 
+/* pmpcfg0: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mhpmcounter4h!(0x1234567);
+    let v_ = csr_read_write_pmpcfg0!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mhpmcounter4h {
+macro_rules! csr_read_write_pmpcfg0 {
     ( $x:expr ) => (
         {
-            let tmp_value: UintCsr32;
+            let value = $x;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mhpmcounter4h, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, pmpcfg0, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mhpmcounter4h: CSR Field Modifications - via register */
+/* pmpcfg0: CSR Field Modifications - via register */
 
 /*******************************************
- * mhpmcounter31h - MRW - Upper 32 bits of  mhpmcounter31, RV32I only. 
+ * pmpcfg1 - MRW - Physical memory protection configuration, RV32 only. 
  */
-/* mhpmcounter31h: CSR Whole register access */
-/* mhpmcounter31h: CSR read.
+/* pmpcfg1: CSR address, as used in the csr field of the CSR instructions. */
+pub const PMPCFG1_CSR_ADDRESS: u16 = 0x3a1;
+/* pmpcfg1: CSR Whole register access */
+/* pmpcfg1: CSR read.
 e.g.
-    let _v = csr_read_mhpmcounter31h!();
+    let _v = csr_read_pmpcfg1!();
  */
 #[macro_export]
-macro_rules! csr_read_mhpmcounter31h {
+macro_rules! csr_read_pmpcfg1 {
     ( ) => (
         {
-            let tmp_value: UintCsr32;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mhpmcounter31h" , out(reg) tmp_value);
+                asm!("csrr    {0}, pmpcfg1" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mhpmcounter31h: CSR write 
+/* pmpcfg1: CSR write 
 e.g.
-    csr_write_mhpmcounter31h!(0x1234567);
+    csr_write_pmpcfg1!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mhpmcounter31h {
+macro_rules! csr_write_pmpcfg1 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mhpmcounter31h, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    pmpcfg1, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mhpmcounter31h: CSR Read and Write 
+/* pmpcfg1: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mhpmcounter31h!(0x1234567);
+    let v_ = csr_read_write_pmpcfg1!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mhpmcounter31h {
+macro_rules! csr_read_write_pmpcfg1 {
     ( $x:expr ) => (
         {
-            let tmp_value: UintCsr32;
+            let value = $x;
+            let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mhpmcounter31h, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, pmpcfg1, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mhpmcounter31h: CSR Field Modifications - via register */
+/* pmpcfg1: CSR Field Modifications - via register */
 
 /*******************************************
- * mhpmevent4 - MRW - Machine performance-monitoring event selector. 
+ * pmpcfg2 - MRW - Physical memory protection configuration. 
  */
-/* mhpmevent4: CSR Whole register access */
-/* mhpmevent4: CSR read.
+/* pmpcfg2: CSR address, as used in the csr field of the CSR instructions. */
+pub const PMPCFG2_CSR_ADDRESS: u16 = 0x3a2;
+/* pmpcfg2: CSR Whole register access */
+/* pmpcfg2: CSR read.
 e.g.
-    let _v = csr_read_mhpmevent4!();
+    let _v = csr_read_pmpcfg2!();
  */
 #[macro_export]
-macro_rules! csr_read_mhpmevent4 {
+macro_rules! csr_read_pmpcfg2 {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mhpmevent4" , out(reg) tmp_value);
+                asm!("csrr    {0}, pmpcfg2" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mhpmevent4: CSR write 
+/* pmpcfg2: CSR write 
 e.g.
-    csr_write_mhpmevent4!(0x1234567);
+    csr_write_pmpcfg2!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mhpmevent4 {
+macro_rules! csr_write_pmpcfg2 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mhpmevent4, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    pmpcfg2, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mhpmevent4: CSR Read and Write 
+/* pmpcfg2: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mhpmevent4!(0x1234567);
+    let v_ = csr_read_write_pmpcfg2!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mhpmevent4 {
+macro_rules! csr_read_write_pmpcfg2 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mhpmevent4, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, pmpcfg2, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mhpmevent4: CSR Field Modifications - via register */
+/* pmpcfg2: CSR Field Modifications - via register */
 
 /*******************************************
- * mhpmevent31 - MRW - Machine performance-monitoring event selector. 
+ * pmpcfg3 - MRW - Physical memory protection configuration, RV32 only. 
  */
-/* mhpmevent31: CSR Whole register access */
-/* mhpmevent31: CSR read.
+/* pmpcfg3: CSR address, as used in the csr field of the CSR instructions. */
+pub const PMPCFG3_CSR_ADDRESS: u16 = 0x3a3;
+/* pmpcfg3: CSR Whole register access */
+/* pmpcfg3: CSR read.
 e.g.
-    let _v = csr_read_mhpmevent31!();
+    let _v = csr_read_pmpcfg3!();
  */
 #[macro_export]
-macro_rules! csr_read_mhpmevent31 {
+macro_rules! csr_read_pmpcfg3 {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mhpmevent31" , out(reg) tmp_value);
+                asm!("csrr    {0}, pmpcfg3" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mhpmevent31: CSR write 
+/* pmpcfg3: CSR write 
 e.g.
-    csr_write_mhpmevent31!(0x1234567);
+    csr_write_pmpcfg3!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mhpmevent31 {
+macro_rules! csr_write_pmpcfg3 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mhpmevent31, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    pmpcfg3, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mhpmevent31: CSR Read and Write 
+/* pmpcfg3: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mhpmevent31!(0x1234567);
+    let v_ = csr_read_write_pmpcfg3!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mhpmevent31 {
+macro_rules! csr_read_write_pmpcfg3 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mhpmevent31, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, pmpcfg3, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mhpmevent31: CSR Field Modifications - via register */
+/* pmpcfg3: CSR Field Modifications - via register */
 
 /*******************************************
- * tselect - MRW - Debug/Trace trigger register select. 
+ * pmpaddr0 - MRW - Physical memory protection address register. 
  */
-/* tselect: CSR Whole register access */
-/* tselect: CSR read.
+/* pmpaddr0: CSR address, as used in the csr field of the CSR instructions. */
+pub const PMPADDR0_CSR_ADDRESS: u16 = 0x3b0;
+/* pmpaddr0: CSR Whole register access */
+/* pmpaddr0: CSR read.
 e.g.
-    let _v = csr_read_tselect!();
+    let _v = csr_read_pmpaddr0!();
  */
 #[macro_export]
-macro_rules! csr_read_tselect {
+macro_rules! csr_read_pmpaddr0 {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, tselect" , out(reg) tmp_value);
+                asm!("csrr    {0}, pmpaddr0" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* tselect: CSR write 
+/* pmpaddr0: CSR write 
 e.g.
-    csr_write_tselect!(0x1234567);
+    csr_write_pmpaddr0!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_tselect {
+macro_rules! csr_write_pmpaddr0 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    tselect, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    pmpaddr0, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* tselect: CSR Read and Write 
+/* pmpaddr0: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_tselect!(0x1234567);
+    let v_ = csr_read_write_pmpaddr0!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_tselect {
+macro_rules! csr_read_write_pmpaddr0 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, tselect, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, pmpaddr0, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* tselect: CSR Field Modifications - via register */
+/* pmpaddr0: CSR Field Modifications - via register */
 
 /*******************************************
- * tdata1 - MRW - First Debug/Trace trigger data register. 
+ * pmpaddr1 - MRW - Physical memory protection address register. 
  */
-/* tdata1: CSR Whole register access */
-/* tdata1: CSR read.
+/* pmpaddr1: CSR address, as used in the csr field of the CSR instructions. */
+pub const PMPADDR1_CSR_ADDRESS: u16 = 0x3b1;
+/* pmpaddr1: CSR Whole register access */
+/* pmpaddr1: CSR read.
 e.g.
-    let _v = csr_read_tdata1!();
+    let _v = csr_read_pmpaddr1!();
  */
 #[macro_export]
-macro_rules! csr_read_tdata1 {
+macro_rules! csr_read_pmpaddr1 {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, tdata1" , out(reg) tmp_value);
+                asm!("csrr    {0}, pmpaddr1" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* tdata1: CSR write 
+/* pmpaddr1: CSR write 
 e.g.
-    csr_write_tdata1!(0x1234567);
+    csr_write_pmpaddr1!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_tdata1 {
+macro_rules! csr_write_pmpaddr1 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    tdata1, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    pmpaddr1, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* tdata1: CSR Read and Write 
+/* pmpaddr1: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_tdata1!(0x1234567);
+    let v_ = csr_read_write_pmpaddr1!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_tdata1 {
+macro_rules! csr_read_write_pmpaddr1 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, tdata1, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, pmpaddr1, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* tdata1: CSR Field Modifications - via register */
+/* pmpaddr1: CSR Field Modifications - via register */
 
 /*******************************************
- * tdata2 - MRW - Second Debug/Trace trigger data register. 
+ * pmpaddr15 - MRW - Physical memory protection address register. 
  */
-/* tdata2: CSR Whole register access */
-/* tdata2: CSR read.
+/* pmpaddr15: CSR address, as used in the csr field of the CSR instructions. */
+pub const PMPADDR15_CSR_ADDRESS: u16 = 0x3bf;
+/* pmpaddr15: CSR Whole register access */
+/* pmpaddr15: CSR read.
 e.g.
-    let _v = csr_read_tdata2!();
+    let _v = csr_read_pmpaddr15!();
  */
 #[macro_export]
-macro_rules! csr_read_tdata2 {
+macro_rules! csr_read_pmpaddr15 {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, tdata2" , out(reg) tmp_value);
+                asm!("csrr    {0}, pmpaddr15" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* tdata2: CSR write 
+/* pmpaddr15: CSR write 
 e.g.
-    csr_write_tdata2!(0x1234567);
+    csr_write_pmpaddr15!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_tdata2 {
+macro_rules! csr_write_pmpaddr15 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    tdata2, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    pmpaddr15, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* tdata2: CSR Read and Write 
+/* pmpaddr15: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_tdata2!(0x1234567);
+    let v_ = csr_read_write_pmpaddr15!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_tdata2 {
+macro_rules! csr_read_write_pmpaddr15 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, tdata2, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, pmpaddr15, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* tdata2: CSR Field Modifications - via register */
+/* pmpaddr15: CSR Field Modifications - via register */
 
 /*******************************************
- * tdata3 - MRW - Third Debug/Trace trigger data register. 
+ * mhpmcounter4 - MRW - Machine performance-monitoring counter. 
  */
-/* tdata3: CSR Whole register access */
-/* tdata3: CSR read.
+/* mhpmcounter4: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMCOUNTER4_CSR_ADDRESS: u16 = 0xb04;
+/* mhpmcounter4: CSR Whole register access */
+/* mhpmcounter4: CSR read.
 e.g.
-    let _v = csr_read_tdata3!();
+    let _v = csr_read_mhpmcounter4!();
  */
 #[macro_export]
-macro_rules! csr_read_tdata3 {
+macro_rules! csr_read_mhpmcounter4 {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, tdata3" , out(reg) tmp_value);
+                asm!("csrr    {0}, mhpmcounter4" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* tdata3: CSR write 
+/* mhpmcounter4: CSR write 
 e.g.
-    csr_write_tdata3!(0x1234567);
+    csr_write_mhpmcounter4!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_tdata3 {
+macro_rules! csr_write_mhpmcounter4 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    tdata3, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmcounter4, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* tdata3: CSR Read and Write 
+/* mhpmcounter4: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_tdata3!(0x1234567);
+    let v_ = csr_read_write_mhpmcounter4!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_tdata3 {
+macro_rules! csr_read_write_mhpmcounter4 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, tdata3, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mhpmcounter4, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* tdata3: CSR Field Modifications - via register */
+/* mhpmcounter4: CSR Field Modifications - via register */
 
 /*******************************************
- * dcsr - DRW - Debug control and status register. 
+ * mhpmcounter31 - MRW - Machine performance-monitoring counter. 
  */
-/* dcsr: CSR Whole register access */
-/* dcsr: CSR read.
+/* mhpmcounter31: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMCOUNTER31_CSR_ADDRESS: u16 = 0xb1f;
+/* mhpmcounter31: CSR Whole register access */
+/* mhpmcounter31: CSR read.
 e.g.
-    let _v = csr_read_dcsr!();
+    let _v = csr_read_mhpmcounter31!();
  */
 #[macro_export]
-macro_rules! csr_read_dcsr {
+macro_rules! csr_read_mhpmcounter31 {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, dcsr" , out(reg) tmp_value);
+                asm!("csrr    {0}, mhpmcounter31" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* dcsr: CSR write 
+/* mhpmcounter31: CSR write 
 e.g.
-    csr_write_dcsr!(0x1234567);
+    csr_write_mhpmcounter31!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_dcsr {
+macro_rules! csr_write_mhpmcounter31 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    dcsr, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmcounter31, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* dcsr: CSR Read and Write 
+/* mhpmcounter31: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_dcsr!(0x1234567);
+    let v_ = csr_read_write_mhpmcounter31!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_dcsr {
+macro_rules! csr_read_write_mhpmcounter31 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, dcsr, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mhpmcounter31, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* dcsr: CSR Field Modifications - via register */
+/* mhpmcounter31: CSR Field Modifications - via register */
 
 /*******************************************
- * dpc - DRW - Debug PC. 
+ * mcycleh - MRW - Upper 32 bits of  mcycle, RV32I only. 
  */
-/* dpc: CSR Whole register access */
-/* dpc: CSR read.
+/* mcycleh: CSR address, as used in the csr field of the CSR instructions. */
+pub const MCYCLEH_CSR_ADDRESS: u16 = 0xb80;
+/* mcycleh: CSR Whole register access */
+/* mcycleh: CSR read.
 e.g.
-    let _v = csr_read_dpc!();
+    let _v = csr_read_mcycleh!();
  */
 #[macro_export]
-macro_rules! csr_read_dpc {
+macro_rules! csr_read_mcycleh {
     ( ) => (
         {
-            let tmp_value: UintXlen;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, dpc" , out(reg) tmp_value);
+                asm!("csrr    {0}, mcycleh" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* dpc: CSR write 
+/* mcycleh: CSR write 
 e.g.
-    csr_write_dpc!(0x1234567);
+    csr_write_mcycleh!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_dpc {
+macro_rules! csr_write_mcycleh {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    dpc, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mcycleh, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* dpc: CSR Read and Write 
+/* mcycleh: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_dpc!(0x1234567);
+    let v_ = csr_read_write_mcycleh!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_dpc {
+macro_rules! csr_read_write_mcycleh {
     ( $x:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $x;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, dpc, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mcycleh, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* dpc: CSR Field Modifications - via register */
+/* mcycleh: CSR Field Modifications - via register */
 
 /*******************************************
- * dscratch0 - DRW - Debug scratch register 0. 
+ * minstreth - MRW - Upper 32 bits of  minstret, RV32I only. 
  */
-/* dscratch0: CSR Whole register access */
-/* dscratch0: CSR read.
+/* minstreth: CSR address, as used in the csr field of the CSR instructions. */
+pub const MINSTRETH_CSR_ADDRESS: u16 = 0xb82;
+/* minstreth: CSR Whole register access */
+/* minstreth: CSR read.
 e.g.
-    let _v = csr_read_dscratch0!();
+    let _v = csr_read_minstreth!();
  */
 #[macro_export]
-macro_rules! csr_read_dscratch0 {
+macro_rules! csr_read_minstreth {
     ( ) => (
         {
-            let tmp_value: UintXlen;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, dscratch0" , out(reg) tmp_value);
+                asm!("csrr    {0}, minstreth" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* dscratch0: CSR write 
+/* minstreth: CSR write 
 e.g.
-    csr_write_dscratch0!(0x1234567);
+    csr_write_minstreth!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_dscratch0 {
+macro_rules! csr_write_minstreth {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    dscratch0, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    minstreth, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* dscratch0: CSR Read and Write 
+/* minstreth: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_dscratch0!(0x1234567);
+    let v_ = csr_read_write_minstreth!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_dscratch0 {
+macro_rules! csr_read_write_minstreth {
     ( $x:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $x;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, dscratch0, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, minstreth, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* dscratch0: CSR Field Modifications - via register */
+/* minstreth: CSR Field Modifications - via register */
 
 /*******************************************
- * dscratch1 - DRW - Debug scratch register 1. 
+ * mhpmcounter3h - MRW - Upper 32 bits of  mhpmcounter3, RV32I only. 
  */
-/* dscratch1: CSR Whole register access */
-/* dscratch1: CSR read.
+/* mhpmcounter3h: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMCOUNTER3H_CSR_ADDRESS: u16 = 0xb83;
+/* mhpmcounter3h: CSR Whole register access */
+/* mhpmcounter3h: CSR read.
 e.g.
-    let _v = csr_read_dscratch1!();
+    let _v = csr_read_mhpmcounter3h!();
  */
 #[macro_export]
-macro_rules! csr_read_dscratch1 {
+macro_rules! csr_read_mhpmcounter3h {
     ( ) => (
         {
-            let tmp_value: UintXlen;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, dscratch1" , out(reg) tmp_value);
+                asm!("csrr    {0}, mhpmcounter3h" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* dscratch1: CSR write 
+/* mhpmcounter3h: CSR write 
 e.g.
-    csr_write_dscratch1!(0x1234567);
+    csr_write_mhpmcounter3h!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_dscratch1 {
+macro_rules! csr_write_mhpmcounter3h {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    dscratch1, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmcounter3h, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* dscratch1: CSR Read and Write 
+/* mhpmcounter3h: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_dscratch1!(0x1234567);
+    let v_ = csr_read_write_mhpmcounter3h!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_dscratch1 {
+macro_rules! csr_read_write_mhpmcounter3h {
     ( $x:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $x;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, dscratch1, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mhpmcounter3h, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* dscratch1: CSR Field Modifications - via register */
+/* mhpmcounter3h: CSR Field Modifications - via register */
 
 /*******************************************
- * hie - HRW - Hypervisor interrupt-enable register. 
+ * mhpmcounter4h - MRW - Upper 32 bits of  mhpmcounter4, RV32I only. 
  */
-/* hie: CSR Whole register access */
-/* hie: CSR read.
+/* mhpmcounter4h: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMCOUNTER4H_CSR_ADDRESS: u16 = 0xb84;
+/* mhpmcounter4h: CSR Whole register access */
+/* mhpmcounter4h: CSR read.
 e.g.
-    let _v = csr_read_hie!();
+    let _v = csr_read_mhpmcounter4h!();
  */
 #[macro_export]
-macro_rules! csr_read_hie {
+macro_rules! csr_read_mhpmcounter4h {
     ( ) => (
         {
-            let tmp_value: UintXlen;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, hie" , out(reg) tmp_value);
+                asm!("csrr    {0}, mhpmcounter4h" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* hie: CSR write 
+/* mhpmcounter4h: CSR write 
 e.g.
-    csr_write_hie!(0x1234567);
+    csr_write_mhpmcounter4h!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_hie {
+macro_rules! csr_write_mhpmcounter4h {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    hie, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmcounter4h, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* hie: CSR Read and Write 
+/* mhpmcounter4h: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_hie!(0x1234567);
+    let v_ = csr_read_write_mhpmcounter4h!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_hie {
+macro_rules! csr_read_write_mhpmcounter4h {
     ( $x:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $x;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, hie, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mhpmcounter4h, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* hie: CSR Field Modifications - via register */
+/* mhpmcounter4h: CSR Field Modifications - via register */
 
 /*******************************************
- * hgeie - HRW - Hypervisor guest external interrupt-enable register. 
+ * mhpmcounter31h - MRW - Upper 32 bits of  mhpmcounter31, RV32I only. 
  */
-/* hgeie: CSR Whole register access */
-/* hgeie: CSR read.
+/* mhpmcounter31h: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMCOUNTER31H_CSR_ADDRESS: u16 = 0xb9f;
+/* mhpmcounter31h: CSR Whole register access */
+/* mhpmcounter31h: CSR read.
 e.g.
-    let _v = csr_read_hgeie!();
+    let _v = csr_read_mhpmcounter31h!();
  */
 #[macro_export]
-macro_rules! csr_read_hgeie {
+macro_rules! csr_read_mhpmcounter31h {
     ( ) => (
         {
-            let tmp_value: UintXlen;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, hgeie" , out(reg) tmp_value);
+                asm!("csrr    {0}, mhpmcounter31h" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* hgeie: CSR write 
+/* mhpmcounter31h: CSR write 
 e.g.
-    csr_write_hgeie!(0x1234567);
+    csr_write_mhpmcounter31h!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_hgeie {
+macro_rules! csr_write_mhpmcounter31h {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    hgeie, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmcounter31h, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* hgeie: CSR Read and Write 
+/* mhpmcounter31h: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_hgeie!(0x1234567);
+    let v_ = csr_read_write_mhpmcounter31h!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_hgeie {
+macro_rules! csr_read_write_mhpmcounter31h {
     ( $x:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $x;
+            let tmp_value: UintCsr32;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, hgeie, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mhpmcounter31h, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* hgeie: CSR Field Modifications - via register */
+/* mhpmcounter31h: CSR Field Modifications - via register */
 
 /*******************************************
- * htval - HRW - Hypervisor bad guest physical address. 
+ * mhpmevent4 - MRW - Machine performance-monitoring event selector. 
  */
-/* htval: CSR Whole register access */
-/* htval: CSR read.
+/* mhpmevent4: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMEVENT4_CSR_ADDRESS: u16 = 0x324;
+/* mhpmevent4: CSR Whole register access */
+/* mhpmevent4: CSR read.
 e.g.
-    let _v = csr_read_htval!();
+    let _v = csr_read_mhpmevent4!();
  */
 #[macro_export]
-macro_rules! csr_read_htval {
+macro_rules! csr_read_mhpmevent4 {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, htval" , out(reg) tmp_value);
+                asm!("csrr    {0}, mhpmevent4" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* htval: CSR write 
+/* mhpmevent4: CSR write 
 e.g.
-    csr_write_htval!(0x1234567);
+    csr_write_mhpmevent4!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_htval {
+macro_rules! csr_write_mhpmevent4 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    htval, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmevent4, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* htval: CSR Read and Write 
+/* mhpmevent4: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_htval!(0x1234567);
+    let v_ = csr_read_write_mhpmevent4!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_htval {
+macro_rules! csr_read_write_mhpmevent4 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, htval, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mhpmevent4, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* htval: CSR Field Modifications - via register */
-
-/*******************************************
- * hip - HRW - Hypervisor interrupt pending. 
- */
-/* hip: CSR Whole register access */
-/* hip: CSR read.
+/* mhpmevent4: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_hip!();
- */
+csr_set_bits_mhpmevent4!(0x0F0F0F);
+*/
 #[macro_export]
-macro_rules! csr_read_hip {
-    ( ) => (
+macro_rules! csr_set_bits_mhpmevent4 {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, hip" , out(reg) tmp_value);
+                asm!("csrrs    zero, mhpmevent4, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* hip: CSR write 
+/* Register CSR bit clear instructions
 e.g.
-    csr_write_hip!(0x1234567);
-*/
+csr_clr_bits_mhpmevent4!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_write_hip {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    hip, {0}" , in(reg) $x);
+macro_rules! csr_clr_bits_mhpmevent4 {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mhpmevent4, {0}", in(reg) value);
+            }
         }
     );
 }
-/* hip: CSR Read and Write 
+/* Register CSR read and then bit set instructions
 e.g.
-    let v_ = csr_read_write_hip!(0x1234567);
+let org_value_ = csr_read_set_bits_mhpmevent4!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_read_write_hip {
-    ( $x:expr ) => (
+macro_rules! csr_read_set_bits_mhpmevent4 {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, hip, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrs    {0}, mhpmevent4, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-
-/* hip: CSR Field Modifications - via register */
-
-/*******************************************
- * htinst - HRW - Hypervisor trap instruction (transformed). 
- */
-/* htinst: CSR Whole register access */
-/* htinst: CSR read.
+/* Register CSR read and then bit clear instructions
 e.g.
-    let _v = csr_read_htinst!();
+let org_value_ = csr_read_clr_bits_mhpmevent4!(0x0F0F0F)
  */
 #[macro_export]
-macro_rules! csr_read_htinst {
-    ( ) => (
+macro_rules! csr_read_clr_bits_mhpmevent4 {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, htinst" , out(reg) tmp_value);
+                asm!("csrrc    {0}, mhpmevent4, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
-/* htinst: CSR write 
+/* mhpmevent4: CSR Field Modifications - via immediate */
+/* mhpmevent4, CSR write value via immediate value (only up to 5 bits).
 e.g.
-    csr_write_htinst!(0x1234567);
-*/
+csr_write_imm_mhpmevent4!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_write_htinst {
-    ( $x:expr ) => (
+macro_rules! csr_write_imm_mhpmevent4 {
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
-            asm!("csrw    htinst, {0}" , in(reg) $x);
+            asm!(concat!("csrrwi    zero, mhpmevent4, ", stringify!($value)));
         }
     );
 }
-/* htinst: CSR Read and Write 
+
+/* mhpmevent4, CSR set bits via immediate value mask (only up to 5 bits).
 e.g.
-    let v_ = csr_read_write_htinst!(0x1234567);
-*/
+csr_set_bits_imm_mhpmevent4!(0x1F);
+ */
 #[macro_export]
-macro_rules! csr_read_write_htinst {
-    ( $x:expr ) => (
-        {
-            let tmp_value: UintXlen;
-            unsafe {
-                use core::arch::asm;
-                asm!("csrrw    {0}, htinst, {1}" , out(reg) tmp_value, in(reg) $x);
-            }
-            tmp_value
+macro_rules! csr_set_bits_imm_mhpmevent4 {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mhpmevent4, ", stringify!($value)));
         }
     );
 }
-
-/* htinst: CSR Field Modifications - via register */
-
-/*******************************************
- * hgeip - HRO - Hypervisor guest external interrupt pending. 
- */
-/* hgeip: CSR Whole register access */
-/* hgeip: CSR read.
+/* mhpmevent4, CSR clear bits via immediate value mask (only up to 5 bits).
 e.g.
-    let _v = csr_read_hgeip!();
+csr_clr_bits_imm_mhpmevent4!(0x1F);
  */
 #[macro_export]
-macro_rules! csr_read_hgeip {
-    ( ) => (
-        {
-            let tmp_value: UintXlen;
-            unsafe {
-                use core::arch::asm;
-                asm!("csrr    {0}, hgeip" , out(reg) tmp_value);
-            }
-            tmp_value
+macro_rules! csr_clr_bits_imm_mhpmevent4 {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mhpmevent4, ", stringify!($value)));
         }
     );
 }
 
-/* hgeip: CSR Field Modifications - via register */
-
 /*******************************************
- * mtinst - MRW - Machine trap instruction (transformed). 
+ * mhpmevent31 - MRW - Machine performance-monitoring event selector. 
  */
-/* mtinst: CSR Whole register access */
-/* mtinst: CSR read.
+/* mhpmevent31: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMEVENT31_CSR_ADDRESS: u16 = 0x33f;
+/* mhpmevent31: CSR Whole register access */
+/* mhpmevent31: CSR read.
 e.g.
-    let _v = csr_read_mtinst!();
+    let _v = csr_read_mhpmevent31!();
  */
 #[macro_export]
-macro_rules! csr_read_mtinst {
+macro_rules! csr_read_mhpmevent31 {
     ( ) => (
         {
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mtinst" , out(reg) tmp_value);
+                asm!("csrr    {0}, mhpmevent31" , out(reg) tmp_value);
             }
             tmp_value
         }
     );
 }
-/* mtinst: CSR write 
+/* mhpmevent31: CSR write 
 e.g.
-    csr_write_mtinst!(0x1234567);
+    csr_write_mhpmevent31!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_write_mtinst {
+macro_rules! csr_write_mhpmevent31 {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mtinst, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmevent31, {0}" , in(reg) value);
+            }
         }
     );
 }
-/* mtinst: CSR Read and Write 
+/* mhpmevent31: CSR Read and Write 
 e.g.
-    let v_ = csr_read_write_mtinst!(0x1234567);
+    let v_ = csr_read_write_mhpmevent31!(0x1234567);
 */
 #[macro_export]
-macro_rules! csr_read_write_mtinst {
+macro_rules! csr_read_write_mhpmevent31 {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mtinst, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, mhpmevent31, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
 
-/* mtinst: CSR Field Modifications - via register */
-
-/*******************************************
- * mtval2 - MRW - Machine bad guest physical address. 
- */
-/* mtval2: CSR Whole register access */
-/* mtval2: CSR read.
+/* mhpmevent31: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
 e.g.
-    let _v = csr_read_mtval2!();
+csr_set_bits_mhpmevent31!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mhpmevent31 {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mhpmevent31, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mhpmevent31!(0x0F0F0F)
  */
 #[macro_export]
-macro_rules! csr_read_mtval2 {
-    ( ) => (
+macro_rules! csr_clr_bits_mhpmevent31 {
+    ( $mask:expr ) => (
         {
-            let tmp_value: UintXlen;
+            let value = $mask;
             unsafe {
                 use core::arch::asm;
-                asm!("csrr    {0}, mtval2" , out(reg) tmp_value);
+                asm!("csrrc    zero, mhpmevent31, {0}", in(reg) value);
             }
-            tmp_value
         }
     );
 }
-/* mtval2: CSR write 
+/* Register CSR read and then bit set instructions
 e.g.
-    csr_write_mtval2!(0x1234567);
+let org_value_ = csr_read_set_bits_mhpmevent31!(0x0F0F0F)
 */
 #[macro_export]
-macro_rules! csr_write_mtval2 {
-    ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    mtval2, {0}" , in(reg) $x);
+macro_rules! csr_read_set_bits_mhpmevent31 {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mhpmevent31, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
         }
     );
 }
-/* mtval2: CSR Read and Write 
+/* Register CSR read and then bit clear instructions
 e.g.
-    let v_ = csr_read_write_mtval2!(0x1234567);
-*/
+let org_value_ = csr_read_clr_bits_mhpmevent31!(0x0F0F0F)
+ */
 #[macro_export]
-macro_rules! csr_read_write_mtval2 {
-    ( $x:expr ) => (
+macro_rules! csr_read_clr_bits_mhpmevent31 {
+    ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: UintXlen;
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, mtval2, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrc    {0}, mhpmevent31, {1}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
     );
 }
+/* mhpmevent31: CSR Field Modifications - via immediate */
+/* mhpmevent31, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mhpmevent31!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mhpmevent31 {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mhpmevent31, ", stringify!($value)));
+        }
+    );
+}
 
-/* mtval2: CSR Field Modifications - via register */
-
-/*******************************************
- * mstatus - MRW - Machine Status 
+/* mhpmevent31, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mhpmevent31!(0x1F);
  */
-pub const MSTATUS_MIE_BIT_OFFSET:isize   = 3;
-pub const MSTATUS_MIE_BIT_WIDTH:isize    = 1;
-pub const MSTATUS_MIE_BIT_MASK:UintXlen = 0x8;
-pub const MSTATUS_MIE_ALL_SET_MASK:UintXlen = 0x1;
-pub const MSTATUS_SIE_BIT_OFFSET:isize   = 2;
-pub const MSTATUS_SIE_BIT_WIDTH:isize    = 1;
-pub const MSTATUS_SIE_BIT_MASK:UintXlen = 0x4;
-pub const MSTATUS_SIE_ALL_SET_MASK:UintXlen = 0x1;
-pub const MSTATUS_MPIE_BIT_OFFSET:isize   = 7;
+#[macro_export]
+macro_rules! csr_set_bits_imm_mhpmevent31 {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mhpmevent31, ", stringify!($value)));
+        }
+    );
+}
+/* mhpmevent31, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mhpmevent31!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mhpmevent31 {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mhpmevent31, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * tselect - MRW - Debug/Trace trigger register select. 
+ */
+/* tselect: CSR address, as used in the csr field of the CSR instructions. */
+pub const TSELECT_CSR_ADDRESS: u16 = 0x7a0;
+/* tselect: CSR Whole register access */
+/* tselect: CSR read.
+e.g.
+    let _v = csr_read_tselect!();
+ */
+#[macro_export]
+macro_rules! csr_read_tselect {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, tselect" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* tselect: CSR write 
+e.g.
+    csr_write_tselect!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_tselect {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    tselect, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* tselect: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_tselect!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_tselect {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, tselect, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* tselect: CSR Field Modifications - via register */
+
+/*******************************************
+ * tdata1 - MRW - First Debug/Trace trigger data register. 
+ */
+/* tdata1: CSR address, as used in the csr field of the CSR instructions. */
+pub const TDATA1_CSR_ADDRESS: u16 = 0x7a1;
+/* tdata1: CSR Whole register access */
+/* tdata1: CSR read.
+e.g.
+    let _v = csr_read_tdata1!();
+ */
+#[macro_export]
+macro_rules! csr_read_tdata1 {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, tdata1" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* tdata1: CSR write 
+e.g.
+    csr_write_tdata1!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_tdata1 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    tdata1, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* tdata1: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_tdata1!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_tdata1 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, tdata1, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* tdata1: CSR Field Modifications - via register */
+
+/*******************************************
+ * tdata2 - MRW - Second Debug/Trace trigger data register. 
+ */
+/* tdata2: CSR address, as used in the csr field of the CSR instructions. */
+pub const TDATA2_CSR_ADDRESS: u16 = 0x7a2;
+/* tdata2: CSR Whole register access */
+/* tdata2: CSR read.
+e.g.
+    let _v = csr_read_tdata2!();
+ */
+#[macro_export]
+macro_rules! csr_read_tdata2 {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, tdata2" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* tdata2: CSR write 
+e.g.
+    csr_write_tdata2!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_tdata2 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    tdata2, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* tdata2: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_tdata2!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_tdata2 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, tdata2, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* tdata2: CSR Field Modifications - via register */
+
+/*******************************************
+ * tdata3 - MRW - Third Debug/Trace trigger data register. 
+ */
+/* tdata3: CSR address, as used in the csr field of the CSR instructions. */
+pub const TDATA3_CSR_ADDRESS: u16 = 0x7a3;
+/* tdata3: CSR Whole register access */
+/* tdata3: CSR read.
+e.g.
+    let _v = csr_read_tdata3!();
+ */
+#[macro_export]
+macro_rules! csr_read_tdata3 {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, tdata3" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* tdata3: CSR write 
+e.g.
+    csr_write_tdata3!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_tdata3 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    tdata3, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* tdata3: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_tdata3!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_tdata3 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, tdata3, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* tdata3: CSR Field Modifications - via register */
+
+/*******************************************
+ * dcsr - DRW - Debug control and status register. 
+ */
+/* dcsr: CSR address, as used in the csr field of the CSR instructions. */
+pub const DCSR_CSR_ADDRESS: u16 = 0x7b0;
+/* dcsr: CSR Whole register access */
+/* dcsr: CSR read.
+e.g.
+    let _v = csr_read_dcsr!();
+ */
+#[macro_export]
+macro_rules! csr_read_dcsr {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, dcsr" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* dcsr: CSR write 
+e.g.
+    csr_write_dcsr!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_dcsr {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    dcsr, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* dcsr: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_dcsr!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_dcsr {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, dcsr, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* dcsr: CSR Field Modifications - via register */
+
+/*******************************************
+ * dpc - DRW - Debug PC. 
+ */
+/* dpc: CSR address, as used in the csr field of the CSR instructions. */
+pub const DPC_CSR_ADDRESS: u16 = 0x7b1;
+/* dpc: CSR Whole register access */
+/* dpc: CSR read.
+e.g.
+    let _v = csr_read_dpc!();
+ */
+#[macro_export]
+macro_rules! csr_read_dpc {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, dpc" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* dpc: CSR write 
+e.g.
+    csr_write_dpc!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_dpc {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    dpc, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* dpc: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_dpc!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_dpc {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, dpc, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* dpc: CSR Field Modifications - via register */
+
+/*******************************************
+ * dscratch0 - DRW - Debug scratch register 0. 
+ */
+/* dscratch0: CSR address, as used in the csr field of the CSR instructions. */
+pub const DSCRATCH0_CSR_ADDRESS: u16 = 0x7b2;
+/* dscratch0: CSR Whole register access */
+/* dscratch0: CSR read.
+e.g.
+    let _v = csr_read_dscratch0!();
+ */
+#[macro_export]
+macro_rules! csr_read_dscratch0 {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, dscratch0" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* dscratch0: CSR write 
+e.g.
+    csr_write_dscratch0!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_dscratch0 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    dscratch0, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* dscratch0: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_dscratch0!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_dscratch0 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, dscratch0, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* dscratch0: CSR Field Modifications - via register */
+
+/*******************************************
+ * dscratch1 - DRW - Debug scratch register 1. 
+ */
+/* dscratch1: CSR address, as used in the csr field of the CSR instructions. */
+pub const DSCRATCH1_CSR_ADDRESS: u16 = 0x7b3;
+/* dscratch1: CSR Whole register access */
+/* dscratch1: CSR read.
+e.g.
+    let _v = csr_read_dscratch1!();
+ */
+#[macro_export]
+macro_rules! csr_read_dscratch1 {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, dscratch1" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* dscratch1: CSR write 
+e.g.
+    csr_write_dscratch1!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_dscratch1 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    dscratch1, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* dscratch1: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_dscratch1!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_dscratch1 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, dscratch1, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* dscratch1: CSR Field Modifications - via register */
+
+/*******************************************
+ * hie - HRW - Hypervisor interrupt-enable register. 
+ */
+/* hie: CSR address, as used in the csr field of the CSR instructions. */
+pub const HIE_CSR_ADDRESS: u16 = 0x604;
+/* hie: CSR Whole register access */
+/* hie: CSR read.
+e.g.
+    let _v = csr_read_hie!();
+ */
+#[macro_export]
+macro_rules! csr_read_hie {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hie" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hie: CSR write 
+e.g.
+    csr_write_hie!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_hie {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hie, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* hie: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_hie!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_hie {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, hie, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hie: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_hie!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_hie {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, hie, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_hie!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_hie {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, hie, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_hie!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_hie {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, hie, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_hie!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_hie {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, hie, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hie: CSR Field Modifications - via immediate */
+/* hie, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_hie!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_hie {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, hie, ", stringify!($value)));
+        }
+    );
+}
+
+/* hie, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_hie!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_hie {
+    ( HIE_VSSI_BIT_MASK) => { $crate::csr_set_bits_imm_hie!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, hie, ", stringify!($value)));
+        }
+    );
+}
+/* hie, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_hie!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_hie {
+    ( HIE_VSSI_BIT_MASK) => { $crate::csr_clr_bits_imm_hie!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, hie, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * hgeie - HRW - Hypervisor guest external interrupt-enable register. 
+ */
+/* hgeie: CSR address, as used in the csr field of the CSR instructions. */
+pub const HGEIE_CSR_ADDRESS: u16 = 0x607;
+/* hgeie: CSR Whole register access */
+/* hgeie: CSR read.
+e.g.
+    let _v = csr_read_hgeie!();
+ */
+#[macro_export]
+macro_rules! csr_read_hgeie {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hgeie" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hgeie: CSR write 
+e.g.
+    csr_write_hgeie!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_hgeie {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hgeie, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* hgeie: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_hgeie!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_hgeie {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, hgeie, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hgeie: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_hgeie!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_hgeie {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, hgeie, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_hgeie!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_hgeie {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, hgeie, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_hgeie!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_hgeie {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, hgeie, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_hgeie!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_hgeie {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, hgeie, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hgeie: CSR Field Modifications - via immediate */
+/* hgeie, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_hgeie!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_hgeie {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, hgeie, ", stringify!($value)));
+        }
+    );
+}
+
+/* hgeie, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_hgeie!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_hgeie {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, hgeie, ", stringify!($value)));
+        }
+    );
+}
+/* hgeie, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_hgeie!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_hgeie {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, hgeie, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * htval - HRW - Hypervisor bad guest physical address. 
+ */
+/* htval: CSR address, as used in the csr field of the CSR instructions. */
+pub const HTVAL_CSR_ADDRESS: u16 = 0x643;
+/* htval: CSR Whole register access */
+/* htval: CSR read.
+e.g.
+    let _v = csr_read_htval!();
+ */
+#[macro_export]
+macro_rules! csr_read_htval {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, htval" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* htval: CSR write 
+e.g.
+    csr_write_htval!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_htval {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    htval, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* htval: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_htval!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_htval {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, htval, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* htval: CSR Field Modifications - via register */
+
+/*******************************************
+ * hip - HRW - Hypervisor interrupt pending. 
+ */
+/* hip: CSR address, as used in the csr field of the CSR instructions. */
+pub const HIP_CSR_ADDRESS: u16 = 0x644;
+/* hip: CSR Whole register access */
+/* hip: CSR read.
+e.g.
+    let _v = csr_read_hip!();
+ */
+#[macro_export]
+macro_rules! csr_read_hip {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hip" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hip: CSR write 
+e.g.
+    csr_write_hip!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_hip {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hip, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* hip: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_hip!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_hip {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, hip, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hip: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_hip!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_hip {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, hip, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_hip!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_hip {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, hip, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_hip!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_hip {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, hip, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_hip!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_hip {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, hip, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hip: CSR Field Modifications - via immediate */
+/* hip, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_hip!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_hip {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, hip, ", stringify!($value)));
+        }
+    );
+}
+
+/* hip, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_hip!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_hip {
+    ( HIP_VSSI_BIT_MASK) => { $crate::csr_set_bits_imm_hip!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, hip, ", stringify!($value)));
+        }
+    );
+}
+/* hip, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_hip!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_hip {
+    ( HIP_VSSI_BIT_MASK) => { $crate::csr_clr_bits_imm_hip!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, hip, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * htinst - HRW - Hypervisor trap instruction (transformed). 
+ */
+/* htinst: CSR address, as used in the csr field of the CSR instructions. */
+pub const HTINST_CSR_ADDRESS: u16 = 0x64a;
+/* htinst: CSR Whole register access */
+/* htinst: CSR read.
+e.g.
+    let _v = csr_read_htinst!();
+ */
+#[macro_export]
+macro_rules! csr_read_htinst {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, htinst" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* htinst: CSR write 
+e.g.
+    csr_write_htinst!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_htinst {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    htinst, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* htinst: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_htinst!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_htinst {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, htinst, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* htinst: CSR Field Modifications - via register */
+
+/*******************************************
+ * hgeip - HRO - Hypervisor guest external interrupt pending. 
+ */
+/* hgeip: CSR address, as used in the csr field of the CSR instructions. */
+pub const HGEIP_CSR_ADDRESS: u16 = 0xe12;
+/* hgeip: CSR Whole register access */
+/* hgeip: CSR read.
+e.g.
+    let _v = csr_read_hgeip!();
+ */
+#[macro_export]
+macro_rules! csr_read_hgeip {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hgeip" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hgeip: CSR Field Modifications - via register */
+
+/*******************************************
+ * mtinst - MRW - Machine trap instruction (transformed). 
+ */
+/* mtinst: CSR address, as used in the csr field of the CSR instructions. */
+pub const MTINST_CSR_ADDRESS: u16 = 0x34a;
+/* mtinst: CSR Whole register access */
+/* mtinst: CSR read.
+e.g.
+    let _v = csr_read_mtinst!();
+ */
+#[macro_export]
+macro_rules! csr_read_mtinst {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mtinst" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mtinst: CSR write 
+e.g.
+    csr_write_mtinst!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mtinst {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mtinst, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mtinst: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mtinst!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mtinst {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mtinst, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mtinst: CSR Field Modifications - via register */
+
+/*******************************************
+ * mtval2 - MRW - Machine bad guest physical address. 
+ */
+/* mtval2: CSR address, as used in the csr field of the CSR instructions. */
+pub const MTVAL2_CSR_ADDRESS: u16 = 0x34b;
+/* mtval2: CSR Whole register access */
+/* mtval2: CSR read.
+e.g.
+    let _v = csr_read_mtval2!();
+ */
+#[macro_export]
+macro_rules! csr_read_mtval2 {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mtval2" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mtval2: CSR write 
+e.g.
+    csr_write_mtval2!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mtval2 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mtval2, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mtval2: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mtval2!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mtval2 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mtval2, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mtval2: CSR Field Modifications - via register */
+
+/*******************************************
+ * mhpmevent3h - MRW - Upper 32 bits of mhpmevent3, RV32 only. 
+ */
+/* mhpmevent3h: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMEVENT3H_CSR_ADDRESS: u16 = 0x723;
+/* mhpmevent3h: CSR Whole register access */
+/* mhpmevent3h: CSR read.
+e.g.
+    let _v = csr_read_mhpmevent3h!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent3h {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mhpmevent3h" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mhpmevent3h: CSR write 
+e.g.
+    csr_write_mhpmevent3h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mhpmevent3h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmevent3h, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mhpmevent3h: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mhpmevent3h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mhpmevent3h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mhpmevent3h, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mhpmevent3h: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mhpmevent3h!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mhpmevent3h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mhpmevent3h, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mhpmevent3h!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_mhpmevent3h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mhpmevent3h, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_mhpmevent3h!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_mhpmevent3h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mhpmevent3h, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_mhpmevent3h!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_mhpmevent3h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, mhpmevent3h, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mhpmevent3h: CSR Field Modifications - via immediate */
+/* mhpmevent3h, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mhpmevent3h!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mhpmevent3h {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mhpmevent3h, ", stringify!($value)));
+        }
+    );
+}
+
+/* mhpmevent3h, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mhpmevent3h!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_mhpmevent3h {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mhpmevent3h, ", stringify!($value)));
+        }
+    );
+}
+/* mhpmevent3h, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mhpmevent3h!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mhpmevent3h {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mhpmevent3h, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * mhpmevent4h - MRW - Upper 32 bits of mhpmevent4, RV32 only. 
+ */
+/* mhpmevent4h: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMEVENT4H_CSR_ADDRESS: u16 = 0x724;
+/* mhpmevent4h: CSR Whole register access */
+/* mhpmevent4h: CSR read.
+e.g.
+    let _v = csr_read_mhpmevent4h!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent4h {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mhpmevent4h" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mhpmevent4h: CSR write 
+e.g.
+    csr_write_mhpmevent4h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mhpmevent4h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmevent4h, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mhpmevent4h: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mhpmevent4h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mhpmevent4h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mhpmevent4h, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mhpmevent4h: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mhpmevent4h!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mhpmevent4h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mhpmevent4h, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mhpmevent4h!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_mhpmevent4h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mhpmevent4h, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_mhpmevent4h!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_mhpmevent4h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mhpmevent4h, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_mhpmevent4h!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_mhpmevent4h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, mhpmevent4h, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mhpmevent4h: CSR Field Modifications - via immediate */
+/* mhpmevent4h, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mhpmevent4h!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mhpmevent4h {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mhpmevent4h, ", stringify!($value)));
+        }
+    );
+}
+
+/* mhpmevent4h, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mhpmevent4h!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_mhpmevent4h {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mhpmevent4h, ", stringify!($value)));
+        }
+    );
+}
+/* mhpmevent4h, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mhpmevent4h!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mhpmevent4h {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mhpmevent4h, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * mhpmevent31h - MRW - Upper 32 bits of mhpmevent31, RV32 only. 
+ */
+/* mhpmevent31h: CSR address, as used in the csr field of the CSR instructions. */
+pub const MHPMEVENT31H_CSR_ADDRESS: u16 = 0x73f;
+/* mhpmevent31h: CSR Whole register access */
+/* mhpmevent31h: CSR read.
+e.g.
+    let _v = csr_read_mhpmevent31h!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent31h {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mhpmevent31h" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mhpmevent31h: CSR write 
+e.g.
+    csr_write_mhpmevent31h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mhpmevent31h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mhpmevent31h, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mhpmevent31h: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mhpmevent31h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mhpmevent31h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mhpmevent31h, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mhpmevent31h: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mhpmevent31h!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mhpmevent31h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mhpmevent31h, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mhpmevent31h!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_mhpmevent31h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mhpmevent31h, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_mhpmevent31h!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_mhpmevent31h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mhpmevent31h, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_mhpmevent31h!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_mhpmevent31h {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, mhpmevent31h, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mhpmevent31h: CSR Field Modifications - via immediate */
+/* mhpmevent31h, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mhpmevent31h!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mhpmevent31h {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mhpmevent31h, ", stringify!($value)));
+        }
+    );
+}
+
+/* mhpmevent31h, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mhpmevent31h!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_mhpmevent31h {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mhpmevent31h, ", stringify!($value)));
+        }
+    );
+}
+/* mhpmevent31h, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mhpmevent31h!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mhpmevent31h {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mhpmevent31h, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * scountovf - SRO - Supervisor count overflow. 
+ */
+/* scountovf: CSR address, as used in the csr field of the CSR instructions. */
+pub const SCOUNTOVF_CSR_ADDRESS: u16 = 0xda0;
+/* scountovf: CSR Whole register access */
+/* scountovf: CSR read.
+e.g.
+    let _v = csr_read_scountovf!();
+ */
+#[macro_export]
+macro_rules! csr_read_scountovf {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, scountovf" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* scountovf: CSR Field Modifications - via register */
+
+/*******************************************
+ * mcyclecfg - MRW - Machine cycle counter configuration. 
+ */
+/* mcyclecfg: CSR address, as used in the csr field of the CSR instructions. */
+pub const MCYCLECFG_CSR_ADDRESS: u16 = 0x321;
+/* mcyclecfg: CSR Whole register access */
+/* mcyclecfg: CSR read.
+e.g.
+    let _v = csr_read_mcyclecfg!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcyclecfg {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mcyclecfg" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mcyclecfg: CSR write 
+e.g.
+    csr_write_mcyclecfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mcyclecfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mcyclecfg, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mcyclecfg: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mcyclecfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mcyclecfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mcyclecfg, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mcyclecfg: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mcyclecfg!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mcyclecfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mcyclecfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mcyclecfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_mcyclecfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mcyclecfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_mcyclecfg!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_mcyclecfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mcyclecfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_mcyclecfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_mcyclecfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, mcyclecfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mcyclecfg: CSR Field Modifications - via immediate */
+/* mcyclecfg, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mcyclecfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mcyclecfg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mcyclecfg, ", stringify!($value)));
+        }
+    );
+}
+
+/* mcyclecfg, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mcyclecfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_mcyclecfg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mcyclecfg, ", stringify!($value)));
+        }
+    );
+}
+/* mcyclecfg, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mcyclecfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mcyclecfg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mcyclecfg, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * mcyclecfgh - MRW - Upper 32 bits of mcyclecfg, RV32 only. 
+ */
+/* mcyclecfgh: CSR address, as used in the csr field of the CSR instructions. */
+pub const MCYCLECFGH_CSR_ADDRESS: u16 = 0x721;
+/* mcyclecfgh: CSR Whole register access */
+/* mcyclecfgh: CSR read.
+e.g.
+    let _v = csr_read_mcyclecfgh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcyclecfgh {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mcyclecfgh" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mcyclecfgh: CSR write 
+e.g.
+    csr_write_mcyclecfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mcyclecfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mcyclecfgh, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mcyclecfgh: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mcyclecfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mcyclecfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mcyclecfgh, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mcyclecfgh: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mcyclecfgh!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mcyclecfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mcyclecfgh, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mcyclecfgh!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_mcyclecfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mcyclecfgh, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_mcyclecfgh!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_mcyclecfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mcyclecfgh, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_mcyclecfgh!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_mcyclecfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, mcyclecfgh, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mcyclecfgh: CSR Field Modifications - via immediate */
+/* mcyclecfgh, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mcyclecfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mcyclecfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mcyclecfgh, ", stringify!($value)));
+        }
+    );
+}
+
+/* mcyclecfgh, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mcyclecfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_mcyclecfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mcyclecfgh, ", stringify!($value)));
+        }
+    );
+}
+/* mcyclecfgh, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mcyclecfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mcyclecfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mcyclecfgh, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * minstretcfg - MRW - Machine instret counter configuration. 
+ */
+/* minstretcfg: CSR address, as used in the csr field of the CSR instructions. */
+pub const MINSTRETCFG_CSR_ADDRESS: u16 = 0x322;
+/* minstretcfg: CSR Whole register access */
+/* minstretcfg: CSR read.
+e.g.
+    let _v = csr_read_minstretcfg!();
+ */
+#[macro_export]
+macro_rules! csr_read_minstretcfg {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, minstretcfg" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* minstretcfg: CSR write 
+e.g.
+    csr_write_minstretcfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_minstretcfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    minstretcfg, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* minstretcfg: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_minstretcfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_minstretcfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, minstretcfg, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* minstretcfg: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_minstretcfg!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_minstretcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, minstretcfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_minstretcfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_minstretcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, minstretcfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_minstretcfg!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_minstretcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, minstretcfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_minstretcfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_minstretcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, minstretcfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* minstretcfg: CSR Field Modifications - via immediate */
+/* minstretcfg, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_minstretcfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_minstretcfg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, minstretcfg, ", stringify!($value)));
+        }
+    );
+}
+
+/* minstretcfg, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_minstretcfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_minstretcfg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, minstretcfg, ", stringify!($value)));
+        }
+    );
+}
+/* minstretcfg, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_minstretcfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_minstretcfg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, minstretcfg, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * minstretcfgh - MRW - Upper 32 bits of minstretcfg, RV32 only. 
+ */
+/* minstretcfgh: CSR address, as used in the csr field of the CSR instructions. */
+pub const MINSTRETCFGH_CSR_ADDRESS: u16 = 0x722;
+/* minstretcfgh: CSR Whole register access */
+/* minstretcfgh: CSR read.
+e.g.
+    let _v = csr_read_minstretcfgh!();
+ */
+#[macro_export]
+macro_rules! csr_read_minstretcfgh {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, minstretcfgh" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* minstretcfgh: CSR write 
+e.g.
+    csr_write_minstretcfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_minstretcfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    minstretcfgh, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* minstretcfgh: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_minstretcfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_minstretcfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, minstretcfgh, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* minstretcfgh: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_minstretcfgh!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_minstretcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, minstretcfgh, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_minstretcfgh!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_minstretcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, minstretcfgh, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_minstretcfgh!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_minstretcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, minstretcfgh, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_minstretcfgh!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_minstretcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, minstretcfgh, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* minstretcfgh: CSR Field Modifications - via immediate */
+/* minstretcfgh, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_minstretcfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_minstretcfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, minstretcfgh, ", stringify!($value)));
+        }
+    );
+}
+
+/* minstretcfgh, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_minstretcfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_minstretcfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, minstretcfgh, ", stringify!($value)));
+        }
+    );
+}
+/* minstretcfgh, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_minstretcfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_minstretcfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, minstretcfgh, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * menvcfg - MRW - Machine environment configuration. 
+ */
+/* menvcfg: CSR address, as used in the csr field of the CSR instructions. */
+pub const MENVCFG_CSR_ADDRESS: u16 = 0x30a;
+/* menvcfg: CSR Whole register access */
+/* menvcfg: CSR read.
+e.g.
+    let _v = csr_read_menvcfg!();
+ */
+#[macro_export]
+macro_rules! csr_read_menvcfg {
+    ( ) => (
+        {
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, menvcfg" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* menvcfg: CSR write 
+e.g.
+    csr_write_menvcfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_menvcfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    menvcfg, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* menvcfg: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_menvcfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_menvcfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, menvcfg, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* menvcfg: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_menvcfg!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_menvcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, menvcfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_menvcfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_menvcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, menvcfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_menvcfg!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_menvcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, menvcfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_menvcfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_menvcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, menvcfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* menvcfg: CSR Field Modifications - via immediate */
+/* menvcfg, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_menvcfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_menvcfg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, menvcfg, ", stringify!($value)));
+        }
+    );
+}
+
+/* menvcfg, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_menvcfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_menvcfg {
+    ( MENVCFG_FIOM_BIT_MASK) => { $crate::csr_set_bits_imm_menvcfg!(0x1)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, menvcfg, ", stringify!($value)));
+        }
+    );
+}
+/* menvcfg, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_menvcfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_menvcfg {
+    ( MENVCFG_FIOM_BIT_MASK) => { $crate::csr_clr_bits_imm_menvcfg!(0x1)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, menvcfg, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * menvcfgh - MRW - Upper 32 bits of menvcfg, RV32 only. 
+ */
+/* menvcfgh: CSR address, as used in the csr field of the CSR instructions. */
+pub const MENVCFGH_CSR_ADDRESS: u16 = 0x31a;
+/* menvcfgh: CSR Whole register access */
+/* menvcfgh: CSR read.
+e.g.
+    let _v = csr_read_menvcfgh!();
+ */
+#[macro_export]
+macro_rules! csr_read_menvcfgh {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, menvcfgh" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* menvcfgh: CSR write 
+e.g.
+    csr_write_menvcfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_menvcfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    menvcfgh, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* menvcfgh: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_menvcfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_menvcfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, menvcfgh, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* menvcfgh: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_menvcfgh!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_menvcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, menvcfgh, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_menvcfgh!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_menvcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, menvcfgh, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_menvcfgh!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_menvcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, menvcfgh, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_menvcfgh!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_menvcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, menvcfgh, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* menvcfgh: CSR Field Modifications - via immediate */
+/* menvcfgh, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_menvcfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_menvcfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, menvcfgh, ", stringify!($value)));
+        }
+    );
+}
+
+/* menvcfgh, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_menvcfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_menvcfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, menvcfgh, ", stringify!($value)));
+        }
+    );
+}
+/* menvcfgh, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_menvcfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_menvcfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, menvcfgh, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * henvcfg - HRW - Hypervisor environment configuration. 
+ */
+/* henvcfg: CSR address, as used in the csr field of the CSR instructions. */
+pub const HENVCFG_CSR_ADDRESS: u16 = 0x60a;
+/* henvcfg: CSR Whole register access */
+/* henvcfg: CSR read.
+e.g.
+    let _v = csr_read_henvcfg!();
+ */
+#[macro_export]
+macro_rules! csr_read_henvcfg {
+    ( ) => (
+        {
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, henvcfg" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* henvcfg: CSR write 
+e.g.
+    csr_write_henvcfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_henvcfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    henvcfg, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* henvcfg: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_henvcfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_henvcfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, henvcfg, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* henvcfg: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_henvcfg!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_henvcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, henvcfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_henvcfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_henvcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, henvcfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_henvcfg!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_henvcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, henvcfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_henvcfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_henvcfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, henvcfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* henvcfg: CSR Field Modifications - via immediate */
+/* henvcfg, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_henvcfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_henvcfg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, henvcfg, ", stringify!($value)));
+        }
+    );
+}
+
+/* henvcfg, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_henvcfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_henvcfg {
+    ( HENVCFG_FIOM_BIT_MASK) => { $crate::csr_set_bits_imm_henvcfg!(0x1)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, henvcfg, ", stringify!($value)));
+        }
+    );
+}
+/* henvcfg, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_henvcfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_henvcfg {
+    ( HENVCFG_FIOM_BIT_MASK) => { $crate::csr_clr_bits_imm_henvcfg!(0x1)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, henvcfg, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * henvcfgh - HRW - Upper 32 bits of henvcfg, RV32 only. 
+ */
+/* henvcfgh: CSR address, as used in the csr field of the CSR instructions. */
+pub const HENVCFGH_CSR_ADDRESS: u16 = 0x61a;
+/* henvcfgh: CSR Whole register access */
+/* henvcfgh: CSR read.
+e.g.
+    let _v = csr_read_henvcfgh!();
+ */
+#[macro_export]
+macro_rules! csr_read_henvcfgh {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, henvcfgh" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* henvcfgh: CSR write 
+e.g.
+    csr_write_henvcfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_henvcfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    henvcfgh, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* henvcfgh: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_henvcfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_henvcfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, henvcfgh, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* henvcfgh: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_henvcfgh!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_henvcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, henvcfgh, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_henvcfgh!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_henvcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, henvcfgh, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_henvcfgh!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_henvcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, henvcfgh, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_henvcfgh!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_henvcfgh {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, henvcfgh, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* henvcfgh: CSR Field Modifications - via immediate */
+/* henvcfgh, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_henvcfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_henvcfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, henvcfgh, ", stringify!($value)));
+        }
+    );
+}
+
+/* henvcfgh, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_henvcfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_henvcfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, henvcfgh, ", stringify!($value)));
+        }
+    );
+}
+/* henvcfgh, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_henvcfgh!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_henvcfgh {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, henvcfgh, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * hvip - HRW - Hypervisor virtual interrupt pending. 
+ */
+/* hvip: CSR address, as used in the csr field of the CSR instructions. */
+pub const HVIP_CSR_ADDRESS: u16 = 0x645;
+/* hvip: CSR Whole register access */
+/* hvip: CSR read.
+e.g.
+    let _v = csr_read_hvip!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvip {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hvip" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hvip: CSR write 
+e.g.
+    csr_write_hvip!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_hvip {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hvip, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* hvip: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_hvip!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_hvip {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, hvip, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hvip: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_hvip!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_hvip {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, hvip, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_hvip!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_hvip {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, hvip, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_hvip!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_hvip {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, hvip, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_hvip!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_hvip {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, hvip, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hvip: CSR Field Modifications - via immediate */
+/* hvip, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_hvip!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_hvip {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, hvip, ", stringify!($value)));
+        }
+    );
+}
+
+/* hvip, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_hvip!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_hvip {
+    ( HVIP_VSSI_BIT_MASK) => { $crate::csr_set_bits_imm_hvip!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, hvip, ", stringify!($value)));
+        }
+    );
+}
+/* hvip, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_hvip!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_hvip {
+    ( HVIP_VSSI_BIT_MASK) => { $crate::csr_clr_bits_imm_hvip!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, hvip, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * hvictl - HRW - Hypervisor virtual interrupt control. 
+ */
+/* hvictl: CSR address, as used in the csr field of the CSR instructions. */
+pub const HVICTL_CSR_ADDRESS: u16 = 0x609;
+/* hvictl: CSR Whole register access */
+/* hvictl: CSR read.
+e.g.
+    let _v = csr_read_hvictl!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvictl {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hvictl" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hvictl: CSR write 
+e.g.
+    csr_write_hvictl!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_hvictl {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hvictl, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* hvictl: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_hvictl!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_hvictl {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, hvictl, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hvictl: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_hvictl!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_hvictl {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, hvictl, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_hvictl!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_hvictl {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, hvictl, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_hvictl!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_hvictl {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, hvictl, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_hvictl!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_hvictl {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, hvictl, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hvictl: CSR Field Modifications - via immediate */
+/* hvictl, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_hvictl!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_hvictl {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, hvictl, ", stringify!($value)));
+        }
+    );
+}
+
+/* hvictl, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_hvictl!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_hvictl {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, hvictl, ", stringify!($value)));
+        }
+    );
+}
+/* hvictl, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_hvictl!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_hvictl {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, hvictl, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * hviprio1 - HRW - Hypervisor VS-level interrupt priorities. 
+ */
+/* hviprio1: CSR address, as used in the csr field of the CSR instructions. */
+pub const HVIPRIO1_CSR_ADDRESS: u16 = 0x646;
+/* hviprio1: CSR Whole register access */
+/* hviprio1: CSR read.
+e.g.
+    let _v = csr_read_hviprio1!();
+ */
+#[macro_export]
+macro_rules! csr_read_hviprio1 {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hviprio1" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hviprio1: CSR write 
+e.g.
+    csr_write_hviprio1!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_hviprio1 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hviprio1, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* hviprio1: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_hviprio1!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_hviprio1 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, hviprio1, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hviprio1: CSR Field Modifications - via register */
+
+/*******************************************
+ * hviprio2 - HRW - Hypervisor VS-level interrupt priorities. 
+ */
+/* hviprio2: CSR address, as used in the csr field of the CSR instructions. */
+pub const HVIPRIO2_CSR_ADDRESS: u16 = 0x647;
+/* hviprio2: CSR Whole register access */
+/* hviprio2: CSR read.
+e.g.
+    let _v = csr_read_hviprio2!();
+ */
+#[macro_export]
+macro_rules! csr_read_hviprio2 {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hviprio2" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hviprio2: CSR write 
+e.g.
+    csr_write_hviprio2!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_hviprio2 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hviprio2, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* hviprio2: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_hviprio2!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_hviprio2 {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, hviprio2, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hviprio2: CSR Field Modifications - via register */
+
+/*******************************************
+ * hviprio1h - HRW - Upper 32 bits of hviprio1, RV32 only. 
+ */
+/* hviprio1h: CSR address, as used in the csr field of the CSR instructions. */
+pub const HVIPRIO1H_CSR_ADDRESS: u16 = 0x656;
+/* hviprio1h: CSR Whole register access */
+/* hviprio1h: CSR read.
+e.g.
+    let _v = csr_read_hviprio1h!();
+ */
+#[macro_export]
+macro_rules! csr_read_hviprio1h {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hviprio1h" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hviprio1h: CSR write 
+e.g.
+    csr_write_hviprio1h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_hviprio1h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hviprio1h, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* hviprio1h: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_hviprio1h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_hviprio1h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, hviprio1h, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hviprio1h: CSR Field Modifications - via register */
+
+/*******************************************
+ * hviprio2h - HRW - Upper 32 bits of hviprio2, RV32 only. 
+ */
+/* hviprio2h: CSR address, as used in the csr field of the CSR instructions. */
+pub const HVIPRIO2H_CSR_ADDRESS: u16 = 0x657;
+/* hviprio2h: CSR Whole register access */
+/* hviprio2h: CSR read.
+e.g.
+    let _v = csr_read_hviprio2h!();
+ */
+#[macro_export]
+macro_rules! csr_read_hviprio2h {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, hviprio2h" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* hviprio2h: CSR write 
+e.g.
+    csr_write_hviprio2h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_hviprio2h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    hviprio2h, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* hviprio2h: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_hviprio2h!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_hviprio2h {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, hviprio2h, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* hviprio2h: CSR Field Modifications - via register */
+
+/*******************************************
+ * mconfigptr - MRO - Pointer to configuration data structure. 
+ */
+/* mconfigptr: CSR address, as used in the csr field of the CSR instructions. */
+pub const MCONFIGPTR_CSR_ADDRESS: u16 = 0xf15;
+/* mconfigptr: CSR Whole register access */
+/* mconfigptr: CSR read.
+e.g.
+    let _v = csr_read_mconfigptr!();
+ */
+#[macro_export]
+macro_rules! csr_read_mconfigptr {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mconfigptr" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mconfigptr: CSR Field Modifications - via register */
+
+/*******************************************
+ * mseccfg - MRW - Machine security configuration. 
+ */
+/* mseccfg: CSR address, as used in the csr field of the CSR instructions. */
+pub const MSECCFG_CSR_ADDRESS: u16 = 0x747;
+/* mseccfg: CSR Whole register access */
+/* mseccfg: CSR read.
+e.g.
+    let _v = csr_read_mseccfg!();
+ */
+#[macro_export]
+macro_rules! csr_read_mseccfg {
+    ( ) => (
+        {
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mseccfg" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mseccfg: CSR write 
+e.g.
+    csr_write_mseccfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mseccfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mseccfg, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mseccfg: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mseccfg!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mseccfg {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mseccfg, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mseccfg: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_mseccfg!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_mseccfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, mseccfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_mseccfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_mseccfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, mseccfg, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_mseccfg!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_mseccfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, mseccfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_mseccfg!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_mseccfg {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, mseccfg, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mseccfg: CSR Field Modifications - via immediate */
+/* mseccfg, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_mseccfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_mseccfg {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, mseccfg, ", stringify!($value)));
+        }
+    );
+}
+
+/* mseccfg, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_mseccfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_mseccfg {
+    ( MSECCFG_MML_BIT_MASK) => { $crate::csr_set_bits_imm_mseccfg!(0x1)};
+    ( MSECCFG_MMWP_BIT_MASK) => { $crate::csr_set_bits_imm_mseccfg!(0x2)};
+    ( MSECCFG_RLB_BIT_MASK) => { $crate::csr_set_bits_imm_mseccfg!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, mseccfg, ", stringify!($value)));
+        }
+    );
+}
+/* mseccfg, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_mseccfg!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_mseccfg {
+    ( MSECCFG_MML_BIT_MASK) => { $crate::csr_clr_bits_imm_mseccfg!(0x1)};
+    ( MSECCFG_MMWP_BIT_MASK) => { $crate::csr_clr_bits_imm_mseccfg!(0x2)};
+    ( MSECCFG_RLB_BIT_MASK) => { $crate::csr_clr_bits_imm_mseccfg!(0x4)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, mseccfg, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * mseccfgh - MRW - Upper 32 bits of mseccfg, RV32 only. 
+ */
+/* mseccfgh: CSR address, as used in the csr field of the CSR instructions. */
+pub const MSECCFGH_CSR_ADDRESS: u16 = 0x757;
+/* mseccfgh: CSR Whole register access */
+/* mseccfgh: CSR read.
+e.g.
+    let _v = csr_read_mseccfgh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mseccfgh {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, mseccfgh" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* mseccfgh: CSR write 
+e.g.
+    csr_write_mseccfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_mseccfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    mseccfgh, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* mseccfgh: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_mseccfgh!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_mseccfgh {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, mseccfgh, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* mseccfgh: CSR Field Modifications - via register */
+
+/*******************************************
+ * stimecmp - SRW - Supervisor timer compare. 
+ */
+/* stimecmp: CSR address, as used in the csr field of the CSR instructions. */
+pub const STIMECMP_CSR_ADDRESS: u16 = 0x14d;
+/* stimecmp: CSR Whole register access */
+/* stimecmp: CSR read.
+e.g.
+    let _v = csr_read_stimecmp!();
+ */
+#[macro_export]
+macro_rules! csr_read_stimecmp {
+    ( ) => (
+        {
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, stimecmp" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* stimecmp: CSR write 
+e.g.
+    csr_write_stimecmp!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_stimecmp {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    stimecmp, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* stimecmp: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_stimecmp!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_stimecmp {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, stimecmp, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* stimecmp: CSR Field Modifications - via register */
+
+/*******************************************
+ * stimecmph - SRW - Upper 32 bits of stimecmp, RV32 only. 
+ */
+/* stimecmph: CSR address, as used in the csr field of the CSR instructions. */
+pub const STIMECMPH_CSR_ADDRESS: u16 = 0x15d;
+/* stimecmph: CSR Whole register access */
+/* stimecmph: CSR read.
+e.g.
+    let _v = csr_read_stimecmph!();
+ */
+#[macro_export]
+macro_rules! csr_read_stimecmph {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, stimecmph" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* stimecmph: CSR write 
+e.g.
+    csr_write_stimecmph!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_stimecmph {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    stimecmph, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* stimecmph: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_stimecmph!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_stimecmph {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, stimecmph, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* stimecmph: CSR Field Modifications - via register */
+
+/*******************************************
+ * vstimecmp - HRW - Virtual supervisor timer compare. 
+ */
+/* vstimecmp: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSTIMECMP_CSR_ADDRESS: u16 = 0x24d;
+/* vstimecmp: CSR Whole register access */
+/* vstimecmp: CSR read.
+e.g.
+    let _v = csr_read_vstimecmp!();
+ */
+#[macro_export]
+macro_rules! csr_read_vstimecmp {
+    ( ) => (
+        {
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, vstimecmp" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* vstimecmp: CSR write 
+e.g.
+    csr_write_vstimecmp!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_vstimecmp {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vstimecmp, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* vstimecmp: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_vstimecmp!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_vstimecmp {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr64;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, vstimecmp, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* vstimecmp: CSR Field Modifications - via register */
+
+/*******************************************
+ * vstimecmph - HRW - Upper 32 bits of vstimecmp, RV32 only. 
+ */
+/* vstimecmph: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSTIMECMPH_CSR_ADDRESS: u16 = 0x25d;
+/* vstimecmph: CSR Whole register access */
+/* vstimecmph: CSR read.
+e.g.
+    let _v = csr_read_vstimecmph!();
+ */
+#[macro_export]
+macro_rules! csr_read_vstimecmph {
+    ( ) => (
+        {
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, vstimecmph" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* vstimecmph: CSR write 
+e.g.
+    csr_write_vstimecmph!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_vstimecmph {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vstimecmph, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* vstimecmph: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_vstimecmph!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_vstimecmph {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintCsr32;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, vstimecmph, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* vstimecmph: CSR Field Modifications - via register */
+
+/*******************************************
+ * vstart - URW - Vector start position. 
+ */
+/* vstart: CSR address, as used in the csr field of the CSR instructions. */
+pub const VSTART_CSR_ADDRESS: u16 = 0x008;
+/* vstart: CSR Whole register access */
+/* vstart: CSR read.
+e.g.
+    let _v = csr_read_vstart!();
+ */
+#[macro_export]
+macro_rules! csr_read_vstart {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, vstart" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* vstart: CSR write 
+e.g.
+    csr_write_vstart!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_vstart {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vstart, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* vstart: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_vstart!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_vstart {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, vstart, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* vstart: CSR Field Modifications - via register */
+
+/*******************************************
+ * vxsat - URW - Fixed-point accrued saturation flag. 
+ */
+/* vxsat: CSR address, as used in the csr field of the CSR instructions. */
+pub const VXSAT_CSR_ADDRESS: u16 = 0x009;
+/* vxsat: CSR Whole register access */
+/* vxsat: CSR read.
+e.g.
+    let _v = csr_read_vxsat!();
+ */
+#[macro_export]
+macro_rules! csr_read_vxsat {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, vxsat" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* vxsat: CSR write 
+e.g.
+    csr_write_vxsat!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_vxsat {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vxsat, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* vxsat: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_vxsat!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_vxsat {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, vxsat, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* vxsat: CSR Field Modifications - via register */
+
+/*******************************************
+ * vxrm - URW - Fixed-point rounding mode. 
+ */
+/* vxrm: CSR address, as used in the csr field of the CSR instructions. */
+pub const VXRM_CSR_ADDRESS: u16 = 0x00a;
+/* vxrm: CSR Whole register access */
+/* vxrm: CSR read.
+e.g.
+    let _v = csr_read_vxrm!();
+ */
+#[macro_export]
+macro_rules! csr_read_vxrm {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, vxrm" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* vxrm: CSR write 
+e.g.
+    csr_write_vxrm!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_vxrm {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vxrm, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* vxrm: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_vxrm!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_vxrm {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, vxrm, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* vxrm: CSR Field Modifications - via register */
+
+/*******************************************
+ * vcsr - URW - Vector control and status register. 
+ */
+/* vcsr: CSR address, as used in the csr field of the CSR instructions. */
+pub const VCSR_CSR_ADDRESS: u16 = 0x00f;
+/* vcsr: CSR Whole register access */
+/* vcsr: CSR read.
+e.g.
+    let _v = csr_read_vcsr!();
+ */
+#[macro_export]
+macro_rules! csr_read_vcsr {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, vcsr" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+/* vcsr: CSR write 
+e.g.
+    csr_write_vcsr!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_write_vcsr {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    vcsr, {0}" , in(reg) value);
+            }
+        }
+    );
+}
+/* vcsr: CSR Read and Write 
+e.g.
+    let v_ = csr_read_write_vcsr!(0x1234567);
+*/
+#[macro_export]
+macro_rules! csr_read_write_vcsr {
+    ( $x:expr ) => (
+        {
+            let value = $x;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrw    {0}, vcsr, {1}" , out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* vcsr: CSR Field Modifications - via register */
+/* Register CSR bit set instructions.
+e.g.
+csr_set_bits_vcsr!(0x0F0F0F);
+*/
+#[macro_export]
+macro_rules! csr_set_bits_vcsr {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, vcsr, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR bit clear instructions
+e.g.
+csr_clr_bits_vcsr!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_vcsr {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, vcsr, {0}", in(reg) value);
+            }
+        }
+    );
+}
+/* Register CSR read and then bit set instructions
+e.g.
+let org_value_ = csr_read_set_bits_vcsr!(0x0F0F0F)
+*/
+#[macro_export]
+macro_rules! csr_read_set_bits_vcsr {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    {0}, vcsr, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* Register CSR read and then bit clear instructions
+e.g.
+let org_value_ = csr_read_clr_bits_vcsr!(0x0F0F0F)
+ */
+#[macro_export]
+macro_rules! csr_read_clr_bits_vcsr {
+    ( $mask:expr ) => (
+        {
+            let value = $mask;
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    {0}, vcsr, {1}", out(reg) tmp_value, in(reg) value);
+            }
+            tmp_value
+        }
+    );
+}
+/* vcsr: CSR Field Modifications - via immediate */
+/* vcsr, CSR write value via immediate value (only up to 5 bits).
+e.g.
+csr_write_imm_vcsr!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_write_imm_vcsr {
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrwi    zero, vcsr, ", stringify!($value)));
+        }
+    );
+}
+
+/* vcsr, CSR set bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_set_bits_imm_vcsr!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_set_bits_imm_vcsr {
+    ( VCSR_VXSAT_BIT_MASK) => { $crate::csr_set_bits_imm_vcsr!(0x1)};
+    ( VCSR_VXRM_BIT_MASK) => { $crate::csr_set_bits_imm_vcsr!(0x6)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrsi    zero, vcsr, ", stringify!($value)));
+        }
+    );
+}
+/* vcsr, CSR clear bits via immediate value mask (only up to 5 bits).
+e.g.
+csr_clr_bits_imm_vcsr!(0x1F);
+ */
+#[macro_export]
+macro_rules! csr_clr_bits_imm_vcsr {
+    ( VCSR_VXSAT_BIT_MASK) => { $crate::csr_clr_bits_imm_vcsr!(0x1)};
+    ( VCSR_VXRM_BIT_MASK) => { $crate::csr_clr_bits_imm_vcsr!(0x6)};
+    ( $value:literal ) => (
+        unsafe {
+            use core::arch::asm;
+            asm!(concat!("csrrci    zero, vcsr, ", stringify!($value)));
+        }
+    );
+}
+
+/*******************************************
+ * vl - URO - Vector length. 
+ */
+/* vl: CSR address, as used in the csr field of the CSR instructions. */
+pub const VL_CSR_ADDRESS: u16 = 0xc20;
+/* vl: CSR Whole register access */
+/* vl: CSR read.
+e.g.
+    let _v = csr_read_vl!();
+ */
+#[macro_export]
+macro_rules! csr_read_vl {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, vl" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* vl: CSR Field Modifications - via register */
+
+/*******************************************
+ * vtype - URO - Vector data type register. 
+ */
+/* vtype: CSR address, as used in the csr field of the CSR instructions. */
+pub const VTYPE_CSR_ADDRESS: u16 = 0xc21;
+/* vtype: CSR Whole register access */
+/* vtype: CSR read.
+e.g.
+    let _v = csr_read_vtype!();
+ */
+#[macro_export]
+macro_rules! csr_read_vtype {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, vtype" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* vtype: CSR Field Modifications - via register */
+
+/*******************************************
+ * vlenb - URO - VLEN/8 (vector register length in bytes). 
+ */
+/* vlenb: CSR address, as used in the csr field of the CSR instructions. */
+pub const VLENB_CSR_ADDRESS: u16 = 0xc22;
+/* vlenb: CSR Whole register access */
+/* vlenb: CSR read.
+e.g.
+    let _v = csr_read_vlenb!();
+ */
+#[macro_export]
+macro_rules! csr_read_vlenb {
+    ( ) => (
+        {
+            let tmp_value: UintXlen;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrr    {0}, vlenb" , out(reg) tmp_value);
+            }
+            tmp_value
+        }
+    );
+}
+
+/* vlenb: CSR Field Modifications - via register */
+
+/*******************************************
+ * misa - MRW - Machine ISA 
+ */
+pub const MISA_EXTENSIONS_BIT_OFFSET:isize   = 0;
+pub const MISA_EXTENSIONS_BIT_WIDTH:isize    = 26;
+pub const MISA_EXTENSIONS_BIT_MASK:UintXlen = 0x3ffffff;
+pub const MISA_EXTENSIONS_ALL_SET_MASK:UintXlen = 0x3ffffff;
+/* misa.extensions: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_misa_extensions!();
+ */
+#[macro_export]
+macro_rules! csr_read_misa_extensions {
+    ( ) => (
+        ($crate::csr_read_misa!() & $crate::MISA_EXTENSIONS_BIT_MASK) >> $crate::MISA_EXTENSIONS_BIT_OFFSET
+    );
+}
+/* misa.extensions: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_misa_extensions!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_misa_extensions {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_misa!();
+            let new_value = (org_value & !$crate::MISA_EXTENSIONS_BIT_MASK)
+                | ((($x) << $crate::MISA_EXTENSIONS_BIT_OFFSET) & $crate::MISA_EXTENSIONS_BIT_MASK);
+            $crate::csr_write_misa!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "32")]
+pub const MISA_MXL_BIT_OFFSET:isize   = 30;
+#[cfg(target_pointer_width = "32")]
+pub const MISA_MXL_BIT_WIDTH:isize    = 2;
+#[cfg(target_pointer_width = "32")]
+pub const MISA_MXL_BIT_MASK:UintXlen = 0xc0000000;
+#[cfg(target_pointer_width = "32")]
+pub const MISA_MXL_ALL_SET_MASK:UintXlen = 0x3;
+#[cfg(target_pointer_width = "64")]
+pub const MISA_MXL_BIT_OFFSET:isize   = 62;
+#[cfg(target_pointer_width = "64")]
+pub const MISA_MXL_BIT_WIDTH:isize    = 2;
+#[cfg(target_pointer_width = "64")]
+pub const MISA_MXL_BIT_MASK:UintXlen = 0xc000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MISA_MXL_ALL_SET_MASK:UintXlen = 0x3;
+/* misa.mxl: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_misa_mxl!();
+ */
+#[macro_export]
+macro_rules! csr_read_misa_mxl {
+    ( ) => (
+        ($crate::csr_read_misa!() & $crate::MISA_MXL_BIT_MASK) >> $crate::MISA_MXL_BIT_OFFSET
+    );
+}
+/* misa.mxl: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_misa_mxl!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_misa_mxl {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_misa!();
+            let new_value = (org_value & !$crate::MISA_MXL_BIT_MASK)
+                | ((($x) << $crate::MISA_MXL_BIT_OFFSET) & $crate::MISA_MXL_BIT_MASK);
+            $crate::csr_write_misa!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mvendorid - MRO - Machine Vendor ID 
+ */
+pub const MVENDORID_OFFSET_BIT_OFFSET:isize   = 0;
+pub const MVENDORID_OFFSET_BIT_WIDTH:isize    = 7;
+pub const MVENDORID_OFFSET_BIT_MASK:UintXlen = 0x7f;
+pub const MVENDORID_OFFSET_ALL_SET_MASK:UintXlen = 0x7f;
+/* mvendorid.offset: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mvendorid_offset!();
+ */
+#[macro_export]
+macro_rules! csr_read_mvendorid_offset {
+    ( ) => (
+        (($crate::csr_read_mvendorid!() as $crate::UintXlen) & $crate::MVENDORID_OFFSET_BIT_MASK) >> $crate::MVENDORID_OFFSET_BIT_OFFSET
+    );
+}
+pub const MVENDORID_BANK_BIT_OFFSET:isize   = 7;
+pub const MVENDORID_BANK_BIT_WIDTH:isize    = 25;
+pub const MVENDORID_BANK_BIT_MASK:UintXlen = 0xffffff80;
+pub const MVENDORID_BANK_ALL_SET_MASK:UintXlen = 0x1ffffff;
+/* mvendorid.bank: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mvendorid_bank!();
+ */
+#[macro_export]
+macro_rules! csr_read_mvendorid_bank {
+    ( ) => (
+        (($crate::csr_read_mvendorid!() as $crate::UintXlen) & $crate::MVENDORID_BANK_BIT_MASK) >> $crate::MVENDORID_BANK_BIT_OFFSET
+    );
+}
+
+/*******************************************
+ * mstatus - MRW - Machine Status 
+ */
+pub const MSTATUS_MIE_BIT_OFFSET:isize   = 3;
+pub const MSTATUS_MIE_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_MIE_BIT_MASK:UintXlen = 0x8;
+pub const MSTATUS_MIE_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.mie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_mie!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_mie {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_MIE_BIT_MASK) >> $crate::MSTATUS_MIE_BIT_OFFSET
+    );
+}
+/* mstatus.mie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_mie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_mie {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_MIE_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_MIE_BIT_OFFSET) & $crate::MSTATUS_MIE_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_SIE_BIT_OFFSET:isize   = 1;
+pub const MSTATUS_SIE_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_SIE_BIT_MASK:UintXlen = 0x2;
+pub const MSTATUS_SIE_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.sie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_sie!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_sie {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_SIE_BIT_MASK) >> $crate::MSTATUS_SIE_BIT_OFFSET
+    );
+}
+/* mstatus.sie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_sie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_sie {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_SIE_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_SIE_BIT_OFFSET) & $crate::MSTATUS_SIE_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_MPIE_BIT_OFFSET:isize   = 7;
 pub const MSTATUS_MPIE_BIT_WIDTH:isize    = 1;
 pub const MSTATUS_MPIE_BIT_MASK:UintXlen = 0x80;
 pub const MSTATUS_MPIE_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.mpie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_mpie!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_mpie {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_MPIE_BIT_MASK) >> $crate::MSTATUS_MPIE_BIT_OFFSET
+    );
+}
+/* mstatus.mpie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_mpie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_mpie {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_MPIE_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_MPIE_BIT_OFFSET) & $crate::MSTATUS_MPIE_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
 pub const MSTATUS_SPIE_BIT_OFFSET:isize   = 5;
 pub const MSTATUS_SPIE_BIT_WIDTH:isize    = 1;
 pub const MSTATUS_SPIE_BIT_MASK:UintXlen = 0x20;
 pub const MSTATUS_SPIE_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.spie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_spie!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_spie {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_SPIE_BIT_MASK) >> $crate::MSTATUS_SPIE_BIT_OFFSET
+    );
+}
+/* mstatus.spie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_spie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_spie {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_SPIE_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_SPIE_BIT_OFFSET) & $crate::MSTATUS_SPIE_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
 pub const MSTATUS_MPRV_BIT_OFFSET:isize   = 17;
 pub const MSTATUS_MPRV_BIT_WIDTH:isize    = 1;
 pub const MSTATUS_MPRV_BIT_MASK:UintXlen = 0x20000;
 pub const MSTATUS_MPRV_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.mprv: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_mprv!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_mprv {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_MPRV_BIT_MASK) >> $crate::MSTATUS_MPRV_BIT_OFFSET
+    );
+}
+/* mstatus.mprv: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_mprv!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_mprv {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_MPRV_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_MPRV_BIT_OFFSET) & $crate::MSTATUS_MPRV_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
 pub const MSTATUS_MPP_BIT_OFFSET:isize   = 11;
 pub const MSTATUS_MPP_BIT_WIDTH:isize    = 2;
 pub const MSTATUS_MPP_BIT_MASK:UintXlen = 0x1800;
 pub const MSTATUS_MPP_ALL_SET_MASK:UintXlen = 0x3;
+/* mstatus.mpp: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_mpp!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_mpp {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_MPP_BIT_MASK) >> $crate::MSTATUS_MPP_BIT_OFFSET
+    );
+}
+/* mstatus.mpp: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_mpp!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_mpp {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_MPP_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_MPP_BIT_OFFSET) & $crate::MSTATUS_MPP_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
 pub const MSTATUS_SPP_BIT_OFFSET:isize   = 8;
 pub const MSTATUS_SPP_BIT_WIDTH:isize    = 1;
 pub const MSTATUS_SPP_BIT_MASK:UintXlen = 0x100;
 pub const MSTATUS_SPP_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.spp: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_spp!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_spp {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_SPP_BIT_MASK) >> $crate::MSTATUS_SPP_BIT_OFFSET
+    );
+}
+/* mstatus.spp: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_spp!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_spp {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_SPP_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_SPP_BIT_OFFSET) & $crate::MSTATUS_SPP_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_UBE_BIT_OFFSET:isize   = 6;
+pub const MSTATUS_UBE_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_UBE_BIT_MASK:UintXlen = 0x40;
+pub const MSTATUS_UBE_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.ube: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_ube!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_ube {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_UBE_BIT_MASK) >> $crate::MSTATUS_UBE_BIT_OFFSET
+    );
+}
+/* mstatus.ube: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_ube!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_ube {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_UBE_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_UBE_BIT_OFFSET) & $crate::MSTATUS_UBE_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_VS_BIT_OFFSET:isize   = 9;
+pub const MSTATUS_VS_BIT_WIDTH:isize    = 2;
+pub const MSTATUS_VS_BIT_MASK:UintXlen = 0x600;
+pub const MSTATUS_VS_ALL_SET_MASK:UintXlen = 0x3;
+/* mstatus.vs: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_vs!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_vs {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_VS_BIT_MASK) >> $crate::MSTATUS_VS_BIT_OFFSET
+    );
+}
+/* mstatus.vs: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_vs!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_vs {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_VS_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_VS_BIT_OFFSET) & $crate::MSTATUS_VS_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_FS_BIT_OFFSET:isize   = 13;
+pub const MSTATUS_FS_BIT_WIDTH:isize    = 2;
+pub const MSTATUS_FS_BIT_MASK:UintXlen = 0x6000;
+pub const MSTATUS_FS_ALL_SET_MASK:UintXlen = 0x3;
+/* mstatus.fs: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_fs!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_fs {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_FS_BIT_MASK) >> $crate::MSTATUS_FS_BIT_OFFSET
+    );
+}
+/* mstatus.fs: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_fs!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_fs {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_FS_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_FS_BIT_OFFSET) & $crate::MSTATUS_FS_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_XS_BIT_OFFSET:isize   = 15;
+pub const MSTATUS_XS_BIT_WIDTH:isize    = 2;
+pub const MSTATUS_XS_BIT_MASK:UintXlen = 0x18000;
+pub const MSTATUS_XS_ALL_SET_MASK:UintXlen = 0x3;
+/* mstatus.xs: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_xs!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_xs {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_XS_BIT_MASK) >> $crate::MSTATUS_XS_BIT_OFFSET
+    );
+}
+/* mstatus.xs: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_xs!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_xs {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_XS_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_XS_BIT_OFFSET) & $crate::MSTATUS_XS_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_SUM_BIT_OFFSET:isize   = 18;
+pub const MSTATUS_SUM_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_SUM_BIT_MASK:UintXlen = 0x40000;
+pub const MSTATUS_SUM_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.sum: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_sum!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_sum {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_SUM_BIT_MASK) >> $crate::MSTATUS_SUM_BIT_OFFSET
+    );
+}
+/* mstatus.sum: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_sum!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_sum {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_SUM_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_SUM_BIT_OFFSET) & $crate::MSTATUS_SUM_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_MXR_BIT_OFFSET:isize   = 19;
+pub const MSTATUS_MXR_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_MXR_BIT_MASK:UintXlen = 0x80000;
+pub const MSTATUS_MXR_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.mxr: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_mxr!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_mxr {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_MXR_BIT_MASK) >> $crate::MSTATUS_MXR_BIT_OFFSET
+    );
+}
+/* mstatus.mxr: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_mxr!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_mxr {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_MXR_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_MXR_BIT_OFFSET) & $crate::MSTATUS_MXR_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_TVM_BIT_OFFSET:isize   = 20;
+pub const MSTATUS_TVM_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_TVM_BIT_MASK:UintXlen = 0x100000;
+pub const MSTATUS_TVM_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.tvm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_tvm!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_tvm {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_TVM_BIT_MASK) >> $crate::MSTATUS_TVM_BIT_OFFSET
+    );
+}
+/* mstatus.tvm: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_tvm!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_tvm {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_TVM_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_TVM_BIT_OFFSET) & $crate::MSTATUS_TVM_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_TW_BIT_OFFSET:isize   = 21;
+pub const MSTATUS_TW_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_TW_BIT_MASK:UintXlen = 0x200000;
+pub const MSTATUS_TW_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.tw: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_tw!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_tw {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_TW_BIT_MASK) >> $crate::MSTATUS_TW_BIT_OFFSET
+    );
+}
+/* mstatus.tw: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_tw!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_tw {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_TW_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_TW_BIT_OFFSET) & $crate::MSTATUS_TW_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_TSR_BIT_OFFSET:isize   = 22;
+pub const MSTATUS_TSR_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_TSR_BIT_MASK:UintXlen = 0x400000;
+pub const MSTATUS_TSR_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.tsr: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_tsr!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_tsr {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_TSR_BIT_MASK) >> $crate::MSTATUS_TSR_BIT_OFFSET
+    );
+}
+/* mstatus.tsr: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_tsr!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_tsr {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_TSR_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_TSR_BIT_OFFSET) & $crate::MSTATUS_TSR_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_SPELP_BIT_OFFSET:isize   = 23;
+pub const MSTATUS_SPELP_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_SPELP_BIT_MASK:UintXlen = 0x800000;
+pub const MSTATUS_SPELP_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.spelp: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_spelp!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_spelp {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_SPELP_BIT_MASK) >> $crate::MSTATUS_SPELP_BIT_OFFSET
+    );
+}
+/* mstatus.spelp: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_spelp!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_spelp {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_SPELP_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_SPELP_BIT_OFFSET) & $crate::MSTATUS_SPELP_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_SDT_BIT_OFFSET:isize   = 24;
+pub const MSTATUS_SDT_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_SDT_BIT_MASK:UintXlen = 0x1000000;
+pub const MSTATUS_SDT_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.sdt: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_sdt!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_sdt {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_SDT_BIT_MASK) >> $crate::MSTATUS_SDT_BIT_OFFSET
+    );
+}
+/* mstatus.sdt: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_sdt!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_sdt {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_SDT_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_SDT_BIT_OFFSET) & $crate::MSTATUS_SDT_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_UXL_BIT_OFFSET:isize   = 32;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_UXL_BIT_WIDTH:isize    = 2;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_UXL_BIT_MASK:UintXlen = 0x300000000;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_UXL_ALL_SET_MASK:UintXlen = 0x3;
+/* mstatus.uxl: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_uxl!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mstatus_uxl {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_UXL_BIT_MASK) >> $crate::MSTATUS_UXL_BIT_OFFSET
+    );
+}
+/* mstatus.uxl: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_uxl!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mstatus_uxl {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_UXL_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_UXL_BIT_OFFSET) & $crate::MSTATUS_UXL_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_SXL_BIT_OFFSET:isize   = 34;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_SXL_BIT_WIDTH:isize    = 2;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_SXL_BIT_MASK:UintXlen = 0xc00000000;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_SXL_ALL_SET_MASK:UintXlen = 0x3;
+/* mstatus.sxl: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_sxl!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mstatus_sxl {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_SXL_BIT_MASK) >> $crate::MSTATUS_SXL_BIT_OFFSET
+    );
+}
+/* mstatus.sxl: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_sxl!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mstatus_sxl {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_SXL_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_SXL_BIT_OFFSET) & $crate::MSTATUS_SXL_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_SBE_BIT_OFFSET:isize   = 36;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_SBE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_SBE_BIT_MASK:UintXlen = 0x1000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_SBE_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.sbe: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_sbe!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mstatus_sbe {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_SBE_BIT_MASK) >> $crate::MSTATUS_SBE_BIT_OFFSET
+    );
+}
+/* mstatus.sbe: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_sbe!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mstatus_sbe {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_SBE_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_SBE_BIT_OFFSET) & $crate::MSTATUS_SBE_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MBE_BIT_OFFSET:isize   = 37;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MBE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MBE_BIT_MASK:UintXlen = 0x2000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MBE_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.mbe: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_mbe!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mstatus_mbe {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_MBE_BIT_MASK) >> $crate::MSTATUS_MBE_BIT_OFFSET
+    );
+}
+/* mstatus.mbe: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_mbe!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mstatus_mbe {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_MBE_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_MBE_BIT_OFFSET) & $crate::MSTATUS_MBE_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_GVA_BIT_OFFSET:isize   = 38;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_GVA_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_GVA_BIT_MASK:UintXlen = 0x4000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_GVA_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.gva: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_gva!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mstatus_gva {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_GVA_BIT_MASK) >> $crate::MSTATUS_GVA_BIT_OFFSET
+    );
+}
+/* mstatus.gva: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_gva!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mstatus_gva {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_GVA_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_GVA_BIT_OFFSET) & $crate::MSTATUS_GVA_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MPV_BIT_OFFSET:isize   = 39;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MPV_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MPV_BIT_MASK:UintXlen = 0x8000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MPV_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.mpv: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_mpv!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mstatus_mpv {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_MPV_BIT_MASK) >> $crate::MSTATUS_MPV_BIT_OFFSET
+    );
+}
+/* mstatus.mpv: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_mpv!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mstatus_mpv {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_MPV_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_MPV_BIT_OFFSET) & $crate::MSTATUS_MPV_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MPELP_BIT_OFFSET:isize   = 41;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MPELP_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MPELP_BIT_MASK:UintXlen = 0x20000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MPELP_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.mpelp: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_mpelp!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mstatus_mpelp {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_MPELP_BIT_MASK) >> $crate::MSTATUS_MPELP_BIT_OFFSET
+    );
+}
+/* mstatus.mpelp: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_mpelp!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mstatus_mpelp {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_MPELP_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_MPELP_BIT_OFFSET) & $crate::MSTATUS_MPELP_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MDT_BIT_OFFSET:isize   = 42;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MDT_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MDT_BIT_MASK:UintXlen = 0x40000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MSTATUS_MDT_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.mdt: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_mdt!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mstatus_mdt {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_MDT_BIT_MASK) >> $crate::MSTATUS_MDT_BIT_OFFSET
+    );
+}
+/* mstatus.mdt: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_mdt!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mstatus_mdt {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_MDT_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_MDT_BIT_OFFSET) & $crate::MSTATUS_MDT_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+pub const MSTATUS_SD_BIT_OFFSET:isize   = __riscv_xlen-1;
+pub const MSTATUS_SD_BIT_WIDTH:isize    = 1;
+pub const MSTATUS_SD_BIT_MASK:UintXlen = 0x1 << (__riscv_xlen-1);
+pub const MSTATUS_SD_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatus.sd: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatus_sd!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatus_sd {
+    ( ) => (
+        ($crate::csr_read_mstatus!() & $crate::MSTATUS_SD_BIT_MASK) >> $crate::MSTATUS_SD_BIT_OFFSET
+    );
+}
+/* mstatus.sd: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatus_sd!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatus_sd {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatus!();
+            let new_value = (org_value & !$crate::MSTATUS_SD_BIT_MASK)
+                | ((($x) << $crate::MSTATUS_SD_BIT_OFFSET) & $crate::MSTATUS_SD_BIT_MASK);
+            $crate::csr_write_mstatus!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mstatush - MRW - Additional machine status register, RV32 only. 
+ */
+pub const MSTATUSH_SBE_BIT_OFFSET:isize   = 4;
+pub const MSTATUSH_SBE_BIT_WIDTH:isize    = 1;
+pub const MSTATUSH_SBE_BIT_MASK:UintXlen = 0x10;
+pub const MSTATUSH_SBE_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatush.sbe: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatush_sbe!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatush_sbe {
+    ( ) => (
+        ($crate::csr_read_mstatush!() & $crate::MSTATUSH_SBE_BIT_MASK) >> $crate::MSTATUSH_SBE_BIT_OFFSET
+    );
+}
+/* mstatush.sbe: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatush_sbe!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatush_sbe {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatush!();
+            let new_value = (org_value & !$crate::MSTATUSH_SBE_BIT_MASK)
+                | ((($x) << $crate::MSTATUSH_SBE_BIT_OFFSET) & $crate::MSTATUSH_SBE_BIT_MASK);
+            $crate::csr_write_mstatush!(new_value);
+        }
+    );
+}
+pub const MSTATUSH_MBE_BIT_OFFSET:isize   = 5;
+pub const MSTATUSH_MBE_BIT_WIDTH:isize    = 1;
+pub const MSTATUSH_MBE_BIT_MASK:UintXlen = 0x20;
+pub const MSTATUSH_MBE_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatush.mbe: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatush_mbe!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatush_mbe {
+    ( ) => (
+        ($crate::csr_read_mstatush!() & $crate::MSTATUSH_MBE_BIT_MASK) >> $crate::MSTATUSH_MBE_BIT_OFFSET
+    );
+}
+/* mstatush.mbe: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatush_mbe!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatush_mbe {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatush!();
+            let new_value = (org_value & !$crate::MSTATUSH_MBE_BIT_MASK)
+                | ((($x) << $crate::MSTATUSH_MBE_BIT_OFFSET) & $crate::MSTATUSH_MBE_BIT_MASK);
+            $crate::csr_write_mstatush!(new_value);
+        }
+    );
+}
+pub const MSTATUSH_GVA_BIT_OFFSET:isize   = 6;
+pub const MSTATUSH_GVA_BIT_WIDTH:isize    = 1;
+pub const MSTATUSH_GVA_BIT_MASK:UintXlen = 0x40;
+pub const MSTATUSH_GVA_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatush.gva: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatush_gva!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatush_gva {
+    ( ) => (
+        ($crate::csr_read_mstatush!() & $crate::MSTATUSH_GVA_BIT_MASK) >> $crate::MSTATUSH_GVA_BIT_OFFSET
+    );
+}
+/* mstatush.gva: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatush_gva!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatush_gva {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatush!();
+            let new_value = (org_value & !$crate::MSTATUSH_GVA_BIT_MASK)
+                | ((($x) << $crate::MSTATUSH_GVA_BIT_OFFSET) & $crate::MSTATUSH_GVA_BIT_MASK);
+            $crate::csr_write_mstatush!(new_value);
+        }
+    );
+}
+pub const MSTATUSH_MPV_BIT_OFFSET:isize   = 7;
+pub const MSTATUSH_MPV_BIT_WIDTH:isize    = 1;
+pub const MSTATUSH_MPV_BIT_MASK:UintXlen = 0x80;
+pub const MSTATUSH_MPV_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatush.mpv: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatush_mpv!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatush_mpv {
+    ( ) => (
+        ($crate::csr_read_mstatush!() & $crate::MSTATUSH_MPV_BIT_MASK) >> $crate::MSTATUSH_MPV_BIT_OFFSET
+    );
+}
+/* mstatush.mpv: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatush_mpv!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatush_mpv {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatush!();
+            let new_value = (org_value & !$crate::MSTATUSH_MPV_BIT_MASK)
+                | ((($x) << $crate::MSTATUSH_MPV_BIT_OFFSET) & $crate::MSTATUSH_MPV_BIT_MASK);
+            $crate::csr_write_mstatush!(new_value);
+        }
+    );
+}
+pub const MSTATUSH_MPELP_BIT_OFFSET:isize   = 9;
+pub const MSTATUSH_MPELP_BIT_WIDTH:isize    = 1;
+pub const MSTATUSH_MPELP_BIT_MASK:UintXlen = 0x200;
+pub const MSTATUSH_MPELP_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatush.mpelp: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatush_mpelp!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatush_mpelp {
+    ( ) => (
+        ($crate::csr_read_mstatush!() & $crate::MSTATUSH_MPELP_BIT_MASK) >> $crate::MSTATUSH_MPELP_BIT_OFFSET
+    );
+}
+/* mstatush.mpelp: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatush_mpelp!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatush_mpelp {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatush!();
+            let new_value = (org_value & !$crate::MSTATUSH_MPELP_BIT_MASK)
+                | ((($x) << $crate::MSTATUSH_MPELP_BIT_OFFSET) & $crate::MSTATUSH_MPELP_BIT_MASK);
+            $crate::csr_write_mstatush!(new_value);
+        }
+    );
+}
+pub const MSTATUSH_MDT_BIT_OFFSET:isize   = 10;
+pub const MSTATUSH_MDT_BIT_WIDTH:isize    = 1;
+pub const MSTATUSH_MDT_BIT_MASK:UintXlen = 0x400;
+pub const MSTATUSH_MDT_ALL_SET_MASK:UintXlen = 0x1;
+/* mstatush.mdt: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mstatush_mdt!();
+ */
+#[macro_export]
+macro_rules! csr_read_mstatush_mdt {
+    ( ) => (
+        ($crate::csr_read_mstatush!() & $crate::MSTATUSH_MDT_BIT_MASK) >> $crate::MSTATUSH_MDT_BIT_OFFSET
+    );
+}
+/* mstatush.mdt: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mstatush_mdt!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mstatush_mdt {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mstatush!();
+            let new_value = (org_value & !$crate::MSTATUSH_MDT_BIT_MASK)
+                | ((($x) << $crate::MSTATUSH_MDT_BIT_OFFSET) & $crate::MSTATUSH_MDT_BIT_MASK);
+            $crate::csr_write_mstatush!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mtvec - MRW - Machine Trap Vector Base Address 
+ */
+pub const MTVEC_BASE_BIT_OFFSET:isize   = 2;
+pub const MTVEC_BASE_BIT_WIDTH:isize    = (__riscv_xlen-1)-(2) + 1;
+pub const MTVEC_BASE_BIT_MASK:UintXlen = (UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (2);
+pub const MTVEC_BASE_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1));
+/* mtvec.base: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mtvec_base!();
+ */
+#[macro_export]
+macro_rules! csr_read_mtvec_base {
+    ( ) => (
+        ($crate::csr_read_mtvec!() & $crate::MTVEC_BASE_BIT_MASK) >> $crate::MTVEC_BASE_BIT_OFFSET
+    );
+}
+/* mtvec.base: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mtvec_base!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mtvec_base {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mtvec!();
+            let new_value = (org_value & !$crate::MTVEC_BASE_BIT_MASK)
+                | ((($x) << $crate::MTVEC_BASE_BIT_OFFSET) & $crate::MTVEC_BASE_BIT_MASK);
+            $crate::csr_write_mtvec!(new_value);
+        }
+    );
+}
+pub const MTVEC_MODE_BIT_OFFSET:isize   = 0;
+pub const MTVEC_MODE_BIT_WIDTH:isize    = 2;
+pub const MTVEC_MODE_BIT_MASK:UintXlen = 0x3;
+pub const MTVEC_MODE_ALL_SET_MASK:UintXlen = 0x3;
+/* mtvec.mode: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mtvec_mode!();
+ */
+#[macro_export]
+macro_rules! csr_read_mtvec_mode {
+    ( ) => (
+        ($crate::csr_read_mtvec!() & $crate::MTVEC_MODE_BIT_MASK) >> $crate::MTVEC_MODE_BIT_OFFSET
+    );
+}
+/* mtvec.mode: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mtvec_mode!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mtvec_mode {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mtvec!();
+            let new_value = (org_value & !$crate::MTVEC_MODE_BIT_MASK)
+                | ((($x) << $crate::MTVEC_MODE_BIT_OFFSET) & $crate::MTVEC_MODE_BIT_MASK);
+            $crate::csr_write_mtvec!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * medeleg - MRW - Machine Exception Delegation 
+ */
+pub const MEDELEG_INSTRUCTION_MISALIGNED_BIT_OFFSET:isize   = 0;
+pub const MEDELEG_INSTRUCTION_MISALIGNED_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK:UintXlen = 0x1;
+pub const MEDELEG_INSTRUCTION_MISALIGNED_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.instruction_misaligned: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_instruction_misaligned!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_instruction_misaligned {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK) >> $crate::MEDELEG_INSTRUCTION_MISALIGNED_BIT_OFFSET
+    );
+}
+/* medeleg.instruction_misaligned: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_instruction_misaligned!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_instruction_misaligned {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_INSTRUCTION_MISALIGNED_BIT_OFFSET) & $crate::MEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_INSTRUCTION_FAULT_BIT_OFFSET:isize   = 1;
+pub const MEDELEG_INSTRUCTION_FAULT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_INSTRUCTION_FAULT_BIT_MASK:UintXlen = 0x2;
+pub const MEDELEG_INSTRUCTION_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.instruction_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_instruction_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_instruction_fault {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_INSTRUCTION_FAULT_BIT_MASK) >> $crate::MEDELEG_INSTRUCTION_FAULT_BIT_OFFSET
+    );
+}
+/* medeleg.instruction_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_instruction_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_instruction_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_INSTRUCTION_FAULT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_INSTRUCTION_FAULT_BIT_OFFSET) & $crate::MEDELEG_INSTRUCTION_FAULT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_ILLEGAL_INSTRUCTION_BIT_OFFSET:isize   = 2;
+pub const MEDELEG_ILLEGAL_INSTRUCTION_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK:UintXlen = 0x4;
+pub const MEDELEG_ILLEGAL_INSTRUCTION_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.illegal_instruction: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_illegal_instruction!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_illegal_instruction {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK) >> $crate::MEDELEG_ILLEGAL_INSTRUCTION_BIT_OFFSET
+    );
+}
+/* medeleg.illegal_instruction: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_illegal_instruction!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_illegal_instruction {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_ILLEGAL_INSTRUCTION_BIT_OFFSET) & $crate::MEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_BREAKPOINT_BIT_OFFSET:isize   = 3;
+pub const MEDELEG_BREAKPOINT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_BREAKPOINT_BIT_MASK:UintXlen = 0x8;
+pub const MEDELEG_BREAKPOINT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.breakpoint: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_breakpoint!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_breakpoint {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_BREAKPOINT_BIT_MASK) >> $crate::MEDELEG_BREAKPOINT_BIT_OFFSET
+    );
+}
+/* medeleg.breakpoint: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_breakpoint!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_breakpoint {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_BREAKPOINT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_BREAKPOINT_BIT_OFFSET) & $crate::MEDELEG_BREAKPOINT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_LOAD_MISALIGNED_BIT_OFFSET:isize   = 4;
+pub const MEDELEG_LOAD_MISALIGNED_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_LOAD_MISALIGNED_BIT_MASK:UintXlen = 0x10;
+pub const MEDELEG_LOAD_MISALIGNED_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.load_misaligned: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_load_misaligned!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_load_misaligned {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_LOAD_MISALIGNED_BIT_MASK) >> $crate::MEDELEG_LOAD_MISALIGNED_BIT_OFFSET
+    );
+}
+/* medeleg.load_misaligned: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_load_misaligned!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_load_misaligned {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_LOAD_MISALIGNED_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_LOAD_MISALIGNED_BIT_OFFSET) & $crate::MEDELEG_LOAD_MISALIGNED_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_LOAD_FAULT_BIT_OFFSET:isize   = 5;
+pub const MEDELEG_LOAD_FAULT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_LOAD_FAULT_BIT_MASK:UintXlen = 0x20;
+pub const MEDELEG_LOAD_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.load_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_load_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_load_fault {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_LOAD_FAULT_BIT_MASK) >> $crate::MEDELEG_LOAD_FAULT_BIT_OFFSET
+    );
+}
+/* medeleg.load_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_load_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_load_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_LOAD_FAULT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_LOAD_FAULT_BIT_OFFSET) & $crate::MEDELEG_LOAD_FAULT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_STORE_MISALIGNED_BIT_OFFSET:isize   = 6;
+pub const MEDELEG_STORE_MISALIGNED_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_STORE_MISALIGNED_BIT_MASK:UintXlen = 0x40;
+pub const MEDELEG_STORE_MISALIGNED_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.store_misaligned: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_store_misaligned!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_store_misaligned {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_STORE_MISALIGNED_BIT_MASK) >> $crate::MEDELEG_STORE_MISALIGNED_BIT_OFFSET
+    );
+}
+/* medeleg.store_misaligned: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_store_misaligned!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_store_misaligned {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_STORE_MISALIGNED_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_STORE_MISALIGNED_BIT_OFFSET) & $crate::MEDELEG_STORE_MISALIGNED_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_STORE_FAULT_BIT_OFFSET:isize   = 7;
+pub const MEDELEG_STORE_FAULT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_STORE_FAULT_BIT_MASK:UintXlen = 0x80;
+pub const MEDELEG_STORE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.store_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_store_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_store_fault {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_STORE_FAULT_BIT_MASK) >> $crate::MEDELEG_STORE_FAULT_BIT_OFFSET
+    );
+}
+/* medeleg.store_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_store_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_store_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_STORE_FAULT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_STORE_FAULT_BIT_OFFSET) & $crate::MEDELEG_STORE_FAULT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_ECALL_U_BIT_OFFSET:isize   = 8;
+pub const MEDELEG_ECALL_U_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_ECALL_U_BIT_MASK:UintXlen = 0x100;
+pub const MEDELEG_ECALL_U_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.ecall_u: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_ecall_u!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_ecall_u {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_ECALL_U_BIT_MASK) >> $crate::MEDELEG_ECALL_U_BIT_OFFSET
+    );
+}
+/* medeleg.ecall_u: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_ecall_u!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_ecall_u {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_ECALL_U_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_ECALL_U_BIT_OFFSET) & $crate::MEDELEG_ECALL_U_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_ECALL_S_BIT_OFFSET:isize   = 9;
+pub const MEDELEG_ECALL_S_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_ECALL_S_BIT_MASK:UintXlen = 0x200;
+pub const MEDELEG_ECALL_S_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.ecall_s: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_ecall_s!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_ecall_s {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_ECALL_S_BIT_MASK) >> $crate::MEDELEG_ECALL_S_BIT_OFFSET
+    );
+}
+/* medeleg.ecall_s: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_ecall_s!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_ecall_s {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_ECALL_S_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_ECALL_S_BIT_OFFSET) & $crate::MEDELEG_ECALL_S_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_ECALL_VS_BIT_OFFSET:isize   = 10;
+pub const MEDELEG_ECALL_VS_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_ECALL_VS_BIT_MASK:UintXlen = 0x400;
+pub const MEDELEG_ECALL_VS_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.ecall_vs: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_ecall_vs!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_ecall_vs {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_ECALL_VS_BIT_MASK) >> $crate::MEDELEG_ECALL_VS_BIT_OFFSET
+    );
+}
+/* medeleg.ecall_vs: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_ecall_vs!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_ecall_vs {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_ECALL_VS_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_ECALL_VS_BIT_OFFSET) & $crate::MEDELEG_ECALL_VS_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_OFFSET:isize   = 12;
+pub const MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK:UintXlen = 0x1000;
+pub const MEDELEG_INSTRUCTION_PAGE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.instruction_page_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_instruction_page_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_instruction_page_fault {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK) >> $crate::MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_OFFSET
+    );
+}
+/* medeleg.instruction_page_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_instruction_page_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_instruction_page_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_OFFSET) & $crate::MEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_LOAD_PAGE_FAULT_BIT_OFFSET:isize   = 13;
+pub const MEDELEG_LOAD_PAGE_FAULT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_LOAD_PAGE_FAULT_BIT_MASK:UintXlen = 0x2000;
+pub const MEDELEG_LOAD_PAGE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.load_page_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_load_page_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_load_page_fault {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_LOAD_PAGE_FAULT_BIT_MASK) >> $crate::MEDELEG_LOAD_PAGE_FAULT_BIT_OFFSET
+    );
+}
+/* medeleg.load_page_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_load_page_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_load_page_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_LOAD_PAGE_FAULT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_LOAD_PAGE_FAULT_BIT_OFFSET) & $crate::MEDELEG_LOAD_PAGE_FAULT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_STORE_PAGE_FAULT_BIT_OFFSET:isize   = 15;
+pub const MEDELEG_STORE_PAGE_FAULT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_STORE_PAGE_FAULT_BIT_MASK:UintXlen = 0x8000;
+pub const MEDELEG_STORE_PAGE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.store_page_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_store_page_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_store_page_fault {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_STORE_PAGE_FAULT_BIT_MASK) >> $crate::MEDELEG_STORE_PAGE_FAULT_BIT_OFFSET
+    );
+}
+/* medeleg.store_page_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_store_page_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_store_page_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_STORE_PAGE_FAULT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_STORE_PAGE_FAULT_BIT_OFFSET) & $crate::MEDELEG_STORE_PAGE_FAULT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_OFFSET:isize   = 20;
+pub const MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_MASK:UintXlen = 0x100000;
+pub const MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.instruction_guest_page_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_instruction_guest_page_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_instruction_guest_page_fault {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_MASK) >> $crate::MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_OFFSET
+    );
+}
+/* medeleg.instruction_guest_page_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_instruction_guest_page_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_instruction_guest_page_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_OFFSET) & $crate::MEDELEG_INSTRUCTION_GUEST_PAGE_FAULT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_OFFSET:isize   = 21;
+pub const MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_MASK:UintXlen = 0x200000;
+pub const MEDELEG_LOAD_GUEST_PAGE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.load_guest_page_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_load_guest_page_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_load_guest_page_fault {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_MASK) >> $crate::MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_OFFSET
+    );
+}
+/* medeleg.load_guest_page_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_load_guest_page_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_load_guest_page_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_OFFSET) & $crate::MEDELEG_LOAD_GUEST_PAGE_FAULT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_VIRTUAL_INSTRUCTION_BIT_OFFSET:isize   = 22;
+pub const MEDELEG_VIRTUAL_INSTRUCTION_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_VIRTUAL_INSTRUCTION_BIT_MASK:UintXlen = 0x400000;
+pub const MEDELEG_VIRTUAL_INSTRUCTION_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.virtual_instruction: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_virtual_instruction!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_virtual_instruction {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_VIRTUAL_INSTRUCTION_BIT_MASK) >> $crate::MEDELEG_VIRTUAL_INSTRUCTION_BIT_OFFSET
+    );
+}
+/* medeleg.virtual_instruction: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_virtual_instruction!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_virtual_instruction {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_VIRTUAL_INSTRUCTION_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_VIRTUAL_INSTRUCTION_BIT_OFFSET) & $crate::MEDELEG_VIRTUAL_INSTRUCTION_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+pub const MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_OFFSET:isize   = 23;
+pub const MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_WIDTH:isize    = 1;
+pub const MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_MASK:UintXlen = 0x800000;
+pub const MEDELEG_STORE_GUEST_PAGE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* medeleg.store_guest_page_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_medeleg_store_guest_page_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_medeleg_store_guest_page_fault {
+    ( ) => (
+        ($crate::csr_read_medeleg!() & $crate::MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_MASK) >> $crate::MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_OFFSET
+    );
+}
+/* medeleg.store_guest_page_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_medeleg_store_guest_page_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_medeleg_store_guest_page_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_medeleg!();
+            let new_value = (org_value & !$crate::MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_MASK)
+                | ((($x) << $crate::MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_OFFSET) & $crate::MEDELEG_STORE_GUEST_PAGE_FAULT_BIT_MASK);
+            $crate::csr_write_medeleg!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mideleg - MRW - Machine Interrupt Delegation 
+ */
+pub const MIDELEG_SSI_BIT_OFFSET:isize   = 1;
+pub const MIDELEG_SSI_BIT_WIDTH:isize    = 1;
+pub const MIDELEG_SSI_BIT_MASK:UintXlen = 0x2;
+pub const MIDELEG_SSI_ALL_SET_MASK:UintXlen = 0x1;
+/* mideleg.ssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mideleg_ssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mideleg_ssi {
+    ( ) => (
+        ($crate::csr_read_mideleg!() & $crate::MIDELEG_SSI_BIT_MASK) >> $crate::MIDELEG_SSI_BIT_OFFSET
+    );
+}
+/* mideleg.ssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mideleg_ssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mideleg_ssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mideleg!();
+            let new_value = (org_value & !$crate::MIDELEG_SSI_BIT_MASK)
+                | ((($x) << $crate::MIDELEG_SSI_BIT_OFFSET) & $crate::MIDELEG_SSI_BIT_MASK);
+            $crate::csr_write_mideleg!(new_value);
+        }
+    );
+}
+pub const MIDELEG_VSSI_BIT_OFFSET:isize   = 2;
+pub const MIDELEG_VSSI_BIT_WIDTH:isize    = 1;
+pub const MIDELEG_VSSI_BIT_MASK:UintXlen = 0x4;
+pub const MIDELEG_VSSI_ALL_SET_MASK:UintXlen = 0x1;
+/* mideleg.vssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mideleg_vssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mideleg_vssi {
+    ( ) => (
+        ($crate::csr_read_mideleg!() & $crate::MIDELEG_VSSI_BIT_MASK) >> $crate::MIDELEG_VSSI_BIT_OFFSET
+    );
+}
+/* mideleg.vssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mideleg_vssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mideleg_vssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mideleg!();
+            let new_value = (org_value & !$crate::MIDELEG_VSSI_BIT_MASK)
+                | ((($x) << $crate::MIDELEG_VSSI_BIT_OFFSET) & $crate::MIDELEG_VSSI_BIT_MASK);
+            $crate::csr_write_mideleg!(new_value);
+        }
+    );
+}
+pub const MIDELEG_STI_BIT_OFFSET:isize   = 5;
+pub const MIDELEG_STI_BIT_WIDTH:isize    = 1;
+pub const MIDELEG_STI_BIT_MASK:UintXlen = 0x20;
+pub const MIDELEG_STI_ALL_SET_MASK:UintXlen = 0x1;
+/* mideleg.sti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mideleg_sti!();
+ */
+#[macro_export]
+macro_rules! csr_read_mideleg_sti {
+    ( ) => (
+        ($crate::csr_read_mideleg!() & $crate::MIDELEG_STI_BIT_MASK) >> $crate::MIDELEG_STI_BIT_OFFSET
+    );
+}
+/* mideleg.sti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mideleg_sti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mideleg_sti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mideleg!();
+            let new_value = (org_value & !$crate::MIDELEG_STI_BIT_MASK)
+                | ((($x) << $crate::MIDELEG_STI_BIT_OFFSET) & $crate::MIDELEG_STI_BIT_MASK);
+            $crate::csr_write_mideleg!(new_value);
+        }
+    );
+}
+pub const MIDELEG_VSTI_BIT_OFFSET:isize   = 6;
+pub const MIDELEG_VSTI_BIT_WIDTH:isize    = 1;
+pub const MIDELEG_VSTI_BIT_MASK:UintXlen = 0x40;
+pub const MIDELEG_VSTI_ALL_SET_MASK:UintXlen = 0x1;
+/* mideleg.vsti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mideleg_vsti!();
+ */
+#[macro_export]
+macro_rules! csr_read_mideleg_vsti {
+    ( ) => (
+        ($crate::csr_read_mideleg!() & $crate::MIDELEG_VSTI_BIT_MASK) >> $crate::MIDELEG_VSTI_BIT_OFFSET
+    );
+}
+/* mideleg.vsti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mideleg_vsti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mideleg_vsti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mideleg!();
+            let new_value = (org_value & !$crate::MIDELEG_VSTI_BIT_MASK)
+                | ((($x) << $crate::MIDELEG_VSTI_BIT_OFFSET) & $crate::MIDELEG_VSTI_BIT_MASK);
+            $crate::csr_write_mideleg!(new_value);
+        }
+    );
+}
+pub const MIDELEG_SEI_BIT_OFFSET:isize   = 9;
+pub const MIDELEG_SEI_BIT_WIDTH:isize    = 1;
+pub const MIDELEG_SEI_BIT_MASK:UintXlen = 0x200;
+pub const MIDELEG_SEI_ALL_SET_MASK:UintXlen = 0x1;
+/* mideleg.sei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mideleg_sei!();
+ */
+#[macro_export]
+macro_rules! csr_read_mideleg_sei {
+    ( ) => (
+        ($crate::csr_read_mideleg!() & $crate::MIDELEG_SEI_BIT_MASK) >> $crate::MIDELEG_SEI_BIT_OFFSET
+    );
+}
+/* mideleg.sei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mideleg_sei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mideleg_sei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mideleg!();
+            let new_value = (org_value & !$crate::MIDELEG_SEI_BIT_MASK)
+                | ((($x) << $crate::MIDELEG_SEI_BIT_OFFSET) & $crate::MIDELEG_SEI_BIT_MASK);
+            $crate::csr_write_mideleg!(new_value);
+        }
+    );
+}
+pub const MIDELEG_VSEI_BIT_OFFSET:isize   = 10;
+pub const MIDELEG_VSEI_BIT_WIDTH:isize    = 1;
+pub const MIDELEG_VSEI_BIT_MASK:UintXlen = 0x400;
+pub const MIDELEG_VSEI_ALL_SET_MASK:UintXlen = 0x1;
+/* mideleg.vsei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mideleg_vsei!();
+ */
+#[macro_export]
+macro_rules! csr_read_mideleg_vsei {
+    ( ) => (
+        ($crate::csr_read_mideleg!() & $crate::MIDELEG_VSEI_BIT_MASK) >> $crate::MIDELEG_VSEI_BIT_OFFSET
+    );
+}
+/* mideleg.vsei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mideleg_vsei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mideleg_vsei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mideleg!();
+            let new_value = (org_value & !$crate::MIDELEG_VSEI_BIT_MASK)
+                | ((($x) << $crate::MIDELEG_VSEI_BIT_OFFSET) & $crate::MIDELEG_VSEI_BIT_MASK);
+            $crate::csr_write_mideleg!(new_value);
+        }
+    );
+}
+pub const MIDELEG_SGEI_BIT_OFFSET:isize   = 12;
+pub const MIDELEG_SGEI_BIT_WIDTH:isize    = 1;
+pub const MIDELEG_SGEI_BIT_MASK:UintXlen = 0x1000;
+pub const MIDELEG_SGEI_ALL_SET_MASK:UintXlen = 0x1;
+/* mideleg.sgei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mideleg_sgei!();
+ */
+#[macro_export]
+macro_rules! csr_read_mideleg_sgei {
+    ( ) => (
+        ($crate::csr_read_mideleg!() & $crate::MIDELEG_SGEI_BIT_MASK) >> $crate::MIDELEG_SGEI_BIT_OFFSET
+    );
+}
+/* mideleg.sgei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mideleg_sgei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mideleg_sgei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mideleg!();
+            let new_value = (org_value & !$crate::MIDELEG_SGEI_BIT_MASK)
+                | ((($x) << $crate::MIDELEG_SGEI_BIT_OFFSET) & $crate::MIDELEG_SGEI_BIT_MASK);
+            $crate::csr_write_mideleg!(new_value);
+        }
+    );
+}
+pub const MIDELEG_LCOFI_BIT_OFFSET:isize   = 13;
+pub const MIDELEG_LCOFI_BIT_WIDTH:isize    = 1;
+pub const MIDELEG_LCOFI_BIT_MASK:UintXlen = 0x2000;
+pub const MIDELEG_LCOFI_ALL_SET_MASK:UintXlen = 0x1;
+/* mideleg.lcofi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mideleg_lcofi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mideleg_lcofi {
+    ( ) => (
+        ($crate::csr_read_mideleg!() & $crate::MIDELEG_LCOFI_BIT_MASK) >> $crate::MIDELEG_LCOFI_BIT_OFFSET
+    );
+}
+/* mideleg.lcofi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mideleg_lcofi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mideleg_lcofi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mideleg!();
+            let new_value = (org_value & !$crate::MIDELEG_LCOFI_BIT_MASK)
+                | ((($x) << $crate::MIDELEG_LCOFI_BIT_OFFSET) & $crate::MIDELEG_LCOFI_BIT_MASK);
+            $crate::csr_write_mideleg!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mip - MRW - Machine Interrupt Pending 
+ */
+pub const MIP_MSI_BIT_OFFSET:isize   = 3;
+pub const MIP_MSI_BIT_WIDTH:isize    = 1;
+pub const MIP_MSI_BIT_MASK:UintXlen = 0x8;
+pub const MIP_MSI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.msi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_msi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_msi {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_MSI_BIT_MASK) >> $crate::MIP_MSI_BIT_OFFSET
+    );
+}
+/* mip.msi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_msi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_msi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_MSI_BIT_MASK)
+                | ((($x) << $crate::MIP_MSI_BIT_OFFSET) & $crate::MIP_MSI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_MTI_BIT_OFFSET:isize   = 7;
+pub const MIP_MTI_BIT_WIDTH:isize    = 1;
+pub const MIP_MTI_BIT_MASK:UintXlen = 0x80;
+pub const MIP_MTI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.mti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_mti!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_mti {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_MTI_BIT_MASK) >> $crate::MIP_MTI_BIT_OFFSET
+    );
+}
+/* mip.mti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_mti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_mti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_MTI_BIT_MASK)
+                | ((($x) << $crate::MIP_MTI_BIT_OFFSET) & $crate::MIP_MTI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_MEI_BIT_OFFSET:isize   = 11;
+pub const MIP_MEI_BIT_WIDTH:isize    = 1;
+pub const MIP_MEI_BIT_MASK:UintXlen = 0x800;
+pub const MIP_MEI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.mei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_mei!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_mei {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_MEI_BIT_MASK) >> $crate::MIP_MEI_BIT_OFFSET
+    );
+}
+/* mip.mei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_mei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_mei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_MEI_BIT_MASK)
+                | ((($x) << $crate::MIP_MEI_BIT_OFFSET) & $crate::MIP_MEI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_SSI_BIT_OFFSET:isize   = 1;
+pub const MIP_SSI_BIT_WIDTH:isize    = 1;
+pub const MIP_SSI_BIT_MASK:UintXlen = 0x2;
+pub const MIP_SSI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.ssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_ssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_ssi {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_SSI_BIT_MASK) >> $crate::MIP_SSI_BIT_OFFSET
+    );
+}
+/* mip.ssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_ssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_ssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_SSI_BIT_MASK)
+                | ((($x) << $crate::MIP_SSI_BIT_OFFSET) & $crate::MIP_SSI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_STI_BIT_OFFSET:isize   = 5;
+pub const MIP_STI_BIT_WIDTH:isize    = 1;
+pub const MIP_STI_BIT_MASK:UintXlen = 0x20;
+pub const MIP_STI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.sti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_sti!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_sti {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_STI_BIT_MASK) >> $crate::MIP_STI_BIT_OFFSET
+    );
+}
+/* mip.sti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_sti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_sti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_STI_BIT_MASK)
+                | ((($x) << $crate::MIP_STI_BIT_OFFSET) & $crate::MIP_STI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_SEI_BIT_OFFSET:isize   = 9;
+pub const MIP_SEI_BIT_WIDTH:isize    = 1;
+pub const MIP_SEI_BIT_MASK:UintXlen = 0x200;
+pub const MIP_SEI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.sei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_sei!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_sei {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_SEI_BIT_MASK) >> $crate::MIP_SEI_BIT_OFFSET
+    );
+}
+/* mip.sei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_sei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_sei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_SEI_BIT_MASK)
+                | ((($x) << $crate::MIP_SEI_BIT_OFFSET) & $crate::MIP_SEI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_USI_BIT_OFFSET:isize   = 0;
+pub const MIP_USI_BIT_WIDTH:isize    = 1;
+pub const MIP_USI_BIT_MASK:UintXlen = 0x1;
+pub const MIP_USI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.usi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_usi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_usi {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_USI_BIT_MASK) >> $crate::MIP_USI_BIT_OFFSET
+    );
+}
+/* mip.usi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_usi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_usi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_USI_BIT_MASK)
+                | ((($x) << $crate::MIP_USI_BIT_OFFSET) & $crate::MIP_USI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_UTI_BIT_OFFSET:isize   = 4;
+pub const MIP_UTI_BIT_WIDTH:isize    = 1;
+pub const MIP_UTI_BIT_MASK:UintXlen = 0x10;
+pub const MIP_UTI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.uti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_uti!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_uti {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_UTI_BIT_MASK) >> $crate::MIP_UTI_BIT_OFFSET
+    );
+}
+/* mip.uti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_uti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_uti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_UTI_BIT_MASK)
+                | ((($x) << $crate::MIP_UTI_BIT_OFFSET) & $crate::MIP_UTI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_UEI_BIT_OFFSET:isize   = 8;
+pub const MIP_UEI_BIT_WIDTH:isize    = 1;
+pub const MIP_UEI_BIT_MASK:UintXlen = 0x100;
+pub const MIP_UEI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.uei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_uei!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_uei {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_UEI_BIT_MASK) >> $crate::MIP_UEI_BIT_OFFSET
+    );
+}
+/* mip.uei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_uei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_uei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_UEI_BIT_MASK)
+                | ((($x) << $crate::MIP_UEI_BIT_OFFSET) & $crate::MIP_UEI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_PLATFORM_DEFINED_BIT_OFFSET:isize   = 16;
+pub const MIP_PLATFORM_DEFINED_BIT_WIDTH:isize    = (__riscv_xlen-1)-(16) + 1;
+pub const MIP_PLATFORM_DEFINED_BIT_MASK:UintXlen = (UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (16);
+pub const MIP_PLATFORM_DEFINED_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1));
+/* mip.platform_defined: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_platform_defined!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_platform_defined {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_PLATFORM_DEFINED_BIT_MASK) >> $crate::MIP_PLATFORM_DEFINED_BIT_OFFSET
+    );
+}
+/* mip.platform_defined: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_platform_defined!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_platform_defined {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_PLATFORM_DEFINED_BIT_MASK)
+                | ((($x) << $crate::MIP_PLATFORM_DEFINED_BIT_OFFSET) & $crate::MIP_PLATFORM_DEFINED_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+pub const MIP_LCOFI_BIT_OFFSET:isize   = 13;
+pub const MIP_LCOFI_BIT_WIDTH:isize    = 1;
+pub const MIP_LCOFI_BIT_MASK:UintXlen = 0x2000;
+pub const MIP_LCOFI_ALL_SET_MASK:UintXlen = 0x1;
+/* mip.lcofi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mip_lcofi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mip_lcofi {
+    ( ) => (
+        ($crate::csr_read_mip!() & $crate::MIP_LCOFI_BIT_MASK) >> $crate::MIP_LCOFI_BIT_OFFSET
+    );
+}
+/* mip.lcofi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mip_lcofi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mip_lcofi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mip!();
+            let new_value = (org_value & !$crate::MIP_LCOFI_BIT_MASK)
+                | ((($x) << $crate::MIP_LCOFI_BIT_OFFSET) & $crate::MIP_LCOFI_BIT_MASK);
+            $crate::csr_write_mip!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mie - MRW - Machine Interrupt Enable 
+ */
+pub const MIE_MSI_BIT_OFFSET:isize   = 3;
+pub const MIE_MSI_BIT_WIDTH:isize    = 1;
+pub const MIE_MSI_BIT_MASK:UintXlen = 0x8;
+pub const MIE_MSI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.msi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_msi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_msi {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_MSI_BIT_MASK) >> $crate::MIE_MSI_BIT_OFFSET
+    );
+}
+/* mie.msi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_msi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_msi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_MSI_BIT_MASK)
+                | ((($x) << $crate::MIE_MSI_BIT_OFFSET) & $crate::MIE_MSI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_MTI_BIT_OFFSET:isize   = 7;
+pub const MIE_MTI_BIT_WIDTH:isize    = 1;
+pub const MIE_MTI_BIT_MASK:UintXlen = 0x80;
+pub const MIE_MTI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.mti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_mti!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_mti {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_MTI_BIT_MASK) >> $crate::MIE_MTI_BIT_OFFSET
+    );
+}
+/* mie.mti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_mti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_mti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_MTI_BIT_MASK)
+                | ((($x) << $crate::MIE_MTI_BIT_OFFSET) & $crate::MIE_MTI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_MEI_BIT_OFFSET:isize   = 11;
+pub const MIE_MEI_BIT_WIDTH:isize    = 1;
+pub const MIE_MEI_BIT_MASK:UintXlen = 0x800;
+pub const MIE_MEI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.mei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_mei!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_mei {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_MEI_BIT_MASK) >> $crate::MIE_MEI_BIT_OFFSET
+    );
+}
+/* mie.mei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_mei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_mei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_MEI_BIT_MASK)
+                | ((($x) << $crate::MIE_MEI_BIT_OFFSET) & $crate::MIE_MEI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_SSI_BIT_OFFSET:isize   = 1;
+pub const MIE_SSI_BIT_WIDTH:isize    = 1;
+pub const MIE_SSI_BIT_MASK:UintXlen = 0x2;
+pub const MIE_SSI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.ssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_ssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_ssi {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_SSI_BIT_MASK) >> $crate::MIE_SSI_BIT_OFFSET
+    );
+}
+/* mie.ssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_ssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_ssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_SSI_BIT_MASK)
+                | ((($x) << $crate::MIE_SSI_BIT_OFFSET) & $crate::MIE_SSI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_STI_BIT_OFFSET:isize   = 5;
+pub const MIE_STI_BIT_WIDTH:isize    = 1;
+pub const MIE_STI_BIT_MASK:UintXlen = 0x20;
+pub const MIE_STI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.sti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_sti!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_sti {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_STI_BIT_MASK) >> $crate::MIE_STI_BIT_OFFSET
+    );
+}
+/* mie.sti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_sti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_sti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_STI_BIT_MASK)
+                | ((($x) << $crate::MIE_STI_BIT_OFFSET) & $crate::MIE_STI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_SEI_BIT_OFFSET:isize   = 9;
+pub const MIE_SEI_BIT_WIDTH:isize    = 1;
+pub const MIE_SEI_BIT_MASK:UintXlen = 0x200;
+pub const MIE_SEI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.sei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_sei!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_sei {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_SEI_BIT_MASK) >> $crate::MIE_SEI_BIT_OFFSET
+    );
+}
+/* mie.sei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_sei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_sei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_SEI_BIT_MASK)
+                | ((($x) << $crate::MIE_SEI_BIT_OFFSET) & $crate::MIE_SEI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_USI_BIT_OFFSET:isize   = 0;
+pub const MIE_USI_BIT_WIDTH:isize    = 1;
+pub const MIE_USI_BIT_MASK:UintXlen = 0x1;
+pub const MIE_USI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.usi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_usi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_usi {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_USI_BIT_MASK) >> $crate::MIE_USI_BIT_OFFSET
+    );
+}
+/* mie.usi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_usi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_usi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_USI_BIT_MASK)
+                | ((($x) << $crate::MIE_USI_BIT_OFFSET) & $crate::MIE_USI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_UTI_BIT_OFFSET:isize   = 4;
+pub const MIE_UTI_BIT_WIDTH:isize    = 1;
+pub const MIE_UTI_BIT_MASK:UintXlen = 0x10;
+pub const MIE_UTI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.uti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_uti!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_uti {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_UTI_BIT_MASK) >> $crate::MIE_UTI_BIT_OFFSET
+    );
+}
+/* mie.uti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_uti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_uti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_UTI_BIT_MASK)
+                | ((($x) << $crate::MIE_UTI_BIT_OFFSET) & $crate::MIE_UTI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_UEI_BIT_OFFSET:isize   = 8;
+pub const MIE_UEI_BIT_WIDTH:isize    = 1;
+pub const MIE_UEI_BIT_MASK:UintXlen = 0x100;
+pub const MIE_UEI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.uei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_uei!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_uei {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_UEI_BIT_MASK) >> $crate::MIE_UEI_BIT_OFFSET
+    );
+}
+/* mie.uei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_uei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_uei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_UEI_BIT_MASK)
+                | ((($x) << $crate::MIE_UEI_BIT_OFFSET) & $crate::MIE_UEI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_PLATFORM_DEFINED_BIT_OFFSET:isize   = 16;
+pub const MIE_PLATFORM_DEFINED_BIT_WIDTH:isize    = (__riscv_xlen-1)-(16) + 1;
+pub const MIE_PLATFORM_DEFINED_BIT_MASK:UintXlen = (UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1))) << (16);
+pub const MIE_PLATFORM_DEFINED_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(16) + 1));
+/* mie.platform_defined: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_platform_defined!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_platform_defined {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_PLATFORM_DEFINED_BIT_MASK) >> $crate::MIE_PLATFORM_DEFINED_BIT_OFFSET
+    );
+}
+/* mie.platform_defined: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_platform_defined!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_platform_defined {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_PLATFORM_DEFINED_BIT_MASK)
+                | ((($x) << $crate::MIE_PLATFORM_DEFINED_BIT_OFFSET) & $crate::MIE_PLATFORM_DEFINED_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+pub const MIE_LCOFI_BIT_OFFSET:isize   = 13;
+pub const MIE_LCOFI_BIT_WIDTH:isize    = 1;
+pub const MIE_LCOFI_BIT_MASK:UintXlen = 0x2000;
+pub const MIE_LCOFI_ALL_SET_MASK:UintXlen = 0x1;
+/* mie.lcofi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mie_lcofi!();
+ */
+#[macro_export]
+macro_rules! csr_read_mie_lcofi {
+    ( ) => (
+        ($crate::csr_read_mie!() & $crate::MIE_LCOFI_BIT_MASK) >> $crate::MIE_LCOFI_BIT_OFFSET
+    );
+}
+/* mie.lcofi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mie_lcofi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mie_lcofi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mie!();
+            let new_value = (org_value & !$crate::MIE_LCOFI_BIT_MASK)
+                | ((($x) << $crate::MIE_LCOFI_BIT_OFFSET) & $crate::MIE_LCOFI_BIT_MASK);
+            $crate::csr_write_mie!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mcountinhibit - MRW - Machine Counter Inhibit 
+ */
+pub const MCOUNTINHIBIT_CY_BIT_OFFSET:isize   = 0;
+pub const MCOUNTINHIBIT_CY_BIT_WIDTH:isize    = 1;
+pub const MCOUNTINHIBIT_CY_BIT_MASK:UintXlen = 0x1;
+pub const MCOUNTINHIBIT_CY_ALL_SET_MASK:UintXlen = 0x1;
+/* mcountinhibit.cy: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcountinhibit_cy!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcountinhibit_cy {
+    ( ) => (
+        (($crate::csr_read_mcountinhibit!() as $crate::UintXlen) & $crate::MCOUNTINHIBIT_CY_BIT_MASK) >> $crate::MCOUNTINHIBIT_CY_BIT_OFFSET
+    );
+}
+/* mcountinhibit.cy: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcountinhibit_cy!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcountinhibit_cy {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcountinhibit!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCOUNTINHIBIT_CY_BIT_MASK)
+                | ((($x) << $crate::MCOUNTINHIBIT_CY_BIT_OFFSET) & $crate::MCOUNTINHIBIT_CY_BIT_MASK);
+            $crate::csr_write_mcountinhibit!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MCOUNTINHIBIT_IR_BIT_OFFSET:isize   = 2;
+pub const MCOUNTINHIBIT_IR_BIT_WIDTH:isize    = 1;
+pub const MCOUNTINHIBIT_IR_BIT_MASK:UintXlen = 0x4;
+pub const MCOUNTINHIBIT_IR_ALL_SET_MASK:UintXlen = 0x1;
+/* mcountinhibit.ir: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcountinhibit_ir!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcountinhibit_ir {
+    ( ) => (
+        (($crate::csr_read_mcountinhibit!() as $crate::UintXlen) & $crate::MCOUNTINHIBIT_IR_BIT_MASK) >> $crate::MCOUNTINHIBIT_IR_BIT_OFFSET
+    );
+}
+/* mcountinhibit.ir: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcountinhibit_ir!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcountinhibit_ir {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcountinhibit!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCOUNTINHIBIT_IR_BIT_MASK)
+                | ((($x) << $crate::MCOUNTINHIBIT_IR_BIT_OFFSET) & $crate::MCOUNTINHIBIT_IR_BIT_MASK);
+            $crate::csr_write_mcountinhibit!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MCOUNTINHIBIT_HPM_BIT_OFFSET:isize   = 3;
+pub const MCOUNTINHIBIT_HPM_BIT_WIDTH:isize    = 29;
+pub const MCOUNTINHIBIT_HPM_BIT_MASK:UintXlen = 0xfffffff8;
+pub const MCOUNTINHIBIT_HPM_ALL_SET_MASK:UintXlen = 0x1fffffff;
+/* mcountinhibit.hpm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcountinhibit_hpm!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcountinhibit_hpm {
+    ( ) => (
+        (($crate::csr_read_mcountinhibit!() as $crate::UintXlen) & $crate::MCOUNTINHIBIT_HPM_BIT_MASK) >> $crate::MCOUNTINHIBIT_HPM_BIT_OFFSET
+    );
+}
+/* mcountinhibit.hpm: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcountinhibit_hpm!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcountinhibit_hpm {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcountinhibit!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCOUNTINHIBIT_HPM_BIT_MASK)
+                | ((($x) << $crate::MCOUNTINHIBIT_HPM_BIT_OFFSET) & $crate::MCOUNTINHIBIT_HPM_BIT_MASK);
+            $crate::csr_write_mcountinhibit!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+
+/*******************************************
+ * mhpmevent3 - MRW - Event Counter Event Select 
+ */
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT3_EVENT_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT3_EVENT_BIT_WIDTH:isize    = 32;
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT3_EVENT_BIT_MASK:UintXlen = 0xffffffff;
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT3_EVENT_ALL_SET_MASK:UintXlen = 0xffffffff;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_EVENT_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_EVENT_BIT_WIDTH:isize    = 56;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_EVENT_BIT_MASK:UintXlen = 0xffffffffffffff;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_EVENT_ALL_SET_MASK:UintXlen = 0xffffffffffffff;
+/* mhpmevent3.event: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3_event!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent3_event {
+    ( ) => (
+        ($crate::csr_read_mhpmevent3!() & $crate::MHPMEVENT3_EVENT_BIT_MASK) >> $crate::MHPMEVENT3_EVENT_BIT_OFFSET
+    );
+}
+/* mhpmevent3.event: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3_event!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent3_event {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent3!();
+            let new_value = (org_value & !$crate::MHPMEVENT3_EVENT_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3_EVENT_BIT_OFFSET) & $crate::MHPMEVENT3_EVENT_BIT_MASK);
+            $crate::csr_write_mhpmevent3!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_OF_BIT_OFFSET:isize   = 63;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_OF_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_OF_BIT_MASK:UintXlen = 0x8000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_OF_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3.of: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3_of!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent3_of {
+    ( ) => (
+        ($crate::csr_read_mhpmevent3!() & $crate::MHPMEVENT3_OF_BIT_MASK) >> $crate::MHPMEVENT3_OF_BIT_OFFSET
+    );
+}
+/* mhpmevent3.of: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3_of!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent3_of {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent3!();
+            let new_value = (org_value & !$crate::MHPMEVENT3_OF_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3_OF_BIT_OFFSET) & $crate::MHPMEVENT3_OF_BIT_MASK);
+            $crate::csr_write_mhpmevent3!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_MINH_BIT_OFFSET:isize   = 62;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_MINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_MINH_BIT_MASK:UintXlen = 0x4000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3_minh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent3_minh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent3!() & $crate::MHPMEVENT3_MINH_BIT_MASK) >> $crate::MHPMEVENT3_MINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3_minh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent3_minh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent3!();
+            let new_value = (org_value & !$crate::MHPMEVENT3_MINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3_MINH_BIT_OFFSET) & $crate::MHPMEVENT3_MINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_SINH_BIT_OFFSET:isize   = 61;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_SINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_SINH_BIT_MASK:UintXlen = 0x2000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3_sinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent3_sinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent3!() & $crate::MHPMEVENT3_SINH_BIT_MASK) >> $crate::MHPMEVENT3_SINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3_sinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent3_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent3!();
+            let new_value = (org_value & !$crate::MHPMEVENT3_SINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3_SINH_BIT_OFFSET) & $crate::MHPMEVENT3_SINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_UINH_BIT_OFFSET:isize   = 60;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_UINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_UINH_BIT_MASK:UintXlen = 0x1000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3_uinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent3_uinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent3!() & $crate::MHPMEVENT3_UINH_BIT_MASK) >> $crate::MHPMEVENT3_UINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3_uinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent3_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent3!();
+            let new_value = (org_value & !$crate::MHPMEVENT3_UINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3_UINH_BIT_OFFSET) & $crate::MHPMEVENT3_UINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_VSINH_BIT_OFFSET:isize   = 59;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_VSINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_VSINH_BIT_MASK:UintXlen = 0x800000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3_vsinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent3_vsinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent3!() & $crate::MHPMEVENT3_VSINH_BIT_MASK) >> $crate::MHPMEVENT3_VSINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3_vsinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent3_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent3!();
+            let new_value = (org_value & !$crate::MHPMEVENT3_VSINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3_VSINH_BIT_OFFSET) & $crate::MHPMEVENT3_VSINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_VUINH_BIT_OFFSET:isize   = 58;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_VUINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_VUINH_BIT_MASK:UintXlen = 0x400000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT3_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3_vuinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent3_vuinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent3!() & $crate::MHPMEVENT3_VUINH_BIT_MASK) >> $crate::MHPMEVENT3_VUINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3_vuinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent3_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent3!();
+            let new_value = (org_value & !$crate::MHPMEVENT3_VUINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3_VUINH_BIT_OFFSET) & $crate::MHPMEVENT3_VUINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mcounteren - MRW - Counter Enable 
+ */
+pub const MCOUNTEREN_CY_BIT_OFFSET:isize   = 0;
+pub const MCOUNTEREN_CY_BIT_WIDTH:isize    = 1;
+pub const MCOUNTEREN_CY_BIT_MASK:UintXlen = 0x1;
+pub const MCOUNTEREN_CY_ALL_SET_MASK:UintXlen = 0x1;
+/* mcounteren.cy: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcounteren_cy!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcounteren_cy {
+    ( ) => (
+        (($crate::csr_read_mcounteren!() as $crate::UintXlen) & $crate::MCOUNTEREN_CY_BIT_MASK) >> $crate::MCOUNTEREN_CY_BIT_OFFSET
+    );
+}
+/* mcounteren.cy: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcounteren_cy!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcounteren_cy {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcounteren!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCOUNTEREN_CY_BIT_MASK)
+                | ((($x) << $crate::MCOUNTEREN_CY_BIT_OFFSET) & $crate::MCOUNTEREN_CY_BIT_MASK);
+            $crate::csr_write_mcounteren!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MCOUNTEREN_TM_BIT_OFFSET:isize   = 1;
+pub const MCOUNTEREN_TM_BIT_WIDTH:isize    = 1;
+pub const MCOUNTEREN_TM_BIT_MASK:UintXlen = 0x2;
+pub const MCOUNTEREN_TM_ALL_SET_MASK:UintXlen = 0x1;
+/* mcounteren.tm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcounteren_tm!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcounteren_tm {
+    ( ) => (
+        (($crate::csr_read_mcounteren!() as $crate::UintXlen) & $crate::MCOUNTEREN_TM_BIT_MASK) >> $crate::MCOUNTEREN_TM_BIT_OFFSET
+    );
+}
+/* mcounteren.tm: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcounteren_tm!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcounteren_tm {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcounteren!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCOUNTEREN_TM_BIT_MASK)
+                | ((($x) << $crate::MCOUNTEREN_TM_BIT_OFFSET) & $crate::MCOUNTEREN_TM_BIT_MASK);
+            $crate::csr_write_mcounteren!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MCOUNTEREN_IR_BIT_OFFSET:isize   = 2;
+pub const MCOUNTEREN_IR_BIT_WIDTH:isize    = 1;
+pub const MCOUNTEREN_IR_BIT_MASK:UintXlen = 0x4;
+pub const MCOUNTEREN_IR_ALL_SET_MASK:UintXlen = 0x1;
+/* mcounteren.ir: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcounteren_ir!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcounteren_ir {
+    ( ) => (
+        (($crate::csr_read_mcounteren!() as $crate::UintXlen) & $crate::MCOUNTEREN_IR_BIT_MASK) >> $crate::MCOUNTEREN_IR_BIT_OFFSET
+    );
+}
+/* mcounteren.ir: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcounteren_ir!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcounteren_ir {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcounteren!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCOUNTEREN_IR_BIT_MASK)
+                | ((($x) << $crate::MCOUNTEREN_IR_BIT_OFFSET) & $crate::MCOUNTEREN_IR_BIT_MASK);
+            $crate::csr_write_mcounteren!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MCOUNTEREN_HPM_BIT_OFFSET:isize   = 3;
+pub const MCOUNTEREN_HPM_BIT_WIDTH:isize    = 29;
+pub const MCOUNTEREN_HPM_BIT_MASK:UintXlen = 0xfffffff8;
+pub const MCOUNTEREN_HPM_ALL_SET_MASK:UintXlen = 0x1fffffff;
+/* mcounteren.hpm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcounteren_hpm!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcounteren_hpm {
+    ( ) => (
+        (($crate::csr_read_mcounteren!() as $crate::UintXlen) & $crate::MCOUNTEREN_HPM_BIT_MASK) >> $crate::MCOUNTEREN_HPM_BIT_OFFSET
+    );
+}
+/* mcounteren.hpm: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcounteren_hpm!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcounteren_hpm {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcounteren!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCOUNTEREN_HPM_BIT_MASK)
+                | ((($x) << $crate::MCOUNTEREN_HPM_BIT_OFFSET) & $crate::MCOUNTEREN_HPM_BIT_MASK);
+            $crate::csr_write_mcounteren!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+
+/*******************************************
+ * scounteren - SRW - Counter Enable 
+ */
+pub const SCOUNTEREN_CY_BIT_OFFSET:isize   = 0;
+pub const SCOUNTEREN_CY_BIT_WIDTH:isize    = 1;
+pub const SCOUNTEREN_CY_BIT_MASK:UintXlen = 0x1;
+pub const SCOUNTEREN_CY_ALL_SET_MASK:UintXlen = 0x1;
+/* scounteren.cy: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_scounteren_cy!();
+ */
+#[macro_export]
+macro_rules! csr_read_scounteren_cy {
+    ( ) => (
+        (($crate::csr_read_scounteren!() as $crate::UintXlen) & $crate::SCOUNTEREN_CY_BIT_MASK) >> $crate::SCOUNTEREN_CY_BIT_OFFSET
+    );
+}
+/* scounteren.cy: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_scounteren_cy!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_scounteren_cy {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_scounteren!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::SCOUNTEREN_CY_BIT_MASK)
+                | ((($x) << $crate::SCOUNTEREN_CY_BIT_OFFSET) & $crate::SCOUNTEREN_CY_BIT_MASK);
+            $crate::csr_write_scounteren!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const SCOUNTEREN_TM_BIT_OFFSET:isize   = 1;
+pub const SCOUNTEREN_TM_BIT_WIDTH:isize    = 1;
+pub const SCOUNTEREN_TM_BIT_MASK:UintXlen = 0x2;
+pub const SCOUNTEREN_TM_ALL_SET_MASK:UintXlen = 0x1;
+/* scounteren.tm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_scounteren_tm!();
+ */
+#[macro_export]
+macro_rules! csr_read_scounteren_tm {
+    ( ) => (
+        (($crate::csr_read_scounteren!() as $crate::UintXlen) & $crate::SCOUNTEREN_TM_BIT_MASK) >> $crate::SCOUNTEREN_TM_BIT_OFFSET
+    );
+}
+/* scounteren.tm: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_scounteren_tm!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_scounteren_tm {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_scounteren!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::SCOUNTEREN_TM_BIT_MASK)
+                | ((($x) << $crate::SCOUNTEREN_TM_BIT_OFFSET) & $crate::SCOUNTEREN_TM_BIT_MASK);
+            $crate::csr_write_scounteren!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const SCOUNTEREN_IR_BIT_OFFSET:isize   = 2;
+pub const SCOUNTEREN_IR_BIT_WIDTH:isize    = 1;
+pub const SCOUNTEREN_IR_BIT_MASK:UintXlen = 0x4;
+pub const SCOUNTEREN_IR_ALL_SET_MASK:UintXlen = 0x1;
+/* scounteren.ir: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_scounteren_ir!();
+ */
+#[macro_export]
+macro_rules! csr_read_scounteren_ir {
+    ( ) => (
+        (($crate::csr_read_scounteren!() as $crate::UintXlen) & $crate::SCOUNTEREN_IR_BIT_MASK) >> $crate::SCOUNTEREN_IR_BIT_OFFSET
+    );
+}
+/* scounteren.ir: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_scounteren_ir!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_scounteren_ir {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_scounteren!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::SCOUNTEREN_IR_BIT_MASK)
+                | ((($x) << $crate::SCOUNTEREN_IR_BIT_OFFSET) & $crate::SCOUNTEREN_IR_BIT_MASK);
+            $crate::csr_write_scounteren!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const SCOUNTEREN_HPM_BIT_OFFSET:isize   = 3;
+pub const SCOUNTEREN_HPM_BIT_WIDTH:isize    = 29;
+pub const SCOUNTEREN_HPM_BIT_MASK:UintXlen = 0xfffffff8;
+pub const SCOUNTEREN_HPM_ALL_SET_MASK:UintXlen = 0x1fffffff;
+/* scounteren.hpm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_scounteren_hpm!();
+ */
+#[macro_export]
+macro_rules! csr_read_scounteren_hpm {
+    ( ) => (
+        (($crate::csr_read_scounteren!() as $crate::UintXlen) & $crate::SCOUNTEREN_HPM_BIT_MASK) >> $crate::SCOUNTEREN_HPM_BIT_OFFSET
+    );
+}
+/* scounteren.hpm: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_scounteren_hpm!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_scounteren_hpm {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_scounteren!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::SCOUNTEREN_HPM_BIT_MASK)
+                | ((($x) << $crate::SCOUNTEREN_HPM_BIT_OFFSET) & $crate::SCOUNTEREN_HPM_BIT_MASK);
+            $crate::csr_write_scounteren!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+
+/*******************************************
+ * mcause - MRW - Machine Exception Cause 
+ */
+pub const MCAUSE_INTERRUPT_BIT_OFFSET:isize   = __riscv_xlen-1;
+pub const MCAUSE_INTERRUPT_BIT_WIDTH:isize    = 1;
+pub const MCAUSE_INTERRUPT_BIT_MASK:UintXlen = 0x1 << (__riscv_xlen-1);
+pub const MCAUSE_INTERRUPT_ALL_SET_MASK:UintXlen = 0x1;
+/* mcause.interrupt: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcause_interrupt!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcause_interrupt {
+    ( ) => (
+        ($crate::csr_read_mcause!() & $crate::MCAUSE_INTERRUPT_BIT_MASK) >> $crate::MCAUSE_INTERRUPT_BIT_OFFSET
+    );
+}
+/* mcause.interrupt: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcause_interrupt!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcause_interrupt {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mcause!();
+            let new_value = (org_value & !$crate::MCAUSE_INTERRUPT_BIT_MASK)
+                | ((($x) << $crate::MCAUSE_INTERRUPT_BIT_OFFSET) & $crate::MCAUSE_INTERRUPT_BIT_MASK);
+            $crate::csr_write_mcause!(new_value);
+        }
+    );
+}
+pub const MCAUSE_EXCEPTION_CODE_BIT_OFFSET:isize   = 0;
+pub const MCAUSE_EXCEPTION_CODE_BIT_WIDTH:isize    = (__riscv_xlen-2) + 1;
+pub const MCAUSE_EXCEPTION_CODE_BIT_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-2) + 1));
+pub const MCAUSE_EXCEPTION_CODE_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-2) + 1));
+/* mcause.exception_code: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcause_exception_code!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcause_exception_code {
+    ( ) => (
+        ($crate::csr_read_mcause!() & $crate::MCAUSE_EXCEPTION_CODE_BIT_MASK) >> $crate::MCAUSE_EXCEPTION_CODE_BIT_OFFSET
+    );
+}
+/* mcause.exception_code: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcause_exception_code!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcause_exception_code {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mcause!();
+            let new_value = (org_value & !$crate::MCAUSE_EXCEPTION_CODE_BIT_MASK)
+                | ((($x) << $crate::MCAUSE_EXCEPTION_CODE_BIT_OFFSET) & $crate::MCAUSE_EXCEPTION_CODE_BIT_MASK);
+            $crate::csr_write_mcause!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * scause - SRW - Supervisor Exception Cause 
+ */
+pub const SCAUSE_INTERRUPT_BIT_OFFSET:isize   = __riscv_xlen-1;
+pub const SCAUSE_INTERRUPT_BIT_WIDTH:isize    = 1;
+pub const SCAUSE_INTERRUPT_BIT_MASK:UintXlen = 0x1 << (__riscv_xlen-1);
+pub const SCAUSE_INTERRUPT_ALL_SET_MASK:UintXlen = 0x1;
+/* scause.interrupt: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_scause_interrupt!();
+ */
+#[macro_export]
+macro_rules! csr_read_scause_interrupt {
+    ( ) => (
+        ($crate::csr_read_scause!() & $crate::SCAUSE_INTERRUPT_BIT_MASK) >> $crate::SCAUSE_INTERRUPT_BIT_OFFSET
+    );
+}
+/* scause.interrupt: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_scause_interrupt!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_scause_interrupt {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_scause!();
+            let new_value = (org_value & !$crate::SCAUSE_INTERRUPT_BIT_MASK)
+                | ((($x) << $crate::SCAUSE_INTERRUPT_BIT_OFFSET) & $crate::SCAUSE_INTERRUPT_BIT_MASK);
+            $crate::csr_write_scause!(new_value);
+        }
+    );
+}
+pub const SCAUSE_EXCEPTION_CODE_BIT_OFFSET:isize   = 0;
+pub const SCAUSE_EXCEPTION_CODE_BIT_WIDTH:isize    = (__riscv_xlen-2) + 1;
+pub const SCAUSE_EXCEPTION_CODE_BIT_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-2) + 1));
+pub const SCAUSE_EXCEPTION_CODE_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-2) + 1));
+/* scause.exception_code: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_scause_exception_code!();
+ */
+#[macro_export]
+macro_rules! csr_read_scause_exception_code {
+    ( ) => (
+        ($crate::csr_read_scause!() & $crate::SCAUSE_EXCEPTION_CODE_BIT_MASK) >> $crate::SCAUSE_EXCEPTION_CODE_BIT_OFFSET
+    );
+}
+/* scause.exception_code: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_scause_exception_code!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_scause_exception_code {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_scause!();
+            let new_value = (org_value & !$crate::SCAUSE_EXCEPTION_CODE_BIT_MASK)
+                | ((($x) << $crate::SCAUSE_EXCEPTION_CODE_BIT_OFFSET) & $crate::SCAUSE_EXCEPTION_CODE_BIT_MASK);
+            $crate::csr_write_scause!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * sstatus - SRW - Supervisor Status 
+ */
+pub const SSTATUS_SIE_BIT_OFFSET:isize   = 1;
+pub const SSTATUS_SIE_BIT_WIDTH:isize    = 1;
+pub const SSTATUS_SIE_BIT_MASK:UintXlen = 0x2;
+pub const SSTATUS_SIE_ALL_SET_MASK:UintXlen = 0x1;
+/* sstatus.sie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_sie!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_sie {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_SIE_BIT_MASK) >> $crate::SSTATUS_SIE_BIT_OFFSET
+    );
+}
+/* sstatus.sie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_sie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_sie {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_SIE_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_SIE_BIT_OFFSET) & $crate::SSTATUS_SIE_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_SPIE_BIT_OFFSET:isize   = 5;
+pub const SSTATUS_SPIE_BIT_WIDTH:isize    = 1;
+pub const SSTATUS_SPIE_BIT_MASK:UintXlen = 0x20;
+pub const SSTATUS_SPIE_ALL_SET_MASK:UintXlen = 0x1;
+/* sstatus.spie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_spie!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_spie {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_SPIE_BIT_MASK) >> $crate::SSTATUS_SPIE_BIT_OFFSET
+    );
+}
+/* sstatus.spie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_spie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_spie {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_SPIE_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_SPIE_BIT_OFFSET) & $crate::SSTATUS_SPIE_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_SPP_BIT_OFFSET:isize   = 8;
+pub const SSTATUS_SPP_BIT_WIDTH:isize    = 1;
+pub const SSTATUS_SPP_BIT_MASK:UintXlen = 0x100;
+pub const SSTATUS_SPP_ALL_SET_MASK:UintXlen = 0x1;
+/* sstatus.spp: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_spp!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_spp {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_SPP_BIT_MASK) >> $crate::SSTATUS_SPP_BIT_OFFSET
+    );
+}
+/* sstatus.spp: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_spp!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_spp {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_SPP_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_SPP_BIT_OFFSET) & $crate::SSTATUS_SPP_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_UBE_BIT_OFFSET:isize   = 6;
+pub const SSTATUS_UBE_BIT_WIDTH:isize    = 1;
+pub const SSTATUS_UBE_BIT_MASK:UintXlen = 0x40;
+pub const SSTATUS_UBE_ALL_SET_MASK:UintXlen = 0x1;
+/* sstatus.ube: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_ube!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_ube {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_UBE_BIT_MASK) >> $crate::SSTATUS_UBE_BIT_OFFSET
+    );
+}
+/* sstatus.ube: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_ube!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_ube {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_UBE_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_UBE_BIT_OFFSET) & $crate::SSTATUS_UBE_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_VS_BIT_OFFSET:isize   = 9;
+pub const SSTATUS_VS_BIT_WIDTH:isize    = 2;
+pub const SSTATUS_VS_BIT_MASK:UintXlen = 0x600;
+pub const SSTATUS_VS_ALL_SET_MASK:UintXlen = 0x3;
+/* sstatus.vs: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_vs!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_vs {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_VS_BIT_MASK) >> $crate::SSTATUS_VS_BIT_OFFSET
+    );
+}
+/* sstatus.vs: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_vs!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_vs {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_VS_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_VS_BIT_OFFSET) & $crate::SSTATUS_VS_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_FS_BIT_OFFSET:isize   = 13;
+pub const SSTATUS_FS_BIT_WIDTH:isize    = 2;
+pub const SSTATUS_FS_BIT_MASK:UintXlen = 0x6000;
+pub const SSTATUS_FS_ALL_SET_MASK:UintXlen = 0x3;
+/* sstatus.fs: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_fs!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_fs {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_FS_BIT_MASK) >> $crate::SSTATUS_FS_BIT_OFFSET
+    );
+}
+/* sstatus.fs: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_fs!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_fs {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_FS_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_FS_BIT_OFFSET) & $crate::SSTATUS_FS_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_XS_BIT_OFFSET:isize   = 15;
+pub const SSTATUS_XS_BIT_WIDTH:isize    = 2;
+pub const SSTATUS_XS_BIT_MASK:UintXlen = 0x18000;
+pub const SSTATUS_XS_ALL_SET_MASK:UintXlen = 0x3;
+/* sstatus.xs: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_xs!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_xs {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_XS_BIT_MASK) >> $crate::SSTATUS_XS_BIT_OFFSET
+    );
+}
+/* sstatus.xs: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_xs!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_xs {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_XS_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_XS_BIT_OFFSET) & $crate::SSTATUS_XS_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_SUM_BIT_OFFSET:isize   = 18;
+pub const SSTATUS_SUM_BIT_WIDTH:isize    = 1;
+pub const SSTATUS_SUM_BIT_MASK:UintXlen = 0x40000;
+pub const SSTATUS_SUM_ALL_SET_MASK:UintXlen = 0x1;
+/* sstatus.sum: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_sum!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_sum {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_SUM_BIT_MASK) >> $crate::SSTATUS_SUM_BIT_OFFSET
+    );
+}
+/* sstatus.sum: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_sum!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_sum {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_SUM_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_SUM_BIT_OFFSET) & $crate::SSTATUS_SUM_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_MXR_BIT_OFFSET:isize   = 19;
+pub const SSTATUS_MXR_BIT_WIDTH:isize    = 1;
+pub const SSTATUS_MXR_BIT_MASK:UintXlen = 0x80000;
+pub const SSTATUS_MXR_ALL_SET_MASK:UintXlen = 0x1;
+/* sstatus.mxr: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_mxr!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_mxr {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_MXR_BIT_MASK) >> $crate::SSTATUS_MXR_BIT_OFFSET
+    );
+}
+/* sstatus.mxr: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_mxr!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_mxr {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_MXR_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_MXR_BIT_OFFSET) & $crate::SSTATUS_MXR_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_SPELP_BIT_OFFSET:isize   = 23;
+pub const SSTATUS_SPELP_BIT_WIDTH:isize    = 1;
+pub const SSTATUS_SPELP_BIT_MASK:UintXlen = 0x800000;
+pub const SSTATUS_SPELP_ALL_SET_MASK:UintXlen = 0x1;
+/* sstatus.spelp: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_spelp!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_spelp {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_SPELP_BIT_MASK) >> $crate::SSTATUS_SPELP_BIT_OFFSET
+    );
+}
+/* sstatus.spelp: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_spelp!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_spelp {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_SPELP_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_SPELP_BIT_OFFSET) & $crate::SSTATUS_SPELP_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_SDT_BIT_OFFSET:isize   = 24;
+pub const SSTATUS_SDT_BIT_WIDTH:isize    = 1;
+pub const SSTATUS_SDT_BIT_MASK:UintXlen = 0x1000000;
+pub const SSTATUS_SDT_ALL_SET_MASK:UintXlen = 0x1;
+/* sstatus.sdt: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_sdt!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_sdt {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_SDT_BIT_MASK) >> $crate::SSTATUS_SDT_BIT_OFFSET
+    );
+}
+/* sstatus.sdt: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_sdt!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_sdt {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_SDT_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_SDT_BIT_OFFSET) & $crate::SSTATUS_SDT_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const SSTATUS_UXL_BIT_OFFSET:isize   = 32;
+#[cfg(target_pointer_width = "64")]
+pub const SSTATUS_UXL_BIT_WIDTH:isize    = 2;
+#[cfg(target_pointer_width = "64")]
+pub const SSTATUS_UXL_BIT_MASK:UintXlen = 0x300000000;
+#[cfg(target_pointer_width = "64")]
+pub const SSTATUS_UXL_ALL_SET_MASK:UintXlen = 0x3;
+/* sstatus.uxl: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_uxl!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_sstatus_uxl {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_UXL_BIT_MASK) >> $crate::SSTATUS_UXL_BIT_OFFSET
+    );
+}
+/* sstatus.uxl: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_uxl!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_sstatus_uxl {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_UXL_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_UXL_BIT_OFFSET) & $crate::SSTATUS_UXL_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+pub const SSTATUS_SD_BIT_OFFSET:isize   = __riscv_xlen-1;
+pub const SSTATUS_SD_BIT_WIDTH:isize    = 1;
+pub const SSTATUS_SD_BIT_MASK:UintXlen = 0x1 << (__riscv_xlen-1);
+pub const SSTATUS_SD_ALL_SET_MASK:UintXlen = 0x1;
+/* sstatus.sd: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sstatus_sd!();
+ */
+#[macro_export]
+macro_rules! csr_read_sstatus_sd {
+    ( ) => (
+        ($crate::csr_read_sstatus!() & $crate::SSTATUS_SD_BIT_MASK) >> $crate::SSTATUS_SD_BIT_OFFSET
+    );
+}
+/* sstatus.sd: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sstatus_sd!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sstatus_sd {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sstatus!();
+            let new_value = (org_value & !$crate::SSTATUS_SD_BIT_MASK)
+                | ((($x) << $crate::SSTATUS_SD_BIT_OFFSET) & $crate::SSTATUS_SD_BIT_MASK);
+            $crate::csr_write_sstatus!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * stvec - SRW - Supervisor Trap Vector Base Address 
+ */
+pub const STVEC_BASE_BIT_OFFSET:isize   = 2;
+pub const STVEC_BASE_BIT_WIDTH:isize    = (__riscv_xlen-1)-(2) + 1;
+pub const STVEC_BASE_BIT_MASK:UintXlen = (UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (2);
+pub const STVEC_BASE_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1));
+/* stvec.base: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_stvec_base!();
+ */
+#[macro_export]
+macro_rules! csr_read_stvec_base {
+    ( ) => (
+        ($crate::csr_read_stvec!() & $crate::STVEC_BASE_BIT_MASK) >> $crate::STVEC_BASE_BIT_OFFSET
+    );
+}
+/* stvec.base: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_stvec_base!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_stvec_base {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_stvec!();
+            let new_value = (org_value & !$crate::STVEC_BASE_BIT_MASK)
+                | ((($x) << $crate::STVEC_BASE_BIT_OFFSET) & $crate::STVEC_BASE_BIT_MASK);
+            $crate::csr_write_stvec!(new_value);
+        }
+    );
+}
+pub const STVEC_MODE_BIT_OFFSET:isize   = 0;
+pub const STVEC_MODE_BIT_WIDTH:isize    = 2;
+pub const STVEC_MODE_BIT_MASK:UintXlen = 0x3;
+pub const STVEC_MODE_ALL_SET_MASK:UintXlen = 0x3;
+/* stvec.mode: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_stvec_mode!();
+ */
+#[macro_export]
+macro_rules! csr_read_stvec_mode {
+    ( ) => (
+        ($crate::csr_read_stvec!() & $crate::STVEC_MODE_BIT_MASK) >> $crate::STVEC_MODE_BIT_OFFSET
+    );
+}
+/* stvec.mode: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_stvec_mode!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_stvec_mode {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_stvec!();
+            let new_value = (org_value & !$crate::STVEC_MODE_BIT_MASK)
+                | ((($x) << $crate::STVEC_MODE_BIT_OFFSET) & $crate::STVEC_MODE_BIT_MASK);
+            $crate::csr_write_stvec!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * sip - SRW - Supervisor Interrupt Pending 
+ */
+pub const SIP_SSI_BIT_OFFSET:isize   = 1;
+pub const SIP_SSI_BIT_WIDTH:isize    = 1;
+pub const SIP_SSI_BIT_MASK:UintXlen = 0x2;
+pub const SIP_SSI_ALL_SET_MASK:UintXlen = 0x1;
+/* sip.ssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sip_ssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_sip_ssi {
+    ( ) => (
+        ($crate::csr_read_sip!() & $crate::SIP_SSI_BIT_MASK) >> $crate::SIP_SSI_BIT_OFFSET
+    );
+}
+/* sip.ssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sip_ssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sip_ssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sip!();
+            let new_value = (org_value & !$crate::SIP_SSI_BIT_MASK)
+                | ((($x) << $crate::SIP_SSI_BIT_OFFSET) & $crate::SIP_SSI_BIT_MASK);
+            $crate::csr_write_sip!(new_value);
+        }
+    );
+}
+pub const SIP_STI_BIT_OFFSET:isize   = 5;
+pub const SIP_STI_BIT_WIDTH:isize    = 1;
+pub const SIP_STI_BIT_MASK:UintXlen = 0x20;
+pub const SIP_STI_ALL_SET_MASK:UintXlen = 0x1;
+/* sip.sti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sip_sti!();
+ */
+#[macro_export]
+macro_rules! csr_read_sip_sti {
+    ( ) => (
+        ($crate::csr_read_sip!() & $crate::SIP_STI_BIT_MASK) >> $crate::SIP_STI_BIT_OFFSET
+    );
+}
+/* sip.sti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sip_sti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sip_sti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sip!();
+            let new_value = (org_value & !$crate::SIP_STI_BIT_MASK)
+                | ((($x) << $crate::SIP_STI_BIT_OFFSET) & $crate::SIP_STI_BIT_MASK);
+            $crate::csr_write_sip!(new_value);
+        }
+    );
+}
+pub const SIP_SEI_BIT_OFFSET:isize   = 9;
+pub const SIP_SEI_BIT_WIDTH:isize    = 1;
+pub const SIP_SEI_BIT_MASK:UintXlen = 0x200;
+pub const SIP_SEI_ALL_SET_MASK:UintXlen = 0x1;
+/* sip.sei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sip_sei!();
+ */
+#[macro_export]
+macro_rules! csr_read_sip_sei {
+    ( ) => (
+        ($crate::csr_read_sip!() & $crate::SIP_SEI_BIT_MASK) >> $crate::SIP_SEI_BIT_OFFSET
+    );
+}
+/* sip.sei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sip_sei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sip_sei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sip!();
+            let new_value = (org_value & !$crate::SIP_SEI_BIT_MASK)
+                | ((($x) << $crate::SIP_SEI_BIT_OFFSET) & $crate::SIP_SEI_BIT_MASK);
+            $crate::csr_write_sip!(new_value);
+        }
+    );
+}
+pub const SIP_USI_BIT_OFFSET:isize   = 0;
+pub const SIP_USI_BIT_WIDTH:isize    = 1;
+pub const SIP_USI_BIT_MASK:UintXlen = 0x1;
+pub const SIP_USI_ALL_SET_MASK:UintXlen = 0x1;
+/* sip.usi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sip_usi!();
+ */
+#[macro_export]
+macro_rules! csr_read_sip_usi {
+    ( ) => (
+        ($crate::csr_read_sip!() & $crate::SIP_USI_BIT_MASK) >> $crate::SIP_USI_BIT_OFFSET
+    );
+}
+/* sip.usi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sip_usi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sip_usi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sip!();
+            let new_value = (org_value & !$crate::SIP_USI_BIT_MASK)
+                | ((($x) << $crate::SIP_USI_BIT_OFFSET) & $crate::SIP_USI_BIT_MASK);
+            $crate::csr_write_sip!(new_value);
+        }
+    );
+}
+pub const SIP_UTI_BIT_OFFSET:isize   = 4;
+pub const SIP_UTI_BIT_WIDTH:isize    = 1;
+pub const SIP_UTI_BIT_MASK:UintXlen = 0x10;
+pub const SIP_UTI_ALL_SET_MASK:UintXlen = 0x1;
+/* sip.uti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sip_uti!();
+ */
+#[macro_export]
+macro_rules! csr_read_sip_uti {
+    ( ) => (
+        ($crate::csr_read_sip!() & $crate::SIP_UTI_BIT_MASK) >> $crate::SIP_UTI_BIT_OFFSET
+    );
+}
+/* sip.uti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sip_uti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sip_uti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sip!();
+            let new_value = (org_value & !$crate::SIP_UTI_BIT_MASK)
+                | ((($x) << $crate::SIP_UTI_BIT_OFFSET) & $crate::SIP_UTI_BIT_MASK);
+            $crate::csr_write_sip!(new_value);
+        }
+    );
+}
+pub const SIP_UEI_BIT_OFFSET:isize   = 8;
+pub const SIP_UEI_BIT_WIDTH:isize    = 1;
+pub const SIP_UEI_BIT_MASK:UintXlen = 0x100;
+pub const SIP_UEI_ALL_SET_MASK:UintXlen = 0x1;
+/* sip.uei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sip_uei!();
+ */
+#[macro_export]
+macro_rules! csr_read_sip_uei {
+    ( ) => (
+        ($crate::csr_read_sip!() & $crate::SIP_UEI_BIT_MASK) >> $crate::SIP_UEI_BIT_OFFSET
+    );
+}
+/* sip.uei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sip_uei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sip_uei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sip!();
+            let new_value = (org_value & !$crate::SIP_UEI_BIT_MASK)
+                | ((($x) << $crate::SIP_UEI_BIT_OFFSET) & $crate::SIP_UEI_BIT_MASK);
+            $crate::csr_write_sip!(new_value);
+        }
+    );
+}
+pub const SIP_LCOFI_BIT_OFFSET:isize   = 13;
+pub const SIP_LCOFI_BIT_WIDTH:isize    = 1;
+pub const SIP_LCOFI_BIT_MASK:UintXlen = 0x2000;
+pub const SIP_LCOFI_ALL_SET_MASK:UintXlen = 0x1;
+/* sip.lcofi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sip_lcofi!();
+ */
+#[macro_export]
+macro_rules! csr_read_sip_lcofi {
+    ( ) => (
+        ($crate::csr_read_sip!() & $crate::SIP_LCOFI_BIT_MASK) >> $crate::SIP_LCOFI_BIT_OFFSET
+    );
+}
+/* sip.lcofi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sip_lcofi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sip_lcofi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sip!();
+            let new_value = (org_value & !$crate::SIP_LCOFI_BIT_MASK)
+                | ((($x) << $crate::SIP_LCOFI_BIT_OFFSET) & $crate::SIP_LCOFI_BIT_MASK);
+            $crate::csr_write_sip!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * sie - SRW - Supervisor Interrupt Enable 
+ */
+pub const SIE_SSI_BIT_OFFSET:isize   = 1;
+pub const SIE_SSI_BIT_WIDTH:isize    = 1;
+pub const SIE_SSI_BIT_MASK:UintXlen = 0x2;
+pub const SIE_SSI_ALL_SET_MASK:UintXlen = 0x1;
+/* sie.ssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sie_ssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_sie_ssi {
+    ( ) => (
+        ($crate::csr_read_sie!() & $crate::SIE_SSI_BIT_MASK) >> $crate::SIE_SSI_BIT_OFFSET
+    );
+}
+/* sie.ssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sie_ssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sie_ssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sie!();
+            let new_value = (org_value & !$crate::SIE_SSI_BIT_MASK)
+                | ((($x) << $crate::SIE_SSI_BIT_OFFSET) & $crate::SIE_SSI_BIT_MASK);
+            $crate::csr_write_sie!(new_value);
+        }
+    );
+}
+pub const SIE_STI_BIT_OFFSET:isize   = 5;
+pub const SIE_STI_BIT_WIDTH:isize    = 1;
+pub const SIE_STI_BIT_MASK:UintXlen = 0x20;
+pub const SIE_STI_ALL_SET_MASK:UintXlen = 0x1;
+/* sie.sti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sie_sti!();
+ */
+#[macro_export]
+macro_rules! csr_read_sie_sti {
+    ( ) => (
+        ($crate::csr_read_sie!() & $crate::SIE_STI_BIT_MASK) >> $crate::SIE_STI_BIT_OFFSET
+    );
+}
+/* sie.sti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sie_sti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sie_sti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sie!();
+            let new_value = (org_value & !$crate::SIE_STI_BIT_MASK)
+                | ((($x) << $crate::SIE_STI_BIT_OFFSET) & $crate::SIE_STI_BIT_MASK);
+            $crate::csr_write_sie!(new_value);
+        }
+    );
+}
+pub const SIE_SEI_BIT_OFFSET:isize   = 9;
+pub const SIE_SEI_BIT_WIDTH:isize    = 1;
+pub const SIE_SEI_BIT_MASK:UintXlen = 0x200;
+pub const SIE_SEI_ALL_SET_MASK:UintXlen = 0x1;
+/* sie.sei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sie_sei!();
+ */
+#[macro_export]
+macro_rules! csr_read_sie_sei {
+    ( ) => (
+        ($crate::csr_read_sie!() & $crate::SIE_SEI_BIT_MASK) >> $crate::SIE_SEI_BIT_OFFSET
+    );
+}
+/* sie.sei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sie_sei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sie_sei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sie!();
+            let new_value = (org_value & !$crate::SIE_SEI_BIT_MASK)
+                | ((($x) << $crate::SIE_SEI_BIT_OFFSET) & $crate::SIE_SEI_BIT_MASK);
+            $crate::csr_write_sie!(new_value);
+        }
+    );
+}
+pub const SIE_USI_BIT_OFFSET:isize   = 0;
+pub const SIE_USI_BIT_WIDTH:isize    = 1;
+pub const SIE_USI_BIT_MASK:UintXlen = 0x1;
+pub const SIE_USI_ALL_SET_MASK:UintXlen = 0x1;
+/* sie.usi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sie_usi!();
+ */
+#[macro_export]
+macro_rules! csr_read_sie_usi {
+    ( ) => (
+        ($crate::csr_read_sie!() & $crate::SIE_USI_BIT_MASK) >> $crate::SIE_USI_BIT_OFFSET
+    );
+}
+/* sie.usi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sie_usi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sie_usi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sie!();
+            let new_value = (org_value & !$crate::SIE_USI_BIT_MASK)
+                | ((($x) << $crate::SIE_USI_BIT_OFFSET) & $crate::SIE_USI_BIT_MASK);
+            $crate::csr_write_sie!(new_value);
+        }
+    );
+}
+pub const SIE_UTI_BIT_OFFSET:isize   = 4;
+pub const SIE_UTI_BIT_WIDTH:isize    = 1;
+pub const SIE_UTI_BIT_MASK:UintXlen = 0x10;
+pub const SIE_UTI_ALL_SET_MASK:UintXlen = 0x1;
+/* sie.uti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sie_uti!();
+ */
+#[macro_export]
+macro_rules! csr_read_sie_uti {
+    ( ) => (
+        ($crate::csr_read_sie!() & $crate::SIE_UTI_BIT_MASK) >> $crate::SIE_UTI_BIT_OFFSET
+    );
+}
+/* sie.uti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sie_uti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sie_uti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sie!();
+            let new_value = (org_value & !$crate::SIE_UTI_BIT_MASK)
+                | ((($x) << $crate::SIE_UTI_BIT_OFFSET) & $crate::SIE_UTI_BIT_MASK);
+            $crate::csr_write_sie!(new_value);
+        }
+    );
+}
+pub const SIE_UEI_BIT_OFFSET:isize   = 8;
+pub const SIE_UEI_BIT_WIDTH:isize    = 1;
+pub const SIE_UEI_BIT_MASK:UintXlen = 0x100;
+pub const SIE_UEI_ALL_SET_MASK:UintXlen = 0x1;
+/* sie.uei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sie_uei!();
+ */
+#[macro_export]
+macro_rules! csr_read_sie_uei {
+    ( ) => (
+        ($crate::csr_read_sie!() & $crate::SIE_UEI_BIT_MASK) >> $crate::SIE_UEI_BIT_OFFSET
+    );
+}
+/* sie.uei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sie_uei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sie_uei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sie!();
+            let new_value = (org_value & !$crate::SIE_UEI_BIT_MASK)
+                | ((($x) << $crate::SIE_UEI_BIT_OFFSET) & $crate::SIE_UEI_BIT_MASK);
+            $crate::csr_write_sie!(new_value);
+        }
+    );
+}
+pub const SIE_LCOFI_BIT_OFFSET:isize   = 13;
+pub const SIE_LCOFI_BIT_WIDTH:isize    = 1;
+pub const SIE_LCOFI_BIT_MASK:UintXlen = 0x2000;
+pub const SIE_LCOFI_ALL_SET_MASK:UintXlen = 0x1;
+/* sie.lcofi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_sie_lcofi!();
+ */
+#[macro_export]
+macro_rules! csr_read_sie_lcofi {
+    ( ) => (
+        ($crate::csr_read_sie!() & $crate::SIE_LCOFI_BIT_MASK) >> $crate::SIE_LCOFI_BIT_OFFSET
+    );
+}
+/* sie.lcofi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_sie_lcofi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_sie_lcofi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_sie!();
+            let new_value = (org_value & !$crate::SIE_LCOFI_BIT_MASK)
+                | ((($x) << $crate::SIE_LCOFI_BIT_OFFSET) & $crate::SIE_LCOFI_BIT_MASK);
+            $crate::csr_write_sie!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * ustatus - URW - User mode restricted view of mstatus 
+ */
+pub const USTATUS_UIE_BIT_OFFSET:isize   = 1;
+pub const USTATUS_UIE_BIT_WIDTH:isize    = 1;
+pub const USTATUS_UIE_BIT_MASK:UintXlen = 0x2;
+pub const USTATUS_UIE_ALL_SET_MASK:UintXlen = 0x1;
+/* ustatus.uie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_ustatus_uie!();
+ */
+#[macro_export]
+macro_rules! csr_read_ustatus_uie {
+    ( ) => (
+        ($crate::csr_read_ustatus!() & $crate::USTATUS_UIE_BIT_MASK) >> $crate::USTATUS_UIE_BIT_OFFSET
+    );
+}
+/* ustatus.uie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_ustatus_uie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_ustatus_uie {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_ustatus!();
+            let new_value = (org_value & !$crate::USTATUS_UIE_BIT_MASK)
+                | ((($x) << $crate::USTATUS_UIE_BIT_OFFSET) & $crate::USTATUS_UIE_BIT_MASK);
+            $crate::csr_write_ustatus!(new_value);
+        }
+    );
+}
+pub const USTATUS_UPIE_BIT_OFFSET:isize   = 3;
+pub const USTATUS_UPIE_BIT_WIDTH:isize    = 1;
+pub const USTATUS_UPIE_BIT_MASK:UintXlen = 0x8;
+pub const USTATUS_UPIE_ALL_SET_MASK:UintXlen = 0x1;
+/* ustatus.upie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_ustatus_upie!();
+ */
+#[macro_export]
+macro_rules! csr_read_ustatus_upie {
+    ( ) => (
+        ($crate::csr_read_ustatus!() & $crate::USTATUS_UPIE_BIT_MASK) >> $crate::USTATUS_UPIE_BIT_OFFSET
+    );
+}
+/* ustatus.upie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_ustatus_upie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_ustatus_upie {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_ustatus!();
+            let new_value = (org_value & !$crate::USTATUS_UPIE_BIT_MASK)
+                | ((($x) << $crate::USTATUS_UPIE_BIT_OFFSET) & $crate::USTATUS_UPIE_BIT_MASK);
+            $crate::csr_write_ustatus!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * uip - URW - User Interrupt Pending 
+ */
+pub const UIP_USI_BIT_OFFSET:isize   = 0;
+pub const UIP_USI_BIT_WIDTH:isize    = 1;
+pub const UIP_USI_BIT_MASK:UintXlen = 0x1;
+pub const UIP_USI_ALL_SET_MASK:UintXlen = 0x1;
+/* uip.usi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_uip_usi!();
+ */
+#[macro_export]
+macro_rules! csr_read_uip_usi {
+    ( ) => (
+        ($crate::csr_read_uip!() & $crate::UIP_USI_BIT_MASK) >> $crate::UIP_USI_BIT_OFFSET
+    );
+}
+/* uip.usi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_uip_usi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_uip_usi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_uip!();
+            let new_value = (org_value & !$crate::UIP_USI_BIT_MASK)
+                | ((($x) << $crate::UIP_USI_BIT_OFFSET) & $crate::UIP_USI_BIT_MASK);
+            $crate::csr_write_uip!(new_value);
+        }
+    );
+}
+pub const UIP_UTI_BIT_OFFSET:isize   = 4;
+pub const UIP_UTI_BIT_WIDTH:isize    = 1;
+pub const UIP_UTI_BIT_MASK:UintXlen = 0x10;
+pub const UIP_UTI_ALL_SET_MASK:UintXlen = 0x1;
+/* uip.uti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_uip_uti!();
+ */
+#[macro_export]
+macro_rules! csr_read_uip_uti {
+    ( ) => (
+        ($crate::csr_read_uip!() & $crate::UIP_UTI_BIT_MASK) >> $crate::UIP_UTI_BIT_OFFSET
+    );
+}
+/* uip.uti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_uip_uti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_uip_uti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_uip!();
+            let new_value = (org_value & !$crate::UIP_UTI_BIT_MASK)
+                | ((($x) << $crate::UIP_UTI_BIT_OFFSET) & $crate::UIP_UTI_BIT_MASK);
+            $crate::csr_write_uip!(new_value);
+        }
+    );
+}
+pub const UIP_UEI_BIT_OFFSET:isize   = 8;
+pub const UIP_UEI_BIT_WIDTH:isize    = 1;
+pub const UIP_UEI_BIT_MASK:UintXlen = 0x100;
+pub const UIP_UEI_ALL_SET_MASK:UintXlen = 0x1;
+/* uip.uei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_uip_uei!();
+ */
+#[macro_export]
+macro_rules! csr_read_uip_uei {
+    ( ) => (
+        ($crate::csr_read_uip!() & $crate::UIP_UEI_BIT_MASK) >> $crate::UIP_UEI_BIT_OFFSET
+    );
+}
+/* uip.uei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_uip_uei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_uip_uei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_uip!();
+            let new_value = (org_value & !$crate::UIP_UEI_BIT_MASK)
+                | ((($x) << $crate::UIP_UEI_BIT_OFFSET) & $crate::UIP_UEI_BIT_MASK);
+            $crate::csr_write_uip!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * uie - URW - User Interrupt Enable 
+ */
+pub const UIE_USI_BIT_OFFSET:isize   = 0;
+pub const UIE_USI_BIT_WIDTH:isize    = 1;
+pub const UIE_USI_BIT_MASK:UintXlen = 0x1;
+pub const UIE_USI_ALL_SET_MASK:UintXlen = 0x1;
+/* uie.usi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_uie_usi!();
+ */
+#[macro_export]
+macro_rules! csr_read_uie_usi {
+    ( ) => (
+        ($crate::csr_read_uie!() & $crate::UIE_USI_BIT_MASK) >> $crate::UIE_USI_BIT_OFFSET
+    );
+}
+/* uie.usi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_uie_usi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_uie_usi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_uie!();
+            let new_value = (org_value & !$crate::UIE_USI_BIT_MASK)
+                | ((($x) << $crate::UIE_USI_BIT_OFFSET) & $crate::UIE_USI_BIT_MASK);
+            $crate::csr_write_uie!(new_value);
+        }
+    );
+}
+pub const UIE_UTI_BIT_OFFSET:isize   = 4;
+pub const UIE_UTI_BIT_WIDTH:isize    = 1;
+pub const UIE_UTI_BIT_MASK:UintXlen = 0x10;
+pub const UIE_UTI_ALL_SET_MASK:UintXlen = 0x1;
+/* uie.uti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_uie_uti!();
+ */
+#[macro_export]
+macro_rules! csr_read_uie_uti {
+    ( ) => (
+        ($crate::csr_read_uie!() & $crate::UIE_UTI_BIT_MASK) >> $crate::UIE_UTI_BIT_OFFSET
+    );
+}
+/* uie.uti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_uie_uti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_uie_uti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_uie!();
+            let new_value = (org_value & !$crate::UIE_UTI_BIT_MASK)
+                | ((($x) << $crate::UIE_UTI_BIT_OFFSET) & $crate::UIE_UTI_BIT_MASK);
+            $crate::csr_write_uie!(new_value);
+        }
+    );
+}
+pub const UIE_UEI_BIT_OFFSET:isize   = 8;
+pub const UIE_UEI_BIT_WIDTH:isize    = 1;
+pub const UIE_UEI_BIT_MASK:UintXlen = 0x100;
+pub const UIE_UEI_ALL_SET_MASK:UintXlen = 0x1;
+/* uie.uei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_uie_uei!();
+ */
+#[macro_export]
+macro_rules! csr_read_uie_uei {
+    ( ) => (
+        ($crate::csr_read_uie!() & $crate::UIE_UEI_BIT_MASK) >> $crate::UIE_UEI_BIT_OFFSET
+    );
+}
+/* uie.uei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_uie_uei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_uie_uei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_uie!();
+            let new_value = (org_value & !$crate::UIE_UEI_BIT_MASK)
+                | ((($x) << $crate::UIE_UEI_BIT_OFFSET) & $crate::UIE_UEI_BIT_MASK);
+            $crate::csr_write_uie!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * ucause - URW - User Exception Cause 
+ */
+pub const UCAUSE_INTERRUPT_BIT_OFFSET:isize   = __riscv_xlen-1;
+pub const UCAUSE_INTERRUPT_BIT_WIDTH:isize    = 1;
+pub const UCAUSE_INTERRUPT_BIT_MASK:UintXlen = 0x1 << (__riscv_xlen-1);
+pub const UCAUSE_INTERRUPT_ALL_SET_MASK:UintXlen = 0x1;
+/* ucause.interrupt: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_ucause_interrupt!();
+ */
+#[macro_export]
+macro_rules! csr_read_ucause_interrupt {
+    ( ) => (
+        ($crate::csr_read_ucause!() & $crate::UCAUSE_INTERRUPT_BIT_MASK) >> $crate::UCAUSE_INTERRUPT_BIT_OFFSET
+    );
+}
+/* ucause.interrupt: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_ucause_interrupt!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_ucause_interrupt {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_ucause!();
+            let new_value = (org_value & !$crate::UCAUSE_INTERRUPT_BIT_MASK)
+                | ((($x) << $crate::UCAUSE_INTERRUPT_BIT_OFFSET) & $crate::UCAUSE_INTERRUPT_BIT_MASK);
+            $crate::csr_write_ucause!(new_value);
+        }
+    );
+}
+pub const UCAUSE_EXCEPTION_CODE_BIT_OFFSET:isize   = 0;
+pub const UCAUSE_EXCEPTION_CODE_BIT_WIDTH:isize    = (__riscv_xlen-2) + 1;
+pub const UCAUSE_EXCEPTION_CODE_BIT_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-2) + 1));
+pub const UCAUSE_EXCEPTION_CODE_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-2) + 1));
+/* ucause.exception_code: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_ucause_exception_code!();
+ */
+#[macro_export]
+macro_rules! csr_read_ucause_exception_code {
+    ( ) => (
+        ($crate::csr_read_ucause!() & $crate::UCAUSE_EXCEPTION_CODE_BIT_MASK) >> $crate::UCAUSE_EXCEPTION_CODE_BIT_OFFSET
+    );
+}
+/* ucause.exception_code: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_ucause_exception_code!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_ucause_exception_code {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_ucause!();
+            let new_value = (org_value & !$crate::UCAUSE_EXCEPTION_CODE_BIT_MASK)
+                | ((($x) << $crate::UCAUSE_EXCEPTION_CODE_BIT_OFFSET) & $crate::UCAUSE_EXCEPTION_CODE_BIT_MASK);
+            $crate::csr_write_ucause!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * utvec - URW - User Trap Vector Base Address 
+ */
+pub const UTVEC_BASE_BIT_OFFSET:isize   = 2;
+pub const UTVEC_BASE_BIT_WIDTH:isize    = (__riscv_xlen-1)-(2) + 1;
+pub const UTVEC_BASE_BIT_MASK:UintXlen = (UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1))) << (2);
+pub const UTVEC_BASE_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(2) + 1));
+/* utvec.base: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_utvec_base!();
+ */
+#[macro_export]
+macro_rules! csr_read_utvec_base {
+    ( ) => (
+        ($crate::csr_read_utvec!() & $crate::UTVEC_BASE_BIT_MASK) >> $crate::UTVEC_BASE_BIT_OFFSET
+    );
+}
+/* utvec.base: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_utvec_base!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_utvec_base {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_utvec!();
+            let new_value = (org_value & !$crate::UTVEC_BASE_BIT_MASK)
+                | ((($x) << $crate::UTVEC_BASE_BIT_OFFSET) & $crate::UTVEC_BASE_BIT_MASK);
+            $crate::csr_write_utvec!(new_value);
+        }
+    );
+}
+pub const UTVEC_MODE_BIT_OFFSET:isize   = 0;
+pub const UTVEC_MODE_BIT_WIDTH:isize    = 2;
+pub const UTVEC_MODE_BIT_MASK:UintXlen = 0x3;
+pub const UTVEC_MODE_ALL_SET_MASK:UintXlen = 0x3;
+/* utvec.mode: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_utvec_mode!();
+ */
+#[macro_export]
+macro_rules! csr_read_utvec_mode {
+    ( ) => (
+        ($crate::csr_read_utvec!() & $crate::UTVEC_MODE_BIT_MASK) >> $crate::UTVEC_MODE_BIT_OFFSET
+    );
+}
+/* utvec.mode: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_utvec_mode!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_utvec_mode {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_utvec!();
+            let new_value = (org_value & !$crate::UTVEC_MODE_BIT_MASK)
+                | ((($x) << $crate::UTVEC_MODE_BIT_OFFSET) & $crate::UTVEC_MODE_BIT_MASK);
+            $crate::csr_write_utvec!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * fflags - URW - Floating-Point Accrued Exceptions. 
+ */
+pub const FFLAGS_NX_BIT_OFFSET:isize   = 0;
+pub const FFLAGS_NX_BIT_WIDTH:isize    = 1;
+pub const FFLAGS_NX_BIT_MASK:UintXlen = 0x1;
+pub const FFLAGS_NX_ALL_SET_MASK:UintXlen = 0x1;
+/* fflags.nx: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fflags_nx!();
+ */
+#[macro_export]
+macro_rules! csr_read_fflags_nx {
+    ( ) => (
+        ($crate::csr_read_fflags!() & $crate::FFLAGS_NX_BIT_MASK) >> $crate::FFLAGS_NX_BIT_OFFSET
+    );
+}
+/* fflags.nx: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fflags_nx!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fflags_nx {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fflags!();
+            let new_value = (org_value & !$crate::FFLAGS_NX_BIT_MASK)
+                | ((($x) << $crate::FFLAGS_NX_BIT_OFFSET) & $crate::FFLAGS_NX_BIT_MASK);
+            $crate::csr_write_fflags!(new_value);
+        }
+    );
+}
+pub const FFLAGS_UF_BIT_OFFSET:isize   = 1;
+pub const FFLAGS_UF_BIT_WIDTH:isize    = 1;
+pub const FFLAGS_UF_BIT_MASK:UintXlen = 0x2;
+pub const FFLAGS_UF_ALL_SET_MASK:UintXlen = 0x1;
+/* fflags.uf: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fflags_uf!();
+ */
+#[macro_export]
+macro_rules! csr_read_fflags_uf {
+    ( ) => (
+        ($crate::csr_read_fflags!() & $crate::FFLAGS_UF_BIT_MASK) >> $crate::FFLAGS_UF_BIT_OFFSET
+    );
+}
+/* fflags.uf: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fflags_uf!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fflags_uf {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fflags!();
+            let new_value = (org_value & !$crate::FFLAGS_UF_BIT_MASK)
+                | ((($x) << $crate::FFLAGS_UF_BIT_OFFSET) & $crate::FFLAGS_UF_BIT_MASK);
+            $crate::csr_write_fflags!(new_value);
+        }
+    );
+}
+pub const FFLAGS_OF_BIT_OFFSET:isize   = 2;
+pub const FFLAGS_OF_BIT_WIDTH:isize    = 1;
+pub const FFLAGS_OF_BIT_MASK:UintXlen = 0x4;
+pub const FFLAGS_OF_ALL_SET_MASK:UintXlen = 0x1;
+/* fflags.of: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fflags_of!();
+ */
+#[macro_export]
+macro_rules! csr_read_fflags_of {
+    ( ) => (
+        ($crate::csr_read_fflags!() & $crate::FFLAGS_OF_BIT_MASK) >> $crate::FFLAGS_OF_BIT_OFFSET
+    );
+}
+/* fflags.of: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fflags_of!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fflags_of {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fflags!();
+            let new_value = (org_value & !$crate::FFLAGS_OF_BIT_MASK)
+                | ((($x) << $crate::FFLAGS_OF_BIT_OFFSET) & $crate::FFLAGS_OF_BIT_MASK);
+            $crate::csr_write_fflags!(new_value);
+        }
+    );
+}
+pub const FFLAGS_DZ_BIT_OFFSET:isize   = 3;
+pub const FFLAGS_DZ_BIT_WIDTH:isize    = 1;
+pub const FFLAGS_DZ_BIT_MASK:UintXlen = 0x8;
+pub const FFLAGS_DZ_ALL_SET_MASK:UintXlen = 0x1;
+/* fflags.dz: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fflags_dz!();
+ */
+#[macro_export]
+macro_rules! csr_read_fflags_dz {
+    ( ) => (
+        ($crate::csr_read_fflags!() & $crate::FFLAGS_DZ_BIT_MASK) >> $crate::FFLAGS_DZ_BIT_OFFSET
+    );
+}
+/* fflags.dz: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fflags_dz!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fflags_dz {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fflags!();
+            let new_value = (org_value & !$crate::FFLAGS_DZ_BIT_MASK)
+                | ((($x) << $crate::FFLAGS_DZ_BIT_OFFSET) & $crate::FFLAGS_DZ_BIT_MASK);
+            $crate::csr_write_fflags!(new_value);
+        }
+    );
+}
+pub const FFLAGS_NV_BIT_OFFSET:isize   = 4;
+pub const FFLAGS_NV_BIT_WIDTH:isize    = 1;
+pub const FFLAGS_NV_BIT_MASK:UintXlen = 0x10;
+pub const FFLAGS_NV_ALL_SET_MASK:UintXlen = 0x1;
+/* fflags.nv: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fflags_nv!();
+ */
+#[macro_export]
+macro_rules! csr_read_fflags_nv {
+    ( ) => (
+        ($crate::csr_read_fflags!() & $crate::FFLAGS_NV_BIT_MASK) >> $crate::FFLAGS_NV_BIT_OFFSET
+    );
+}
+/* fflags.nv: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fflags_nv!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fflags_nv {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fflags!();
+            let new_value = (org_value & !$crate::FFLAGS_NV_BIT_MASK)
+                | ((($x) << $crate::FFLAGS_NV_BIT_OFFSET) & $crate::FFLAGS_NV_BIT_MASK);
+            $crate::csr_write_fflags!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * fcsr - URW - Floating-Point Control and Status 
+ */
+pub const FCSR_NX_BIT_OFFSET:isize   = 0;
+pub const FCSR_NX_BIT_WIDTH:isize    = 1;
+pub const FCSR_NX_BIT_MASK:UintXlen = 0x1;
+pub const FCSR_NX_ALL_SET_MASK:UintXlen = 0x1;
+/* fcsr.nx: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fcsr_nx!();
+ */
+#[macro_export]
+macro_rules! csr_read_fcsr_nx {
+    ( ) => (
+        ($crate::csr_read_fcsr!() & $crate::FCSR_NX_BIT_MASK) >> $crate::FCSR_NX_BIT_OFFSET
+    );
+}
+/* fcsr.nx: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fcsr_nx!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fcsr_nx {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fcsr!();
+            let new_value = (org_value & !$crate::FCSR_NX_BIT_MASK)
+                | ((($x) << $crate::FCSR_NX_BIT_OFFSET) & $crate::FCSR_NX_BIT_MASK);
+            $crate::csr_write_fcsr!(new_value);
+        }
+    );
+}
+pub const FCSR_UF_BIT_OFFSET:isize   = 1;
+pub const FCSR_UF_BIT_WIDTH:isize    = 1;
+pub const FCSR_UF_BIT_MASK:UintXlen = 0x2;
+pub const FCSR_UF_ALL_SET_MASK:UintXlen = 0x1;
+/* fcsr.uf: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fcsr_uf!();
+ */
+#[macro_export]
+macro_rules! csr_read_fcsr_uf {
+    ( ) => (
+        ($crate::csr_read_fcsr!() & $crate::FCSR_UF_BIT_MASK) >> $crate::FCSR_UF_BIT_OFFSET
+    );
+}
+/* fcsr.uf: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fcsr_uf!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fcsr_uf {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fcsr!();
+            let new_value = (org_value & !$crate::FCSR_UF_BIT_MASK)
+                | ((($x) << $crate::FCSR_UF_BIT_OFFSET) & $crate::FCSR_UF_BIT_MASK);
+            $crate::csr_write_fcsr!(new_value);
+        }
+    );
+}
+pub const FCSR_OF_BIT_OFFSET:isize   = 2;
+pub const FCSR_OF_BIT_WIDTH:isize    = 1;
+pub const FCSR_OF_BIT_MASK:UintXlen = 0x4;
+pub const FCSR_OF_ALL_SET_MASK:UintXlen = 0x1;
+/* fcsr.of: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fcsr_of!();
+ */
+#[macro_export]
+macro_rules! csr_read_fcsr_of {
+    ( ) => (
+        ($crate::csr_read_fcsr!() & $crate::FCSR_OF_BIT_MASK) >> $crate::FCSR_OF_BIT_OFFSET
+    );
+}
+/* fcsr.of: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fcsr_of!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fcsr_of {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fcsr!();
+            let new_value = (org_value & !$crate::FCSR_OF_BIT_MASK)
+                | ((($x) << $crate::FCSR_OF_BIT_OFFSET) & $crate::FCSR_OF_BIT_MASK);
+            $crate::csr_write_fcsr!(new_value);
+        }
+    );
+}
+pub const FCSR_DZ_BIT_OFFSET:isize   = 3;
+pub const FCSR_DZ_BIT_WIDTH:isize    = 1;
+pub const FCSR_DZ_BIT_MASK:UintXlen = 0x8;
+pub const FCSR_DZ_ALL_SET_MASK:UintXlen = 0x1;
+/* fcsr.dz: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fcsr_dz!();
+ */
+#[macro_export]
+macro_rules! csr_read_fcsr_dz {
+    ( ) => (
+        ($crate::csr_read_fcsr!() & $crate::FCSR_DZ_BIT_MASK) >> $crate::FCSR_DZ_BIT_OFFSET
+    );
+}
+/* fcsr.dz: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fcsr_dz!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fcsr_dz {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fcsr!();
+            let new_value = (org_value & !$crate::FCSR_DZ_BIT_MASK)
+                | ((($x) << $crate::FCSR_DZ_BIT_OFFSET) & $crate::FCSR_DZ_BIT_MASK);
+            $crate::csr_write_fcsr!(new_value);
+        }
+    );
+}
+pub const FCSR_NV_BIT_OFFSET:isize   = 4;
+pub const FCSR_NV_BIT_WIDTH:isize    = 1;
+pub const FCSR_NV_BIT_MASK:UintXlen = 0x10;
+pub const FCSR_NV_ALL_SET_MASK:UintXlen = 0x1;
+/* fcsr.nv: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fcsr_nv!();
+ */
+#[macro_export]
+macro_rules! csr_read_fcsr_nv {
+    ( ) => (
+        ($crate::csr_read_fcsr!() & $crate::FCSR_NV_BIT_MASK) >> $crate::FCSR_NV_BIT_OFFSET
+    );
+}
+/* fcsr.nv: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fcsr_nv!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fcsr_nv {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fcsr!();
+            let new_value = (org_value & !$crate::FCSR_NV_BIT_MASK)
+                | ((($x) << $crate::FCSR_NV_BIT_OFFSET) & $crate::FCSR_NV_BIT_MASK);
+            $crate::csr_write_fcsr!(new_value);
+        }
+    );
+}
+pub const FCSR_FRM_BIT_OFFSET:isize   = 5;
+pub const FCSR_FRM_BIT_WIDTH:isize    = 3;
+pub const FCSR_FRM_BIT_MASK:UintXlen = 0xe0;
+pub const FCSR_FRM_ALL_SET_MASK:UintXlen = 0x7;
+/* fcsr.frm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_fcsr_frm!();
+ */
+#[macro_export]
+macro_rules! csr_read_fcsr_frm {
+    ( ) => (
+        ($crate::csr_read_fcsr!() & $crate::FCSR_FRM_BIT_MASK) >> $crate::FCSR_FRM_BIT_OFFSET
+    );
+}
+/* fcsr.frm: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_fcsr_frm!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_fcsr_frm {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_fcsr!();
+            let new_value = (org_value & !$crate::FCSR_FRM_BIT_MASK)
+                | ((($x) << $crate::FCSR_FRM_BIT_OFFSET) & $crate::FCSR_FRM_BIT_MASK);
+            $crate::csr_write_fcsr!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * satp - SRW - Supervisor address translation and protection. 
+ */
+#[cfg(target_pointer_width = "32")]
+pub const SATP_MODE_BIT_OFFSET:isize   = 31;
+#[cfg(target_pointer_width = "32")]
+pub const SATP_MODE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "32")]
+pub const SATP_MODE_BIT_MASK:UintXlen = 0x80000000;
+#[cfg(target_pointer_width = "32")]
+pub const SATP_MODE_ALL_SET_MASK:UintXlen = 0x1;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_MODE_BIT_OFFSET:isize   = 60;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_MODE_BIT_WIDTH:isize    = 4;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_MODE_BIT_MASK:UintXlen = 0xf000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_MODE_ALL_SET_MASK:UintXlen = 0xf;
+/* satp.mode: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_satp_mode!();
+ */
+#[macro_export]
+macro_rules! csr_read_satp_mode {
+    ( ) => (
+        ($crate::csr_read_satp!() & $crate::SATP_MODE_BIT_MASK) >> $crate::SATP_MODE_BIT_OFFSET
+    );
+}
+/* satp.mode: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_satp_mode!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_satp_mode {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_satp!();
+            let new_value = (org_value & !$crate::SATP_MODE_BIT_MASK)
+                | ((($x) << $crate::SATP_MODE_BIT_OFFSET) & $crate::SATP_MODE_BIT_MASK);
+            $crate::csr_write_satp!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "32")]
+pub const SATP_ASID_BIT_OFFSET:isize   = 22;
+#[cfg(target_pointer_width = "32")]
+pub const SATP_ASID_BIT_WIDTH:isize    = 9;
+#[cfg(target_pointer_width = "32")]
+pub const SATP_ASID_BIT_MASK:UintXlen = 0x7fc00000;
+#[cfg(target_pointer_width = "32")]
+pub const SATP_ASID_ALL_SET_MASK:UintXlen = 0x1ff;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_ASID_BIT_OFFSET:isize   = 44;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_ASID_BIT_WIDTH:isize    = 16;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_ASID_BIT_MASK:UintXlen = 0xffff00000000000;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_ASID_ALL_SET_MASK:UintXlen = 0xffff;
+/* satp.asid: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_satp_asid!();
+ */
+#[macro_export]
+macro_rules! csr_read_satp_asid {
+    ( ) => (
+        ($crate::csr_read_satp!() & $crate::SATP_ASID_BIT_MASK) >> $crate::SATP_ASID_BIT_OFFSET
+    );
+}
+/* satp.asid: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_satp_asid!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_satp_asid {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_satp!();
+            let new_value = (org_value & !$crate::SATP_ASID_BIT_MASK)
+                | ((($x) << $crate::SATP_ASID_BIT_OFFSET) & $crate::SATP_ASID_BIT_MASK);
+            $crate::csr_write_satp!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "32")]
+pub const SATP_PPN_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "32")]
+pub const SATP_PPN_BIT_WIDTH:isize    = 22;
+#[cfg(target_pointer_width = "32")]
+pub const SATP_PPN_BIT_MASK:UintXlen = 0x3fffff;
+#[cfg(target_pointer_width = "32")]
+pub const SATP_PPN_ALL_SET_MASK:UintXlen = 0x3fffff;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_PPN_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_PPN_BIT_WIDTH:isize    = 44;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_PPN_BIT_MASK:UintXlen = 0xfffffffffff;
+#[cfg(target_pointer_width = "64")]
+pub const SATP_PPN_ALL_SET_MASK:UintXlen = 0xfffffffffff;
+/* satp.ppn: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_satp_ppn!();
+ */
+#[macro_export]
+macro_rules! csr_read_satp_ppn {
+    ( ) => (
+        ($crate::csr_read_satp!() & $crate::SATP_PPN_BIT_MASK) >> $crate::SATP_PPN_BIT_OFFSET
+    );
+}
+/* satp.ppn: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_satp_ppn!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_satp_ppn {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_satp!();
+            let new_value = (org_value & !$crate::SATP_PPN_BIT_MASK)
+                | ((($x) << $crate::SATP_PPN_BIT_OFFSET) & $crate::SATP_PPN_BIT_MASK);
+            $crate::csr_write_satp!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * hstatus - HRW - Hypervisor status register. 
+ */
+pub const HSTATUS_VSBE_BIT_OFFSET:isize   = 5;
+pub const HSTATUS_VSBE_BIT_WIDTH:isize    = 1;
+pub const HSTATUS_VSBE_BIT_MASK:UintXlen = 0x20;
+pub const HSTATUS_VSBE_ALL_SET_MASK:UintXlen = 0x1;
+/* hstatus.vsbe: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_vsbe!();
+ */
+#[macro_export]
+macro_rules! csr_read_hstatus_vsbe {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_VSBE_BIT_MASK) >> $crate::HSTATUS_VSBE_BIT_OFFSET
+    );
+}
+/* hstatus.vsbe: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_vsbe!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hstatus_vsbe {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_VSBE_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_VSBE_BIT_OFFSET) & $crate::HSTATUS_VSBE_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+pub const HSTATUS_GVA_BIT_OFFSET:isize   = 6;
+pub const HSTATUS_GVA_BIT_WIDTH:isize    = 1;
+pub const HSTATUS_GVA_BIT_MASK:UintXlen = 0x40;
+pub const HSTATUS_GVA_ALL_SET_MASK:UintXlen = 0x1;
+/* hstatus.gva: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_gva!();
+ */
+#[macro_export]
+macro_rules! csr_read_hstatus_gva {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_GVA_BIT_MASK) >> $crate::HSTATUS_GVA_BIT_OFFSET
+    );
+}
+/* hstatus.gva: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_gva!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hstatus_gva {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_GVA_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_GVA_BIT_OFFSET) & $crate::HSTATUS_GVA_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+pub const HSTATUS_SPV_BIT_OFFSET:isize   = 7;
+pub const HSTATUS_SPV_BIT_WIDTH:isize    = 1;
+pub const HSTATUS_SPV_BIT_MASK:UintXlen = 0x80;
+pub const HSTATUS_SPV_ALL_SET_MASK:UintXlen = 0x1;
+/* hstatus.spv: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_spv!();
+ */
+#[macro_export]
+macro_rules! csr_read_hstatus_spv {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_SPV_BIT_MASK) >> $crate::HSTATUS_SPV_BIT_OFFSET
+    );
+}
+/* hstatus.spv: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_spv!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hstatus_spv {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_SPV_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_SPV_BIT_OFFSET) & $crate::HSTATUS_SPV_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+pub const HSTATUS_SPVP_BIT_OFFSET:isize   = 8;
+pub const HSTATUS_SPVP_BIT_WIDTH:isize    = 1;
+pub const HSTATUS_SPVP_BIT_MASK:UintXlen = 0x100;
+pub const HSTATUS_SPVP_ALL_SET_MASK:UintXlen = 0x1;
+/* hstatus.spvp: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_spvp!();
+ */
+#[macro_export]
+macro_rules! csr_read_hstatus_spvp {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_SPVP_BIT_MASK) >> $crate::HSTATUS_SPVP_BIT_OFFSET
+    );
+}
+/* hstatus.spvp: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_spvp!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hstatus_spvp {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_SPVP_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_SPVP_BIT_OFFSET) & $crate::HSTATUS_SPVP_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+pub const HSTATUS_HU_BIT_OFFSET:isize   = 9;
+pub const HSTATUS_HU_BIT_WIDTH:isize    = 1;
+pub const HSTATUS_HU_BIT_MASK:UintXlen = 0x200;
+pub const HSTATUS_HU_ALL_SET_MASK:UintXlen = 0x1;
+/* hstatus.hu: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_hu!();
+ */
+#[macro_export]
+macro_rules! csr_read_hstatus_hu {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_HU_BIT_MASK) >> $crate::HSTATUS_HU_BIT_OFFSET
+    );
+}
+/* hstatus.hu: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_hu!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hstatus_hu {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_HU_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_HU_BIT_OFFSET) & $crate::HSTATUS_HU_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+pub const HSTATUS_VGEIN_BIT_OFFSET:isize   = 12;
+pub const HSTATUS_VGEIN_BIT_WIDTH:isize    = 6;
+pub const HSTATUS_VGEIN_BIT_MASK:UintXlen = 0x3f000;
+pub const HSTATUS_VGEIN_ALL_SET_MASK:UintXlen = 0x3f;
+/* hstatus.vgein: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_vgein!();
+ */
+#[macro_export]
+macro_rules! csr_read_hstatus_vgein {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_VGEIN_BIT_MASK) >> $crate::HSTATUS_VGEIN_BIT_OFFSET
+    );
+}
+/* hstatus.vgein: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_vgein!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hstatus_vgein {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_VGEIN_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_VGEIN_BIT_OFFSET) & $crate::HSTATUS_VGEIN_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+pub const HSTATUS_VTVM_BIT_OFFSET:isize   = 20;
+pub const HSTATUS_VTVM_BIT_WIDTH:isize    = 1;
+pub const HSTATUS_VTVM_BIT_MASK:UintXlen = 0x100000;
+pub const HSTATUS_VTVM_ALL_SET_MASK:UintXlen = 0x1;
+/* hstatus.vtvm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_vtvm!();
+ */
+#[macro_export]
+macro_rules! csr_read_hstatus_vtvm {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_VTVM_BIT_MASK) >> $crate::HSTATUS_VTVM_BIT_OFFSET
+    );
+}
+/* hstatus.vtvm: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_vtvm!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hstatus_vtvm {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_VTVM_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_VTVM_BIT_OFFSET) & $crate::HSTATUS_VTVM_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+pub const HSTATUS_VTW_BIT_OFFSET:isize   = 21;
+pub const HSTATUS_VTW_BIT_WIDTH:isize    = 1;
+pub const HSTATUS_VTW_BIT_MASK:UintXlen = 0x200000;
+pub const HSTATUS_VTW_ALL_SET_MASK:UintXlen = 0x1;
+/* hstatus.vtw: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_vtw!();
+ */
+#[macro_export]
+macro_rules! csr_read_hstatus_vtw {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_VTW_BIT_MASK) >> $crate::HSTATUS_VTW_BIT_OFFSET
+    );
+}
+/* hstatus.vtw: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_vtw!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hstatus_vtw {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_VTW_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_VTW_BIT_OFFSET) & $crate::HSTATUS_VTW_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+pub const HSTATUS_VTSR_BIT_OFFSET:isize   = 22;
+pub const HSTATUS_VTSR_BIT_WIDTH:isize    = 1;
+pub const HSTATUS_VTSR_BIT_MASK:UintXlen = 0x400000;
+pub const HSTATUS_VTSR_ALL_SET_MASK:UintXlen = 0x1;
+/* hstatus.vtsr: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_vtsr!();
+ */
+#[macro_export]
+macro_rules! csr_read_hstatus_vtsr {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_VTSR_BIT_MASK) >> $crate::HSTATUS_VTSR_BIT_OFFSET
+    );
+}
+/* hstatus.vtsr: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_vtsr!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hstatus_vtsr {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_VTSR_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_VTSR_BIT_OFFSET) & $crate::HSTATUS_VTSR_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const HSTATUS_VSXL_BIT_OFFSET:isize   = 32;
+#[cfg(target_pointer_width = "64")]
+pub const HSTATUS_VSXL_BIT_WIDTH:isize    = 2;
+#[cfg(target_pointer_width = "64")]
+pub const HSTATUS_VSXL_BIT_MASK:UintXlen = 0x300000000;
+#[cfg(target_pointer_width = "64")]
+pub const HSTATUS_VSXL_ALL_SET_MASK:UintXlen = 0x3;
+/* hstatus.vsxl: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hstatus_vsxl!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_hstatus_vsxl {
+    ( ) => (
+        ($crate::csr_read_hstatus!() & $crate::HSTATUS_VSXL_BIT_MASK) >> $crate::HSTATUS_VSXL_BIT_OFFSET
+    );
+}
+/* hstatus.vsxl: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hstatus_vsxl!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_hstatus_vsxl {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hstatus!();
+            let new_value = (org_value & !$crate::HSTATUS_VSXL_BIT_MASK)
+                | ((($x) << $crate::HSTATUS_VSXL_BIT_OFFSET) & $crate::HSTATUS_VSXL_BIT_MASK);
+            $crate::csr_write_hstatus!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * hedeleg - HRW - Hypervisor exception delegation register. 
+ */
+pub const HEDELEG_INSTRUCTION_MISALIGNED_BIT_OFFSET:isize   = 0;
+pub const HEDELEG_INSTRUCTION_MISALIGNED_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK:UintXlen = 0x1;
+pub const HEDELEG_INSTRUCTION_MISALIGNED_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.instruction_misaligned: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_instruction_misaligned!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_instruction_misaligned {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK) >> $crate::HEDELEG_INSTRUCTION_MISALIGNED_BIT_OFFSET
+    );
+}
+/* hedeleg.instruction_misaligned: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_instruction_misaligned!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_instruction_misaligned {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_INSTRUCTION_MISALIGNED_BIT_OFFSET) & $crate::HEDELEG_INSTRUCTION_MISALIGNED_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_INSTRUCTION_FAULT_BIT_OFFSET:isize   = 1;
+pub const HEDELEG_INSTRUCTION_FAULT_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_INSTRUCTION_FAULT_BIT_MASK:UintXlen = 0x2;
+pub const HEDELEG_INSTRUCTION_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.instruction_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_instruction_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_instruction_fault {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_INSTRUCTION_FAULT_BIT_MASK) >> $crate::HEDELEG_INSTRUCTION_FAULT_BIT_OFFSET
+    );
+}
+/* hedeleg.instruction_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_instruction_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_instruction_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_INSTRUCTION_FAULT_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_INSTRUCTION_FAULT_BIT_OFFSET) & $crate::HEDELEG_INSTRUCTION_FAULT_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_ILLEGAL_INSTRUCTION_BIT_OFFSET:isize   = 2;
+pub const HEDELEG_ILLEGAL_INSTRUCTION_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK:UintXlen = 0x4;
+pub const HEDELEG_ILLEGAL_INSTRUCTION_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.illegal_instruction: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_illegal_instruction!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_illegal_instruction {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK) >> $crate::HEDELEG_ILLEGAL_INSTRUCTION_BIT_OFFSET
+    );
+}
+/* hedeleg.illegal_instruction: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_illegal_instruction!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_illegal_instruction {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_ILLEGAL_INSTRUCTION_BIT_OFFSET) & $crate::HEDELEG_ILLEGAL_INSTRUCTION_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_BREAKPOINT_BIT_OFFSET:isize   = 3;
+pub const HEDELEG_BREAKPOINT_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_BREAKPOINT_BIT_MASK:UintXlen = 0x8;
+pub const HEDELEG_BREAKPOINT_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.breakpoint: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_breakpoint!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_breakpoint {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_BREAKPOINT_BIT_MASK) >> $crate::HEDELEG_BREAKPOINT_BIT_OFFSET
+    );
+}
+/* hedeleg.breakpoint: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_breakpoint!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_breakpoint {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_BREAKPOINT_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_BREAKPOINT_BIT_OFFSET) & $crate::HEDELEG_BREAKPOINT_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_LOAD_MISALIGNED_BIT_OFFSET:isize   = 4;
+pub const HEDELEG_LOAD_MISALIGNED_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_LOAD_MISALIGNED_BIT_MASK:UintXlen = 0x10;
+pub const HEDELEG_LOAD_MISALIGNED_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.load_misaligned: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_load_misaligned!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_load_misaligned {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_LOAD_MISALIGNED_BIT_MASK) >> $crate::HEDELEG_LOAD_MISALIGNED_BIT_OFFSET
+    );
+}
+/* hedeleg.load_misaligned: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_load_misaligned!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_load_misaligned {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_LOAD_MISALIGNED_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_LOAD_MISALIGNED_BIT_OFFSET) & $crate::HEDELEG_LOAD_MISALIGNED_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_LOAD_FAULT_BIT_OFFSET:isize   = 5;
+pub const HEDELEG_LOAD_FAULT_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_LOAD_FAULT_BIT_MASK:UintXlen = 0x20;
+pub const HEDELEG_LOAD_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.load_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_load_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_load_fault {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_LOAD_FAULT_BIT_MASK) >> $crate::HEDELEG_LOAD_FAULT_BIT_OFFSET
+    );
+}
+/* hedeleg.load_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_load_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_load_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_LOAD_FAULT_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_LOAD_FAULT_BIT_OFFSET) & $crate::HEDELEG_LOAD_FAULT_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_STORE_MISALIGNED_BIT_OFFSET:isize   = 6;
+pub const HEDELEG_STORE_MISALIGNED_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_STORE_MISALIGNED_BIT_MASK:UintXlen = 0x40;
+pub const HEDELEG_STORE_MISALIGNED_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.store_misaligned: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_store_misaligned!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_store_misaligned {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_STORE_MISALIGNED_BIT_MASK) >> $crate::HEDELEG_STORE_MISALIGNED_BIT_OFFSET
+    );
+}
+/* hedeleg.store_misaligned: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_store_misaligned!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_store_misaligned {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_STORE_MISALIGNED_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_STORE_MISALIGNED_BIT_OFFSET) & $crate::HEDELEG_STORE_MISALIGNED_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_STORE_FAULT_BIT_OFFSET:isize   = 7;
+pub const HEDELEG_STORE_FAULT_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_STORE_FAULT_BIT_MASK:UintXlen = 0x80;
+pub const HEDELEG_STORE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.store_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_store_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_store_fault {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_STORE_FAULT_BIT_MASK) >> $crate::HEDELEG_STORE_FAULT_BIT_OFFSET
+    );
+}
+/* hedeleg.store_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_store_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_store_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_STORE_FAULT_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_STORE_FAULT_BIT_OFFSET) & $crate::HEDELEG_STORE_FAULT_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_ECALL_U_BIT_OFFSET:isize   = 8;
+pub const HEDELEG_ECALL_U_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_ECALL_U_BIT_MASK:UintXlen = 0x100;
+pub const HEDELEG_ECALL_U_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.ecall_u: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_ecall_u!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_ecall_u {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_ECALL_U_BIT_MASK) >> $crate::HEDELEG_ECALL_U_BIT_OFFSET
+    );
+}
+/* hedeleg.ecall_u: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_ecall_u!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_ecall_u {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_ECALL_U_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_ECALL_U_BIT_OFFSET) & $crate::HEDELEG_ECALL_U_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_OFFSET:isize   = 12;
+pub const HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK:UintXlen = 0x1000;
+pub const HEDELEG_INSTRUCTION_PAGE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.instruction_page_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_instruction_page_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_instruction_page_fault {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK) >> $crate::HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_OFFSET
+    );
+}
+/* hedeleg.instruction_page_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_instruction_page_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_instruction_page_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_OFFSET) & $crate::HEDELEG_INSTRUCTION_PAGE_FAULT_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_LOAD_PAGE_FAULT_BIT_OFFSET:isize   = 13;
+pub const HEDELEG_LOAD_PAGE_FAULT_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_LOAD_PAGE_FAULT_BIT_MASK:UintXlen = 0x2000;
+pub const HEDELEG_LOAD_PAGE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.load_page_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_load_page_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_load_page_fault {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_LOAD_PAGE_FAULT_BIT_MASK) >> $crate::HEDELEG_LOAD_PAGE_FAULT_BIT_OFFSET
+    );
+}
+/* hedeleg.load_page_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_load_page_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_load_page_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_LOAD_PAGE_FAULT_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_LOAD_PAGE_FAULT_BIT_OFFSET) & $crate::HEDELEG_LOAD_PAGE_FAULT_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+pub const HEDELEG_STORE_PAGE_FAULT_BIT_OFFSET:isize   = 15;
+pub const HEDELEG_STORE_PAGE_FAULT_BIT_WIDTH:isize    = 1;
+pub const HEDELEG_STORE_PAGE_FAULT_BIT_MASK:UintXlen = 0x8000;
+pub const HEDELEG_STORE_PAGE_FAULT_ALL_SET_MASK:UintXlen = 0x1;
+/* hedeleg.store_page_fault: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hedeleg_store_page_fault!();
+ */
+#[macro_export]
+macro_rules! csr_read_hedeleg_store_page_fault {
+    ( ) => (
+        ($crate::csr_read_hedeleg!() & $crate::HEDELEG_STORE_PAGE_FAULT_BIT_MASK) >> $crate::HEDELEG_STORE_PAGE_FAULT_BIT_OFFSET
+    );
+}
+/* hedeleg.store_page_fault: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hedeleg_store_page_fault!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hedeleg_store_page_fault {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hedeleg!();
+            let new_value = (org_value & !$crate::HEDELEG_STORE_PAGE_FAULT_BIT_MASK)
+                | ((($x) << $crate::HEDELEG_STORE_PAGE_FAULT_BIT_OFFSET) & $crate::HEDELEG_STORE_PAGE_FAULT_BIT_MASK);
+            $crate::csr_write_hedeleg!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * hideleg - HRW - Hypervisor interrupt delegation register. 
+ */
+pub const HIDELEG_VSSI_BIT_OFFSET:isize   = 2;
+pub const HIDELEG_VSSI_BIT_WIDTH:isize    = 1;
+pub const HIDELEG_VSSI_BIT_MASK:UintXlen = 0x4;
+pub const HIDELEG_VSSI_ALL_SET_MASK:UintXlen = 0x1;
+/* hideleg.vssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hideleg_vssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_hideleg_vssi {
+    ( ) => (
+        ($crate::csr_read_hideleg!() & $crate::HIDELEG_VSSI_BIT_MASK) >> $crate::HIDELEG_VSSI_BIT_OFFSET
+    );
+}
+/* hideleg.vssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hideleg_vssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hideleg_vssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hideleg!();
+            let new_value = (org_value & !$crate::HIDELEG_VSSI_BIT_MASK)
+                | ((($x) << $crate::HIDELEG_VSSI_BIT_OFFSET) & $crate::HIDELEG_VSSI_BIT_MASK);
+            $crate::csr_write_hideleg!(new_value);
+        }
+    );
+}
+pub const HIDELEG_VSTI_BIT_OFFSET:isize   = 6;
+pub const HIDELEG_VSTI_BIT_WIDTH:isize    = 1;
+pub const HIDELEG_VSTI_BIT_MASK:UintXlen = 0x40;
+pub const HIDELEG_VSTI_ALL_SET_MASK:UintXlen = 0x1;
+/* hideleg.vsti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hideleg_vsti!();
+ */
+#[macro_export]
+macro_rules! csr_read_hideleg_vsti {
+    ( ) => (
+        ($crate::csr_read_hideleg!() & $crate::HIDELEG_VSTI_BIT_MASK) >> $crate::HIDELEG_VSTI_BIT_OFFSET
+    );
+}
+/* hideleg.vsti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hideleg_vsti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hideleg_vsti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hideleg!();
+            let new_value = (org_value & !$crate::HIDELEG_VSTI_BIT_MASK)
+                | ((($x) << $crate::HIDELEG_VSTI_BIT_OFFSET) & $crate::HIDELEG_VSTI_BIT_MASK);
+            $crate::csr_write_hideleg!(new_value);
+        }
+    );
+}
+pub const HIDELEG_VSEI_BIT_OFFSET:isize   = 10;
+pub const HIDELEG_VSEI_BIT_WIDTH:isize    = 1;
+pub const HIDELEG_VSEI_BIT_MASK:UintXlen = 0x400;
+pub const HIDELEG_VSEI_ALL_SET_MASK:UintXlen = 0x1;
+/* hideleg.vsei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hideleg_vsei!();
+ */
+#[macro_export]
+macro_rules! csr_read_hideleg_vsei {
+    ( ) => (
+        ($crate::csr_read_hideleg!() & $crate::HIDELEG_VSEI_BIT_MASK) >> $crate::HIDELEG_VSEI_BIT_OFFSET
+    );
+}
+/* hideleg.vsei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hideleg_vsei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hideleg_vsei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hideleg!();
+            let new_value = (org_value & !$crate::HIDELEG_VSEI_BIT_MASK)
+                | ((($x) << $crate::HIDELEG_VSEI_BIT_OFFSET) & $crate::HIDELEG_VSEI_BIT_MASK);
+            $crate::csr_write_hideleg!(new_value);
+        }
+    );
+}
+pub const HIDELEG_LCOFI_BIT_OFFSET:isize   = 13;
+pub const HIDELEG_LCOFI_BIT_WIDTH:isize    = 1;
+pub const HIDELEG_LCOFI_BIT_MASK:UintXlen = 0x2000;
+pub const HIDELEG_LCOFI_ALL_SET_MASK:UintXlen = 0x1;
+/* hideleg.lcofi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hideleg_lcofi!();
+ */
+#[macro_export]
+macro_rules! csr_read_hideleg_lcofi {
+    ( ) => (
+        ($crate::csr_read_hideleg!() & $crate::HIDELEG_LCOFI_BIT_MASK) >> $crate::HIDELEG_LCOFI_BIT_OFFSET
+    );
+}
+/* hideleg.lcofi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hideleg_lcofi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hideleg_lcofi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hideleg!();
+            let new_value = (org_value & !$crate::HIDELEG_LCOFI_BIT_MASK)
+                | ((($x) << $crate::HIDELEG_LCOFI_BIT_OFFSET) & $crate::HIDELEG_LCOFI_BIT_MASK);
+            $crate::csr_write_hideleg!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * hgatp - HRW - Hypervisor guest address translation and protection. 
+ */
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_MODE_BIT_OFFSET:isize   = 31;
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_MODE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_MODE_BIT_MASK:UintXlen = 0x80000000;
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_MODE_ALL_SET_MASK:UintXlen = 0x1;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_MODE_BIT_OFFSET:isize   = 60;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_MODE_BIT_WIDTH:isize    = 4;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_MODE_BIT_MASK:UintXlen = 0xf000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_MODE_ALL_SET_MASK:UintXlen = 0xf;
+/* hgatp.mode: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hgatp_mode!();
+ */
+#[macro_export]
+macro_rules! csr_read_hgatp_mode {
+    ( ) => (
+        ($crate::csr_read_hgatp!() & $crate::HGATP_MODE_BIT_MASK) >> $crate::HGATP_MODE_BIT_OFFSET
+    );
+}
+/* hgatp.mode: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hgatp_mode!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hgatp_mode {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hgatp!();
+            let new_value = (org_value & !$crate::HGATP_MODE_BIT_MASK)
+                | ((($x) << $crate::HGATP_MODE_BIT_OFFSET) & $crate::HGATP_MODE_BIT_MASK);
+            $crate::csr_write_hgatp!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_VMID_BIT_OFFSET:isize   = 22;
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_VMID_BIT_WIDTH:isize    = 7;
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_VMID_BIT_MASK:UintXlen = 0x1fc00000;
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_VMID_ALL_SET_MASK:UintXlen = 0x7f;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_VMID_BIT_OFFSET:isize   = 44;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_VMID_BIT_WIDTH:isize    = 14;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_VMID_BIT_MASK:UintXlen = 0x3fff00000000000;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_VMID_ALL_SET_MASK:UintXlen = 0x3fff;
+/* hgatp.vmid: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hgatp_vmid!();
+ */
+#[macro_export]
+macro_rules! csr_read_hgatp_vmid {
+    ( ) => (
+        ($crate::csr_read_hgatp!() & $crate::HGATP_VMID_BIT_MASK) >> $crate::HGATP_VMID_BIT_OFFSET
+    );
+}
+/* hgatp.vmid: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hgatp_vmid!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hgatp_vmid {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hgatp!();
+            let new_value = (org_value & !$crate::HGATP_VMID_BIT_MASK)
+                | ((($x) << $crate::HGATP_VMID_BIT_OFFSET) & $crate::HGATP_VMID_BIT_MASK);
+            $crate::csr_write_hgatp!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_PPN_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_PPN_BIT_WIDTH:isize    = 22;
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_PPN_BIT_MASK:UintXlen = 0x3fffff;
+#[cfg(target_pointer_width = "32")]
+pub const HGATP_PPN_ALL_SET_MASK:UintXlen = 0x3fffff;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_PPN_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_PPN_BIT_WIDTH:isize    = 44;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_PPN_BIT_MASK:UintXlen = 0xfffffffffff;
+#[cfg(target_pointer_width = "64")]
+pub const HGATP_PPN_ALL_SET_MASK:UintXlen = 0xfffffffffff;
+/* hgatp.ppn: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hgatp_ppn!();
+ */
+#[macro_export]
+macro_rules! csr_read_hgatp_ppn {
+    ( ) => (
+        ($crate::csr_read_hgatp!() & $crate::HGATP_PPN_BIT_MASK) >> $crate::HGATP_PPN_BIT_OFFSET
+    );
+}
+/* hgatp.ppn: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hgatp_ppn!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hgatp_ppn {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hgatp!();
+            let new_value = (org_value & !$crate::HGATP_PPN_BIT_MASK)
+                | ((($x) << $crate::HGATP_PPN_BIT_OFFSET) & $crate::HGATP_PPN_BIT_MASK);
+            $crate::csr_write_hgatp!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * vsatp - HRW - Virtual supervisor address translation and protection. 
+ */
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_MODE_BIT_OFFSET:isize   = 31;
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_MODE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_MODE_BIT_MASK:UintXlen = 0x80000000;
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_MODE_ALL_SET_MASK:UintXlen = 0x1;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_MODE_BIT_OFFSET:isize   = 60;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_MODE_BIT_WIDTH:isize    = 4;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_MODE_BIT_MASK:UintXlen = 0xf000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_MODE_ALL_SET_MASK:UintXlen = 0xf;
+/* vsatp.mode: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vsatp_mode!();
+ */
+#[macro_export]
+macro_rules! csr_read_vsatp_mode {
+    ( ) => (
+        ($crate::csr_read_vsatp!() & $crate::VSATP_MODE_BIT_MASK) >> $crate::VSATP_MODE_BIT_OFFSET
+    );
+}
+/* vsatp.mode: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_vsatp_mode!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_vsatp_mode {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_vsatp!();
+            let new_value = (org_value & !$crate::VSATP_MODE_BIT_MASK)
+                | ((($x) << $crate::VSATP_MODE_BIT_OFFSET) & $crate::VSATP_MODE_BIT_MASK);
+            $crate::csr_write_vsatp!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_ASID_BIT_OFFSET:isize   = 22;
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_ASID_BIT_WIDTH:isize    = 9;
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_ASID_BIT_MASK:UintXlen = 0x7fc00000;
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_ASID_ALL_SET_MASK:UintXlen = 0x1ff;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_ASID_BIT_OFFSET:isize   = 44;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_ASID_BIT_WIDTH:isize    = 16;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_ASID_BIT_MASK:UintXlen = 0xffff00000000000;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_ASID_ALL_SET_MASK:UintXlen = 0xffff;
+/* vsatp.asid: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vsatp_asid!();
+ */
+#[macro_export]
+macro_rules! csr_read_vsatp_asid {
+    ( ) => (
+        ($crate::csr_read_vsatp!() & $crate::VSATP_ASID_BIT_MASK) >> $crate::VSATP_ASID_BIT_OFFSET
+    );
+}
+/* vsatp.asid: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_vsatp_asid!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_vsatp_asid {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_vsatp!();
+            let new_value = (org_value & !$crate::VSATP_ASID_BIT_MASK)
+                | ((($x) << $crate::VSATP_ASID_BIT_OFFSET) & $crate::VSATP_ASID_BIT_MASK);
+            $crate::csr_write_vsatp!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_PPN_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_PPN_BIT_WIDTH:isize    = 22;
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_PPN_BIT_MASK:UintXlen = 0x3fffff;
+#[cfg(target_pointer_width = "32")]
+pub const VSATP_PPN_ALL_SET_MASK:UintXlen = 0x3fffff;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_PPN_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_PPN_BIT_WIDTH:isize    = 44;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_PPN_BIT_MASK:UintXlen = 0xfffffffffff;
+#[cfg(target_pointer_width = "64")]
+pub const VSATP_PPN_ALL_SET_MASK:UintXlen = 0xfffffffffff;
+/* vsatp.ppn: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vsatp_ppn!();
+ */
+#[macro_export]
+macro_rules! csr_read_vsatp_ppn {
+    ( ) => (
+        ($crate::csr_read_vsatp!() & $crate::VSATP_PPN_BIT_MASK) >> $crate::VSATP_PPN_BIT_OFFSET
+    );
+}
+/* vsatp.ppn: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_vsatp_ppn!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_vsatp_ppn {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_vsatp!();
+            let new_value = (org_value & !$crate::VSATP_PPN_BIT_MASK)
+                | ((($x) << $crate::VSATP_PPN_BIT_OFFSET) & $crate::VSATP_PPN_BIT_MASK);
+            $crate::csr_write_vsatp!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mhpmevent4 - MRW - Machine performance-monitoring event selector. 
+ */
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT4_EVENT_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT4_EVENT_BIT_WIDTH:isize    = 32;
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT4_EVENT_BIT_MASK:UintXlen = 0xffffffff;
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT4_EVENT_ALL_SET_MASK:UintXlen = 0xffffffff;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_EVENT_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_EVENT_BIT_WIDTH:isize    = 56;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_EVENT_BIT_MASK:UintXlen = 0xffffffffffffff;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_EVENT_ALL_SET_MASK:UintXlen = 0xffffffffffffff;
+/* mhpmevent4.event: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4_event!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent4_event {
+    ( ) => (
+        ($crate::csr_read_mhpmevent4!() & $crate::MHPMEVENT4_EVENT_BIT_MASK) >> $crate::MHPMEVENT4_EVENT_BIT_OFFSET
+    );
+}
+/* mhpmevent4.event: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4_event!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent4_event {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent4!();
+            let new_value = (org_value & !$crate::MHPMEVENT4_EVENT_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4_EVENT_BIT_OFFSET) & $crate::MHPMEVENT4_EVENT_BIT_MASK);
+            $crate::csr_write_mhpmevent4!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_OF_BIT_OFFSET:isize   = 63;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_OF_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_OF_BIT_MASK:UintXlen = 0x8000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_OF_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4.of: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4_of!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent4_of {
+    ( ) => (
+        ($crate::csr_read_mhpmevent4!() & $crate::MHPMEVENT4_OF_BIT_MASK) >> $crate::MHPMEVENT4_OF_BIT_OFFSET
+    );
+}
+/* mhpmevent4.of: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4_of!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent4_of {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent4!();
+            let new_value = (org_value & !$crate::MHPMEVENT4_OF_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4_OF_BIT_OFFSET) & $crate::MHPMEVENT4_OF_BIT_MASK);
+            $crate::csr_write_mhpmevent4!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_MINH_BIT_OFFSET:isize   = 62;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_MINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_MINH_BIT_MASK:UintXlen = 0x4000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4_minh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent4_minh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent4!() & $crate::MHPMEVENT4_MINH_BIT_MASK) >> $crate::MHPMEVENT4_MINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4_minh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent4_minh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent4!();
+            let new_value = (org_value & !$crate::MHPMEVENT4_MINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4_MINH_BIT_OFFSET) & $crate::MHPMEVENT4_MINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_SINH_BIT_OFFSET:isize   = 61;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_SINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_SINH_BIT_MASK:UintXlen = 0x2000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4_sinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent4_sinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent4!() & $crate::MHPMEVENT4_SINH_BIT_MASK) >> $crate::MHPMEVENT4_SINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4_sinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent4_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent4!();
+            let new_value = (org_value & !$crate::MHPMEVENT4_SINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4_SINH_BIT_OFFSET) & $crate::MHPMEVENT4_SINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_UINH_BIT_OFFSET:isize   = 60;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_UINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_UINH_BIT_MASK:UintXlen = 0x1000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4_uinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent4_uinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent4!() & $crate::MHPMEVENT4_UINH_BIT_MASK) >> $crate::MHPMEVENT4_UINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4_uinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent4_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent4!();
+            let new_value = (org_value & !$crate::MHPMEVENT4_UINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4_UINH_BIT_OFFSET) & $crate::MHPMEVENT4_UINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_VSINH_BIT_OFFSET:isize   = 59;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_VSINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_VSINH_BIT_MASK:UintXlen = 0x800000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4_vsinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent4_vsinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent4!() & $crate::MHPMEVENT4_VSINH_BIT_MASK) >> $crate::MHPMEVENT4_VSINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4_vsinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent4_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent4!();
+            let new_value = (org_value & !$crate::MHPMEVENT4_VSINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4_VSINH_BIT_OFFSET) & $crate::MHPMEVENT4_VSINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_VUINH_BIT_OFFSET:isize   = 58;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_VUINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_VUINH_BIT_MASK:UintXlen = 0x400000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT4_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4_vuinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent4_vuinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent4!() & $crate::MHPMEVENT4_VUINH_BIT_MASK) >> $crate::MHPMEVENT4_VUINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4_vuinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent4_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent4!();
+            let new_value = (org_value & !$crate::MHPMEVENT4_VUINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4_VUINH_BIT_OFFSET) & $crate::MHPMEVENT4_VUINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mhpmevent31 - MRW - Machine performance-monitoring event selector. 
+ */
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT31_EVENT_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT31_EVENT_BIT_WIDTH:isize    = 32;
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT31_EVENT_BIT_MASK:UintXlen = 0xffffffff;
+#[cfg(target_pointer_width = "32")]
+pub const MHPMEVENT31_EVENT_ALL_SET_MASK:UintXlen = 0xffffffff;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_EVENT_BIT_OFFSET:isize   = 0;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_EVENT_BIT_WIDTH:isize    = 56;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_EVENT_BIT_MASK:UintXlen = 0xffffffffffffff;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_EVENT_ALL_SET_MASK:UintXlen = 0xffffffffffffff;
+/* mhpmevent31.event: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31_event!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent31_event {
+    ( ) => (
+        ($crate::csr_read_mhpmevent31!() & $crate::MHPMEVENT31_EVENT_BIT_MASK) >> $crate::MHPMEVENT31_EVENT_BIT_OFFSET
+    );
+}
+/* mhpmevent31.event: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31_event!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent31_event {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent31!();
+            let new_value = (org_value & !$crate::MHPMEVENT31_EVENT_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31_EVENT_BIT_OFFSET) & $crate::MHPMEVENT31_EVENT_BIT_MASK);
+            $crate::csr_write_mhpmevent31!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_OF_BIT_OFFSET:isize   = 63;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_OF_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_OF_BIT_MASK:UintXlen = 0x8000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_OF_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31.of: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31_of!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent31_of {
+    ( ) => (
+        ($crate::csr_read_mhpmevent31!() & $crate::MHPMEVENT31_OF_BIT_MASK) >> $crate::MHPMEVENT31_OF_BIT_OFFSET
+    );
+}
+/* mhpmevent31.of: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31_of!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent31_of {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent31!();
+            let new_value = (org_value & !$crate::MHPMEVENT31_OF_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31_OF_BIT_OFFSET) & $crate::MHPMEVENT31_OF_BIT_MASK);
+            $crate::csr_write_mhpmevent31!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_MINH_BIT_OFFSET:isize   = 62;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_MINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_MINH_BIT_MASK:UintXlen = 0x4000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31_minh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent31_minh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent31!() & $crate::MHPMEVENT31_MINH_BIT_MASK) >> $crate::MHPMEVENT31_MINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31_minh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent31_minh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent31!();
+            let new_value = (org_value & !$crate::MHPMEVENT31_MINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31_MINH_BIT_OFFSET) & $crate::MHPMEVENT31_MINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_SINH_BIT_OFFSET:isize   = 61;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_SINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_SINH_BIT_MASK:UintXlen = 0x2000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31_sinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent31_sinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent31!() & $crate::MHPMEVENT31_SINH_BIT_MASK) >> $crate::MHPMEVENT31_SINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31_sinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent31_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent31!();
+            let new_value = (org_value & !$crate::MHPMEVENT31_SINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31_SINH_BIT_OFFSET) & $crate::MHPMEVENT31_SINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_UINH_BIT_OFFSET:isize   = 60;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_UINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_UINH_BIT_MASK:UintXlen = 0x1000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31_uinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent31_uinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent31!() & $crate::MHPMEVENT31_UINH_BIT_MASK) >> $crate::MHPMEVENT31_UINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31_uinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent31_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent31!();
+            let new_value = (org_value & !$crate::MHPMEVENT31_UINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31_UINH_BIT_OFFSET) & $crate::MHPMEVENT31_UINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_VSINH_BIT_OFFSET:isize   = 59;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_VSINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_VSINH_BIT_MASK:UintXlen = 0x800000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31_vsinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent31_vsinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent31!() & $crate::MHPMEVENT31_VSINH_BIT_MASK) >> $crate::MHPMEVENT31_VSINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31_vsinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent31_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent31!();
+            let new_value = (org_value & !$crate::MHPMEVENT31_VSINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31_VSINH_BIT_OFFSET) & $crate::MHPMEVENT31_VSINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_VUINH_BIT_OFFSET:isize   = 58;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_VUINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_VUINH_BIT_MASK:UintXlen = 0x400000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MHPMEVENT31_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31_vuinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mhpmevent31_vuinh {
+    ( ) => (
+        ($crate::csr_read_mhpmevent31!() & $crate::MHPMEVENT31_VUINH_BIT_MASK) >> $crate::MHPMEVENT31_VUINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31_vuinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mhpmevent31_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mhpmevent31!();
+            let new_value = (org_value & !$crate::MHPMEVENT31_VUINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31_VUINH_BIT_OFFSET) & $crate::MHPMEVENT31_VUINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * hie - HRW - Hypervisor interrupt-enable register. 
+ */
+pub const HIE_VSSI_BIT_OFFSET:isize   = 2;
+pub const HIE_VSSI_BIT_WIDTH:isize    = 1;
+pub const HIE_VSSI_BIT_MASK:UintXlen = 0x4;
+pub const HIE_VSSI_ALL_SET_MASK:UintXlen = 0x1;
+/* hie.vssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hie_vssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_hie_vssi {
+    ( ) => (
+        ($crate::csr_read_hie!() & $crate::HIE_VSSI_BIT_MASK) >> $crate::HIE_VSSI_BIT_OFFSET
+    );
+}
+/* hie.vssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hie_vssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hie_vssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hie!();
+            let new_value = (org_value & !$crate::HIE_VSSI_BIT_MASK)
+                | ((($x) << $crate::HIE_VSSI_BIT_OFFSET) & $crate::HIE_VSSI_BIT_MASK);
+            $crate::csr_write_hie!(new_value);
+        }
+    );
+}
+pub const HIE_VSTI_BIT_OFFSET:isize   = 6;
+pub const HIE_VSTI_BIT_WIDTH:isize    = 1;
+pub const HIE_VSTI_BIT_MASK:UintXlen = 0x40;
+pub const HIE_VSTI_ALL_SET_MASK:UintXlen = 0x1;
+/* hie.vsti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hie_vsti!();
+ */
+#[macro_export]
+macro_rules! csr_read_hie_vsti {
+    ( ) => (
+        ($crate::csr_read_hie!() & $crate::HIE_VSTI_BIT_MASK) >> $crate::HIE_VSTI_BIT_OFFSET
+    );
+}
+/* hie.vsti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hie_vsti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hie_vsti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hie!();
+            let new_value = (org_value & !$crate::HIE_VSTI_BIT_MASK)
+                | ((($x) << $crate::HIE_VSTI_BIT_OFFSET) & $crate::HIE_VSTI_BIT_MASK);
+            $crate::csr_write_hie!(new_value);
+        }
+    );
+}
+pub const HIE_VSEI_BIT_OFFSET:isize   = 10;
+pub const HIE_VSEI_BIT_WIDTH:isize    = 1;
+pub const HIE_VSEI_BIT_MASK:UintXlen = 0x400;
+pub const HIE_VSEI_ALL_SET_MASK:UintXlen = 0x1;
+/* hie.vsei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hie_vsei!();
+ */
+#[macro_export]
+macro_rules! csr_read_hie_vsei {
+    ( ) => (
+        ($crate::csr_read_hie!() & $crate::HIE_VSEI_BIT_MASK) >> $crate::HIE_VSEI_BIT_OFFSET
+    );
+}
+/* hie.vsei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hie_vsei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hie_vsei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hie!();
+            let new_value = (org_value & !$crate::HIE_VSEI_BIT_MASK)
+                | ((($x) << $crate::HIE_VSEI_BIT_OFFSET) & $crate::HIE_VSEI_BIT_MASK);
+            $crate::csr_write_hie!(new_value);
+        }
+    );
+}
+pub const HIE_SGEI_BIT_OFFSET:isize   = 12;
+pub const HIE_SGEI_BIT_WIDTH:isize    = 1;
+pub const HIE_SGEI_BIT_MASK:UintXlen = 0x1000;
+pub const HIE_SGEI_ALL_SET_MASK:UintXlen = 0x1;
+/* hie.sgei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hie_sgei!();
+ */
+#[macro_export]
+macro_rules! csr_read_hie_sgei {
+    ( ) => (
+        ($crate::csr_read_hie!() & $crate::HIE_SGEI_BIT_MASK) >> $crate::HIE_SGEI_BIT_OFFSET
+    );
+}
+/* hie.sgei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hie_sgei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hie_sgei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hie!();
+            let new_value = (org_value & !$crate::HIE_SGEI_BIT_MASK)
+                | ((($x) << $crate::HIE_SGEI_BIT_OFFSET) & $crate::HIE_SGEI_BIT_MASK);
+            $crate::csr_write_hie!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * hgeie - HRW - Hypervisor guest external interrupt-enable register. 
+ */
+pub const HGEIE_GEI_BIT_OFFSET:isize   = 1;
+pub const HGEIE_GEI_BIT_WIDTH:isize    = (__riscv_xlen-1)-(1) + 1;
+pub const HGEIE_GEI_BIT_MASK:UintXlen = (UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (1);
+pub const HGEIE_GEI_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1));
+/* hgeie.gei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hgeie_gei!();
+ */
+#[macro_export]
+macro_rules! csr_read_hgeie_gei {
+    ( ) => (
+        ($crate::csr_read_hgeie!() & $crate::HGEIE_GEI_BIT_MASK) >> $crate::HGEIE_GEI_BIT_OFFSET
+    );
+}
+/* hgeie.gei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hgeie_gei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hgeie_gei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hgeie!();
+            let new_value = (org_value & !$crate::HGEIE_GEI_BIT_MASK)
+                | ((($x) << $crate::HGEIE_GEI_BIT_OFFSET) & $crate::HGEIE_GEI_BIT_MASK);
+            $crate::csr_write_hgeie!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * hip - HRW - Hypervisor interrupt pending. 
+ */
+pub const HIP_VSSI_BIT_OFFSET:isize   = 2;
+pub const HIP_VSSI_BIT_WIDTH:isize    = 1;
+pub const HIP_VSSI_BIT_MASK:UintXlen = 0x4;
+pub const HIP_VSSI_ALL_SET_MASK:UintXlen = 0x1;
+/* hip.vssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hip_vssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_hip_vssi {
+    ( ) => (
+        ($crate::csr_read_hip!() & $crate::HIP_VSSI_BIT_MASK) >> $crate::HIP_VSSI_BIT_OFFSET
+    );
+}
+/* hip.vssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hip_vssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hip_vssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hip!();
+            let new_value = (org_value & !$crate::HIP_VSSI_BIT_MASK)
+                | ((($x) << $crate::HIP_VSSI_BIT_OFFSET) & $crate::HIP_VSSI_BIT_MASK);
+            $crate::csr_write_hip!(new_value);
+        }
+    );
+}
+pub const HIP_VSTI_BIT_OFFSET:isize   = 6;
+pub const HIP_VSTI_BIT_WIDTH:isize    = 1;
+pub const HIP_VSTI_BIT_MASK:UintXlen = 0x40;
+pub const HIP_VSTI_ALL_SET_MASK:UintXlen = 0x1;
+/* hip.vsti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hip_vsti!();
+ */
+#[macro_export]
+macro_rules! csr_read_hip_vsti {
+    ( ) => (
+        ($crate::csr_read_hip!() & $crate::HIP_VSTI_BIT_MASK) >> $crate::HIP_VSTI_BIT_OFFSET
+    );
+}
+/* hip.vsti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hip_vsti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hip_vsti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hip!();
+            let new_value = (org_value & !$crate::HIP_VSTI_BIT_MASK)
+                | ((($x) << $crate::HIP_VSTI_BIT_OFFSET) & $crate::HIP_VSTI_BIT_MASK);
+            $crate::csr_write_hip!(new_value);
+        }
+    );
+}
+pub const HIP_VSEI_BIT_OFFSET:isize   = 10;
+pub const HIP_VSEI_BIT_WIDTH:isize    = 1;
+pub const HIP_VSEI_BIT_MASK:UintXlen = 0x400;
+pub const HIP_VSEI_ALL_SET_MASK:UintXlen = 0x1;
+/* hip.vsei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hip_vsei!();
+ */
+#[macro_export]
+macro_rules! csr_read_hip_vsei {
+    ( ) => (
+        ($crate::csr_read_hip!() & $crate::HIP_VSEI_BIT_MASK) >> $crate::HIP_VSEI_BIT_OFFSET
+    );
+}
+/* hip.vsei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hip_vsei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hip_vsei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hip!();
+            let new_value = (org_value & !$crate::HIP_VSEI_BIT_MASK)
+                | ((($x) << $crate::HIP_VSEI_BIT_OFFSET) & $crate::HIP_VSEI_BIT_MASK);
+            $crate::csr_write_hip!(new_value);
+        }
+    );
+}
+pub const HIP_SGEI_BIT_OFFSET:isize   = 12;
+pub const HIP_SGEI_BIT_WIDTH:isize    = 1;
+pub const HIP_SGEI_BIT_MASK:UintXlen = 0x1000;
+pub const HIP_SGEI_ALL_SET_MASK:UintXlen = 0x1;
+/* hip.sgei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hip_sgei!();
+ */
+#[macro_export]
+macro_rules! csr_read_hip_sgei {
+    ( ) => (
+        ($crate::csr_read_hip!() & $crate::HIP_SGEI_BIT_MASK) >> $crate::HIP_SGEI_BIT_OFFSET
+    );
+}
+/* hip.sgei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hip_sgei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hip_sgei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hip!();
+            let new_value = (org_value & !$crate::HIP_SGEI_BIT_MASK)
+                | ((($x) << $crate::HIP_SGEI_BIT_OFFSET) & $crate::HIP_SGEI_BIT_MASK);
+            $crate::csr_write_hip!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * hgeip - HRO - Hypervisor guest external interrupt pending. 
+ */
+pub const HGEIP_GEI_BIT_OFFSET:isize   = 1;
+pub const HGEIP_GEI_BIT_WIDTH:isize    = (__riscv_xlen-1)-(1) + 1;
+pub const HGEIP_GEI_BIT_MASK:UintXlen = (UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1))) << (1);
+pub const HGEIP_GEI_ALL_SET_MASK:UintXlen = UintXlen::MAX >> (__riscv_xlen-((__riscv_xlen-1)-(1) + 1));
+/* hgeip.gei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hgeip_gei!();
+ */
+#[macro_export]
+macro_rules! csr_read_hgeip_gei {
+    ( ) => (
+        ($crate::csr_read_hgeip!() & $crate::HGEIP_GEI_BIT_MASK) >> $crate::HGEIP_GEI_BIT_OFFSET
+    );
+}
+
+/*******************************************
+ * mhpmevent3h - MRW - Upper 32 bits of mhpmevent3, RV32 only. 
+ */
+pub const MHPMEVENT3H_OF_BIT_OFFSET:isize   = 31;
+pub const MHPMEVENT3H_OF_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT3H_OF_BIT_MASK:UintXlen = 0x80000000;
+pub const MHPMEVENT3H_OF_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3h.of: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3h_of!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent3h_of {
+    ( ) => (
+        (($crate::csr_read_mhpmevent3h!() as $crate::UintXlen) & $crate::MHPMEVENT3H_OF_BIT_MASK) >> $crate::MHPMEVENT3H_OF_BIT_OFFSET
+    );
+}
+/* mhpmevent3h.of: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3h_of!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent3h_of {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent3h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT3H_OF_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3H_OF_BIT_OFFSET) & $crate::MHPMEVENT3H_OF_BIT_MASK);
+            $crate::csr_write_mhpmevent3h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT3H_MINH_BIT_OFFSET:isize   = 30;
+pub const MHPMEVENT3H_MINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT3H_MINH_BIT_MASK:UintXlen = 0x40000000;
+pub const MHPMEVENT3H_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3h.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3h_minh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent3h_minh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent3h!() as $crate::UintXlen) & $crate::MHPMEVENT3H_MINH_BIT_MASK) >> $crate::MHPMEVENT3H_MINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3h.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3h_minh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent3h_minh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent3h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT3H_MINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3H_MINH_BIT_OFFSET) & $crate::MHPMEVENT3H_MINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT3H_SINH_BIT_OFFSET:isize   = 29;
+pub const MHPMEVENT3H_SINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT3H_SINH_BIT_MASK:UintXlen = 0x20000000;
+pub const MHPMEVENT3H_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3h.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3h_sinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent3h_sinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent3h!() as $crate::UintXlen) & $crate::MHPMEVENT3H_SINH_BIT_MASK) >> $crate::MHPMEVENT3H_SINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3h.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3h_sinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent3h_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent3h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT3H_SINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3H_SINH_BIT_OFFSET) & $crate::MHPMEVENT3H_SINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT3H_UINH_BIT_OFFSET:isize   = 28;
+pub const MHPMEVENT3H_UINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT3H_UINH_BIT_MASK:UintXlen = 0x10000000;
+pub const MHPMEVENT3H_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3h.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3h_uinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent3h_uinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent3h!() as $crate::UintXlen) & $crate::MHPMEVENT3H_UINH_BIT_MASK) >> $crate::MHPMEVENT3H_UINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3h.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3h_uinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent3h_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent3h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT3H_UINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3H_UINH_BIT_OFFSET) & $crate::MHPMEVENT3H_UINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT3H_VSINH_BIT_OFFSET:isize   = 27;
+pub const MHPMEVENT3H_VSINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT3H_VSINH_BIT_MASK:UintXlen = 0x8000000;
+pub const MHPMEVENT3H_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3h.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3h_vsinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent3h_vsinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent3h!() as $crate::UintXlen) & $crate::MHPMEVENT3H_VSINH_BIT_MASK) >> $crate::MHPMEVENT3H_VSINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3h.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3h_vsinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent3h_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent3h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT3H_VSINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3H_VSINH_BIT_OFFSET) & $crate::MHPMEVENT3H_VSINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT3H_VUINH_BIT_OFFSET:isize   = 26;
+pub const MHPMEVENT3H_VUINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT3H_VUINH_BIT_MASK:UintXlen = 0x4000000;
+pub const MHPMEVENT3H_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent3h.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent3h_vuinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent3h_vuinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent3h!() as $crate::UintXlen) & $crate::MHPMEVENT3H_VUINH_BIT_MASK) >> $crate::MHPMEVENT3H_VUINH_BIT_OFFSET
+    );
+}
+/* mhpmevent3h.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent3h_vuinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent3h_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent3h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT3H_VUINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT3H_VUINH_BIT_OFFSET) & $crate::MHPMEVENT3H_VUINH_BIT_MASK);
+            $crate::csr_write_mhpmevent3h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+
+/*******************************************
+ * mhpmevent4h - MRW - Upper 32 bits of mhpmevent4, RV32 only. 
+ */
+pub const MHPMEVENT4H_OF_BIT_OFFSET:isize   = 31;
+pub const MHPMEVENT4H_OF_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT4H_OF_BIT_MASK:UintXlen = 0x80000000;
+pub const MHPMEVENT4H_OF_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4h.of: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4h_of!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent4h_of {
+    ( ) => (
+        (($crate::csr_read_mhpmevent4h!() as $crate::UintXlen) & $crate::MHPMEVENT4H_OF_BIT_MASK) >> $crate::MHPMEVENT4H_OF_BIT_OFFSET
+    );
+}
+/* mhpmevent4h.of: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4h_of!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent4h_of {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent4h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT4H_OF_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4H_OF_BIT_OFFSET) & $crate::MHPMEVENT4H_OF_BIT_MASK);
+            $crate::csr_write_mhpmevent4h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT4H_MINH_BIT_OFFSET:isize   = 30;
+pub const MHPMEVENT4H_MINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT4H_MINH_BIT_MASK:UintXlen = 0x40000000;
+pub const MHPMEVENT4H_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4h.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4h_minh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent4h_minh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent4h!() as $crate::UintXlen) & $crate::MHPMEVENT4H_MINH_BIT_MASK) >> $crate::MHPMEVENT4H_MINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4h.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4h_minh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent4h_minh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent4h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT4H_MINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4H_MINH_BIT_OFFSET) & $crate::MHPMEVENT4H_MINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT4H_SINH_BIT_OFFSET:isize   = 29;
+pub const MHPMEVENT4H_SINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT4H_SINH_BIT_MASK:UintXlen = 0x20000000;
+pub const MHPMEVENT4H_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4h.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4h_sinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent4h_sinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent4h!() as $crate::UintXlen) & $crate::MHPMEVENT4H_SINH_BIT_MASK) >> $crate::MHPMEVENT4H_SINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4h.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4h_sinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent4h_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent4h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT4H_SINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4H_SINH_BIT_OFFSET) & $crate::MHPMEVENT4H_SINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT4H_UINH_BIT_OFFSET:isize   = 28;
+pub const MHPMEVENT4H_UINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT4H_UINH_BIT_MASK:UintXlen = 0x10000000;
+pub const MHPMEVENT4H_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4h.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4h_uinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent4h_uinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent4h!() as $crate::UintXlen) & $crate::MHPMEVENT4H_UINH_BIT_MASK) >> $crate::MHPMEVENT4H_UINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4h.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4h_uinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent4h_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent4h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT4H_UINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4H_UINH_BIT_OFFSET) & $crate::MHPMEVENT4H_UINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT4H_VSINH_BIT_OFFSET:isize   = 27;
+pub const MHPMEVENT4H_VSINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT4H_VSINH_BIT_MASK:UintXlen = 0x8000000;
+pub const MHPMEVENT4H_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4h.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4h_vsinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent4h_vsinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent4h!() as $crate::UintXlen) & $crate::MHPMEVENT4H_VSINH_BIT_MASK) >> $crate::MHPMEVENT4H_VSINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4h.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4h_vsinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent4h_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent4h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT4H_VSINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4H_VSINH_BIT_OFFSET) & $crate::MHPMEVENT4H_VSINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT4H_VUINH_BIT_OFFSET:isize   = 26;
+pub const MHPMEVENT4H_VUINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT4H_VUINH_BIT_MASK:UintXlen = 0x4000000;
+pub const MHPMEVENT4H_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent4h.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent4h_vuinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent4h_vuinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent4h!() as $crate::UintXlen) & $crate::MHPMEVENT4H_VUINH_BIT_MASK) >> $crate::MHPMEVENT4H_VUINH_BIT_OFFSET
+    );
+}
+/* mhpmevent4h.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent4h_vuinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent4h_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent4h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT4H_VUINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT4H_VUINH_BIT_OFFSET) & $crate::MHPMEVENT4H_VUINH_BIT_MASK);
+            $crate::csr_write_mhpmevent4h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+
+/*******************************************
+ * mhpmevent31h - MRW - Upper 32 bits of mhpmevent31, RV32 only. 
+ */
+pub const MHPMEVENT31H_OF_BIT_OFFSET:isize   = 31;
+pub const MHPMEVENT31H_OF_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT31H_OF_BIT_MASK:UintXlen = 0x80000000;
+pub const MHPMEVENT31H_OF_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31h.of: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31h_of!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent31h_of {
+    ( ) => (
+        (($crate::csr_read_mhpmevent31h!() as $crate::UintXlen) & $crate::MHPMEVENT31H_OF_BIT_MASK) >> $crate::MHPMEVENT31H_OF_BIT_OFFSET
+    );
+}
+/* mhpmevent31h.of: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31h_of!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent31h_of {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent31h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT31H_OF_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31H_OF_BIT_OFFSET) & $crate::MHPMEVENT31H_OF_BIT_MASK);
+            $crate::csr_write_mhpmevent31h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT31H_MINH_BIT_OFFSET:isize   = 30;
+pub const MHPMEVENT31H_MINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT31H_MINH_BIT_MASK:UintXlen = 0x40000000;
+pub const MHPMEVENT31H_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31h.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31h_minh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent31h_minh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent31h!() as $crate::UintXlen) & $crate::MHPMEVENT31H_MINH_BIT_MASK) >> $crate::MHPMEVENT31H_MINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31h.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31h_minh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent31h_minh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent31h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT31H_MINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31H_MINH_BIT_OFFSET) & $crate::MHPMEVENT31H_MINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT31H_SINH_BIT_OFFSET:isize   = 29;
+pub const MHPMEVENT31H_SINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT31H_SINH_BIT_MASK:UintXlen = 0x20000000;
+pub const MHPMEVENT31H_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31h.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31h_sinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent31h_sinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent31h!() as $crate::UintXlen) & $crate::MHPMEVENT31H_SINH_BIT_MASK) >> $crate::MHPMEVENT31H_SINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31h.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31h_sinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent31h_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent31h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT31H_SINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31H_SINH_BIT_OFFSET) & $crate::MHPMEVENT31H_SINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT31H_UINH_BIT_OFFSET:isize   = 28;
+pub const MHPMEVENT31H_UINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT31H_UINH_BIT_MASK:UintXlen = 0x10000000;
+pub const MHPMEVENT31H_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31h.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31h_uinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent31h_uinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent31h!() as $crate::UintXlen) & $crate::MHPMEVENT31H_UINH_BIT_MASK) >> $crate::MHPMEVENT31H_UINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31h.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31h_uinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent31h_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent31h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT31H_UINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31H_UINH_BIT_OFFSET) & $crate::MHPMEVENT31H_UINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT31H_VSINH_BIT_OFFSET:isize   = 27;
+pub const MHPMEVENT31H_VSINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT31H_VSINH_BIT_MASK:UintXlen = 0x8000000;
+pub const MHPMEVENT31H_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31h.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31h_vsinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent31h_vsinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent31h!() as $crate::UintXlen) & $crate::MHPMEVENT31H_VSINH_BIT_MASK) >> $crate::MHPMEVENT31H_VSINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31h.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31h_vsinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent31h_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent31h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT31H_VSINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31H_VSINH_BIT_OFFSET) & $crate::MHPMEVENT31H_VSINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MHPMEVENT31H_VUINH_BIT_OFFSET:isize   = 26;
+pub const MHPMEVENT31H_VUINH_BIT_WIDTH:isize    = 1;
+pub const MHPMEVENT31H_VUINH_BIT_MASK:UintXlen = 0x4000000;
+pub const MHPMEVENT31H_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mhpmevent31h.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mhpmevent31h_vuinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mhpmevent31h_vuinh {
+    ( ) => (
+        (($crate::csr_read_mhpmevent31h!() as $crate::UintXlen) & $crate::MHPMEVENT31H_VUINH_BIT_MASK) >> $crate::MHPMEVENT31H_VUINH_BIT_OFFSET
+    );
+}
+/* mhpmevent31h.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mhpmevent31h_vuinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mhpmevent31h_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mhpmevent31h!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MHPMEVENT31H_VUINH_BIT_MASK)
+                | ((($x) << $crate::MHPMEVENT31H_VUINH_BIT_OFFSET) & $crate::MHPMEVENT31H_VUINH_BIT_MASK);
+            $crate::csr_write_mhpmevent31h!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+
+/*******************************************
+ * scountovf - SRO - Supervisor count overflow. 
+ */
+pub const SCOUNTOVF_HPM_BIT_OFFSET:isize   = 3;
+pub const SCOUNTOVF_HPM_BIT_WIDTH:isize    = 29;
+pub const SCOUNTOVF_HPM_BIT_MASK:UintXlen = 0xfffffff8;
+pub const SCOUNTOVF_HPM_ALL_SET_MASK:UintXlen = 0x1fffffff;
+/* scountovf.hpm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_scountovf_hpm!();
+ */
+#[macro_export]
+macro_rules! csr_read_scountovf_hpm {
+    ( ) => (
+        (($crate::csr_read_scountovf!() as $crate::UintXlen) & $crate::SCOUNTOVF_HPM_BIT_MASK) >> $crate::SCOUNTOVF_HPM_BIT_OFFSET
+    );
+}
+
+/*******************************************
+ * mcyclecfg - MRW - Machine cycle counter configuration. 
+ */
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_MINH_BIT_OFFSET:isize   = 62;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_MINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_MINH_BIT_MASK:UintXlen = 0x4000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfg.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfg_minh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mcyclecfg_minh {
+    ( ) => (
+        ($crate::csr_read_mcyclecfg!() & $crate::MCYCLECFG_MINH_BIT_MASK) >> $crate::MCYCLECFG_MINH_BIT_OFFSET
+    );
+}
+/* mcyclecfg.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfg_minh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mcyclecfg_minh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mcyclecfg!();
+            let new_value = (org_value & !$crate::MCYCLECFG_MINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFG_MINH_BIT_OFFSET) & $crate::MCYCLECFG_MINH_BIT_MASK);
+            $crate::csr_write_mcyclecfg!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_SINH_BIT_OFFSET:isize   = 61;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_SINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_SINH_BIT_MASK:UintXlen = 0x2000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfg.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfg_sinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mcyclecfg_sinh {
+    ( ) => (
+        ($crate::csr_read_mcyclecfg!() & $crate::MCYCLECFG_SINH_BIT_MASK) >> $crate::MCYCLECFG_SINH_BIT_OFFSET
+    );
+}
+/* mcyclecfg.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfg_sinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mcyclecfg_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mcyclecfg!();
+            let new_value = (org_value & !$crate::MCYCLECFG_SINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFG_SINH_BIT_OFFSET) & $crate::MCYCLECFG_SINH_BIT_MASK);
+            $crate::csr_write_mcyclecfg!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_UINH_BIT_OFFSET:isize   = 60;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_UINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_UINH_BIT_MASK:UintXlen = 0x1000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfg.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfg_uinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mcyclecfg_uinh {
+    ( ) => (
+        ($crate::csr_read_mcyclecfg!() & $crate::MCYCLECFG_UINH_BIT_MASK) >> $crate::MCYCLECFG_UINH_BIT_OFFSET
+    );
+}
+/* mcyclecfg.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfg_uinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mcyclecfg_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mcyclecfg!();
+            let new_value = (org_value & !$crate::MCYCLECFG_UINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFG_UINH_BIT_OFFSET) & $crate::MCYCLECFG_UINH_BIT_MASK);
+            $crate::csr_write_mcyclecfg!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_VSINH_BIT_OFFSET:isize   = 59;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_VSINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_VSINH_BIT_MASK:UintXlen = 0x800000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfg.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfg_vsinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mcyclecfg_vsinh {
+    ( ) => (
+        ($crate::csr_read_mcyclecfg!() & $crate::MCYCLECFG_VSINH_BIT_MASK) >> $crate::MCYCLECFG_VSINH_BIT_OFFSET
+    );
+}
+/* mcyclecfg.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfg_vsinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mcyclecfg_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mcyclecfg!();
+            let new_value = (org_value & !$crate::MCYCLECFG_VSINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFG_VSINH_BIT_OFFSET) & $crate::MCYCLECFG_VSINH_BIT_MASK);
+            $crate::csr_write_mcyclecfg!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_VUINH_BIT_OFFSET:isize   = 58;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_VUINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_VUINH_BIT_MASK:UintXlen = 0x400000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MCYCLECFG_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfg.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfg_vuinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_mcyclecfg_vuinh {
+    ( ) => (
+        ($crate::csr_read_mcyclecfg!() & $crate::MCYCLECFG_VUINH_BIT_MASK) >> $crate::MCYCLECFG_VUINH_BIT_OFFSET
+    );
+}
+/* mcyclecfg.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfg_vuinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_mcyclecfg_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_mcyclecfg!();
+            let new_value = (org_value & !$crate::MCYCLECFG_VUINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFG_VUINH_BIT_OFFSET) & $crate::MCYCLECFG_VUINH_BIT_MASK);
+            $crate::csr_write_mcyclecfg!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * mcyclecfgh - MRW - Upper 32 bits of mcyclecfg, RV32 only. 
+ */
+pub const MCYCLECFGH_MINH_BIT_OFFSET:isize   = 30;
+pub const MCYCLECFGH_MINH_BIT_WIDTH:isize    = 1;
+pub const MCYCLECFGH_MINH_BIT_MASK:UintXlen = 0x40000000;
+pub const MCYCLECFGH_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfgh.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfgh_minh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcyclecfgh_minh {
+    ( ) => (
+        (($crate::csr_read_mcyclecfgh!() as $crate::UintXlen) & $crate::MCYCLECFGH_MINH_BIT_MASK) >> $crate::MCYCLECFGH_MINH_BIT_OFFSET
+    );
+}
+/* mcyclecfgh.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfgh_minh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcyclecfgh_minh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcyclecfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCYCLECFGH_MINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFGH_MINH_BIT_OFFSET) & $crate::MCYCLECFGH_MINH_BIT_MASK);
+            $crate::csr_write_mcyclecfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MCYCLECFGH_SINH_BIT_OFFSET:isize   = 29;
+pub const MCYCLECFGH_SINH_BIT_WIDTH:isize    = 1;
+pub const MCYCLECFGH_SINH_BIT_MASK:UintXlen = 0x20000000;
+pub const MCYCLECFGH_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfgh.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfgh_sinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcyclecfgh_sinh {
+    ( ) => (
+        (($crate::csr_read_mcyclecfgh!() as $crate::UintXlen) & $crate::MCYCLECFGH_SINH_BIT_MASK) >> $crate::MCYCLECFGH_SINH_BIT_OFFSET
+    );
+}
+/* mcyclecfgh.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfgh_sinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcyclecfgh_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcyclecfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCYCLECFGH_SINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFGH_SINH_BIT_OFFSET) & $crate::MCYCLECFGH_SINH_BIT_MASK);
+            $crate::csr_write_mcyclecfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MCYCLECFGH_UINH_BIT_OFFSET:isize   = 28;
+pub const MCYCLECFGH_UINH_BIT_WIDTH:isize    = 1;
+pub const MCYCLECFGH_UINH_BIT_MASK:UintXlen = 0x10000000;
+pub const MCYCLECFGH_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfgh.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfgh_uinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcyclecfgh_uinh {
+    ( ) => (
+        (($crate::csr_read_mcyclecfgh!() as $crate::UintXlen) & $crate::MCYCLECFGH_UINH_BIT_MASK) >> $crate::MCYCLECFGH_UINH_BIT_OFFSET
+    );
+}
+/* mcyclecfgh.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfgh_uinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcyclecfgh_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcyclecfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCYCLECFGH_UINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFGH_UINH_BIT_OFFSET) & $crate::MCYCLECFGH_UINH_BIT_MASK);
+            $crate::csr_write_mcyclecfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MCYCLECFGH_VSINH_BIT_OFFSET:isize   = 27;
+pub const MCYCLECFGH_VSINH_BIT_WIDTH:isize    = 1;
+pub const MCYCLECFGH_VSINH_BIT_MASK:UintXlen = 0x8000000;
+pub const MCYCLECFGH_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfgh.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfgh_vsinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcyclecfgh_vsinh {
+    ( ) => (
+        (($crate::csr_read_mcyclecfgh!() as $crate::UintXlen) & $crate::MCYCLECFGH_VSINH_BIT_MASK) >> $crate::MCYCLECFGH_VSINH_BIT_OFFSET
+    );
+}
+/* mcyclecfgh.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfgh_vsinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcyclecfgh_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcyclecfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCYCLECFGH_VSINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFGH_VSINH_BIT_OFFSET) & $crate::MCYCLECFGH_VSINH_BIT_MASK);
+            $crate::csr_write_mcyclecfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MCYCLECFGH_VUINH_BIT_OFFSET:isize   = 26;
+pub const MCYCLECFGH_VUINH_BIT_WIDTH:isize    = 1;
+pub const MCYCLECFGH_VUINH_BIT_MASK:UintXlen = 0x4000000;
+pub const MCYCLECFGH_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* mcyclecfgh.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mcyclecfgh_vuinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_mcyclecfgh_vuinh {
+    ( ) => (
+        (($crate::csr_read_mcyclecfgh!() as $crate::UintXlen) & $crate::MCYCLECFGH_VUINH_BIT_MASK) >> $crate::MCYCLECFGH_VUINH_BIT_OFFSET
+    );
+}
+/* mcyclecfgh.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mcyclecfgh_vuinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_mcyclecfgh_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mcyclecfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MCYCLECFGH_VUINH_BIT_MASK)
+                | ((($x) << $crate::MCYCLECFGH_VUINH_BIT_OFFSET) & $crate::MCYCLECFGH_VUINH_BIT_MASK);
+            $crate::csr_write_mcyclecfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+
+/*******************************************
+ * minstretcfg - MRW - Machine instret counter configuration. 
+ */
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_MINH_BIT_OFFSET:isize   = 62;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_MINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_MINH_BIT_MASK:UintXlen = 0x4000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfg.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfg_minh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_minstretcfg_minh {
+    ( ) => (
+        ($crate::csr_read_minstretcfg!() & $crate::MINSTRETCFG_MINH_BIT_MASK) >> $crate::MINSTRETCFG_MINH_BIT_OFFSET
+    );
+}
+/* minstretcfg.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfg_minh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_minstretcfg_minh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_minstretcfg!();
+            let new_value = (org_value & !$crate::MINSTRETCFG_MINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFG_MINH_BIT_OFFSET) & $crate::MINSTRETCFG_MINH_BIT_MASK);
+            $crate::csr_write_minstretcfg!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_SINH_BIT_OFFSET:isize   = 61;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_SINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_SINH_BIT_MASK:UintXlen = 0x2000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfg.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfg_sinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_minstretcfg_sinh {
+    ( ) => (
+        ($crate::csr_read_minstretcfg!() & $crate::MINSTRETCFG_SINH_BIT_MASK) >> $crate::MINSTRETCFG_SINH_BIT_OFFSET
+    );
+}
+/* minstretcfg.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfg_sinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_minstretcfg_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_minstretcfg!();
+            let new_value = (org_value & !$crate::MINSTRETCFG_SINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFG_SINH_BIT_OFFSET) & $crate::MINSTRETCFG_SINH_BIT_MASK);
+            $crate::csr_write_minstretcfg!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_UINH_BIT_OFFSET:isize   = 60;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_UINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_UINH_BIT_MASK:UintXlen = 0x1000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfg.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfg_uinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_minstretcfg_uinh {
+    ( ) => (
+        ($crate::csr_read_minstretcfg!() & $crate::MINSTRETCFG_UINH_BIT_MASK) >> $crate::MINSTRETCFG_UINH_BIT_OFFSET
+    );
+}
+/* minstretcfg.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfg_uinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_minstretcfg_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_minstretcfg!();
+            let new_value = (org_value & !$crate::MINSTRETCFG_UINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFG_UINH_BIT_OFFSET) & $crate::MINSTRETCFG_UINH_BIT_MASK);
+            $crate::csr_write_minstretcfg!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_VSINH_BIT_OFFSET:isize   = 59;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_VSINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_VSINH_BIT_MASK:UintXlen = 0x800000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfg.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfg_vsinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_minstretcfg_vsinh {
+    ( ) => (
+        ($crate::csr_read_minstretcfg!() & $crate::MINSTRETCFG_VSINH_BIT_MASK) >> $crate::MINSTRETCFG_VSINH_BIT_OFFSET
+    );
+}
+/* minstretcfg.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfg_vsinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_minstretcfg_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_minstretcfg!();
+            let new_value = (org_value & !$crate::MINSTRETCFG_VSINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFG_VSINH_BIT_OFFSET) & $crate::MINSTRETCFG_VSINH_BIT_MASK);
+            $crate::csr_write_minstretcfg!(new_value);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_VUINH_BIT_OFFSET:isize   = 58;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_VUINH_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_VUINH_BIT_MASK:UintXlen = 0x400000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MINSTRETCFG_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfg.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfg_vuinh!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_minstretcfg_vuinh {
+    ( ) => (
+        ($crate::csr_read_minstretcfg!() & $crate::MINSTRETCFG_VUINH_BIT_MASK) >> $crate::MINSTRETCFG_VUINH_BIT_OFFSET
+    );
+}
+/* minstretcfg.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfg_vuinh!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_minstretcfg_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_minstretcfg!();
+            let new_value = (org_value & !$crate::MINSTRETCFG_VUINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFG_VUINH_BIT_OFFSET) & $crate::MINSTRETCFG_VUINH_BIT_MASK);
+            $crate::csr_write_minstretcfg!(new_value);
+        }
+    );
+}
+
+/*******************************************
+ * minstretcfgh - MRW - Upper 32 bits of minstretcfg, RV32 only. 
+ */
+pub const MINSTRETCFGH_MINH_BIT_OFFSET:isize   = 30;
+pub const MINSTRETCFGH_MINH_BIT_WIDTH:isize    = 1;
+pub const MINSTRETCFGH_MINH_BIT_MASK:UintXlen = 0x40000000;
+pub const MINSTRETCFGH_MINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfgh.minh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfgh_minh!();
+ */
+#[macro_export]
+macro_rules! csr_read_minstretcfgh_minh {
+    ( ) => (
+        (($crate::csr_read_minstretcfgh!() as $crate::UintXlen) & $crate::MINSTRETCFGH_MINH_BIT_MASK) >> $crate::MINSTRETCFGH_MINH_BIT_OFFSET
+    );
+}
+/* minstretcfgh.minh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfgh_minh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_minstretcfgh_minh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_minstretcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MINSTRETCFGH_MINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFGH_MINH_BIT_OFFSET) & $crate::MINSTRETCFGH_MINH_BIT_MASK);
+            $crate::csr_write_minstretcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MINSTRETCFGH_SINH_BIT_OFFSET:isize   = 29;
+pub const MINSTRETCFGH_SINH_BIT_WIDTH:isize    = 1;
+pub const MINSTRETCFGH_SINH_BIT_MASK:UintXlen = 0x20000000;
+pub const MINSTRETCFGH_SINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfgh.sinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfgh_sinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_minstretcfgh_sinh {
+    ( ) => (
+        (($crate::csr_read_minstretcfgh!() as $crate::UintXlen) & $crate::MINSTRETCFGH_SINH_BIT_MASK) >> $crate::MINSTRETCFGH_SINH_BIT_OFFSET
+    );
+}
+/* minstretcfgh.sinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfgh_sinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_minstretcfgh_sinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_minstretcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MINSTRETCFGH_SINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFGH_SINH_BIT_OFFSET) & $crate::MINSTRETCFGH_SINH_BIT_MASK);
+            $crate::csr_write_minstretcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MINSTRETCFGH_UINH_BIT_OFFSET:isize   = 28;
+pub const MINSTRETCFGH_UINH_BIT_WIDTH:isize    = 1;
+pub const MINSTRETCFGH_UINH_BIT_MASK:UintXlen = 0x10000000;
+pub const MINSTRETCFGH_UINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfgh.uinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfgh_uinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_minstretcfgh_uinh {
+    ( ) => (
+        (($crate::csr_read_minstretcfgh!() as $crate::UintXlen) & $crate::MINSTRETCFGH_UINH_BIT_MASK) >> $crate::MINSTRETCFGH_UINH_BIT_OFFSET
+    );
+}
+/* minstretcfgh.uinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfgh_uinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_minstretcfgh_uinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_minstretcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MINSTRETCFGH_UINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFGH_UINH_BIT_OFFSET) & $crate::MINSTRETCFGH_UINH_BIT_MASK);
+            $crate::csr_write_minstretcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MINSTRETCFGH_VSINH_BIT_OFFSET:isize   = 27;
+pub const MINSTRETCFGH_VSINH_BIT_WIDTH:isize    = 1;
+pub const MINSTRETCFGH_VSINH_BIT_MASK:UintXlen = 0x8000000;
+pub const MINSTRETCFGH_VSINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfgh.vsinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfgh_vsinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_minstretcfgh_vsinh {
+    ( ) => (
+        (($crate::csr_read_minstretcfgh!() as $crate::UintXlen) & $crate::MINSTRETCFGH_VSINH_BIT_MASK) >> $crate::MINSTRETCFGH_VSINH_BIT_OFFSET
+    );
+}
+/* minstretcfgh.vsinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfgh_vsinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_minstretcfgh_vsinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_minstretcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MINSTRETCFGH_VSINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFGH_VSINH_BIT_OFFSET) & $crate::MINSTRETCFGH_VSINH_BIT_MASK);
+            $crate::csr_write_minstretcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MINSTRETCFGH_VUINH_BIT_OFFSET:isize   = 26;
+pub const MINSTRETCFGH_VUINH_BIT_WIDTH:isize    = 1;
+pub const MINSTRETCFGH_VUINH_BIT_MASK:UintXlen = 0x4000000;
+pub const MINSTRETCFGH_VUINH_ALL_SET_MASK:UintXlen = 0x1;
+/* minstretcfgh.vuinh: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_minstretcfgh_vuinh!();
+ */
+#[macro_export]
+macro_rules! csr_read_minstretcfgh_vuinh {
+    ( ) => (
+        (($crate::csr_read_minstretcfgh!() as $crate::UintXlen) & $crate::MINSTRETCFGH_VUINH_BIT_MASK) >> $crate::MINSTRETCFGH_VUINH_BIT_OFFSET
+    );
+}
+/* minstretcfgh.vuinh: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_minstretcfgh_vuinh!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_minstretcfgh_vuinh {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_minstretcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MINSTRETCFGH_VUINH_BIT_MASK)
+                | ((($x) << $crate::MINSTRETCFGH_VUINH_BIT_OFFSET) & $crate::MINSTRETCFGH_VUINH_BIT_MASK);
+            $crate::csr_write_minstretcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+
+/*******************************************
+ * menvcfg - MRW - Machine environment configuration. 
+ */
+pub const MENVCFG_FIOM_BIT_OFFSET:isize   = 0;
+pub const MENVCFG_FIOM_BIT_WIDTH:isize    = 1;
+pub const MENVCFG_FIOM_BIT_MASK:UintXlen = 0x1;
+pub const MENVCFG_FIOM_ALL_SET_MASK:UintXlen = 0x1;
+/* menvcfg.fiom: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfg_fiom!();
+ */
+#[macro_export]
+macro_rules! csr_read_menvcfg_fiom {
+    ( ) => (
+        (($crate::csr_read_menvcfg!() as $crate::UintXlen) & $crate::MENVCFG_FIOM_BIT_MASK) >> $crate::MENVCFG_FIOM_BIT_OFFSET
+    );
+}
+/* menvcfg.fiom: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfg_fiom!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_menvcfg_fiom {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFG_FIOM_BIT_MASK)
+                | ((($x) << $crate::MENVCFG_FIOM_BIT_OFFSET) & $crate::MENVCFG_FIOM_BIT_MASK);
+            $crate::csr_write_menvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+pub const MENVCFG_CBIE_BIT_OFFSET:isize   = 4;
+pub const MENVCFG_CBIE_BIT_WIDTH:isize    = 2;
+pub const MENVCFG_CBIE_BIT_MASK:UintXlen = 0x30;
+pub const MENVCFG_CBIE_ALL_SET_MASK:UintXlen = 0x3;
+/* menvcfg.cbie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfg_cbie!();
+ */
+#[macro_export]
+macro_rules! csr_read_menvcfg_cbie {
+    ( ) => (
+        (($crate::csr_read_menvcfg!() as $crate::UintXlen) & $crate::MENVCFG_CBIE_BIT_MASK) >> $crate::MENVCFG_CBIE_BIT_OFFSET
+    );
+}
+/* menvcfg.cbie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfg_cbie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_menvcfg_cbie {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFG_CBIE_BIT_MASK)
+                | ((($x) << $crate::MENVCFG_CBIE_BIT_OFFSET) & $crate::MENVCFG_CBIE_BIT_MASK);
+            $crate::csr_write_menvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+pub const MENVCFG_CBCFE_BIT_OFFSET:isize   = 6;
+pub const MENVCFG_CBCFE_BIT_WIDTH:isize    = 1;
+pub const MENVCFG_CBCFE_BIT_MASK:UintXlen = 0x40;
+pub const MENVCFG_CBCFE_ALL_SET_MASK:UintXlen = 0x1;
+/* menvcfg.cbcfe: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfg_cbcfe!();
+ */
+#[macro_export]
+macro_rules! csr_read_menvcfg_cbcfe {
+    ( ) => (
+        (($crate::csr_read_menvcfg!() as $crate::UintXlen) & $crate::MENVCFG_CBCFE_BIT_MASK) >> $crate::MENVCFG_CBCFE_BIT_OFFSET
+    );
+}
+/* menvcfg.cbcfe: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfg_cbcfe!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_menvcfg_cbcfe {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFG_CBCFE_BIT_MASK)
+                | ((($x) << $crate::MENVCFG_CBCFE_BIT_OFFSET) & $crate::MENVCFG_CBCFE_BIT_MASK);
+            $crate::csr_write_menvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+pub const MENVCFG_CBZE_BIT_OFFSET:isize   = 7;
+pub const MENVCFG_CBZE_BIT_WIDTH:isize    = 1;
+pub const MENVCFG_CBZE_BIT_MASK:UintXlen = 0x80;
+pub const MENVCFG_CBZE_ALL_SET_MASK:UintXlen = 0x1;
+/* menvcfg.cbze: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfg_cbze!();
+ */
+#[macro_export]
+macro_rules! csr_read_menvcfg_cbze {
+    ( ) => (
+        (($crate::csr_read_menvcfg!() as $crate::UintXlen) & $crate::MENVCFG_CBZE_BIT_MASK) >> $crate::MENVCFG_CBZE_BIT_OFFSET
+    );
+}
+/* menvcfg.cbze: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfg_cbze!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_menvcfg_cbze {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFG_CBZE_BIT_MASK)
+                | ((($x) << $crate::MENVCFG_CBZE_BIT_OFFSET) & $crate::MENVCFG_CBZE_BIT_MASK);
+            $crate::csr_write_menvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_ADUE_BIT_OFFSET:isize   = 61;
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_ADUE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_ADUE_BIT_MASK:UintXlen = 0x2000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_ADUE_ALL_SET_MASK:UintXlen = 0x1;
+/* menvcfg.adue: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfg_adue!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_menvcfg_adue {
+    ( ) => (
+        (($crate::csr_read_menvcfg!() as $crate::UintXlen) & $crate::MENVCFG_ADUE_BIT_MASK) >> $crate::MENVCFG_ADUE_BIT_OFFSET
+    );
+}
+/* menvcfg.adue: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfg_adue!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_menvcfg_adue {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFG_ADUE_BIT_MASK)
+                | ((($x) << $crate::MENVCFG_ADUE_BIT_OFFSET) & $crate::MENVCFG_ADUE_BIT_MASK);
+            $crate::csr_write_menvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_PBMTE_BIT_OFFSET:isize   = 62;
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_PBMTE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_PBMTE_BIT_MASK:UintXlen = 0x4000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_PBMTE_ALL_SET_MASK:UintXlen = 0x1;
+/* menvcfg.pbmte: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfg_pbmte!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_menvcfg_pbmte {
+    ( ) => (
+        (($crate::csr_read_menvcfg!() as $crate::UintXlen) & $crate::MENVCFG_PBMTE_BIT_MASK) >> $crate::MENVCFG_PBMTE_BIT_OFFSET
+    );
+}
+/* menvcfg.pbmte: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfg_pbmte!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_menvcfg_pbmte {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFG_PBMTE_BIT_MASK)
+                | ((($x) << $crate::MENVCFG_PBMTE_BIT_OFFSET) & $crate::MENVCFG_PBMTE_BIT_MASK);
+            $crate::csr_write_menvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_STCE_BIT_OFFSET:isize   = 63;
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_STCE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_STCE_BIT_MASK:UintXlen = 0x8000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const MENVCFG_STCE_ALL_SET_MASK:UintXlen = 0x1;
+/* menvcfg.stce: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfg_stce!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_menvcfg_stce {
+    ( ) => (
+        (($crate::csr_read_menvcfg!() as $crate::UintXlen) & $crate::MENVCFG_STCE_BIT_MASK) >> $crate::MENVCFG_STCE_BIT_OFFSET
+    );
+}
+/* menvcfg.stce: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfg_stce!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_menvcfg_stce {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFG_STCE_BIT_MASK)
+                | ((($x) << $crate::MENVCFG_STCE_BIT_OFFSET) & $crate::MENVCFG_STCE_BIT_MASK);
+            $crate::csr_write_menvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
 
 /*******************************************
- * mtvec - MRW - Machine Trap Vector Base Address 
+ * menvcfgh - MRW - Upper 32 bits of menvcfg, RV32 only. 
+ */
+pub const MENVCFGH_ADUE_BIT_OFFSET:isize   = 29;
+pub const MENVCFGH_ADUE_BIT_WIDTH:isize    = 1;
+pub const MENVCFGH_ADUE_BIT_MASK:UintXlen = 0x20000000;
+pub const MENVCFGH_ADUE_ALL_SET_MASK:UintXlen = 0x1;
+/* menvcfgh.adue: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfgh_adue!();
+ */
+#[macro_export]
+macro_rules! csr_read_menvcfgh_adue {
+    ( ) => (
+        (($crate::csr_read_menvcfgh!() as $crate::UintXlen) & $crate::MENVCFGH_ADUE_BIT_MASK) >> $crate::MENVCFGH_ADUE_BIT_OFFSET
+    );
+}
+/* menvcfgh.adue: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfgh_adue!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_menvcfgh_adue {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFGH_ADUE_BIT_MASK)
+                | ((($x) << $crate::MENVCFGH_ADUE_BIT_OFFSET) & $crate::MENVCFGH_ADUE_BIT_MASK);
+            $crate::csr_write_menvcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MENVCFGH_PBMTE_BIT_OFFSET:isize   = 30;
+pub const MENVCFGH_PBMTE_BIT_WIDTH:isize    = 1;
+pub const MENVCFGH_PBMTE_BIT_MASK:UintXlen = 0x40000000;
+pub const MENVCFGH_PBMTE_ALL_SET_MASK:UintXlen = 0x1;
+/* menvcfgh.pbmte: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfgh_pbmte!();
+ */
+#[macro_export]
+macro_rules! csr_read_menvcfgh_pbmte {
+    ( ) => (
+        (($crate::csr_read_menvcfgh!() as $crate::UintXlen) & $crate::MENVCFGH_PBMTE_BIT_MASK) >> $crate::MENVCFGH_PBMTE_BIT_OFFSET
+    );
+}
+/* menvcfgh.pbmte: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfgh_pbmte!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_menvcfgh_pbmte {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFGH_PBMTE_BIT_MASK)
+                | ((($x) << $crate::MENVCFGH_PBMTE_BIT_OFFSET) & $crate::MENVCFGH_PBMTE_BIT_MASK);
+            $crate::csr_write_menvcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const MENVCFGH_STCE_BIT_OFFSET:isize   = 31;
+pub const MENVCFGH_STCE_BIT_WIDTH:isize    = 1;
+pub const MENVCFGH_STCE_BIT_MASK:UintXlen = 0x80000000;
+pub const MENVCFGH_STCE_ALL_SET_MASK:UintXlen = 0x1;
+/* menvcfgh.stce: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_menvcfgh_stce!();
+ */
+#[macro_export]
+macro_rules! csr_read_menvcfgh_stce {
+    ( ) => (
+        (($crate::csr_read_menvcfgh!() as $crate::UintXlen) & $crate::MENVCFGH_STCE_BIT_MASK) >> $crate::MENVCFGH_STCE_BIT_OFFSET
+    );
+}
+/* menvcfgh.stce: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_menvcfgh_stce!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_menvcfgh_stce {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_menvcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MENVCFGH_STCE_BIT_MASK)
+                | ((($x) << $crate::MENVCFGH_STCE_BIT_OFFSET) & $crate::MENVCFGH_STCE_BIT_MASK);
+            $crate::csr_write_menvcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+
+/*******************************************
+ * henvcfg - HRW - Hypervisor environment configuration. 
+ */
+pub const HENVCFG_FIOM_BIT_OFFSET:isize   = 0;
+pub const HENVCFG_FIOM_BIT_WIDTH:isize    = 1;
+pub const HENVCFG_FIOM_BIT_MASK:UintXlen = 0x1;
+pub const HENVCFG_FIOM_ALL_SET_MASK:UintXlen = 0x1;
+/* henvcfg.fiom: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfg_fiom!();
+ */
+#[macro_export]
+macro_rules! csr_read_henvcfg_fiom {
+    ( ) => (
+        (($crate::csr_read_henvcfg!() as $crate::UintXlen) & $crate::HENVCFG_FIOM_BIT_MASK) >> $crate::HENVCFG_FIOM_BIT_OFFSET
+    );
+}
+/* henvcfg.fiom: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfg_fiom!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_henvcfg_fiom {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFG_FIOM_BIT_MASK)
+                | ((($x) << $crate::HENVCFG_FIOM_BIT_OFFSET) & $crate::HENVCFG_FIOM_BIT_MASK);
+            $crate::csr_write_henvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+pub const HENVCFG_CBIE_BIT_OFFSET:isize   = 4;
+pub const HENVCFG_CBIE_BIT_WIDTH:isize    = 2;
+pub const HENVCFG_CBIE_BIT_MASK:UintXlen = 0x30;
+pub const HENVCFG_CBIE_ALL_SET_MASK:UintXlen = 0x3;
+/* henvcfg.cbie: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfg_cbie!();
+ */
+#[macro_export]
+macro_rules! csr_read_henvcfg_cbie {
+    ( ) => (
+        (($crate::csr_read_henvcfg!() as $crate::UintXlen) & $crate::HENVCFG_CBIE_BIT_MASK) >> $crate::HENVCFG_CBIE_BIT_OFFSET
+    );
+}
+/* henvcfg.cbie: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfg_cbie!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_henvcfg_cbie {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFG_CBIE_BIT_MASK)
+                | ((($x) << $crate::HENVCFG_CBIE_BIT_OFFSET) & $crate::HENVCFG_CBIE_BIT_MASK);
+            $crate::csr_write_henvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+pub const HENVCFG_CBCFE_BIT_OFFSET:isize   = 6;
+pub const HENVCFG_CBCFE_BIT_WIDTH:isize    = 1;
+pub const HENVCFG_CBCFE_BIT_MASK:UintXlen = 0x40;
+pub const HENVCFG_CBCFE_ALL_SET_MASK:UintXlen = 0x1;
+/* henvcfg.cbcfe: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfg_cbcfe!();
+ */
+#[macro_export]
+macro_rules! csr_read_henvcfg_cbcfe {
+    ( ) => (
+        (($crate::csr_read_henvcfg!() as $crate::UintXlen) & $crate::HENVCFG_CBCFE_BIT_MASK) >> $crate::HENVCFG_CBCFE_BIT_OFFSET
+    );
+}
+/* henvcfg.cbcfe: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfg_cbcfe!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_henvcfg_cbcfe {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFG_CBCFE_BIT_MASK)
+                | ((($x) << $crate::HENVCFG_CBCFE_BIT_OFFSET) & $crate::HENVCFG_CBCFE_BIT_MASK);
+            $crate::csr_write_henvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+pub const HENVCFG_CBZE_BIT_OFFSET:isize   = 7;
+pub const HENVCFG_CBZE_BIT_WIDTH:isize    = 1;
+pub const HENVCFG_CBZE_BIT_MASK:UintXlen = 0x80;
+pub const HENVCFG_CBZE_ALL_SET_MASK:UintXlen = 0x1;
+/* henvcfg.cbze: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfg_cbze!();
+ */
+#[macro_export]
+macro_rules! csr_read_henvcfg_cbze {
+    ( ) => (
+        (($crate::csr_read_henvcfg!() as $crate::UintXlen) & $crate::HENVCFG_CBZE_BIT_MASK) >> $crate::HENVCFG_CBZE_BIT_OFFSET
+    );
+}
+/* henvcfg.cbze: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfg_cbze!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_henvcfg_cbze {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFG_CBZE_BIT_MASK)
+                | ((($x) << $crate::HENVCFG_CBZE_BIT_OFFSET) & $crate::HENVCFG_CBZE_BIT_MASK);
+            $crate::csr_write_henvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_ADUE_BIT_OFFSET:isize   = 61;
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_ADUE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_ADUE_BIT_MASK:UintXlen = 0x2000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_ADUE_ALL_SET_MASK:UintXlen = 0x1;
+/* henvcfg.adue: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfg_adue!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_henvcfg_adue {
+    ( ) => (
+        (($crate::csr_read_henvcfg!() as $crate::UintXlen) & $crate::HENVCFG_ADUE_BIT_MASK) >> $crate::HENVCFG_ADUE_BIT_OFFSET
+    );
+}
+/* henvcfg.adue: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfg_adue!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_henvcfg_adue {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFG_ADUE_BIT_MASK)
+                | ((($x) << $crate::HENVCFG_ADUE_BIT_OFFSET) & $crate::HENVCFG_ADUE_BIT_MASK);
+            $crate::csr_write_henvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_PBMTE_BIT_OFFSET:isize   = 62;
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_PBMTE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_PBMTE_BIT_MASK:UintXlen = 0x4000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_PBMTE_ALL_SET_MASK:UintXlen = 0x1;
+/* henvcfg.pbmte: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfg_pbmte!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_henvcfg_pbmte {
+    ( ) => (
+        (($crate::csr_read_henvcfg!() as $crate::UintXlen) & $crate::HENVCFG_PBMTE_BIT_MASK) >> $crate::HENVCFG_PBMTE_BIT_OFFSET
+    );
+}
+/* henvcfg.pbmte: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfg_pbmte!(0x1);
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_henvcfg_pbmte {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFG_PBMTE_BIT_MASK)
+                | ((($x) << $crate::HENVCFG_PBMTE_BIT_OFFSET) & $crate::HENVCFG_PBMTE_BIT_MASK);
+            $crate::csr_write_henvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_STCE_BIT_OFFSET:isize   = 63;
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_STCE_BIT_WIDTH:isize    = 1;
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_STCE_BIT_MASK:UintXlen = 0x8000000000000000;
+#[cfg(target_pointer_width = "64")]
+pub const HENVCFG_STCE_ALL_SET_MASK:UintXlen = 0x1;
+/* henvcfg.stce: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfg_stce!();
+ */
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_read_henvcfg_stce {
+    ( ) => (
+        (($crate::csr_read_henvcfg!() as $crate::UintXlen) & $crate::HENVCFG_STCE_BIT_MASK) >> $crate::HENVCFG_STCE_BIT_OFFSET
+    );
+}
+/* henvcfg.stce: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfg_stce!(0x1);
  */
-pub const MTVEC_BASE_BIT_OFFSET:isize   = 2;
-pub const MTVEC_BASE_BIT_WIDTH:isize    = (__riscv_xlen-1)-(2) + 1;
-pub const MTVEC_BASE_BIT_MASK:UintXlen = (1<<((__riscv_xlen-1)-(2) + 1-1)) << (2);
-pub const MTVEC_BASE_ALL_SET_MASK:UintXlen = (1<<((__riscv_xlen-1)-(2) + 1-1)) << (0);
-pub const MTVEC_MODE_BIT_OFFSET:isize   = 0;
-pub const MTVEC_MODE_BIT_WIDTH:isize    = 2;
-pub const MTVEC_MODE_BIT_MASK:UintXlen = 0x3;
-pub const MTVEC_MODE_ALL_SET_MASK:UintXlen = 0x3;
+#[cfg(target_pointer_width = "64")]
+#[macro_export]
+macro_rules! csr_write_henvcfg_stce {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFG_STCE_BIT_MASK)
+                | ((($x) << $crate::HENVCFG_STCE_BIT_OFFSET) & $crate::HENVCFG_STCE_BIT_MASK);
+            $crate::csr_write_henvcfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
 
 /*******************************************
- * mip - MRW - Machine Interrupt Pending 
+ * henvcfgh - HRW - Upper 32 bits of henvcfg, RV32 only. 
  */
-pub const MIP_MSI_BIT_OFFSET:isize   = 3;
-pub const MIP_MSI_BIT_WIDTH:isize    = 1;
-pub const MIP_MSI_BIT_MASK:UintXlen = 0x8;
-pub const MIP_MSI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIP_MTI_BIT_OFFSET:isize   = 7;
-pub const MIP_MTI_BIT_WIDTH:isize    = 1;
-pub const MIP_MTI_BIT_MASK:UintXlen = 0x80;
-pub const MIP_MTI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIP_MEI_BIT_OFFSET:isize   = 11;
-pub const MIP_MEI_BIT_WIDTH:isize    = 1;
-pub const MIP_MEI_BIT_MASK:UintXlen = 0x800;
-pub const MIP_MEI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIP_SSI_BIT_OFFSET:isize   = 1;
-pub const MIP_SSI_BIT_WIDTH:isize    = 1;
-pub const MIP_SSI_BIT_MASK:UintXlen = 0x2;
-pub const MIP_SSI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIP_STI_BIT_OFFSET:isize   = 5;
-pub const MIP_STI_BIT_WIDTH:isize    = 1;
-pub const MIP_STI_BIT_MASK:UintXlen = 0x20;
-pub const MIP_STI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIP_SEI_BIT_OFFSET:isize   = 9;
-pub const MIP_SEI_BIT_WIDTH:isize    = 1;
-pub const MIP_SEI_BIT_MASK:UintXlen = 0x200;
-pub const MIP_SEI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIP_USI_BIT_OFFSET:isize   = 0;
-pub const MIP_USI_BIT_WIDTH:isize    = 1;
-pub const MIP_USI_BIT_MASK:UintXlen = 0x1;
-pub const MIP_USI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIP_UTI_BIT_OFFSET:isize   = 4;
-pub const MIP_UTI_BIT_WIDTH:isize    = 1;
-pub const MIP_UTI_BIT_MASK:UintXlen = 0x10;
-pub const MIP_UTI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIP_UEI_BIT_OFFSET:isize   = 8;
-pub const MIP_UEI_BIT_WIDTH:isize    = 1;
-pub const MIP_UEI_BIT_MASK:UintXlen = 0x100;
-pub const MIP_UEI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIP_PLATFORM_DEFINED_BIT_OFFSET:isize   = 16;
-pub const MIP_PLATFORM_DEFINED_BIT_WIDTH:isize    = (__riscv_xlen)-(16) + 1;
-pub const MIP_PLATFORM_DEFINED_BIT_MASK:UintXlen = (1<<((__riscv_xlen)-(16) + 1-1)) << (16);
-pub const MIP_PLATFORM_DEFINED_ALL_SET_MASK:UintXlen = (1<<((__riscv_xlen)-(16) + 1-1)) << (0);
+pub const HENVCFGH_ADUE_BIT_OFFSET:isize   = 29;
+pub const HENVCFGH_ADUE_BIT_WIDTH:isize    = 1;
+pub const HENVCFGH_ADUE_BIT_MASK:UintXlen = 0x20000000;
+pub const HENVCFGH_ADUE_ALL_SET_MASK:UintXlen = 0x1;
+/* henvcfgh.adue: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfgh_adue!();
+ */
+#[macro_export]
+macro_rules! csr_read_henvcfgh_adue {
+    ( ) => (
+        (($crate::csr_read_henvcfgh!() as $crate::UintXlen) & $crate::HENVCFGH_ADUE_BIT_MASK) >> $crate::HENVCFGH_ADUE_BIT_OFFSET
+    );
+}
+/* henvcfgh.adue: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfgh_adue!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_henvcfgh_adue {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFGH_ADUE_BIT_MASK)
+                | ((($x) << $crate::HENVCFGH_ADUE_BIT_OFFSET) & $crate::HENVCFGH_ADUE_BIT_MASK);
+            $crate::csr_write_henvcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const HENVCFGH_PBMTE_BIT_OFFSET:isize   = 30;
+pub const HENVCFGH_PBMTE_BIT_WIDTH:isize    = 1;
+pub const HENVCFGH_PBMTE_BIT_MASK:UintXlen = 0x40000000;
+pub const HENVCFGH_PBMTE_ALL_SET_MASK:UintXlen = 0x1;
+/* henvcfgh.pbmte: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfgh_pbmte!();
+ */
+#[macro_export]
+macro_rules! csr_read_henvcfgh_pbmte {
+    ( ) => (
+        (($crate::csr_read_henvcfgh!() as $crate::UintXlen) & $crate::HENVCFGH_PBMTE_BIT_MASK) >> $crate::HENVCFGH_PBMTE_BIT_OFFSET
+    );
+}
+/* henvcfgh.pbmte: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfgh_pbmte!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_henvcfgh_pbmte {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFGH_PBMTE_BIT_MASK)
+                | ((($x) << $crate::HENVCFGH_PBMTE_BIT_OFFSET) & $crate::HENVCFGH_PBMTE_BIT_MASK);
+            $crate::csr_write_henvcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
+pub const HENVCFGH_STCE_BIT_OFFSET:isize   = 31;
+pub const HENVCFGH_STCE_BIT_WIDTH:isize    = 1;
+pub const HENVCFGH_STCE_BIT_MASK:UintXlen = 0x80000000;
+pub const HENVCFGH_STCE_ALL_SET_MASK:UintXlen = 0x1;
+/* henvcfgh.stce: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_henvcfgh_stce!();
+ */
+#[macro_export]
+macro_rules! csr_read_henvcfgh_stce {
+    ( ) => (
+        (($crate::csr_read_henvcfgh!() as $crate::UintXlen) & $crate::HENVCFGH_STCE_BIT_MASK) >> $crate::HENVCFGH_STCE_BIT_OFFSET
+    );
+}
+/* henvcfgh.stce: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_henvcfgh_stce!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_henvcfgh_stce {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_henvcfgh!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::HENVCFGH_STCE_BIT_MASK)
+                | ((($x) << $crate::HENVCFGH_STCE_BIT_OFFSET) & $crate::HENVCFGH_STCE_BIT_MASK);
+            $crate::csr_write_henvcfgh!(new_value as $crate::UintCsr32);
+        }
+    );
+}
 
 /*******************************************
- * mie - MRW - Machine Interrupt Enable 
+ * hvip - HRW - Hypervisor virtual interrupt pending. 
  */
-pub const MIE_MSI_BIT_OFFSET:isize   = 3;
-pub const MIE_MSI_BIT_WIDTH:isize    = 1;
-pub const MIE_MSI_BIT_MASK:UintXlen = 0x8;
-pub const MIE_MSI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIE_MTI_BIT_OFFSET:isize   = 7;
-pub const MIE_MTI_BIT_WIDTH:isize    = 1;
-pub const MIE_MTI_BIT_MASK:UintXlen = 0x80;
-pub const MIE_MTI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIE_MEI_BIT_OFFSET:isize   = 11;
-pub const MIE_MEI_BIT_WIDTH:isize    = 1;
-pub const MIE_MEI_BIT_MASK:UintXlen = 0x800;
-pub const MIE_MEI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIE_SSI_BIT_OFFSET:isize   = 1;
-pub const MIE_SSI_BIT_WIDTH:isize    = 1;
-pub const MIE_SSI_BIT_MASK:UintXlen = 0x2;
-pub const MIE_SSI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIE_STI_BIT_OFFSET:isize   = 5;
-pub const MIE_STI_BIT_WIDTH:isize    = 1;
-pub const MIE_STI_BIT_MASK:UintXlen = 0x20;
-pub const MIE_STI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIE_SEI_BIT_OFFSET:isize   = 9;
-pub const MIE_SEI_BIT_WIDTH:isize    = 1;
-pub const MIE_SEI_BIT_MASK:UintXlen = 0x200;
-pub const MIE_SEI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIE_USI_BIT_OFFSET:isize   = 0;
-pub const MIE_USI_BIT_WIDTH:isize    = 1;
-pub const MIE_USI_BIT_MASK:UintXlen = 0x1;
-pub const MIE_USI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIE_UTI_BIT_OFFSET:isize   = 4;
-pub const MIE_UTI_BIT_WIDTH:isize    = 1;
-pub const MIE_UTI_BIT_MASK:UintXlen = 0x10;
-pub const MIE_UTI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIE_UEI_BIT_OFFSET:isize   = 8;
-pub const MIE_UEI_BIT_WIDTH:isize    = 1;
-pub const MIE_UEI_BIT_MASK:UintXlen = 0x100;
-pub const MIE_UEI_ALL_SET_MASK:UintXlen = 0x1;
-pub const MIE_PLATFORM_DEFINED_BIT_OFFSET:isize   = 16;
-pub const MIE_PLATFORM_DEFINED_BIT_WIDTH:isize    = (__riscv_xlen)-(16) + 1;
-pub const MIE_PLATFORM_DEFINED_BIT_MASK:UintXlen = (1<<((__riscv_xlen)-(16) + 1-1)) << (16);
-pub const MIE_PLATFORM_DEFINED_ALL_SET_MASK:UintXlen = (1<<((__riscv_xlen)-(16) + 1-1)) << (0);
+pub const HVIP_VSSI_BIT_OFFSET:isize   = 2;
+pub const HVIP_VSSI_BIT_WIDTH:isize    = 1;
+pub const HVIP_VSSI_BIT_MASK:UintXlen = 0x4;
+pub const HVIP_VSSI_ALL_SET_MASK:UintXlen = 0x1;
+/* hvip.vssi: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hvip_vssi!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvip_vssi {
+    ( ) => (
+        ($crate::csr_read_hvip!() & $crate::HVIP_VSSI_BIT_MASK) >> $crate::HVIP_VSSI_BIT_OFFSET
+    );
+}
+/* hvip.vssi: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hvip_vssi!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hvip_vssi {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hvip!();
+            let new_value = (org_value & !$crate::HVIP_VSSI_BIT_MASK)
+                | ((($x) << $crate::HVIP_VSSI_BIT_OFFSET) & $crate::HVIP_VSSI_BIT_MASK);
+            $crate::csr_write_hvip!(new_value);
+        }
+    );
+}
+pub const HVIP_VSTI_BIT_OFFSET:isize   = 6;
+pub const HVIP_VSTI_BIT_WIDTH:isize    = 1;
+pub const HVIP_VSTI_BIT_MASK:UintXlen = 0x40;
+pub const HVIP_VSTI_ALL_SET_MASK:UintXlen = 0x1;
+/* hvip.vsti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hvip_vsti!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvip_vsti {
+    ( ) => (
+        ($crate::csr_read_hvip!() & $crate::HVIP_VSTI_BIT_MASK) >> $crate::HVIP_VSTI_BIT_OFFSET
+    );
+}
+/* hvip.vsti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hvip_vsti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hvip_vsti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hvip!();
+            let new_value = (org_value & !$crate::HVIP_VSTI_BIT_MASK)
+                | ((($x) << $crate::HVIP_VSTI_BIT_OFFSET) & $crate::HVIP_VSTI_BIT_MASK);
+            $crate::csr_write_hvip!(new_value);
+        }
+    );
+}
+pub const HVIP_VSEI_BIT_OFFSET:isize   = 10;
+pub const HVIP_VSEI_BIT_WIDTH:isize    = 1;
+pub const HVIP_VSEI_BIT_MASK:UintXlen = 0x400;
+pub const HVIP_VSEI_ALL_SET_MASK:UintXlen = 0x1;
+/* hvip.vsei: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hvip_vsei!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvip_vsei {
+    ( ) => (
+        ($crate::csr_read_hvip!() & $crate::HVIP_VSEI_BIT_MASK) >> $crate::HVIP_VSEI_BIT_OFFSET
+    );
+}
+/* hvip.vsei: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hvip_vsei!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hvip_vsei {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hvip!();
+            let new_value = (org_value & !$crate::HVIP_VSEI_BIT_MASK)
+                | ((($x) << $crate::HVIP_VSEI_BIT_OFFSET) & $crate::HVIP_VSEI_BIT_MASK);
+            $crate::csr_write_hvip!(new_value);
+        }
+    );
+}
 
 /*******************************************
- * mcountinhibit - MRW - Machine Counter Inhibit 
+ * hvictl - HRW - Hypervisor virtual interrupt control. 
  */
-pub const MCOUNTINHIBIT_CY_BIT_OFFSET:isize   = 0;
-pub const MCOUNTINHIBIT_CY_BIT_WIDTH:isize    = 1;
-pub const MCOUNTINHIBIT_CY_BIT_MASK:UintXlen = 0x1;
-pub const MCOUNTINHIBIT_CY_ALL_SET_MASK:UintXlen = 0x1;
-pub const MCOUNTINHIBIT_IR_BIT_OFFSET:isize   = 2;
-pub const MCOUNTINHIBIT_IR_BIT_WIDTH:isize    = 1;
-pub const MCOUNTINHIBIT_IR_BIT_MASK:UintXlen = 0x4;
-pub const MCOUNTINHIBIT_IR_ALL_SET_MASK:UintXlen = 0x1;
-pub const MCOUNTINHIBIT_HPM_BIT_OFFSET:isize   = 3;
-pub const MCOUNTINHIBIT_HPM_BIT_WIDTH:isize    = 29;
-pub const MCOUNTINHIBIT_HPM_BIT_MASK:UintXlen = 0xfffffff8;
-pub const MCOUNTINHIBIT_HPM_ALL_SET_MASK:UintXlen = 0x1fffffff;
+pub const HVICTL_IPRIO_BIT_OFFSET:isize   = 0;
+pub const HVICTL_IPRIO_BIT_WIDTH:isize    = 8;
+pub const HVICTL_IPRIO_BIT_MASK:UintXlen = 0xff;
+pub const HVICTL_IPRIO_ALL_SET_MASK:UintXlen = 0xff;
+/* hvictl.iprio: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hvictl_iprio!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvictl_iprio {
+    ( ) => (
+        ($crate::csr_read_hvictl!() & $crate::HVICTL_IPRIO_BIT_MASK) >> $crate::HVICTL_IPRIO_BIT_OFFSET
+    );
+}
+/* hvictl.iprio: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hvictl_iprio!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hvictl_iprio {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hvictl!();
+            let new_value = (org_value & !$crate::HVICTL_IPRIO_BIT_MASK)
+                | ((($x) << $crate::HVICTL_IPRIO_BIT_OFFSET) & $crate::HVICTL_IPRIO_BIT_MASK);
+            $crate::csr_write_hvictl!(new_value);
+        }
+    );
+}
+pub const HVICTL_IPRIOM_BIT_OFFSET:isize   = 8;
+pub const HVICTL_IPRIOM_BIT_WIDTH:isize    = 1;
+pub const HVICTL_IPRIOM_BIT_MASK:UintXlen = 0x100;
+pub const HVICTL_IPRIOM_ALL_SET_MASK:UintXlen = 0x1;
+/* hvictl.ipriom: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hvictl_ipriom!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvictl_ipriom {
+    ( ) => (
+        ($crate::csr_read_hvictl!() & $crate::HVICTL_IPRIOM_BIT_MASK) >> $crate::HVICTL_IPRIOM_BIT_OFFSET
+    );
+}
+/* hvictl.ipriom: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hvictl_ipriom!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hvictl_ipriom {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hvictl!();
+            let new_value = (org_value & !$crate::HVICTL_IPRIOM_BIT_MASK)
+                | ((($x) << $crate::HVICTL_IPRIOM_BIT_OFFSET) & $crate::HVICTL_IPRIOM_BIT_MASK);
+            $crate::csr_write_hvictl!(new_value);
+        }
+    );
+}
+pub const HVICTL_DPR_BIT_OFFSET:isize   = 9;
+pub const HVICTL_DPR_BIT_WIDTH:isize    = 1;
+pub const HVICTL_DPR_BIT_MASK:UintXlen = 0x200;
+pub const HVICTL_DPR_ALL_SET_MASK:UintXlen = 0x1;
+/* hvictl.dpr: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hvictl_dpr!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvictl_dpr {
+    ( ) => (
+        ($crate::csr_read_hvictl!() & $crate::HVICTL_DPR_BIT_MASK) >> $crate::HVICTL_DPR_BIT_OFFSET
+    );
+}
+/* hvictl.dpr: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hvictl_dpr!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hvictl_dpr {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hvictl!();
+            let new_value = (org_value & !$crate::HVICTL_DPR_BIT_MASK)
+                | ((($x) << $crate::HVICTL_DPR_BIT_OFFSET) & $crate::HVICTL_DPR_BIT_MASK);
+            $crate::csr_write_hvictl!(new_value);
+        }
+    );
+}
+pub const HVICTL_IID_BIT_OFFSET:isize   = 16;
+pub const HVICTL_IID_BIT_WIDTH:isize    = 12;
+pub const HVICTL_IID_BIT_MASK:UintXlen = 0xfff0000;
+pub const HVICTL_IID_ALL_SET_MASK:UintXlen = 0xfff;
+/* hvictl.iid: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hvictl_iid!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvictl_iid {
+    ( ) => (
+        ($crate::csr_read_hvictl!() & $crate::HVICTL_IID_BIT_MASK) >> $crate::HVICTL_IID_BIT_OFFSET
+    );
+}
+/* hvictl.iid: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hvictl_iid!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hvictl_iid {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hvictl!();
+            let new_value = (org_value & !$crate::HVICTL_IID_BIT_MASK)
+                | ((($x) << $crate::HVICTL_IID_BIT_OFFSET) & $crate::HVICTL_IID_BIT_MASK);
+            $crate::csr_write_hvictl!(new_value);
+        }
+    );
+}
+pub const HVICTL_VTI_BIT_OFFSET:isize   = 30;
+pub const HVICTL_VTI_BIT_WIDTH:isize    = 1;
+pub const HVICTL_VTI_BIT_MASK:UintXlen = 0x40000000;
+pub const HVICTL_VTI_ALL_SET_MASK:UintXlen = 0x1;
+/* hvictl.vti: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_hvictl_vti!();
+ */
+#[macro_export]
+macro_rules! csr_read_hvictl_vti {
+    ( ) => (
+        ($crate::csr_read_hvictl!() & $crate::HVICTL_VTI_BIT_MASK) >> $crate::HVICTL_VTI_BIT_OFFSET
+    );
+}
+/* hvictl.vti: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_hvictl_vti!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_hvictl_vti {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_hvictl!();
+            let new_value = (org_value & !$crate::HVICTL_VTI_BIT_MASK)
+                | ((($x) << $crate::HVICTL_VTI_BIT_OFFSET) & $crate::HVICTL_VTI_BIT_MASK);
+            $crate::csr_write_hvictl!(new_value);
+        }
+    );
+}
 
 /*******************************************
- * mcounteren - MRW - Counter Enable 
+ * mseccfg - MRW - Machine security configuration. 
  */
-pub const MCOUNTEREN_CY_BIT_OFFSET:isize   = 0;
-pub const MCOUNTEREN_CY_BIT_WIDTH:isize    = 1;
-pub const MCOUNTEREN_CY_BIT_MASK:UintXlen = 0x1;
-pub const MCOUNTEREN_CY_ALL_SET_MASK:UintXlen = 0x1;
-pub const MCOUNTEREN_TM_BIT_OFFSET:isize   = 1;
-pub const MCOUNTEREN_TM_BIT_WIDTH:isize    = 1;
-pub const MCOUNTEREN_TM_BIT_MASK:UintXlen = 0x2;
-pub const MCOUNTEREN_TM_ALL_SET_MASK:UintXlen = 0x1;
-pub const MCOUNTEREN_IR_BIT_OFFSET:isize   = 2;
-pub const MCOUNTEREN_IR_BIT_WIDTH:isize    = 1;
-pub const MCOUNTEREN_IR_BIT_MASK:UintXlen = 0x4;
-pub const MCOUNTEREN_IR_ALL_SET_MASK:UintXlen = 0x1;
-pub const MCOUNTEREN_HPM_BIT_OFFSET:isize   = 3;
-pub const MCOUNTEREN_HPM_BIT_WIDTH:isize    = 29;
-pub const MCOUNTEREN_HPM_BIT_MASK:UintXlen = 0xfffffff8;
-pub const MCOUNTEREN_HPM_ALL_SET_MASK:UintXlen = 0x1fffffff;
-
-/*******************************************
- * mcause - MRW - Machine Exception Cause 
+pub const MSECCFG_MML_BIT_OFFSET:isize   = 0;
+pub const MSECCFG_MML_BIT_WIDTH:isize    = 1;
+pub const MSECCFG_MML_BIT_MASK:UintXlen = 0x1;
+pub const MSECCFG_MML_ALL_SET_MASK:UintXlen = 0x1;
+/* mseccfg.mml: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mseccfg_mml!();
  */
-pub const MCAUSE_INTERRUPT_BIT_OFFSET:isize   = __riscv_xlen-1;
-pub const MCAUSE_INTERRUPT_BIT_WIDTH:isize    = 1;
-pub const MCAUSE_INTERRUPT_BIT_MASK:UintXlen = 0x1 << (__riscv_xlen-1);
-pub const MCAUSE_INTERRUPT_ALL_SET_MASK:UintXlen = 0x1;
-pub const MCAUSE_EXCEPTION_CODE_BIT_OFFSET:isize   = 0;
-pub const MCAUSE_EXCEPTION_CODE_BIT_WIDTH:isize    = (__riscv_xlen-2)-(0) + 1;
-pub const MCAUSE_EXCEPTION_CODE_BIT_MASK:UintXlen = (1<<((__riscv_xlen-2)-(0) + 1-1)) << (0);
-pub const MCAUSE_EXCEPTION_CODE_ALL_SET_MASK:UintXlen = (1<<((__riscv_xlen-2)-(0) + 1-1)) << (0);
-
-/*******************************************
- * scause - SRW - Supervisor Exception Cause 
+#[macro_export]
+macro_rules! csr_read_mseccfg_mml {
+    ( ) => (
+        (($crate::csr_read_mseccfg!() as $crate::UintXlen) & $crate::MSECCFG_MML_BIT_MASK) >> $crate::MSECCFG_MML_BIT_OFFSET
+    );
+}
+/* mseccfg.mml: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mseccfg_mml!(0x1);
  */
-pub const SCAUSE_INTERRUPT_BIT_OFFSET:isize   = __riscv_xlen-1;
-pub const SCAUSE_INTERRUPT_BIT_WIDTH:isize    = 1;
-pub const SCAUSE_INTERRUPT_BIT_MASK:UintXlen = 0x1 << (__riscv_xlen-1);
-pub const SCAUSE_INTERRUPT_ALL_SET_MASK:UintXlen = 0x1;
-pub const SCAUSE_EXCEPTION_CODE_BIT_OFFSET:isize   = 0;
-pub const SCAUSE_EXCEPTION_CODE_BIT_WIDTH:isize    = (__riscv_xlen-2)-(0) + 1;
-pub const SCAUSE_EXCEPTION_CODE_BIT_MASK:UintXlen = (1<<((__riscv_xlen-2)-(0) + 1-1)) << (0);
-pub const SCAUSE_EXCEPTION_CODE_ALL_SET_MASK:UintXlen = (1<<((__riscv_xlen-2)-(0) + 1-1)) << (0);
-
-/*******************************************
- * sstatus - SRW - Supervisor Status 
+#[macro_export]
+macro_rules! csr_write_mseccfg_mml {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mseccfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MSECCFG_MML_BIT_MASK)
+                | ((($x) << $crate::MSECCFG_MML_BIT_OFFSET) & $crate::MSECCFG_MML_BIT_MASK);
+            $crate::csr_write_mseccfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+pub const MSECCFG_MMWP_BIT_OFFSET:isize   = 1;
+pub const MSECCFG_MMWP_BIT_WIDTH:isize    = 1;
+pub const MSECCFG_MMWP_BIT_MASK:UintXlen = 0x2;
+pub const MSECCFG_MMWP_ALL_SET_MASK:UintXlen = 0x1;
+/* mseccfg.mmwp: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mseccfg_mmwp!();
  */
-pub const SSTATUS_SIE_BIT_OFFSET:isize   = 2;
-pub const SSTATUS_SIE_BIT_WIDTH:isize    = 1;
-pub const SSTATUS_SIE_BIT_MASK:UintXlen = 0x4;
-pub const SSTATUS_SIE_ALL_SET_MASK:UintXlen = 0x1;
-pub const SSTATUS_SPIE_BIT_OFFSET:isize   = 5;
-pub const SSTATUS_SPIE_BIT_WIDTH:isize    = 1;
-pub const SSTATUS_SPIE_BIT_MASK:UintXlen = 0x20;
-pub const SSTATUS_SPIE_ALL_SET_MASK:UintXlen = 0x1;
-pub const SSTATUS_SPP_BIT_OFFSET:isize   = 8;
-pub const SSTATUS_SPP_BIT_WIDTH:isize    = 1;
-pub const SSTATUS_SPP_BIT_MASK:UintXlen = 0x100;
-pub const SSTATUS_SPP_ALL_SET_MASK:UintXlen = 0x1;
-
-/*******************************************
- * stvec - SRW - Supervisor Trap Vector Base Address 
+#[macro_export]
+macro_rules! csr_read_mseccfg_mmwp {
+    ( ) => (
+        (($crate::csr_read_mseccfg!() as $crate::UintXlen) & $crate::MSECCFG_MMWP_BIT_MASK) >> $crate::MSECCFG_MMWP_BIT_OFFSET
+    );
+}
+/* mseccfg.mmwp: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mseccfg_mmwp!(0x1);
  */
-pub const STVEC_BASE_BIT_OFFSET:isize   = 2;
-pub const STVEC_BASE_BIT_WIDTH:isize    = (__riscv_xlen-1)-(2) + 1;
-pub const STVEC_BASE_BIT_MASK:UintXlen = (1<<((__riscv_xlen-1)-(2) + 1-1)) << (2);
-pub const STVEC_BASE_ALL_SET_MASK:UintXlen = (1<<((__riscv_xlen-1)-(2) + 1-1)) << (0);
-pub const STVEC_MODE_BIT_OFFSET:isize   = 0;
-pub const STVEC_MODE_BIT_WIDTH:isize    = 2;
-pub const STVEC_MODE_BIT_MASK:UintXlen = 0x3;
-pub const STVEC_MODE_ALL_SET_MASK:UintXlen = 0x3;
-
-/*******************************************
- * sip - SRW - Supervisor Interrupt Pending 
+#[macro_export]
+macro_rules! csr_write_mseccfg_mmwp {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mseccfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MSECCFG_MMWP_BIT_MASK)
+                | ((($x) << $crate::MSECCFG_MMWP_BIT_OFFSET) & $crate::MSECCFG_MMWP_BIT_MASK);
+            $crate::csr_write_mseccfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
+pub const MSECCFG_RLB_BIT_OFFSET:isize   = 2;
+pub const MSECCFG_RLB_BIT_WIDTH:isize    = 1;
+pub const MSECCFG_RLB_BIT_MASK:UintXlen = 0x4;
+pub const MSECCFG_RLB_ALL_SET_MASK:UintXlen = 0x1;
+/* mseccfg.rlb: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_mseccfg_rlb!();
  */
-pub const SIP_SSI_BIT_OFFSET:isize   = 1;
-pub const SIP_SSI_BIT_WIDTH:isize    = 1;
-pub const SIP_SSI_BIT_MASK:UintXlen = 0x2;
-pub const SIP_SSI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIP_STI_BIT_OFFSET:isize   = 5;
-pub const SIP_STI_BIT_WIDTH:isize    = 1;
-pub const SIP_STI_BIT_MASK:UintXlen = 0x20;
-pub const SIP_STI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIP_SEI_BIT_OFFSET:isize   = 9;
-pub const SIP_SEI_BIT_WIDTH:isize    = 1;
-pub const SIP_SEI_BIT_MASK:UintXlen = 0x200;
-pub const SIP_SEI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIP_USI_BIT_OFFSET:isize   = 0;
-pub const SIP_USI_BIT_WIDTH:isize    = 1;
-pub const SIP_USI_BIT_MASK:UintXlen = 0x1;
-pub const SIP_USI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIP_UTI_BIT_OFFSET:isize   = 4;
-pub const SIP_UTI_BIT_WIDTH:isize    = 1;
-pub const SIP_UTI_BIT_MASK:UintXlen = 0x10;
-pub const SIP_UTI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIP_UEI_BIT_OFFSET:isize   = 8;
-pub const SIP_UEI_BIT_WIDTH:isize    = 1;
-pub const SIP_UEI_BIT_MASK:UintXlen = 0x100;
-pub const SIP_UEI_ALL_SET_MASK:UintXlen = 0x1;
-
-/*******************************************
- * sie - SRW - Supervisor Interrupt Enable 
+#[macro_export]
+macro_rules! csr_read_mseccfg_rlb {
+    ( ) => (
+        (($crate::csr_read_mseccfg!() as $crate::UintXlen) & $crate::MSECCFG_RLB_BIT_MASK) >> $crate::MSECCFG_RLB_BIT_OFFSET
+    );
+}
+/* mseccfg.rlb: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_mseccfg_rlb!(0x1);
  */
-pub const SIE_SSI_BIT_OFFSET:isize   = 1;
-pub const SIE_SSI_BIT_WIDTH:isize    = 1;
-pub const SIE_SSI_BIT_MASK:UintXlen = 0x2;
-pub const SIE_SSI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIE_STI_BIT_OFFSET:isize   = 5;
-pub const SIE_STI_BIT_WIDTH:isize    = 1;
-pub const SIE_STI_BIT_MASK:UintXlen = 0x20;
-pub const SIE_STI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIE_SEI_BIT_OFFSET:isize   = 9;
-pub const SIE_SEI_BIT_WIDTH:isize    = 1;
-pub const SIE_SEI_BIT_MASK:UintXlen = 0x200;
-pub const SIE_SEI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIE_USI_BIT_OFFSET:isize   = 0;
-pub const SIE_USI_BIT_WIDTH:isize    = 1;
-pub const SIE_USI_BIT_MASK:UintXlen = 0x1;
-pub const SIE_USI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIE_UTI_BIT_OFFSET:isize   = 4;
-pub const SIE_UTI_BIT_WIDTH:isize    = 1;
-pub const SIE_UTI_BIT_MASK:UintXlen = 0x10;
-pub const SIE_UTI_ALL_SET_MASK:UintXlen = 0x1;
-pub const SIE_UEI_BIT_OFFSET:isize   = 8;
-pub const SIE_UEI_BIT_WIDTH:isize    = 1;
-pub const SIE_UEI_BIT_MASK:UintXlen = 0x100;
-pub const SIE_UEI_ALL_SET_MASK:UintXlen = 0x1;
+#[macro_export]
+macro_rules! csr_write_mseccfg_rlb {
+    ( $x:expr ) => (
+        {
+            let org_value = $crate::csr_read_mseccfg!() as $crate::UintXlen;
+            let new_value = (org_value & !$crate::MSECCFG_RLB_BIT_MASK)
+                | ((($x) << $crate::MSECCFG_RLB_BIT_OFFSET) & $crate::MSECCFG_RLB_BIT_MASK);
+            $crate::csr_write_mseccfg!(new_value as $crate::UintCsr64);
+        }
+    );
+}
 
 /*******************************************
- * ustatus - URW - User mode restricted view of mstatus 
+ * vcsr - URW - Vector control and status register. 
  */
-pub const USTATUS_UIE_BIT_OFFSET:isize   = 1;
-pub const USTATUS_UIE_BIT_WIDTH:isize    = 1;
-pub const USTATUS_UIE_BIT_MASK:UintXlen = 0x2;
-pub const USTATUS_UIE_ALL_SET_MASK:UintXlen = 0x1;
-pub const USTATUS_UPIE_BIT_OFFSET:isize   = 3;
-pub const USTATUS_UPIE_BIT_WIDTH:isize    = 1;
-pub const USTATUS_UPIE_BIT_MASK:UintXlen = 0x8;
-pub const USTATUS_UPIE_ALL_SET_MASK:UintXlen = 0x1;
-
-/*******************************************
- * uip - URW - User Interrupt Pending 
+pub const VCSR_VXSAT_BIT_OFFSET:isize   = 0;
+pub const VCSR_VXSAT_BIT_WIDTH:isize    = 1;
+pub const VCSR_VXSAT_BIT_MASK:UintXlen = 0x1;
+pub const VCSR_VXSAT_ALL_SET_MASK:UintXlen = 0x1;
+/* vcsr.vxsat: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vcsr_vxsat!();
  */
-pub const UIP_USI_BIT_OFFSET:isize   = 0;
-pub const UIP_USI_BIT_WIDTH:isize    = 1;
-pub const UIP_USI_BIT_MASK:UintXlen = 0x1;
-pub const UIP_USI_ALL_SET_MASK:UintXlen = 0x1;
-pub const UIP_UTI_BIT_OFFSET:isize   = 4;
-pub const UIP_UTI_BIT_WIDTH:isize    = 1;
-pub const UIP_UTI_BIT_MASK:UintXlen = 0x10;
-pub const UIP_UTI_ALL_SET_MASK:UintXlen = 0x1;
-pub const UIP_UEI_BIT_OFFSET:isize   = 8;
-pub const UIP_UEI_BIT_WIDTH:isize    = 1;
-pub const UIP_UEI_BIT_MASK:UintXlen = 0x100;
-pub const UIP_UEI_ALL_SET_MASK:UintXlen = 0x1;
-
-/*******************************************
- * uie - URW - User Interrupt Enable 
+#[macro_export]
+macro_rules! csr_read_vcsr_vxsat {
+    ( ) => (
+        ($crate::csr_read_vcsr!() & $crate::VCSR_VXSAT_BIT_MASK) >> $crate::VCSR_VXSAT_BIT_OFFSET
+    );
+}
+/* vcsr.vxsat: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_vcsr_vxsat!(0x1);
  */
-pub const UIE_USI_BIT_OFFSET:isize   = 0;
-pub const UIE_USI_BIT_WIDTH:isize    = 1;
-pub const UIE_USI_BIT_MASK:UintXlen = 0x1;
-pub const UIE_USI_ALL_SET_MASK:UintXlen = 0x1;
-pub const UIE_UTI_BIT_OFFSET:isize   = 4;
-pub const UIE_UTI_BIT_WIDTH:isize    = 1;
-pub const UIE_UTI_BIT_MASK:UintXlen = 0x10;
-pub const UIE_UTI_ALL_SET_MASK:UintXlen = 0x1;
-pub const UIE_UEI_BIT_OFFSET:isize   = 8;
-pub const UIE_UEI_BIT_WIDTH:isize    = 1;
-pub const UIE_UEI_BIT_MASK:UintXlen = 0x100;
-pub const UIE_UEI_ALL_SET_MASK:UintXlen = 0x1;
-
-/*******************************************
- * ucause - URW - User Exception Cause 
+#[macro_export]
+macro_rules! csr_write_vcsr_vxsat {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_vcsr!();
+            let new_value = (org_value & !$crate::VCSR_VXSAT_BIT_MASK)
+                | ((($x) << $crate::VCSR_VXSAT_BIT_OFFSET) & $crate::VCSR_VXSAT_BIT_MASK);
+            $crate::csr_write_vcsr!(new_value);
+        }
+    );
+}
+pub const VCSR_VXRM_BIT_OFFSET:isize   = 1;
+pub const VCSR_VXRM_BIT_WIDTH:isize    = 2;
+pub const VCSR_VXRM_BIT_MASK:UintXlen = 0x6;
+pub const VCSR_VXRM_ALL_SET_MASK:UintXlen = 0x3;
+/* vcsr.vxrm: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vcsr_vxrm!();
  */
-pub const UCAUSE_INTERRUPT_BIT_OFFSET:isize   = __riscv_xlen-1;
-pub const UCAUSE_INTERRUPT_BIT_WIDTH:isize    = 1;
-pub const UCAUSE_INTERRUPT_BIT_MASK:UintXlen = 0x1 << (__riscv_xlen-1);
-pub const UCAUSE_INTERRUPT_ALL_SET_MASK:UintXlen = 0x1;
-pub const UCAUSE_EXCEPTION_CODE_BIT_OFFSET:isize   = 0;
-pub const UCAUSE_EXCEPTION_CODE_BIT_WIDTH:isize    = (__riscv_xlen-2)-(0) + 1;
-pub const UCAUSE_EXCEPTION_CODE_BIT_MASK:UintXlen = (1<<((__riscv_xlen-2)-(0) + 1-1)) << (0);
-pub const UCAUSE_EXCEPTION_CODE_ALL_SET_MASK:UintXlen = (1<<((__riscv_xlen-2)-(0) + 1-1)) << (0);
+#[macro_export]
+macro_rules! csr_read_vcsr_vxrm {
+    ( ) => (
+        ($crate::csr_read_vcsr!() & $crate::VCSR_VXRM_BIT_MASK) >> $crate::VCSR_VXRM_BIT_OFFSET
+    );
+}
+/* vcsr.vxrm: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_vcsr_vxrm!(0x1);
+ */
+#[macro_export]
+macro_rules! csr_write_vcsr_vxrm {
+    ( $x:expr ) => (
+        {
+            let org_value: $crate::UintXlen = $crate::csr_read_vcsr!();
+            let new_value = (org_value & !$crate::VCSR_VXRM_BIT_MASK)
+                | ((($x) << $crate::VCSR_VXRM_BIT_OFFSET) & $crate::VCSR_VXRM_BIT_MASK);
+            $crate::csr_write_vcsr!(new_value);
+        }
+    );
+}
 
 /*******************************************
- * utvec - URW - User Trap Vector Base Address 
+ * vtype - URO - Vector data type register. 
  */
-pub const UTVEC_BASE_BIT_OFFSET:isize   = 2;
-pub const UTVEC_BASE_BIT_WIDTH:isize    = (__riscv_xlen-1)-(2) + 1;
-pub const UTVEC_BASE_BIT_MASK:UintXlen = (1<<((__riscv_xlen-1)-(2) + 1-1)) << (2);
-pub const UTVEC_BASE_ALL_SET_MASK:UintXlen = (1<<((__riscv_xlen-1)-(2) + 1-1)) << (0);
-pub const UTVEC_MODE_BIT_OFFSET:isize   = 0;
-pub const UTVEC_MODE_BIT_WIDTH:isize    = 2;
-pub const UTVEC_MODE_BIT_MASK:UintXlen = 0x3;
-pub const UTVEC_MODE_ALL_SET_MASK:UintXlen = 0x3;
+pub const VTYPE_VLMUL_BIT_OFFSET:isize   = 0;
+pub const VTYPE_VLMUL_BIT_WIDTH:isize    = 3;
+pub const VTYPE_VLMUL_BIT_MASK:UintXlen = 0x7;
+pub const VTYPE_VLMUL_ALL_SET_MASK:UintXlen = 0x7;
+/* vtype.vlmul: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vtype_vlmul!();
+ */
+#[macro_export]
+macro_rules! csr_read_vtype_vlmul {
+    ( ) => (
+        ($crate::csr_read_vtype!() & $crate::VTYPE_VLMUL_BIT_MASK) >> $crate::VTYPE_VLMUL_BIT_OFFSET
+    );
+}
+pub const VTYPE_VSEW_BIT_OFFSET:isize   = 3;
+pub const VTYPE_VSEW_BIT_WIDTH:isize    = 3;
+pub const VTYPE_VSEW_BIT_MASK:UintXlen = 0x38;
+pub const VTYPE_VSEW_ALL_SET_MASK:UintXlen = 0x7;
+/* vtype.vsew: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vtype_vsew!();
+ */
+#[macro_export]
+macro_rules! csr_read_vtype_vsew {
+    ( ) => (
+        ($crate::csr_read_vtype!() & $crate::VTYPE_VSEW_BIT_MASK) >> $crate::VTYPE_VSEW_BIT_OFFSET
+    );
+}
+pub const VTYPE_VTA_BIT_OFFSET:isize   = 6;
+pub const VTYPE_VTA_BIT_WIDTH:isize    = 1;
+pub const VTYPE_VTA_BIT_MASK:UintXlen = 0x40;
+pub const VTYPE_VTA_ALL_SET_MASK:UintXlen = 0x1;
+/* vtype.vta: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vtype_vta!();
+ */
+#[macro_export]
+macro_rules! csr_read_vtype_vta {
+    ( ) => (
+        ($crate::csr_read_vtype!() & $crate::VTYPE_VTA_BIT_MASK) >> $crate::VTYPE_VTA_BIT_OFFSET
+    );
+}
+pub const VTYPE_VMA_BIT_OFFSET:isize   = 7;
+pub const VTYPE_VMA_BIT_WIDTH:isize    = 1;
+pub const VTYPE_VMA_BIT_MASK:UintXlen = 0x80;
+pub const VTYPE_VMA_ALL_SET_MASK:UintXlen = 0x1;
+/* vtype.vma: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vtype_vma!();
+ */
+#[macro_export]
+macro_rules! csr_read_vtype_vma {
+    ( ) => (
+        ($crate::csr_read_vtype!() & $crate::VTYPE_VMA_BIT_MASK) >> $crate::VTYPE_VMA_BIT_OFFSET
+    );
+}
+pub const VTYPE_VILL_BIT_OFFSET:isize   = __riscv_xlen-1;
+pub const VTYPE_VILL_BIT_WIDTH:isize    = 1;
+pub const VTYPE_VILL_BIT_MASK:UintXlen = 0x1 << (__riscv_xlen-1);
+pub const VTYPE_VILL_ALL_SET_MASK:UintXlen = 0x1;
+/* vtype.vill: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_vtype_vill!();
+ */
+#[macro_export]
+macro_rules! csr_read_vtype_vill {
+    ( ) => (
+        ($crate::csr_read_vtype!() & $crate::VTYPE_VILL_BIT_MASK) >> $crate::VTYPE_VILL_BIT_OFFSET
+    );
+}
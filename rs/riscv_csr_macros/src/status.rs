@@ -0,0 +1,645 @@
+/*
+   Typed access to the mstatus, mstatush and sstatus fields.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+*/
+
+use crate::UintXlen;
+
+/// State of the F, V or custom extension context (`FS`, `VS` and `XS` fields).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtensionState {
+    Off = 0,
+    Initial = 1,
+    Clean = 2,
+    Dirty = 3,
+}
+
+impl ExtensionState {
+    /// Decode a 2 bit field value.
+    pub const fn from_bits(value: UintXlen) -> Self {
+        match value & 0x3 {
+            0 => ExtensionState::Off,
+            1 => ExtensionState::Initial,
+            2 => ExtensionState::Clean,
+            _ => ExtensionState::Dirty,
+        }
+    }
+    /// Field value to write.
+    pub const fn bits(self) -> UintXlen {
+        self as UintXlen
+    }
+}
+
+/// Privilege mode as held in `MPP` and `SPP`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrivilegeMode {
+    User = 0,
+    Supervisor = 1,
+    Machine = 3,
+}
+
+impl PrivilegeMode {
+    /// Decode a field value, the reserved encoding 2 returns `None`.
+    pub const fn from_bits(value: UintXlen) -> Option<Self> {
+        match value & 0x3 {
+            0 => Some(PrivilegeMode::User),
+            1 => Some(PrivilegeMode::Supervisor),
+            3 => Some(PrivilegeMode::Machine),
+            _ => None,
+        }
+    }
+    /// Field value to write.
+    pub const fn bits(self) -> UintXlen {
+        self as UintXlen
+    }
+}
+
+/// Effective XLEN as held in `UXL` and `SXL`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Xlen {
+    Rv32 = 1,
+    Rv64 = 2,
+    Rv128 = 3,
+}
+
+impl Xlen {
+    /// Decode a field value, 0 is reserved and returns `None`.
+    pub const fn from_bits(value: UintXlen) -> Option<Self> {
+        match value & 0x3 {
+            1 => Some(Xlen::Rv32),
+            2 => Some(Xlen::Rv64),
+            3 => Some(Xlen::Rv128),
+            _ => None,
+        }
+    }
+    /// Field value to write.
+    pub const fn bits(self) -> UintXlen {
+        self as UintXlen
+    }
+    /// Register width in bits.
+    pub const fn bit_width(self) -> u32 {
+        match self {
+            Xlen::Rv32 => 32,
+            Xlen::Rv64 => 64,
+            Xlen::Rv128 => 128,
+        }
+    }
+}
+
+/// Typed access to `mstatus`.
+///
+/// The write functions are read-modify-write and not atomic.
+/// Single bit fields can be changed atomically with `csr_set_bits_mstatus!`
+/// and `csr_clr_bits_mstatus!` and the `MSTATUS_*_BIT_MASK` constants.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod mstatus {
+    use super::{ExtensionState, PrivilegeMode};
+    use crate::UintXlen;
+    #[cfg(target_pointer_width = "64")]
+    use super::Xlen;
+
+    /// Floating point unit state.
+    #[inline]
+    pub fn fs() -> ExtensionState {
+        ExtensionState::from_bits(crate::csr_read_mstatus_fs!())
+    }
+    #[inline]
+    pub fn set_fs(state: ExtensionState) {
+        crate::csr_write_mstatus_fs!(state.bits());
+    }
+    /// Vector unit state.
+    #[inline]
+    pub fn vs() -> ExtensionState {
+        ExtensionState::from_bits(crate::csr_read_mstatus_vs!())
+    }
+    #[inline]
+    pub fn set_vs(state: ExtensionState) {
+        crate::csr_write_mstatus_vs!(state.bits());
+    }
+    /// Summary of the user mode extension state, read only.
+    #[inline]
+    pub fn xs() -> ExtensionState {
+        ExtensionState::from_bits(crate::csr_read_mstatus_xs!())
+    }
+    /// One of FS, VS or XS is dirty.
+    #[inline]
+    pub fn sd() -> bool {
+        crate::csr_read_mstatus_sd!() != 0
+    }
+    /// Previous privilege mode, restored by `mret`.
+    #[inline]
+    pub fn mpp() -> Option<PrivilegeMode> {
+        PrivilegeMode::from_bits(crate::csr_read_mstatus_mpp!())
+    }
+    #[inline]
+    pub fn set_mpp(mode: PrivilegeMode) {
+        crate::csr_write_mstatus_mpp!(mode.bits());
+    }
+    /// Previous privilege mode, restored by `sret`.
+    #[inline]
+    pub fn spp() -> PrivilegeMode {
+        if crate::csr_read_mstatus_spp!() != 0 {
+            PrivilegeMode::Supervisor
+        } else {
+            PrivilegeMode::User
+        }
+    }
+    /// Set SPP, only `User` and `Supervisor` are legal.
+    ///
+    /// # Panics
+    /// If `mode` is `Machine`.
+    #[inline]
+    pub fn set_spp(mode: PrivilegeMode) {
+        assert!(mode != PrivilegeMode::Machine);
+        crate::csr_write_mstatus_spp!(mode.bits());
+    }
+    /// S-mode may access U-mode pages.
+    #[inline]
+    pub fn sum() -> bool {
+        crate::csr_read_mstatus_sum!() != 0
+    }
+    #[inline]
+    pub fn set_sum(value: bool) {
+        crate::csr_write_mstatus_sum!(value as UintXlen);
+    }
+    /// Loads from executable only pages succeed.
+    #[inline]
+    pub fn mxr() -> bool {
+        crate::csr_read_mstatus_mxr!() != 0
+    }
+    #[inline]
+    pub fn set_mxr(value: bool) {
+        crate::csr_write_mstatus_mxr!(value as UintXlen);
+    }
+    /// M-mode loads and stores use the translation and protection of `MPP`.
+    #[inline]
+    pub fn mprv() -> bool {
+        crate::csr_read_mstatus_mprv!() != 0
+    }
+    #[inline]
+    pub fn set_mprv(value: bool) {
+        crate::csr_write_mstatus_mprv!(value as UintXlen);
+    }
+    /// Trap S-mode `satp` accesses and `sfence.vma`.
+    #[inline]
+    pub fn tvm() -> bool {
+        crate::csr_read_mstatus_tvm!() != 0
+    }
+    #[inline]
+    pub fn set_tvm(value: bool) {
+        crate::csr_write_mstatus_tvm!(value as UintXlen);
+    }
+    /// Trap `wfi` in modes below M-mode.
+    #[inline]
+    pub fn tw() -> bool {
+        crate::csr_read_mstatus_tw!() != 0
+    }
+    #[inline]
+    pub fn set_tw(value: bool) {
+        crate::csr_write_mstatus_tw!(value as UintXlen);
+    }
+    /// Trap S-mode `sret`.
+    #[inline]
+    pub fn tsr() -> bool {
+        crate::csr_read_mstatus_tsr!() != 0
+    }
+    #[inline]
+    pub fn set_tsr(value: bool) {
+        crate::csr_write_mstatus_tsr!(value as UintXlen);
+    }
+    /// U-mode explicit memory accesses are big endian.
+    #[inline]
+    pub fn ube() -> bool {
+        crate::csr_read_mstatus_ube!() != 0
+    }
+    #[inline]
+    pub fn set_ube(value: bool) {
+        crate::csr_write_mstatus_ube!(value as UintXlen);
+    }
+    /// S-mode explicit memory accesses are big endian, `mstatush.SBE` on RV32.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn sbe() -> bool {
+        crate::csr_read_mstatus_sbe!() != 0
+    }
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn set_sbe(value: bool) {
+        crate::csr_write_mstatus_sbe!(value as UintXlen);
+    }
+    /// S-mode explicit memory accesses are big endian, `mstatush.SBE` on RV32.
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn sbe() -> bool {
+        crate::csr_read_mstatush_sbe!() != 0
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn set_sbe(value: bool) {
+        crate::csr_write_mstatush_sbe!(value as UintXlen);
+    }
+    /// M-mode explicit memory accesses are big endian, `mstatush.MBE` on RV32.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn mbe() -> bool {
+        crate::csr_read_mstatus_mbe!() != 0
+    }
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn set_mbe(value: bool) {
+        crate::csr_write_mstatus_mbe!(value as UintXlen);
+    }
+    /// M-mode explicit memory accesses are big endian, `mstatush.MBE` on RV32.
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn mbe() -> bool {
+        crate::csr_read_mstatush_mbe!() != 0
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn set_mbe(value: bool) {
+        crate::csr_write_mstatush_mbe!(value as UintXlen);
+    }
+    /// Landing pad expected on return to S-mode or U-mode (Zicfilp).
+    #[inline]
+    pub fn spelp() -> bool {
+        crate::csr_read_mstatus_spelp!() != 0
+    }
+    #[inline]
+    pub fn set_spelp(value: bool) {
+        crate::csr_write_mstatus_spelp!(value as UintXlen);
+    }
+    /// Landing pad expected on `mret` (Zicfilp), `mstatush.MPELP` on RV32.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn mpelp() -> bool {
+        crate::csr_read_mstatus_mpelp!() != 0
+    }
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn set_mpelp(value: bool) {
+        crate::csr_write_mstatus_mpelp!(value as UintXlen);
+    }
+    /// Landing pad expected on `mret` (Zicfilp), `mstatush.MPELP` on RV32.
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn mpelp() -> bool {
+        crate::csr_read_mstatush_mpelp!() != 0
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn set_mpelp(value: bool) {
+        crate::csr_write_mstatush_mpelp!(value as UintXlen);
+    }
+    /// S-mode trap handler is in its critical section (Ssdbltrp).
+    #[inline]
+    pub fn sdt() -> bool {
+        crate::csr_read_mstatus_sdt!() != 0
+    }
+    #[inline]
+    pub fn set_sdt(value: bool) {
+        crate::csr_write_mstatus_sdt!(value as UintXlen);
+    }
+    /// M-mode trap handler is in its critical section (Smdbltrp), `mstatush.MDT` on RV32.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn mdt() -> bool {
+        crate::csr_read_mstatus_mdt!() != 0
+    }
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn set_mdt(value: bool) {
+        crate::csr_write_mstatus_mdt!(value as UintXlen);
+    }
+    /// M-mode trap handler is in its critical section (Smdbltrp), `mstatush.MDT` on RV32.
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn mdt() -> bool {
+        crate::csr_read_mstatush_mdt!() != 0
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn set_mdt(value: bool) {
+        crate::csr_write_mstatush_mdt!(value as UintXlen);
+    }
+    /// XLEN of U-mode.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn uxl() -> Option<Xlen> {
+        Xlen::from_bits(crate::csr_read_mstatus_uxl!())
+    }
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn set_uxl(xlen: Xlen) {
+        crate::csr_write_mstatus_uxl!(xlen.bits());
+    }
+    /// XLEN of S-mode.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn sxl() -> Option<Xlen> {
+        Xlen::from_bits(crate::csr_read_mstatus_sxl!())
+    }
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn set_sxl(xlen: Xlen) {
+        crate::csr_write_mstatus_sxl!(xlen.bits());
+    }
+    /// Previous virtualization mode, restored by `mret`.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn mpv() -> bool {
+        crate::csr_read_mstatus_mpv!() != 0
+    }
+    /// Previous virtualization mode, restored by `mret`.
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn mpv() -> bool {
+        crate::csr_read_mstatush_mpv!() != 0
+    }
+    /// Trap value in `mtval` is a guest virtual address.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn gva() -> bool {
+        crate::csr_read_mstatus_gva!() != 0
+    }
+    /// Trap value in `mtval` is a guest virtual address.
+    #[cfg(target_pointer_width = "32")]
+    #[inline]
+    pub fn gva() -> bool {
+        crate::csr_read_mstatush_gva!() != 0
+    }
+}
+
+/// Typed access to `sstatus`.
+///
+/// The write functions are read-modify-write and not atomic.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod sstatus {
+    use super::{ExtensionState, PrivilegeMode};
+    use crate::UintXlen;
+    #[cfg(target_pointer_width = "64")]
+    use super::Xlen;
+
+    /// Floating point unit state.
+    #[inline]
+    pub fn fs() -> ExtensionState {
+        ExtensionState::from_bits(crate::csr_read_sstatus_fs!())
+    }
+    #[inline]
+    pub fn set_fs(state: ExtensionState) {
+        crate::csr_write_sstatus_fs!(state.bits());
+    }
+    /// Vector unit state.
+    #[inline]
+    pub fn vs() -> ExtensionState {
+        ExtensionState::from_bits(crate::csr_read_sstatus_vs!())
+    }
+    #[inline]
+    pub fn set_vs(state: ExtensionState) {
+        crate::csr_write_sstatus_vs!(state.bits());
+    }
+    /// Summary of the user mode extension state, read only.
+    #[inline]
+    pub fn xs() -> ExtensionState {
+        ExtensionState::from_bits(crate::csr_read_sstatus_xs!())
+    }
+    /// One of FS, VS or XS is dirty.
+    #[inline]
+    pub fn sd() -> bool {
+        crate::csr_read_sstatus_sd!() != 0
+    }
+    /// Previous privilege mode, restored by `sret`.
+    #[inline]
+    pub fn spp() -> PrivilegeMode {
+        if crate::csr_read_sstatus_spp!() != 0 {
+            PrivilegeMode::Supervisor
+        } else {
+            PrivilegeMode::User
+        }
+    }
+    /// Set SPP, only `User` and `Supervisor` are legal.
+    ///
+    /// # Panics
+    /// If `mode` is `Machine`.
+    #[inline]
+    pub fn set_spp(mode: PrivilegeMode) {
+        assert!(mode != PrivilegeMode::Machine);
+        crate::csr_write_sstatus_spp!(mode.bits());
+    }
+    /// S-mode may access U-mode pages.
+    #[inline]
+    pub fn sum() -> bool {
+        crate::csr_read_sstatus_sum!() != 0
+    }
+    #[inline]
+    pub fn set_sum(value: bool) {
+        crate::csr_write_sstatus_sum!(value as UintXlen);
+    }
+    /// Loads from executable only pages succeed.
+    #[inline]
+    pub fn mxr() -> bool {
+        crate::csr_read_sstatus_mxr!() != 0
+    }
+    #[inline]
+    pub fn set_mxr(value: bool) {
+        crate::csr_write_sstatus_mxr!(value as UintXlen);
+    }
+    /// U-mode explicit memory accesses are big endian.
+    #[inline]
+    pub fn ube() -> bool {
+        crate::csr_read_sstatus_ube!() != 0
+    }
+    #[inline]
+    pub fn set_ube(value: bool) {
+        crate::csr_write_sstatus_ube!(value as UintXlen);
+    }
+    /// Landing pad expected on `sret` to S-mode or U-mode (Zicfilp).
+    #[inline]
+    pub fn spelp() -> bool {
+        crate::csr_read_sstatus_spelp!() != 0
+    }
+    #[inline]
+    pub fn set_spelp(value: bool) {
+        crate::csr_write_sstatus_spelp!(value as UintXlen);
+    }
+    /// S-mode trap handler is in its critical section (Ssdbltrp).
+    #[inline]
+    pub fn sdt() -> bool {
+        crate::csr_read_sstatus_sdt!() != 0
+    }
+    #[inline]
+    pub fn set_sdt(value: bool) {
+        crate::csr_write_sstatus_sdt!(value as UintXlen);
+    }
+    /// XLEN of U-mode.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn uxl() -> Option<Xlen> {
+        Xlen::from_bits(crate::csr_read_sstatus_uxl!())
+    }
+}
+
+/// Typed access to `hstatus`, for a hypervisor in HS-mode.
+///
+/// The write functions are read-modify-write and not atomic.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod hstatus {
+    use super::PrivilegeMode;
+    use crate::UintXlen;
+    #[cfg(target_pointer_width = "64")]
+    use super::Xlen;
+
+    /// The trap was taken from the guest, and `sret` enters the guest.
+    #[inline]
+    pub fn spv() -> bool {
+        crate::csr_read_hstatus_spv!() != 0
+    }
+    #[inline]
+    pub fn set_spv(value: bool) {
+        crate::csr_write_hstatus_spv!(value as UintXlen);
+    }
+    /// Guest privilege mode for `HLV`/`HSV` accesses, set on a trap from the guest.
+    #[inline]
+    pub fn spvp() -> PrivilegeMode {
+        if crate::csr_read_hstatus_spvp!() != 0 {
+            PrivilegeMode::Supervisor
+        } else {
+            PrivilegeMode::User
+        }
+    }
+    /// Set SPVP, `User` for VU-mode or `Supervisor` for VS-mode.
+    ///
+    /// # Panics
+    /// If `mode` is `Machine`.
+    #[inline]
+    pub fn set_spvp(mode: PrivilegeMode) {
+        assert!(mode != PrivilegeMode::Machine);
+        crate::csr_write_hstatus_spvp!(mode.bits());
+    }
+    /// Trap value in `stval` is a guest virtual address.
+    #[inline]
+    pub fn gva() -> bool {
+        crate::csr_read_hstatus_gva!() != 0
+    }
+    /// VS-mode data accesses are big endian.
+    #[inline]
+    pub fn vsbe() -> bool {
+        crate::csr_read_hstatus_vsbe!() != 0
+    }
+    /// U-mode may execute the hypervisor load and store instructions.
+    #[inline]
+    pub fn hu() -> bool {
+        crate::csr_read_hstatus_hu!() != 0
+    }
+    #[inline]
+    pub fn set_hu(value: bool) {
+        crate::csr_write_hstatus_hu!(value as UintXlen);
+    }
+    /// Guest external interrupt source that drives `hip.VSEIP`, 0 for none.
+    #[inline]
+    pub fn vgein() -> UintXlen {
+        crate::csr_read_hstatus_vgein!()
+    }
+    #[inline]
+    pub fn set_vgein(source: UintXlen) {
+        crate::csr_write_hstatus_vgein!(source);
+    }
+    /// `SRET` in VS-mode raises a virtual instruction exception.
+    #[inline]
+    pub fn vtsr() -> bool {
+        crate::csr_read_hstatus_vtsr!() != 0
+    }
+    #[inline]
+    pub fn set_vtsr(value: bool) {
+        crate::csr_write_hstatus_vtsr!(value as UintXlen);
+    }
+    /// `WFI` in VS-mode raises a virtual instruction exception.
+    #[inline]
+    pub fn vtw() -> bool {
+        crate::csr_read_hstatus_vtw!() != 0
+    }
+    #[inline]
+    pub fn set_vtw(value: bool) {
+        crate::csr_write_hstatus_vtw!(value as UintXlen);
+    }
+    /// `SFENCE.VMA`, `SINVAL.VMA` and `satp` access in VS-mode raise a virtual instruction exception.
+    #[inline]
+    pub fn vtvm() -> bool {
+        crate::csr_read_hstatus_vtvm!() != 0
+    }
+    #[inline]
+    pub fn set_vtvm(value: bool) {
+        crate::csr_write_hstatus_vtvm!(value as UintXlen);
+    }
+    /// XLEN of VS-mode.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn vsxl() -> Option<Xlen> {
+        Xlen::from_bits(crate::csr_read_hstatus_vsxl!())
+    }
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn set_vsxl(xlen: Xlen) {
+        crate::csr_write_hstatus_vsxl!(xlen.bits());
+    }
+}
+
+/// Status register holding the FS and VS fields of the current privilege mode.
+///
+/// Implemented by `Mstatus` for M-mode and `Sstatus` for S-mode, so
+/// code such as the lazy context switch in `context` works in either mode.
+pub trait StatusRegister {
+    fn fs() -> ExtensionState;
+    fn set_fs(state: ExtensionState);
+    fn vs() -> ExtensionState;
+    fn set_vs(state: ExtensionState);
+}
+
+/// Select `mstatus`, for M-mode code.
+pub struct Mstatus;
+
+/// Select `sstatus`, for S-mode code.
+pub struct Sstatus;
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+impl StatusRegister for Mstatus {
+    #[inline]
+    fn fs() -> ExtensionState {
+        mstatus::fs()
+    }
+    #[inline]
+    fn set_fs(state: ExtensionState) {
+        mstatus::set_fs(state)
+    }
+    #[inline]
+    fn vs() -> ExtensionState {
+        mstatus::vs()
+    }
+    #[inline]
+    fn set_vs(state: ExtensionState) {
+        mstatus::set_vs(state)
+    }
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+impl StatusRegister for Sstatus {
+    #[inline]
+    fn fs() -> ExtensionState {
+        sstatus::fs()
+    }
+    #[inline]
+    fn set_fs(state: ExtensionState) {
+        sstatus::set_fs(state)
+    }
+    #[inline]
+    fn vs() -> ExtensionState {
+        sstatus::vs()
+    }
+    #[inline]
+    fn set_vs(state: ExtensionState) {
+        sstatus::set_vs(state)
+    }
+}
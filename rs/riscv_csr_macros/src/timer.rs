@@ -0,0 +1,154 @@
+/*
+   Supervisor timer on the Sstc stimecmp and vstimecmp registers.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - M-mode must set menvcfg.STCE before S-mode can access stimecmp.
+*/
+
+#[cfg(target_pointer_width = "32")]
+use crate::UintCsr32;
+use crate::{UintCsr64, UintXlen};
+use crate::{SIE_STI_BIT_MASK, SIP_STI_BIT_MASK};
+
+/// Deadline value that never expires.
+pub const NEVER: u64 = u64::MAX;
+
+/// Read the 64 bit `time` counter.
+///
+/// On RV32 `timeh` is re-read to detect a carry from `time`.
+#[inline]
+pub fn now() -> u64 {
+    #[cfg(target_pointer_width = "32")]
+    loop {
+        let hi = crate::csr_read_timeh!();
+        let lo = crate::csr_read_time!();
+        if hi == crate::csr_read_timeh!() {
+            return ((hi as u64) << 32) | lo as u64;
+        }
+    }
+    #[cfg(target_pointer_width = "64")]
+    crate::csr_read_time!()
+}
+
+/// Allow S-mode to use `stimecmp` (`menvcfg.STCE`). M-mode only.
+#[inline]
+pub fn enable_sstc() {
+    #[cfg(target_pointer_width = "64")]
+    crate::csr_set_bits_menvcfg!(crate::MENVCFG_STCE_BIT_MASK);
+    #[cfg(target_pointer_width = "32")]
+    crate::csr_set_bits_menvcfgh!(crate::MENVCFGH_STCE_BIT_MASK);
+}
+
+/* Write a 64 bit compare register.
+   On RV32 the low half is first set to all ones, so no intermediate value
+   is earlier than both the old and new deadline and fires early. */
+macro_rules! write_compare {
+    ( $lo:ident, $hi:ident, $value:expr ) => {{
+        let value: u64 = $value;
+        #[cfg(target_pointer_width = "32")]
+        {
+            crate::$lo!(UintCsr64::MAX);
+            crate::$hi!((value >> 32) as UintCsr32);
+            crate::$lo!(value as UintCsr64);
+        }
+        #[cfg(target_pointer_width = "64")]
+        crate::$lo!(value as UintCsr64);
+    }};
+}
+
+/* Read a 64 bit compare register, it is only written by software. */
+macro_rules! read_compare {
+    ( $lo:ident, $hi:ident ) => {{
+        #[cfg(target_pointer_width = "32")]
+        let value = ((crate::$hi!() as u64) << 32) | crate::$lo!() as u64;
+        #[cfg(target_pointer_width = "64")]
+        let value = crate::$lo!() as u64;
+        value
+    }};
+}
+
+/* Cancel by writing all ones. On RV32 the high half is written first
+   so the intermediate value is never earlier than the old deadline. */
+macro_rules! cancel_compare {
+    ( $lo:ident, $hi:ident ) => {{
+        #[cfg(target_pointer_width = "32")]
+        crate::$hi!(UintCsr32::MAX);
+        crate::$lo!(UintCsr64::MAX);
+    }};
+}
+
+/// S-mode timer using `stimecmp`.
+///
+/// The timer interrupt is pending while `time >= stimecmp`,
+/// it is cleared by writing a later deadline or cancelling.
+pub mod supervisor {
+    use super::*;
+
+    /// Raise the timer interrupt when `time` reaches `deadline`.
+    #[inline]
+    pub fn set_deadline(deadline: u64) {
+        write_compare!(csr_write_stimecmp, csr_write_stimecmph, deadline);
+    }
+
+    /// Raise the timer interrupt `ticks` from now, returns the deadline.
+    #[inline]
+    pub fn set_timeout(ticks: u64) -> u64 {
+        let deadline = now().saturating_add(ticks);
+        set_deadline(deadline);
+        deadline
+    }
+
+    #[inline]
+    pub fn deadline() -> u64 {
+        read_compare!(csr_read_stimecmp, csr_read_stimecmph)
+    }
+
+    /// Disarm the timer and clear a pending timer interrupt.
+    #[inline]
+    pub fn cancel() {
+        cancel_compare!(csr_write_stimecmp, csr_write_stimecmph);
+    }
+
+    /// The timer interrupt is pending, `sip.STIP`.
+    #[inline]
+    pub fn is_pending() -> bool {
+        (crate::csr_read_sip!() & SIP_STI_BIT_MASK) != 0
+    }
+
+    /// Enable the timer interrupt, `sie.STIE`.
+    #[inline]
+    pub fn enable_interrupt() {
+        crate::csr_set_bits_sie!(SIE_STI_BIT_MASK);
+    }
+
+    #[inline]
+    pub fn disable_interrupt() {
+        crate::csr_clr_bits_sie!(SIE_STI_BIT_MASK);
+    }
+}
+
+/// Guest timer using `vstimecmp`, set by HS-mode.
+///
+/// The deadline is compared with the guest time, `time + htimedelta`.
+pub mod guest {
+    use super::*;
+
+    /// Raise the VS-mode timer interrupt when the guest time reaches `deadline`.
+    #[inline]
+    pub fn set_deadline(deadline: u64) {
+        write_compare!(csr_write_vstimecmp, csr_write_vstimecmph, deadline);
+    }
+
+    #[inline]
+    pub fn deadline() -> u64 {
+        read_compare!(csr_read_vstimecmp, csr_read_vstimecmph)
+    }
+
+    /// Disarm the guest timer.
+    #[inline]
+    pub fn cancel() {
+        cancel_compare!(csr_write_vstimecmp, csr_write_vstimecmph);
+    }
+}
@@ -0,0 +1,335 @@
+/*
+   Trap causes, the M-mode trap frame and software delegation of traps to S-mode.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - The trap entry code saves the registers, mepc and mstatus (and
+   mstatush on RV32) to a TrapFrame, and the exit code writes them back
+   before mret.
+*/
+
+use crate::status::PrivilegeMode;
+use crate::UintXlen;
+use crate::{MSTATUS_MPP_BIT_MASK, MSTATUS_MPP_BIT_OFFSET, SCAUSE_INTERRUPT_BIT_MASK};
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::{MSTATUS_SIE_BIT_MASK, MSTATUS_SPIE_BIT_MASK, MSTATUS_SPP_BIT_MASK, STVEC_MODE_BIT_MASK};
+
+/// Synchronous exception codes, the `mcause`/`scause` value with the interrupt bit clear.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exception {
+    InstructionMisaligned = 0,
+    InstructionFault = 1,
+    IllegalInstruction = 2,
+    Breakpoint = 3,
+    LoadMisaligned = 4,
+    LoadFault = 5,
+    StoreMisaligned = 6,
+    StoreFault = 7,
+    UserEcall = 8,
+    SupervisorEcall = 9,
+    VirtualSupervisorEcall = 10,
+    MachineEcall = 11,
+    InstructionPageFault = 12,
+    LoadPageFault = 13,
+    StorePageFault = 15,
+    InstructionGuestPageFault = 20,
+    LoadGuestPageFault = 21,
+    VirtualInstruction = 22,
+    StoreGuestPageFault = 23,
+}
+
+impl Exception {
+    pub const ALL: &'static [Exception] = &[
+        Exception::InstructionMisaligned,
+        Exception::InstructionFault,
+        Exception::IllegalInstruction,
+        Exception::Breakpoint,
+        Exception::LoadMisaligned,
+        Exception::LoadFault,
+        Exception::StoreMisaligned,
+        Exception::StoreFault,
+        Exception::UserEcall,
+        Exception::SupervisorEcall,
+        Exception::VirtualSupervisorEcall,
+        Exception::MachineEcall,
+        Exception::InstructionPageFault,
+        Exception::LoadPageFault,
+        Exception::StorePageFault,
+        Exception::InstructionGuestPageFault,
+        Exception::LoadGuestPageFault,
+        Exception::VirtualInstruction,
+        Exception::StoreGuestPageFault,
+    ];
+
+    /// Decode an exception code, reserved and custom codes return `None`.
+    pub fn from_code(code: UintXlen) -> Option<Self> {
+        Self::ALL.iter().copied().find(|e| e.code() == code)
+    }
+    pub const fn code(self) -> UintXlen {
+        self as UintXlen
+    }
+    /// Bit in `medeleg`/`hedeleg`.
+    pub const fn mask(self) -> UintXlen {
+        1 << self as u32
+    }
+}
+
+/// Interrupt codes, the `mcause`/`scause` value with the interrupt bit clear.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interrupt {
+    SupervisorSoft = 1,
+    VirtualSupervisorSoft = 2,
+    MachineSoft = 3,
+    SupervisorTimer = 5,
+    VirtualSupervisorTimer = 6,
+    MachineTimer = 7,
+    SupervisorExternal = 9,
+    VirtualSupervisorExternal = 10,
+    MachineExternal = 11,
+    SupervisorGuestExternal = 12,
+    /// Local counter overflow, Sscofpmf.
+    CounterOverflow = 13,
+}
+
+impl Interrupt {
+    pub const ALL: &'static [Interrupt] = &[
+        Interrupt::SupervisorSoft,
+        Interrupt::VirtualSupervisorSoft,
+        Interrupt::MachineSoft,
+        Interrupt::SupervisorTimer,
+        Interrupt::VirtualSupervisorTimer,
+        Interrupt::MachineTimer,
+        Interrupt::SupervisorExternal,
+        Interrupt::VirtualSupervisorExternal,
+        Interrupt::MachineExternal,
+        Interrupt::SupervisorGuestExternal,
+        Interrupt::CounterOverflow,
+    ];
+
+    /// Decode an interrupt code, reserved and platform codes return `None`.
+    pub fn from_code(code: UintXlen) -> Option<Self> {
+        Self::ALL.iter().copied().find(|i| i.code() == code)
+    }
+    pub const fn code(self) -> UintXlen {
+        self as UintXlen
+    }
+    /// Bit in `mie`/`mip`/`mideleg`/`hideleg`.
+    pub const fn mask(self) -> UintXlen {
+        1 << self as u32
+    }
+}
+
+/// Decoded `mcause` or `scause`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cause {
+    Exception(Exception),
+    Interrupt(Interrupt),
+}
+
+impl Cause {
+    /// Decode a cause register value, returns `None` for codes not in the enums.
+    pub fn from_bits(value: UintXlen) -> Option<Self> {
+        let code = value & !SCAUSE_INTERRUPT_BIT_MASK;
+        if value & SCAUSE_INTERRUPT_BIT_MASK != 0 {
+            Interrupt::from_code(code).map(Cause::Interrupt)
+        } else {
+            Exception::from_code(code).map(Cause::Exception)
+        }
+    }
+    /// Cause register value.
+    pub const fn bits(self) -> UintXlen {
+        match self {
+            Cause::Exception(e) => e.code(),
+            Cause::Interrupt(i) => i.code() | SCAUSE_INTERRUPT_BIT_MASK,
+        }
+    }
+}
+
+/// A load or store to the memory of a lower privilege mode faulted.
+///
+/// Returned by the M-mode `mprv` accessors and the S-mode `uaccess` copies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccessFault {
+    /// Address of the byte or halfword that faulted.
+    pub addr: UintXlen,
+}
+
+/// Registers saved on entry to an M-mode trap handler.
+///
+/// Laid out for the entry code, `x1` at offset `XLEN/8` through to
+/// `mstatus` at `33 * XLEN/8`, and on RV32 `mstatush` at `34 * XLEN/8`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct TrapFrame {
+    /// `x0` to `x31`, `x0` is not restored.
+    pub regs: [UintXlen; 32],
+    /// Return address for `mret`.
+    pub mepc: UintXlen,
+    /// `mstatus` restored before `mret`.
+    pub mstatus: UintXlen,
+    /// `mstatush` restored before `mret`, RV32 only.
+    #[cfg(target_pointer_width = "32")]
+    pub mstatush: UintXlen,
+}
+
+impl TrapFrame {
+    pub const fn new() -> Self {
+        Self {
+            regs: [0; 32],
+            mepc: 0,
+            mstatus: 0,
+            #[cfg(target_pointer_width = "32")]
+            mstatush: 0,
+        }
+    }
+
+    /// Read `x<n>`, `x0` always reads 0.
+    #[inline]
+    pub fn reg(&self, n: usize) -> UintXlen {
+        if n == 0 {
+            0
+        } else {
+            self.regs[n]
+        }
+    }
+
+    /// Write `x<n>`, writes to `x0` are ignored.
+    #[inline]
+    pub fn set_reg(&mut self, n: usize, value: UintXlen) {
+        if n != 0 {
+            self.regs[n] = value;
+        }
+    }
+
+    /// Privilege mode the trap was taken from, `mstatus.MPP`.
+    pub fn previous_mode(&self) -> Option<PrivilegeMode> {
+        PrivilegeMode::from_bits((self.mstatus & MSTATUS_MPP_BIT_MASK) >> MSTATUS_MPP_BIT_OFFSET)
+    }
+
+    /// The trap was taken from VS-mode or VU-mode, `mstatus.MPV` or `mstatush.MPV` on RV32.
+    #[inline]
+    pub fn previous_virtualized(&self) -> bool {
+        #[cfg(target_pointer_width = "64")]
+        let mpv = self.mstatus & crate::MSTATUS_MPV_BIT_MASK != 0;
+        #[cfg(target_pointer_width = "32")]
+        let mpv = self.mstatush & crate::MSTATUSH_MPV_BIT_MASK != 0;
+        mpv
+    }
+}
+
+/// Address of the S-mode handler for `cause`, as selected by `stvec`.
+///
+/// In vectored mode interrupts go to `BASE + 4 * cause`, exceptions to `BASE`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn supervisor_vector(cause: UintXlen) -> UintXlen {
+    let stvec = crate::csr_read_stvec!();
+    let base = stvec & !STVEC_MODE_BIT_MASK;
+    if (stvec & STVEC_MODE_BIT_MASK) == 1 && (cause & SCAUSE_INTERRUPT_BIT_MASK) != 0 {
+        base.wrapping_add((cause & !SCAUSE_INTERRUPT_BIT_MASK) << 2)
+    } else {
+        base
+    }
+}
+
+/// Deliver a trap taken in M-mode to the S-mode handler, as if it had been delegated.
+///
+/// - `scause`, `sepc` and `stval` are set from `cause`, `frame.mepc` and `tval`.
+/// - In `frame.mstatus`, `SPP` is set to the previous mode, `SPIE` to `SIE` and `SIE` is cleared.
+/// - `frame.mepc` is set to the `stvec` handler and `MPP` to S-mode, so `mret` enters the handler.
+///
+/// `cause` is the `mcause` value, including the interrupt bit.
+///
+/// Returns false, and nothing is changed, if the trap was taken from M-mode,
+/// or from a virtualized mode where it belongs to the VS-mode guest.
+///
+/// `cause` and `tval` must be read on entry to the M-mode handler, before any
+/// access that may trap, e.g. with `mprv::with_mprv()`, overwrites them.
+///
+/// e.g. in the M-mode trap handler
+/// ~~~ignore
+/// let cause = csr_read_mcause!();
+/// let tval = csr_read_mtval!();
+/// ...
+/// if !handled {
+///     redirect_trap_to_supervisor(frame, cause, tval);
+/// }
+/// ~~~
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub fn redirect_trap_to_supervisor(frame: &mut TrapFrame, cause: UintXlen, tval: UintXlen) -> bool {
+    let spp = match frame.previous_mode() {
+        Some(PrivilegeMode::User) => 0,
+        Some(PrivilegeMode::Supervisor) => MSTATUS_SPP_BIT_MASK,
+        _ => return false,
+    };
+    if frame.previous_virtualized() {
+        return false;
+    }
+
+    crate::csr_write_scause!(cause);
+    crate::csr_write_sepc!(frame.mepc);
+    crate::csr_write_stval!(tval);
+
+    let mut mstatus = frame.mstatus & !(MSTATUS_SPP_BIT_MASK | MSTATUS_SPIE_BIT_MASK | MSTATUS_SIE_BIT_MASK);
+    mstatus |= spp;
+    if frame.mstatus & MSTATUS_SIE_BIT_MASK != 0 {
+        mstatus |= MSTATUS_SPIE_BIT_MASK;
+    }
+    mstatus &= !MSTATUS_MPP_BIT_MASK;
+    mstatus |= PrivilegeMode::Supervisor.bits() << MSTATUS_MPP_BIT_OFFSET;
+    frame.mstatus = mstatus;
+    frame.mepc = supervisor_vector(cause);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cause_from_bits() {
+        assert_eq!(Cause::from_bits(2), Some(Cause::Exception(Exception::IllegalInstruction)));
+        assert_eq!(Cause::from_bits(23), Some(Cause::Exception(Exception::StoreGuestPageFault)));
+        assert_eq!(
+            Cause::from_bits(SCAUSE_INTERRUPT_BIT_MASK | 5),
+            Some(Cause::Interrupt(Interrupt::SupervisorTimer))
+        );
+        assert_eq!(
+            Cause::from_bits(SCAUSE_INTERRUPT_BIT_MASK | 13),
+            Some(Cause::Interrupt(Interrupt::CounterOverflow))
+        );
+    }
+
+    #[test]
+    fn cause_reserved_codes() {
+        // Reserved exception codes.
+        assert_eq!(Cause::from_bits(14), None);
+        assert_eq!(Cause::from_bits(16), None);
+        // Interrupt 0 and platform interrupts.
+        assert_eq!(Cause::from_bits(SCAUSE_INTERRUPT_BIT_MASK), None);
+        assert_eq!(Cause::from_bits(SCAUSE_INTERRUPT_BIT_MASK | 16), None);
+    }
+
+    #[test]
+    fn cause_bits_round_trip() {
+        for e in Exception::ALL {
+            assert_eq!(Cause::from_bits(Cause::Exception(*e).bits()), Some(Cause::Exception(*e)));
+        }
+        for i in Interrupt::ALL {
+            assert_eq!(Cause::from_bits(Cause::Interrupt(*i).bits()), Some(Cause::Interrupt(*i)));
+        }
+    }
+
+    #[test]
+    fn frame_registers() {
+        let mut frame = TrapFrame::new();
+        frame.set_reg(0, 1);
+        frame.set_reg(31, 2);
+        assert_eq!(frame.reg(0), 0);
+        assert_eq!(frame.reg(31), 2);
+        frame.mstatus = PrivilegeMode::Supervisor.bits() << MSTATUS_MPP_BIT_OFFSET;
+        assert_eq!(frame.previous_mode(), Some(PrivilegeMode::Supervisor));
+        assert!(!frame.previous_virtualized());
+    }
+}
@@ -0,0 +1,153 @@
+/*
+   S-mode access to U-mode memory, with sstatus.SUM and MXR guards.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - A fault in the copy routine is resumed at a fixup address that
+   returns an error. The S-mode trap handler must call uaccess::fixup()
+   for load and store page faults and access faults taken from S-mode.
+*/
+
+use core::arch::global_asm;
+
+use crate::trap::AccessFault;
+use crate::UintXlen;
+use crate::{SSTATUS_MXR_BIT_MASK, SSTATUS_SUM_BIT_MASK};
+
+/* Copy a2 bytes from a1 to a0, returns the number of bytes not copied.
+   The loads and stores between the start and end labels may fault, the
+   trap handler then resumes at the fixup label with a2 still holding
+   the remaining count. */
+global_asm!(
+    ".pushsection .text.riscv_csr_uaccess, \"ax\", @progbits",
+    ".balign 4",
+    ".global riscv_csr_copy_bytes",
+    "riscv_csr_copy_bytes:",
+    "beqz a2, riscv_csr_uaccess_fixup",
+    ".global riscv_csr_uaccess_start",
+    "riscv_csr_uaccess_start:",
+    "1:",
+    "lbu t0, 0(a1)",
+    "sb t0, 0(a0)",
+    "addi a0, a0, 1",
+    "addi a1, a1, 1",
+    "addi a2, a2, -1",
+    "bnez a2, 1b",
+    ".global riscv_csr_uaccess_end",
+    "riscv_csr_uaccess_end:",
+    ".global riscv_csr_uaccess_fixup",
+    "riscv_csr_uaccess_fixup:",
+    "mv a0, a2",
+    "ret",
+    ".popsection",
+);
+
+extern "C" {
+    fn riscv_csr_copy_bytes(dst: *mut u8, src: *const u8, len: usize) -> usize;
+    fn riscv_csr_uaccess_start();
+    fn riscv_csr_uaccess_end();
+    fn riscv_csr_uaccess_fixup();
+}
+
+/// Resume address for a fault in a user copy.
+///
+/// Call from the S-mode trap handler for load and store page faults (13, 15)
+/// and access faults (5, 7) taken from S-mode. If this returns an address,
+/// write it to `sepc` and return from the trap, the copy then returns `Err`.
+///
+/// e.g.
+/// ~~~ignore
+/// if let Some(pc) = uaccess::fixup(csr_read_sepc!()) {
+///     csr_write_sepc!(pc);
+///     return;
+/// }
+/// ~~~
+pub fn fixup(epc: UintXlen) -> Option<UintXlen> {
+    let start = riscv_csr_uaccess_start as unsafe extern "C" fn() as usize as UintXlen;
+    let end = riscv_csr_uaccess_end as unsafe extern "C" fn() as usize as UintXlen;
+    if (start..end).contains(&epc) {
+        Some(riscv_csr_uaccess_fixup as unsafe extern "C" fn() as usize as UintXlen)
+    } else {
+        None
+    }
+}
+
+/// Allows S-mode to access U-mode pages while in scope, `sstatus.SUM`.
+///
+/// The bits that were clear on creation are cleared again on drop,
+/// so guards can be nested.
+#[must_use]
+pub struct UserAccessGuard {
+    /* Bits set by this guard. */
+    set: UintXlen,
+}
+
+impl UserAccessGuard {
+    /// Set `SUM`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::set_bits(SSTATUS_SUM_BIT_MASK)
+    }
+
+    /// Set `SUM` and `MXR`, so loads from execute-only pages also succeed.
+    #[inline]
+    pub fn with_mxr() -> Self {
+        Self::set_bits(SSTATUS_SUM_BIT_MASK | SSTATUS_MXR_BIT_MASK)
+    }
+
+    #[inline]
+    fn set_bits(bits: UintXlen) -> Self {
+        let org = crate::csr_read_set_bits_sstatus!(bits);
+        Self { set: bits & !org }
+    }
+}
+
+impl Default for UserAccessGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for UserAccessGuard {
+    #[inline]
+    fn drop(&mut self) {
+        if self.set != 0 {
+            crate::csr_clr_bits_sstatus!(self.set);
+        }
+    }
+}
+
+/// Copy `dst.len()` bytes from the user address `src`.
+///
+/// On a fault the error holds the first address that was not read.
+///
+/// # Safety
+/// Must be called from S-mode, with the trap handler calling `fixup()`.
+pub unsafe fn copy_from_user(dst: &mut [u8], src: UintXlen) -> Result<(), AccessFault> {
+    let _guard = UserAccessGuard::new();
+    let remaining = riscv_csr_copy_bytes(dst.as_mut_ptr(), src as usize as *const u8, dst.len());
+    match remaining {
+        0 => Ok(()),
+        _ => Err(AccessFault {
+            addr: src.wrapping_add((dst.len() - remaining) as UintXlen),
+        }),
+    }
+}
+
+/// Copy `src` to the user address `dst`.
+///
+/// On a fault the error holds the first address that was not written.
+///
+/// # Safety
+/// Must be called from S-mode, with the trap handler calling `fixup()`.
+pub unsafe fn copy_to_user(dst: UintXlen, src: &[u8]) -> Result<(), AccessFault> {
+    let _guard = UserAccessGuard::new();
+    let remaining = riscv_csr_copy_bytes(dst as usize as *mut u8, src.as_ptr(), src.len());
+    match remaining {
+        0 => Ok(()),
+        _ => Err(AccessFault {
+            addr: dst.wrapping_add((src.len() - remaining) as UintXlen),
+        }),
+    }
+}
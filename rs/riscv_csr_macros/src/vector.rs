@@ -0,0 +1,289 @@
+/*
+   Vector extension CSR decoding and vector context save/restore.
+   SPDX-License-Identifier: Unlicense
+
+   https://five-embeddev.com/
+
+   NOTE - The vector CSRs can only be accessed when mstatus.VS is not Off.
+*/
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::context::ExtensionContext;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::status::{ExtensionState, StatusRegister};
+use crate::UintXlen;
+use crate::{VTYPE_VILL_BIT_MASK, VTYPE_VMA_BIT_MASK, VTYPE_VTA_BIT_MASK};
+
+/// Selected element width, `vtype.vsew`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sew {
+    E8 = 0,
+    E16 = 1,
+    E32 = 2,
+    E64 = 3,
+}
+
+impl Sew {
+    /// Decode a field value, reserved encodings return `None`.
+    pub const fn from_bits(value: UintXlen) -> Option<Self> {
+        match value & 0x7 {
+            0 => Some(Sew::E8),
+            1 => Some(Sew::E16),
+            2 => Some(Sew::E32),
+            3 => Some(Sew::E64),
+            _ => None,
+        }
+    }
+    /// Field value to write.
+    pub const fn bits(self) -> UintXlen {
+        self as UintXlen
+    }
+    /// Element width in bits.
+    pub const fn bit_width(self) -> u32 {
+        8 << (self as u32)
+    }
+}
+
+/// Vector register group multiplier, `vtype.vlmul`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lmul {
+    M1 = 0,
+    M2 = 1,
+    M4 = 2,
+    M8 = 3,
+    Mf8 = 5,
+    Mf4 = 6,
+    Mf2 = 7,
+}
+
+impl Lmul {
+    /// Decode a field value, the reserved encoding 4 returns `None`.
+    pub const fn from_bits(value: UintXlen) -> Option<Self> {
+        match value & 0x7 {
+            0 => Some(Lmul::M1),
+            1 => Some(Lmul::M2),
+            2 => Some(Lmul::M4),
+            3 => Some(Lmul::M8),
+            5 => Some(Lmul::Mf8),
+            6 => Some(Lmul::Mf4),
+            7 => Some(Lmul::Mf2),
+            _ => None,
+        }
+    }
+    /// Field value to write.
+    pub const fn bits(self) -> UintXlen {
+        self as UintXlen
+    }
+}
+
+/// Decoded `vtype` value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Vtype(pub UintXlen);
+
+impl Vtype {
+    /// Encode a `vtype` value, as used by `vsetvl`.
+    pub const fn new(sew: Sew, lmul: Lmul, tail_agnostic: bool, mask_agnostic: bool) -> Self {
+        let mut value = (sew.bits() << crate::VTYPE_VSEW_BIT_OFFSET) | (lmul.bits() << crate::VTYPE_VLMUL_BIT_OFFSET);
+        if tail_agnostic {
+            value |= VTYPE_VTA_BIT_MASK;
+        }
+        if mask_agnostic {
+            value |= VTYPE_VMA_BIT_MASK;
+        }
+        Self(value)
+    }
+    pub const fn sew(self) -> Option<Sew> {
+        Sew::from_bits((self.0 & crate::VTYPE_VSEW_BIT_MASK) >> crate::VTYPE_VSEW_BIT_OFFSET)
+    }
+    pub const fn lmul(self) -> Option<Lmul> {
+        Lmul::from_bits((self.0 & crate::VTYPE_VLMUL_BIT_MASK) >> crate::VTYPE_VLMUL_BIT_OFFSET)
+    }
+    pub const fn tail_agnostic(self) -> bool {
+        (self.0 & VTYPE_VTA_BIT_MASK) != 0
+    }
+    pub const fn mask_agnostic(self) -> bool {
+        (self.0 & VTYPE_VMA_BIT_MASK) != 0
+    }
+    /// The last `vsetvl` requested an unsupported configuration.
+    pub const fn vill(self) -> bool {
+        (self.0 & VTYPE_VILL_BIT_MASK) != 0
+    }
+}
+
+/// Fixed-point rounding mode, `vxrm`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VxRoundingMode {
+    /// Round to nearest, ties up.
+    Rnu = 0,
+    /// Round to nearest, ties to even.
+    Rne = 1,
+    /// Round down (truncate).
+    Rdn = 2,
+    /// Round to odd.
+    Rod = 3,
+}
+
+impl VxRoundingMode {
+    pub const fn from_bits(value: UintXlen) -> Self {
+        match value & 0x3 {
+            0 => VxRoundingMode::Rnu,
+            1 => VxRoundingMode::Rne,
+            2 => VxRoundingMode::Rdn,
+            _ => VxRoundingMode::Rod,
+        }
+    }
+    pub const fn bits(self) -> UintXlen {
+        self as UintXlen
+    }
+}
+
+/// Vector register length in bytes.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn vlenb() -> UintXlen {
+    crate::csr_read_vlenb!()
+}
+
+/// Vector register length in bits, VLEN.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn vlen() -> UintXlen {
+    vlenb() * 8
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn vl() -> UintXlen {
+    crate::csr_read_vl!()
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn vtype() -> Vtype {
+    Vtype(crate::csr_read_vtype!())
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn vxrm() -> VxRoundingMode {
+    VxRoundingMode::from_bits(crate::csr_read_vxrm!())
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn set_vxrm(mode: VxRoundingMode) {
+    crate::csr_write_vxrm!(mode.bits());
+}
+
+/// Read and clear the fixed-point saturation flag.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[inline]
+pub fn take_vxsat() -> bool {
+    (crate::csr_read_write_vxsat!(0) & 0x1) != 0
+}
+
+/// Vector registers v0-v31 and the vector CSRs of one task.
+///
+/// The register storage is provided by the caller as it depends on VLEN,
+/// it must be at least `32 * vlenb()` bytes.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub struct VectorContext<'a> {
+    pub regs: &'a mut [u8],
+    pub vstart: UintXlen,
+    pub vl: UintXlen,
+    pub vtype: UintXlen,
+    pub vcsr: UintXlen,
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+impl<'a> VectorContext<'a> {
+    /// Bytes of storage needed for the register file of this hart.
+    #[inline]
+    pub fn storage_size() -> usize {
+        32 * vlenb() as usize
+    }
+
+    /// Use `regs` as storage, returns `None` if it is smaller than `storage_size()`.
+    pub fn new(regs: &'a mut [u8]) -> Option<Self> {
+        if regs.len() < Self::storage_size() {
+            return None;
+        }
+        Some(Self {
+            regs,
+            vstart: 0,
+            vl: 0,
+            vtype: 0,
+            vcsr: 0,
+        })
+    }
+}
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+impl ExtensionContext for VectorContext<'_> {
+    #[inline]
+    fn state<S: StatusRegister>() -> ExtensionState {
+        S::vs()
+    }
+    #[inline]
+    fn set_state<S: StatusRegister>(state: ExtensionState) {
+        S::set_vs(state)
+    }
+    /// The V registers are not declared as clobbered, the caller must not use vector instructions.
+    unsafe fn save(&mut self) {
+        use core::arch::asm;
+        self.vstart = crate::csr_read_vstart!();
+        self.vl = crate::csr_read_vl!();
+        self.vtype = crate::csr_read_vtype!();
+        self.vcsr = crate::csr_read_vcsr!();
+        // Whole register stores do not depend on vl or vtype.
+        let group_size = 8 * vlenb() as usize;
+        asm!(
+            ".option push",
+            ".option arch, +v",
+            "csrw vstart, zero",
+            "vs8r.v v0, ({0})",
+            "add {0}, {0}, {1}",
+            "vs8r.v v8, ({0})",
+            "add {0}, {0}, {1}",
+            "vs8r.v v16, ({0})",
+            "add {0}, {0}, {1}",
+            "vs8r.v v24, ({0})",
+            ".option pop",
+            inout(reg) self.regs.as_mut_ptr() => _,
+            in(reg) group_size,
+            options(nostack, preserves_flags));
+    }
+    /// The V registers are not declared as clobbered, the caller must not use vector instructions.
+    unsafe fn restore(&self) {
+        use core::arch::asm;
+        let group_size = 8 * vlenb() as usize;
+        asm!(
+            ".option push",
+            ".option arch, +v",
+            "csrw vstart, zero",
+            "vl8re8.v v0, ({0})",
+            "add {0}, {0}, {1}",
+            "vl8re8.v v8, ({0})",
+            "add {0}, {0}, {1}",
+            "vl8re8.v v16, ({0})",
+            "add {0}, {0}, {1}",
+            "vl8re8.v v24, ({0})",
+            "vsetvl zero, {2}, {3}",
+            ".option pop",
+            inout(reg) self.regs.as_ptr() => _,
+            in(reg) group_size,
+            in(reg) self.vl,
+            in(reg) self.vtype,
+            options(nostack, preserves_flags, readonly));
+        crate::csr_write_vcsr!(self.vcsr);
+        crate::csr_write_vstart!(self.vstart);
+    }
+    fn clear(&mut self) {
+        self.regs.fill(0);
+        self.vstart = 0;
+        self.vl = 0;
+        // vill set until the task runs vsetvl.
+        self.vtype = VTYPE_VILL_BIT_MASK;
+        self.vcsr = 0;
+    }
+}
@@ -0,0 +1,288 @@
+#!/usr/bin/env python3
+# Cross check the generated C, C++ and Rust CSR access files.
+#
+# Extracts every register name, access mode, register width and field
+# offset/width/mask from each generated output, evaluates the values for
+# RV32 and RV64 and reports any mismatch between the languages.
+#
+# Usage:
+#   scripts/check_csr_consistency.py [--c FILE] [--hpp FILE] [--rs FILE]
+#
+# Returns non-zero if any mismatch is found.
+
+import argparse
+import re
+import sys
+
+XLENS = (32, 64)
+
+HEADER_RE = re.compile(r'^ \* (\w+) - (\w*) - .*$', re.M)
+
+
+def _eval_expr(expr, xlen):
+    """ Evaluate a C or Rust constant expression for a given XLEN.
+    """
+    expr = expr.strip()
+    expr = expr.replace('__riscv_xlen', str(xlen))
+    # unsigned long is XLEN bits on both ilp32 and lp64.
+    expr = expr.replace('~0UL', str((1 << xlen) - 1))
+    expr = expr.replace('UintXlen::MAX', str((1 << xlen) - 1))
+    expr = expr.replace('uint_xlen_t', '')
+    expr = re.sub(r'\b(0x[0-9a-fA-F]+|\d+)UL\b', r'\1', expr)
+    if not re.fullmatch(r'[0-9a-fA-Fx()<>+\-~ ]*', expr):
+        raise ValueError("Can't evaluate: " + expr)
+    return eval(expr) & ((1 << xlen) - 1)
+
+
+def _select_xlen(text, xlen):
+    """ Drop the lines that are not used for a given XLEN.
+        Handles '#if __riscv_xlen==N' blocks (C/C++) and
+        '#[cfg(target_pointer_width = "N")]' items (Rust).
+    """
+    lines = []
+    stack = []
+    skip_next = False
+    for line in text.split('\n'):
+        m = re.match(r'#(if|elif) __riscv_xlen==(\d+)', line)
+        if m:
+            if m.group(1) == 'if':
+                stack.append([int(m.group(2)) == xlen, False])
+            else:
+                stack[-1][1] |= stack[-1][0]
+                stack[-1][0] = int(m.group(2)) == xlen and not stack[-1][1]
+            continue
+        if re.match(r'#if', line):
+            stack.append([True, False])
+        elif line.startswith('#else') and stack:
+            stack[-1][0] = not (stack[-1][0] or stack[-1][1])
+            continue
+        elif line.startswith('#endif') and stack:
+            stack.pop()
+        m = re.match(r'#\[cfg\(target_pointer_width = "(\d+)"\)\]$', line)
+        if m:
+            skip_next = int(m.group(1)) != xlen
+            continue
+        if skip_next == 'macro':
+            if line == '}':
+                skip_next = False
+            continue
+        if skip_next:
+            # Skip the whole body of a macro
+            skip_next = 'macro' if line.startswith('#[macro_export]') else False
+            continue
+        if all(active for active, _ in stack):
+            lines.append(line)
+    return '\n'.join(lines)
+
+
+def _ctype_width(ctype, xlen):
+    """ Map the type used for the CSR instruction operand to a bit width.
+    """
+    if ctype in ('uint_csr32_t', 'UintCsr32', 'uint32_t'):
+        return 32
+    if ctype in ('uint_csr64_t', 'UintCsr64', 'uint_xlen_t', 'UintXlen'):
+        return xlen
+    raise ValueError('Unknown type: ' + ctype)
+
+
+def _split_const(name, regs):
+    """ Split REG_FIELD_SUFFIX into (reg, field, suffix) using the register list.
+    """
+    for suffix in ('BIT_OFFSET', 'BIT_WIDTH', 'BIT_MASK', 'ALL_SET_MASK'):
+        if name.endswith('_' + suffix):
+            stem = name[:-len(suffix) - 1]
+            break
+    else:
+        return None
+    candidates = [r for r in regs if stem.startswith(r.upper() + '_')]
+    if not candidates:
+        return None
+    reg = max(candidates, key=len)
+    return reg, stem[len(reg) + 1:].lower(), suffix
+
+
+class CsrSet:
+    """ Registers and fields extracted from one generated file.
+        regs[name] = {'access': set, 'ctype': str}
+        fields[(reg, field)] = {suffix: expr}
+    """
+    def __init__(self, lang):
+        self.lang = lang
+        self.regs = {}
+        self.fields = {}
+        self.errors = []
+
+    def evaluate(self, xlen):
+        regs = {}
+        for name, data in self.regs.items():
+            regs[name] = (''.join(sorted(data['access'])),
+                          _ctype_width(data['ctype'], xlen))
+        fields = {}
+        for key, exprs in self.fields.items():
+            fields[key] = {s: _eval_expr(e, xlen) for s, e in exprs.items()}
+        return regs, fields
+
+
+def parse_c(text):
+    csrs = CsrSet('C')
+    for name, _ in HEADER_RE.findall(text):
+        access = set()
+        ctype = None
+        m = re.search(r'csr_read_%s\(void\) \{\n    (\w+) value;' % name, text)
+        if m:
+            access.add('R')
+            ctype = m.group(1)
+        m = re.search(r'csr_write_%s\((\w+) value\)' % name, text)
+        if m:
+            access.add('W')
+            ctype = ctype or m.group(1)
+        if re.search(r'csr_set_bits_%s\(' % name, text):
+            access.add('B')
+        csrs.regs[name] = {'access': access, 'ctype': ctype}
+    for name, expr in re.findall(r'^#define (\w+) +(.*)$', text, re.M):
+        split = _split_const(name, csrs.regs)
+        if split:
+            reg, field, suffix = split
+            csrs.fields.setdefault((reg, field), {})[suffix] = expr
+    return csrs
+
+
+def parse_hpp(text):
+    csrs = CsrSet('C++')
+    ops_re = re.compile(r'struct (\w+)_ops  \{\n(.*?)\n        \}; /\* \1_ops \*/', re.S)
+    for name, body in ops_re.findall(text):
+        access = set()
+        m = re.search(r'using datatype = (\w+);', body)
+        ctype = m.group(1)
+        if re.search(r'static \w+ read\(void\)', body):
+            access.add('R')
+        if re.search(r'static void write\(', body):
+            access.add('W')
+        if re.search(r'static void set_bits\(', body):
+            access.add('B')
+        csrs.regs[name] = {'access': access, 'ctype': ctype}
+    data_re = re.compile(r'namespace (\w+)_data \{\n(.*?)\n        \} /\* \1_data \*/', re.S)
+    field_re = re.compile(r'struct (\w+) \{\n(.*?)\n            \};', re.S)
+    value_re = re.compile(r'static constexpr \w+ (\w+) *= (.*);')
+    with_fields = set()
+    for reg, body in data_re.findall(text):
+        with_fields.add(reg)
+        for field, fbody in field_re.findall(body):
+            csrs.fields[(reg, field)] = dict(value_re.findall(fbody))
+    # Only registers with fields get set/clear ops in C and Rust,
+    # even if none of the fields are used for this XLEN.
+    for name, data in csrs.regs.items():
+        if name not in with_fields:
+            data['access'].discard('B')
+    return csrs
+
+
+def parse_rs(text):
+    csrs = CsrSet('Rust')
+    for name, _ in HEADER_RE.findall(text):
+        # Registers with fields have a second header above the constants.
+        if name in csrs.regs:
+            continue
+        access = set()
+        ctype = None
+        m = re.search(r'macro_rules! csr_read_%s \{\n.*?let tmp_value: (\w+);' % name, text, re.S)
+        if m:
+            access.add('R')
+            ctype = m.group(1)
+        m = re.search(r'macro_rules! csr_read_write_%s \{\n.*?let tmp_value: (\w+);' % name, text, re.S)
+        if re.search(r'macro_rules! csr_write_%s \{' % name, text):
+            access.add('W')
+            ctype = ctype or m.group(1)
+        if re.search(r'macro_rules! csr_set_bits_%s \{' % name, text):
+            access.add('B')
+        csrs.regs[name] = {'access': access, 'ctype': ctype}
+    for name, expr in re.findall(r'^pub const (\w+):\w+ *= (.*);$', text, re.M):
+        split = _split_const(name, csrs.regs)
+        if split:
+            reg, field, suffix = split
+            csrs.fields.setdefault((reg, field), {})[suffix] = expr
+    # The immediate macros embed a copy of the field mask.
+    imm_re = re.compile(r'\( (\w+)_BIT_MASK\) => \{ \$crate::csr_(?:set|clr)_bits_imm_\w+!\((\w+)\)\};')
+    for name, value in imm_re.findall(text):
+        const = re.search(r'^pub const %s_BIT_MASK:\w+ *= (.*);$' % name, text, re.M)
+        if not const or _eval_expr(const.group(1), 32) != _eval_expr(value, 32):
+            csrs.errors.append('%s_BIT_MASK: immediate macro uses %s' % (name, value))
+    return csrs
+
+
+def check_self(csrs, xlen, fields):
+    """ Check the mask values agree with the offset and width in one file.
+    """
+    errors = []
+    for (reg, field), v in sorted(fields.items()):
+        if set(v) != {'BIT_OFFSET', 'BIT_WIDTH', 'BIT_MASK', 'ALL_SET_MASK'}:
+            errors.append('%s.%s: incomplete field definition' % (reg, field))
+            continue
+        all_set = (1 << v['BIT_WIDTH']) - 1
+        mask = (all_set << v['BIT_OFFSET']) & ((1 << xlen) - 1)
+        if v['ALL_SET_MASK'] != all_set:
+            errors.append('%s.%s: ALL_SET_MASK 0x%x != 0x%x' % (reg, field, v['ALL_SET_MASK'], all_set))
+        if v['BIT_MASK'] != mask:
+            errors.append('%s.%s: BIT_MASK 0x%x != 0x%x' % (reg, field, v['BIT_MASK'], mask))
+    return ['%s RV%d %s' % (csrs.lang, xlen, e) for e in errors]
+
+
+def compare(ref, ref_data, other, other_data, xlen):
+    errors = []
+    prefix = '%s vs %s RV%d ' % (ref.lang, other.lang, xlen)
+    ref_regs, ref_fields = ref_data
+    other_regs, other_fields = other_data
+    for name in sorted(set(ref_regs) | set(other_regs)):
+        if name not in other_regs:
+            errors.append(prefix + '%s: missing from %s' % (name, other.lang))
+        elif name not in ref_regs:
+            errors.append(prefix + '%s: missing from %s' % (name, ref.lang))
+        elif ref_regs[name] != other_regs[name]:
+            errors.append(prefix + '%s: access/width %s != %s' % (name, ref_regs[name], other_regs[name]))
+    for key in sorted(set(ref_fields) | set(other_fields)):
+        name = '%s.%s' % key
+        if key not in other_fields:
+            errors.append(prefix + '%s: missing from %s' % (name, other.lang))
+        elif key not in ref_fields:
+            errors.append(prefix + '%s: missing from %s' % (name, ref.lang))
+        else:
+            for suffix, value in sorted(ref_fields[key].items()):
+                if other_fields[key].get(suffix) != value:
+                    errors.append(prefix + '%s %s: 0x%x != %s' % (
+                        name, suffix, value, hex(other_fields[key].get(suffix, -1))))
+    return errors
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__)
+    parser.add_argument('--c', default='include/riscv-csr.h')
+    parser.add_argument('--hpp', default='include/riscv-csr.hpp')
+    parser.add_argument('--rs', default='rs/riscv_csr_macros/src/riscv_csr_macros.rs')
+    args = parser.parse_args()
+
+    c_text = open(args.c).read()
+    hpp_text = open(args.hpp).read()
+    rs_text = open(args.rs).read()
+
+    errors = []
+    for xlen in XLENS:
+        c = parse_c(_select_xlen(c_text, xlen))
+        hpp = parse_hpp(_select_xlen(hpp_text, xlen))
+        rs = parse_rs(_select_xlen(rs_text, xlen))
+        errors += ['RV%d %s' % (xlen, e) for e in c.errors + hpp.errors + rs.errors]
+        data = [(csrs, csrs.evaluate(xlen)) for csrs in (c, hpp, rs)]
+        for csrs, (_, fields) in data:
+            errors += check_self(csrs, xlen, fields)
+        ref, ref_data = data[0]
+        for other, other_data in data[1:]:
+            errors += compare(ref, ref_data, other, other_data, xlen)
+        print('RV%d: %d registers, %d fields' % (xlen, len(c.regs), len(c.fields)))
+
+    for e in errors:
+        print(e)
+    print('%d mismatches' % len(errors))
+    return 1 if errors else 0
+
+
+if __name__ == '__main__':
+    sys.exit(main())
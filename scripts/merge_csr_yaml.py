@@ -0,0 +1,54 @@
+#!/usr/bin/env python3
+# Merge CSR definition overlays onto the upstream csr.yaml.
+#
+# The overlays in data/csr/ add registers and fields that are not yet in
+# extern/riscv-isa-data/csr.yaml. They are applied in the order given:
+#
+# - Mappings are merged recursively, other values replace the base value.
+# - New keys are added at the end, so new registers and fields are
+#   generated after the upstream ones, in the order of the overlays.
+#
+# Usage:
+#   scripts/merge_csr_yaml.py BASE.yaml [OVERLAY.yaml ...] -o OUT.yaml
+
+import argparse
+import sys
+
+import yaml
+
+
+def merge(base, overlay):
+    """ Merge the `overlay` mapping onto `base`, returns a new mapping.
+    """
+    result = dict(base)
+    for key, value in overlay.items():
+        if isinstance(result.get(key), dict) and isinstance(value, dict):
+            result[key] = merge(result[key], value)
+        else:
+            result[key] = value
+    return result
+
+
+def main():
+    parser = argparse.ArgumentParser(
+        description="Merge CSR definition overlays onto csr.yaml.")
+    parser.add_argument('base')
+    parser.add_argument('overlays', nargs='*')
+    parser.add_argument('-o', '--output', required=True)
+    args = parser.parse_args()
+
+    with open(args.base) as f:
+        data = yaml.safe_load(f)
+    for name in args.overlays:
+        with open(name) as f:
+            overlay = yaml.safe_load(f)
+        if overlay:
+            data = merge(data, overlay)
+
+    with open(args.output, 'w') as f:
+        yaml.safe_dump(data, f, sort_keys=False)
+    return 0
+
+
+if __name__ == '__main__':
+    sys.exit(main())
@@ -119,12 +119,20 @@ static inline {{ctype_arg}} csr_read_clr_bits_{{reg_name}}({{ctype_arg}} mask) {
 
             {%- endif %}
             {%-for field_name,field_data in reg_data.fields.items() %}
-            {%- set bit_width = field_data|csr_bit_width %}
-            {%- set bit_offset = field_data|csr_bit_offset %}
+            {%- for variant in field_data|csr_field_variants %}
+            {%- set bit_width = variant|csr_bit_width %}
+            {%- set bit_offset = variant|csr_bit_offset %}
+            {%- if variant.xlen %}
+#if {{variant|csr_xlen_cfg}}
+            {%- endif %}
 #define {{reg_name|upper}}_{{field_name|upper}}_BIT_OFFSET   {{bit_offset}}
 #define {{reg_name|upper}}_{{field_name|upper}}_BIT_WIDTH    {{bit_width}}
 #define {{reg_name|upper}}_{{field_name|upper}}_BIT_MASK     {{bit_offset|csr_format_mask(bit_width)}}
 #define {{reg_name|upper}}_{{field_name|upper}}_ALL_SET_MASK {{0|csr_format_mask(bit_width)}}
+            {%- if variant.xlen %}
+#endif
+            {%- endif %}
+            {%- endfor%}
             {%- endfor%}
         {%-endif%}
     {%- endif%}
@@ -387,8 +387,12 @@ namespace riscv {
         /** Parameter data for fields in {{reg_name}} */
         namespace {{reg_name}}_data {
             {%-for field_name,field_data in reg_data.fields.items() %}
-            {%- set bit_width = field_data|csr_bit_width %}
-            {%- set bit_offset = field_data|csr_bit_offset %}
+            {%- for variant in field_data|csr_field_variants %}
+            {%- set bit_width = variant|csr_bit_width %}
+            {%- set bit_offset = variant|csr_bit_offset %}
+            {%- if variant.xlen %}
+#if {{variant|csr_xlen_cfg}}
+            {%- endif %}
             /** Parameter data for {{field_name}} */
             struct {{field_name}} {
                 using datatype = {{field_data|csr_ctype}};
@@ -397,6 +401,10 @@ namespace riscv {
                 static constexpr {{ctype_reg}} BIT_MASK   = {{bit_offset|csr_format_mask(bit_width)}};
                 static constexpr {{ctype_reg}} ALL_SET_MASK = {{0|csr_format_mask(bit_width)}};
             };
+            {%- if variant.xlen %}
+#endif
+            {%- endif %}
+            {%- endfor%}
             {%- endfor%}
         } /* {{reg_name}}_data */
         {%-endif%}
@@ -427,7 +435,13 @@ namespace riscv {
             public:
               {%-for field_name,field_data in reg_data.fields.items() %}
                 {%- set field_template_class = "OPS, riscv::csr::" + reg_name + "_data::" + field_name %}
+                {%- if field_data.xlen %}
+#if {{field_data|csr_xlen_cfg}}
+                {%- endif %}
                 {{priv}}_field<{{field_template_class}}> {{field_name}};
+                {%- if field_data.xlen %}
+#endif
+                {%- endif %}
             {%- endfor %}
             {%- endif %}
         };
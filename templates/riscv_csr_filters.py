@@ -33,6 +33,8 @@ def csr_bit_width_rs(field_data):
             lsb = _csr_bit_to_int(field_data["bits"][1])
             return (msb - lsb) + 1
         except:
+            if field_data["bits"][1] == 0:
+                return "(" + _xlen_replace(field_data["bits"][0]) + ") + 1"
             return "(" + _xlen_replace(field_data["bits"][0]) + ")-(" + _xlen_replace(field_data["bits"][1]) + ") + 1"
 
 def csr_field_imm_valid(field_data):
@@ -56,7 +58,8 @@ def csr_format_mask(bit_offset, bit_width):
         if isinstance(bit_width, int) :
             mask = "0x%xUL" % (((1<<bit_width)-1))
         else :
-            mask = "(1UL<<(" + str(bit_width) + "-1))"
+            # Shift down an all ones value, avoids overflow if width == XLEN
+            mask = "(~0UL >> (__riscv_xlen-" + str(bit_width) + "))"
         return "(" + mask + " << (" + str(bit_offset) + "))"
 
 def csr_format_mask_rs(bit_offset, bit_width):
@@ -69,7 +72,11 @@ def csr_format_mask_rs(bit_offset, bit_width):
         if isinstance(bit_width, int) :
             mask = "0x%x" % (((1<<bit_width)-1))
         else :
-            mask = "(1<<(" + str(bit_width) + "-1))"
+            # Shift down an all ones value, avoids overflow if width == XLEN
+            mask = "UintXlen::MAX >> (__riscv_xlen-(" + str(bit_width) + "))"
+            if bit_offset == 0:
+                return mask
+            mask = "(" + mask + ")"
         return  mask + " << (" + str(bit_offset) + ")"
 
 
@@ -85,6 +92,36 @@ def csr_bit_offset_rs(field_data):
     except:
         return  _xlen_replace(field_data["bits"][-1]) 
         
+def csr_field_variants(field_data):
+    """ Field definitions for each XLEN.
+    A field with a different position for RV32 and RV64 has 'rv32' and 'rv64'
+    entries in place of 'bits', otherwise the field is used as is.
+    """
+    variants = []
+    for xlen in (32, 64):
+        key = "rv" + str(xlen)
+        if key in field_data:
+            variant = dict(field_data[key])
+            variant["xlen"] = xlen
+            variants.append(variant)
+    if variants:
+        return variants
+    return [field_data]
+
+def csr_xlen_cfg(field_data):
+    """ Preprocessor condition for fields that only exist for one XLEN.
+    """
+    if "xlen" in field_data:
+        return "__riscv_xlen==" + str(field_data["xlen"])
+    return ""
+
+def csr_xlen_cfg_rs(field_data):
+    """ Attribute prefix for fields that only exist for one XLEN.
+    """
+    if "xlen" in field_data:
+        return '#[cfg(target_pointer_width = "' + str(field_data["xlen"]) + '")]\n'
+    return ""
+
 def arg_ctype(field_data):
     """ The type passed to, or returned from, the wrapper function.
     """
@@ -132,3 +169,6 @@ def setup(env):
     env.filters['csr_ctype_rs'] = csr_ctype_rs
     env.filters['arg_ctype'] = arg_ctype
     env.filters['csr_field_imm_valid'] = csr_field_imm_valid
+    env.filters['csr_xlen_cfg'] = csr_xlen_cfg
+    env.filters['csr_field_variants'] = csr_field_variants
+    env.filters['csr_xlen_cfg_rs'] = csr_xlen_cfg_rs
@@ -6,7 +6,7 @@
 
 */
 
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
 #[cfg(target_pointer_width = "32")]
 pub type UintXlen = u32;
@@ -29,6 +29,38 @@ const __riscv_xlen : isize = 32;
 #[cfg(target_pointer_width = "64")]
 const __riscv_xlen : isize = 64;
 
+/* Typed access built on the register macros below.
+   Code that executes CSR or other RISC-V instructions is only built for
+   RISC-V targets, the rest can be unit tested on the host. */
+pub mod status;
+pub mod fenv;
+pub mod context;
+pub mod vector;
+pub mod atp;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod probe;
+pub mod isa;
+pub mod ident;
+pub mod perf;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod measure;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod profile;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod timer;
+pub mod clock;
+pub mod mtimer;
+pub mod trap;
+pub mod deleg;
+pub mod insn;
+pub mod misaligned;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod mprv;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod uaccess;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub mod hyp;
+
 
 {%- for reg_name,reg_data in data.regs.items() %}
     {%- if not reg_data.mmio %}
@@ -38,6 +70,10 @@ const __riscv_xlen : isize = 64;
  */
 {%- set ctype_reg = reg_data|csr_ctype_rs %}
 {%- set ctype_arg = reg_data|arg_ctype %}
+            {%- if reg_data.address is defined %}
+/* {{reg_name}}: CSR address, as used in the csr field of the CSR instructions. */
+pub const {{reg_name|upper}}_CSR_ADDRESS: u16 = {{'0x%03x'|format(reg_data.address)}};
+            {%- endif %}
 /* {{reg_name}}: CSR Whole register access */
             {%- if "R" in reg_data.priv %}
 /* {{reg_name}}: CSR read.
@@ -66,9 +102,12 @@ e.g.
 #[macro_export]
 macro_rules! csr_write_{{reg_name}} {
     ( $x:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrw    {{reg_name}}, {0}" , in(reg) $x);
+        {
+            let value = $x;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrw    {{reg_name}}, {0}" , in(reg) value);
+            }
         }
     );
 }
@@ -80,10 +119,11 @@ e.g.
 macro_rules! csr_read_write_{{reg_name}} {
     ( $x:expr ) => (
         {
+            let value = $x;
             let tmp_value: {{ctype_reg}};
             unsafe {
                 use core::arch::asm;
-                asm!("csrrw    {0}, {{reg_name}}, {1}" , out(reg) tmp_value, in(reg) $x);
+                asm!("csrrw    {0}, {{reg_name}}, {1}" , out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -102,9 +142,12 @@ csr_set_bits_{{reg_name}}!(0x0F0F0F);
 #[macro_export]
 macro_rules! csr_set_bits_{{reg_name}} {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrs    zero, {{reg_name}}, {{'{'}}{{0}}{{'}'}}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrs    zero, {{reg_name}}, {{'{'}}{{0}}{{'}'}}", in(reg) value);
+            }
         }
     );
 }
@@ -115,9 +158,12 @@ csr_clr_bits_{{reg_name}}!(0x0F0F0F)
 #[macro_export]
 macro_rules! csr_clr_bits_{{reg_name}} {
     ( $mask:expr ) => (
-        unsafe {
-            use core::arch::asm;
-            asm!("csrrc    zero, {{reg_name}}, {{'{'}}{{0}}{{'}'}}", in(reg) $mask);
+        {
+            let value = $mask;
+            unsafe {
+                use core::arch::asm;
+                asm!("csrrc    zero, {{reg_name}}, {{'{'}}{{0}}{{'}'}}", in(reg) value);
+            }
         }
     );
 }
@@ -129,10 +175,11 @@ let org_value_ = csr_read_set_bits_{{reg_name}}!(0x0F0F0F)
 macro_rules! csr_read_set_bits_{{reg_name}} {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: {{ctype_reg}};
             unsafe {
                 use core::arch::asm;
-                asm!("csrrs    {{'{'}}{{0}}{{'}'}}, {{reg_name}}, {{'{'}}{{1}}{{'}'}}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrs    {{'{'}}{{0}}{{'}'}}, {{reg_name}}, {{'{'}}{{1}}{{'}'}}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -146,10 +193,11 @@ let org_value_ = csr_read_clr_bits_{{reg_name}}!(0x0F0F0F)
 macro_rules! csr_read_clr_bits_{{reg_name}} {
     ( $mask:expr ) => (
         {
+            let value = $mask;
             let tmp_value: {{ctype_reg}};
             unsafe {
                 use core::arch::asm;
-                asm!("csrrc    {{'{'}}{{0}}{{'}'}}, {{reg_name}}, {{'{'}}{{1}}{{'}'}}", out(reg) tmp_value, in(reg) $mask);
+                asm!("csrrc    {{'{'}}{{0}}{{'}'}}, {{reg_name}}, {{'{'}}{{1}}{{'}'}}", out(reg) tmp_value, in(reg) value);
             }
             tmp_value
         }
@@ -162,7 +210,7 @@ csr_write_imm_{{reg_name}}!(0x1F);
  */
 #[macro_export]
 macro_rules! csr_write_imm_{{reg_name}} {
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrwi    zero, {{reg_name}}, ", stringify!($value)));
@@ -181,7 +229,7 @@ macro_rules! csr_set_bits_imm_{{reg_name}} {
             {%- if field_data|csr_field_imm_valid %}
                 {%- set bit_width = field_data|csr_bit_width_rs %}
                 {%- set bit_offset = field_data|csr_bit_offset_rs %}
-    ( {{reg_name|upper}}_{{field_name|upper}}_BIT_MASK) => { csr_set_bits_imm_{{reg_name}}!({{bit_offset|csr_format_mask_rs(bit_width)}})};
+    ( {{reg_name|upper}}_{{field_name|upper}}_BIT_MASK) => { $crate::csr_set_bits_imm_{{reg_name}}!({{bit_offset|csr_format_mask_rs(bit_width)}})};
             {%- endif %}
         {%- endfor %}
     {%- endif %}
@@ -203,11 +251,11 @@ macro_rules! csr_clr_bits_imm_{{reg_name}} {
             {%- if field_data|csr_field_imm_valid %}
                 {%- set bit_width = field_data|csr_bit_width_rs %}
                 {%- set bit_offset = field_data|csr_bit_offset_rs %}
-    ( {{reg_name|upper}}_{{field_name|upper}}_BIT_MASK) => { csr_clr_bits_imm_{{reg_name}}!({{bit_offset|csr_format_mask_rs(bit_width)}})};
+    ( {{reg_name|upper}}_{{field_name|upper}}_BIT_MASK) => { $crate::csr_clr_bits_imm_{{reg_name}}!({{bit_offset|csr_format_mask_rs(bit_width)}})};
             {%- endif %}
         {%- endfor %}
     {%- endif %}
-    ( $value:ident ) => (
+    ( $value:literal ) => (
         unsafe {
             use core::arch::asm;
             asm!(concat!("csrrci    zero, {{reg_name}}, ", stringify!($value)));
@@ -216,6 +264,75 @@ macro_rules! csr_clr_bits_imm_{{reg_name}} {
 }
                 {%- endif %}
         {%-endif%}
+    {%- else %}
+
+/*******************************************
+ * {{reg_name}} (memory mapped) - {{reg_data.desc}}
+ */
+            {%- if reg_data.offset is defined %}
+/* {{reg_name}}: Offset from the CLINT base address. */
+pub const {{reg_name|upper}}_MMIO_OFFSET: usize = {{'0x%x'|format(reg_data.offset)}};
+            {%- endif %}
+            {%- if reg_data.stride is defined %}
+/* {{reg_name}}: Address step between harts. */
+pub const {{reg_name|upper}}_MMIO_STRIDE: usize = {{reg_data.stride}};
+            {%- endif %}
+/* {{reg_name}}: 64 bit volatile read.
+   On RV32 the high word is re-read to detect a carry from the low word.
+e.g.
+    let _v = mmio_read_{{reg_name}}!(0x2000000 + {{reg_name|upper}}_MMIO_OFFSET);
+ */
+#[macro_export]
+macro_rules! mmio_read_{{reg_name}} {
+    ( $addr:expr ) => (
+        {
+            let addr: usize = $addr;
+            unsafe {
+                #[cfg(target_pointer_width = "32")]
+                let value = loop {
+                    let hi = core::ptr::read_volatile((addr + 4) as *const u32);
+                    let lo = core::ptr::read_volatile(addr as *const u32);
+                    if hi == core::ptr::read_volatile((addr + 4) as *const u32) {
+                        break ((hi as u64) << 32) | lo as u64;
+                    }
+                };
+                #[cfg(target_pointer_width = "64")]
+                let value = core::ptr::read_volatile(addr as *const u64);
+                value
+            }
+        }
+    );
+}
+/* {{reg_name}}: 64 bit volatile write.
+            {%- if reg_data.counter %}
+   On RV32 the low word is first cleared, so a tick between the word
+   writes does not carry into the new high word.
+            {%- else %}
+   On RV32 the low word is first set to all ones, so the register never
+   holds a value earlier than both the old and new value.
+            {%- endif %}
+e.g.
+    mmio_write_{{reg_name}}!(0x2000000 + {{reg_name|upper}}_MMIO_OFFSET, 0x1234567);
+ */
+#[macro_export]
+macro_rules! mmio_write_{{reg_name}} {
+    ( $addr:expr, $x:expr ) => (
+        {
+            let addr: usize = $addr;
+            let value: u64 = $x;
+            unsafe {
+                #[cfg(target_pointer_width = "32")]
+                {
+                    core::ptr::write_volatile(addr as *mut u32, {% if reg_data.counter %}0{% else %}u32::MAX{% endif %});
+                    core::ptr::write_volatile((addr + 4) as *mut u32, (value >> 32) as u32);
+                    core::ptr::write_volatile(addr as *mut u32, value as u32);
+                }
+                #[cfg(target_pointer_width = "64")]
+                core::ptr::write_volatile(addr as *mut u64, value);
+            }
+        }
+    );
+}
     {%- endif%}
 {%- endfor%}
 
@@ -226,15 +343,62 @@ macro_rules! csr_clr_bits_imm_{{reg_name}} {
 /*******************************************
  * {{reg_name}} - {{reg_data.priv}} - {{reg_data.desc}} 
  */
-{%- set ctype_reg = reg_data|csr_ctype %}
+{%- set ctype_reg = reg_data|csr_ctype_rs %}
 {%- set ctype_arg = reg_data|arg_ctype %}
             {%-for field_name,field_data in reg_data.fields.items() %}
-            {%- set bit_width = field_data|csr_bit_width_rs %}
-            {%- set bit_offset = field_data|csr_bit_offset_rs %}
-pub const {{reg_name|upper}}_{{field_name|upper}}_BIT_OFFSET:isize   = {{bit_offset}};
-pub const {{reg_name|upper}}_{{field_name|upper}}_BIT_WIDTH:isize    = {{bit_width}};
-pub const {{reg_name|upper}}_{{field_name|upper}}_BIT_MASK:UintXlen = {{bit_offset|csr_format_mask_rs(bit_width)}};
-pub const {{reg_name|upper}}_{{field_name|upper}}_ALL_SET_MASK:UintXlen = {{0|csr_format_mask_rs(bit_width)}};
+            {%- set field_const = reg_name|upper + "_" + field_name|upper %}
+            {%- set xlen_cfg = field_data|csr_xlen_cfg_rs %}
+                {%- for variant in field_data|csr_field_variants %}
+                {%- set bit_width = variant|csr_bit_width_rs %}
+                {%- set bit_offset = variant|csr_bit_offset_rs %}
+                {%- set variant_cfg = variant|csr_xlen_cfg_rs %}
+{{variant_cfg}}pub const {{field_const}}_BIT_OFFSET:isize   = {{bit_offset}};
+{{variant_cfg}}pub const {{field_const}}_BIT_WIDTH:isize    = {{bit_width}};
+{{variant_cfg}}pub const {{field_const}}_BIT_MASK:UintXlen = {{bit_offset|csr_format_mask_rs(bit_width)}};
+{{variant_cfg}}pub const {{field_const}}_ALL_SET_MASK:UintXlen = {{0|csr_format_mask_rs(bit_width)}};
+                {%- endfor %}
+                {%- if "R" in reg_data.priv %}
+/* {{reg_name}}.{{field_name}}: Field read, value is shifted down to bit 0.
+e.g.
+    let _v = csr_read_{{reg_name}}_{{field_name}}!();
+ */
+{{xlen_cfg}}#[macro_export]
+macro_rules! csr_read_{{reg_name}}_{{field_name}} {
+    ( ) => (
+        {%- if ctype_reg == "UintXlen" %}
+        ($crate::csr_read_{{reg_name}}!() & $crate::{{field_const}}_BIT_MASK) >> $crate::{{field_const}}_BIT_OFFSET
+        {%- else %}
+        (($crate::csr_read_{{reg_name}}!() as $crate::UintXlen) & $crate::{{field_const}}_BIT_MASK) >> $crate::{{field_const}}_BIT_OFFSET
+        {%- endif %}
+    );
+}
+                {%- endif %}
+                {%- if "RW" in reg_data.priv %}
+/* {{reg_name}}.{{field_name}}: Field write via read-modify-write.
+   NOTE - not atomic.
+e.g.
+    csr_write_{{reg_name}}_{{field_name}}!(0x1);
+ */
+{{xlen_cfg}}#[macro_export]
+macro_rules! csr_write_{{reg_name}}_{{field_name}} {
+    ( $x:expr ) => (
+        {
+            {%- if ctype_reg == "UintXlen" %}
+            let org_value: $crate::UintXlen = $crate::csr_read_{{reg_name}}!();
+            {%- else %}
+            let org_value = $crate::csr_read_{{reg_name}}!() as $crate::UintXlen;
+            {%- endif %}
+            let new_value = (org_value & !$crate::{{field_const}}_BIT_MASK)
+                | ((($x) << $crate::{{field_const}}_BIT_OFFSET) & $crate::{{field_const}}_BIT_MASK);
+            {%- if ctype_reg == "UintXlen" %}
+            $crate::csr_write_{{reg_name}}!(new_value);
+            {%- else %}
+            $crate::csr_write_{{reg_name}}!(new_value as {{"$crate::" + ctype_reg}});
+            {%- endif %}
+        }
+    );
+}
+                {%- endif %}
             {%- endfor%}
         {%-endif%}
     {%-endif%}